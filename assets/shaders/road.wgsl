@@ -0,0 +1,53 @@
+#import bevy_pbr::{
+    forward_io::VertexOutput,
+    mesh_view_bindings::globals,
+    pbr_functions::{apply_pbr_lighting, calculate_view, main_pass_post_lighting_processing, prepare_world_normal},
+    pbr_types::pbr_input_new,
+}
+
+struct RoadMaterialUniform {
+    base_color: vec4<f32>,
+    scroll_speed: f32,
+    tile_scale: f32,
+    scroll: f32,
+    _pad: f32,
+};
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0)
+var<uniform> road: RoadMaterialUniform;
+
+// Darkening of the seams between tiles: 1 on a seam, 0 in the middle of a tile.
+fn seam_mask(along: f32) -> f32 {
+    let cell = fract(along);
+    return 1.0 - smoothstep(0.0, 0.08, min(cell, 1.0 - cell));
+}
+
+@fragment
+fn fragment(
+    input: VertexOutput,
+    @builtin(front_facing) is_front: bool,
+) -> @location(0) vec4<f32> {
+    // u runs along the patch (its local +X, toward the village), v across the road
+    let along = input.uv.x * road.tile_scale - (road.scroll + road.scroll_speed * globals.time);
+    let across = abs(input.uv.y - 0.5);
+
+    // Busier roads wear deeper seams
+    let wear = 0.12 + 0.06 * clamp(road.scroll_speed, 0.0, 2.0);
+    let verge = smoothstep(0.38, 0.5, across) * 0.15;
+    let shade = 1.0 - seam_mask(along) * wear - verge;
+
+    var pbr = pbr_input_new();
+    pbr.material.base_color = vec4<f32>(road.base_color.rgb * shade, road.base_color.a);
+    pbr.material.perceptual_roughness = 1.0;
+    pbr.material.metallic = 0.0;
+    pbr.frag_coord = input.position;
+    pbr.world_position = input.world_position;
+    pbr.world_normal = prepare_world_normal(input.world_normal, false, is_front);
+    pbr.N = normalize(pbr.world_normal);
+    pbr.V = calculate_view(input.world_position, false);
+    pbr.is_orthographic = false;
+
+    var color = apply_pbr_lighting(pbr);
+    color = main_pass_post_lighting_processing(pbr, color);
+    return color;
+}
@@ -0,0 +1,47 @@
+#import bevy_pbr::mesh_bindings
+#import bevy_pbr::mesh_functions
+#import bevy_pbr::mesh_view_bindings
+
+struct ShieldMaterialUniform {
+    color: vec4<f32>,
+    time: f32,
+    _pad: vec3<f32>,
+};
+
+@group(1) @binding(0)
+var<uniform> shield: ShieldMaterialUniform;
+
+// Distance to the nearest hexagon edge for a point in hex-grid space (0 at the edge).
+fn hex_edge_distance(p: vec2<f32>) -> f32 {
+    let s = vec2<f32>(1.0, 1.7320508);
+    let a = p - s * floor(p / s + 0.5);
+    let b = p - s * floor((p - s * 0.5) / s + 0.5) - s * 0.5;
+    let h = select(b, a, dot(a, a) < dot(b, b));
+    let q = abs(h);
+    return 0.5 - max(dot(q, normalize(s)), q.x);
+}
+
+@fragment
+fn fragment(input: bevy_pbr::MeshFragmentInput) -> bevy_pbr::MeshFragmentOutput {
+    var pbr = bevy_pbr::pbr_input(
+        input,
+        vec3<f32>(0.0),
+        0.0,
+        0.0,
+        0.5,
+        0.0,
+        vec3<f32>(0.0),
+    );
+
+    // Wrap the hex grid around the dome using spherical coordinates
+    let dir = normalize(input.model_position);
+    let uv = vec2<f32>(atan2(dir.z, dir.x) * 3.0, asin(clamp(dir.y, -1.0, 1.0)) * 3.0);
+    let edge = 1.0 - smoothstep(0.0, 0.06, hex_edge_distance(uv));
+
+    let pulse = 0.75 + 0.25 * sin(shield.time * 3.0);
+    let mask = clamp(edge * pulse + 0.12, 0.0, 1.0);
+    pbr.emissive = shield.color.rgb * mask * 1.5;
+    pbr.alpha = shield.color.a * mask;
+
+    return bevy_pbr::pbr_fragment(pbr);
+}
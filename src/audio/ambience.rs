@@ -0,0 +1,120 @@
+use super::{AmbienceChannel, AudioAssets};
+use crate::components::Enemy;
+use bevy::prelude::*;
+use bevy_kira_audio::AudioSource as KiraAudioSource;
+use bevy_kira_audio::prelude::*;
+use std::time::Duration;
+
+/// Seconds the outgoing and incoming ambience loops overlap on a tier change.
+const AMBIENCE_CROSSFADE_SECS: f32 = 1.0;
+/// Seconds a new tier has to hold before the ambience follows it, so counts hovering around a
+/// tier boundary don't flip the loop back and forth.
+const AMBIENCE_SWITCH_DELAY_SECS: f32 = 2.0;
+
+/// How busy the battlefield is, driving the ambience loop.
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct CombatIntensity {
+    pub current_enemy_count: u32,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AmbienceTier {
+    /// No enemies alive.
+    #[default]
+    Peaceful,
+    /// 1 to 5 enemies.
+    Light,
+    /// 6 to 20 enemies.
+    Heavy,
+    /// More than 20 enemies.
+    Intense,
+}
+
+impl AmbienceTier {
+    pub fn from_enemy_count(count: u32) -> Self {
+        match count {
+            0 => AmbienceTier::Peaceful,
+            1..=5 => AmbienceTier::Light,
+            6..=20 => AmbienceTier::Heavy,
+            _ => AmbienceTier::Intense,
+        }
+    }
+
+    fn handle(self, assets: &AudioAssets) -> Handle<KiraAudioSource> {
+        match self {
+            AmbienceTier::Peaceful => assets.ambience_peaceful.clone(),
+            AmbienceTier::Light => assets.ambience_light.clone(),
+            AmbienceTier::Heavy => assets.ambience_heavy.clone(),
+            AmbienceTier::Intense => assets.ambience_intense.clone(),
+        }
+    }
+}
+
+/// Loop playing on the ambience channel and the tier waiting to replace it.
+#[derive(Default)]
+pub(super) struct AmbienceState {
+    /// Tier of the playing loop; `None` until the first one starts.
+    current: Option<AmbienceTier>,
+    instance: Option<Handle<AudioInstance>>,
+    pending: AmbienceTier,
+    /// How long `pending` has been the tier for the enemy count.
+    pending_secs: f32,
+}
+
+pub(super) fn update_combat_intensity(
+    enemies: Query<(), With<Enemy>>,
+    mut intensity: ResMut<CombatIntensity>,
+) {
+    let count = enemies.iter().count() as u32;
+    if intensity.current_enemy_count != count {
+        intensity.current_enemy_count = count;
+    }
+}
+
+/// Loops the ambience for the current enemy count, crossfading to another tier once it has held
+/// for `AMBIENCE_SWITCH_DELAY_SECS`. Volume comes from the ambience channel (master * ambience).
+pub(super) fn manage_ambience_system(
+    time: Res<Time<Real>>,
+    intensity: Res<CombatIntensity>,
+    assets: Res<AudioAssets>,
+    ambience: Res<AudioChannel<AmbienceChannel>>,
+    mut instances: ResMut<Assets<AudioInstance>>,
+    mut state: Local<AmbienceState>,
+) {
+    let tier = AmbienceTier::from_enemy_count(intensity.current_enemy_count);
+    if tier != state.pending {
+        state.pending = tier;
+        state.pending_secs = 0.0;
+    } else {
+        state.pending_secs += time.delta_secs();
+    }
+
+    // The first loop starts right away; later ones wait out the debounce
+    let switch = match state.current {
+        None => true,
+        Some(current) => {
+            current != state.pending && state.pending_secs > AMBIENCE_SWITCH_DELAY_SECS
+        }
+    };
+    if !switch {
+        return;
+    }
+
+    let fade = AudioTween::linear(Duration::from_secs_f32(AMBIENCE_CROSSFADE_SECS));
+    if let Some(instance) = state
+        .instance
+        .take()
+        .and_then(|handle| instances.get_mut(&handle))
+    {
+        instance.stop(fade.clone());
+    }
+    let tier = state.pending;
+    state.instance = Some(
+        ambience
+            .play(tier.handle(&assets))
+            .looped()
+            .fade_in(fade)
+            .handle(),
+    );
+    state.current = Some(tier);
+}
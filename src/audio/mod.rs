@@ -1,6 +1,16 @@
+use crate::build::market::MarketTransactionEvent;
+use crate::components::{GameState, HarvestableKind, Village};
+use crate::constants::{
+    C_AUDIO_DOPPLER_FACTOR, C_FOOTSTEP_INTERVAL_SECS, C_VOLUME_LERP_SPEED, Tunables,
+};
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy_kira_audio::AudioSource as KiraAudioSource;
 use bevy_kira_audio::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+pub mod ambience;
+pub mod music;
 pub mod sfx;
 pub mod util;
 
@@ -15,10 +25,13 @@ pub struct MusicChannel;
 pub struct AmbienceChannel;
 
 // Global volume controls per category
-#[derive(Resource, Clone, Copy)]
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AudioVolumes {
     pub master: f32,
     pub sfx: f32,
+    pub music: f32,
+    pub ambience: f32,
 }
 
 impl Default for AudioVolumes {
@@ -26,15 +39,84 @@ impl Default for AudioVolumes {
         Self {
             master: 1.0,
             sfx: 1.0,
+            music: 1.0,
+            ambience: 1.0,
         }
     }
 }
 
+// Volumes actually applied to playback. Settings write `target` (or `AudioVolumes`, which is
+// mirrored into `target`) and `current` follows at `Tunables.volume_lerp_speed`, so muting
+// fades out instead of cutting off abruptly.
+#[derive(Resource, Default, Clone, Copy)]
+pub struct AudioVolumesSmoother {
+    pub target: AudioVolumes,
+    pub current: AudioVolumes,
+}
+
+fn smooth_audio_volumes(
+    time: Res<Time<Real>>,
+    volumes: Res<AudioVolumes>,
+    tunables: Option<Res<Tunables>>,
+    mut smoother: ResMut<AudioVolumesSmoother>,
+) {
+    if volumes.is_changed() {
+        smoother.target = *volumes;
+    }
+    let speed = tunables.map_or(C_VOLUME_LERP_SPEED, |t| t.volume_lerp_speed);
+    let step = speed * time.delta_secs();
+    let target = smoother.target;
+    let current = &mut smoother.current;
+    current.master = move_toward(current.master, target.master, step);
+    current.sfx = move_toward(current.sfx, target.sfx, step);
+    current.music = move_toward(current.music, target.music, step);
+    current.ambience = move_toward(current.ambience, target.ambience, step);
+}
+
+/// Pushes the smoothed ambience volume onto its channel. SFX volumes are applied per sound
+/// (they are also spatialized) and `music::music_system` owns the music channel's volume.
+fn apply_ambience_channel_volume(
+    volumes: Res<AudioVolumesSmoother>,
+    ambience: Res<AudioChannel<AmbienceChannel>>,
+    mut applied: Local<Option<f32>>,
+) {
+    let volume = (volumes.current.master * volumes.current.ambience).clamp(0.0, 1.0);
+    if *applied != Some(volume) {
+        ambience.set_volume(volume);
+        *applied = Some(volume);
+    }
+}
+
+/// Speeds up or slows down sound effects with the virtual clock. Re-applied every frame while not
+/// at 1x, because a channel's playback rate only reaches the sounds already playing on it.
+fn apply_sfx_playback_rate(
+    time: Res<Time<Virtual>>,
+    sfx: Res<AudioChannel<SfxChannel>>,
+    mut applied: Local<Option<f32>>,
+) {
+    let rate = time.relative_speed();
+    if *applied != Some(rate) || rate != 1.0 {
+        sfx.set_playback_rate(rate as f64);
+        *applied = Some(rate);
+    }
+}
+
+fn move_toward(current: f32, target: f32, max_delta: f32) -> f32 {
+    if (target - current).abs() <= max_delta {
+        target
+    } else {
+        current + (target - current).signum() * max_delta
+    }
+}
+
 // Events/messages that other gameplay systems can emit
 #[derive(Debug, Clone, Copy)]
 pub enum TowerShotKind {
     Bow,
     Crossbow,
+    Lightning,
+    Catapult,
+    Cannon,
 }
 
 #[derive(Event, Message, Debug, Clone, Copy)]
@@ -43,16 +125,64 @@ pub struct TowerShotEvent {
     pub position: Vec3,
 }
 
+/// Ground under the player, selecting which footstep sample plays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SurfaceKind {
+    Grass,
+    Road,
+}
+
 #[derive(Event, Message, Debug, Clone, Copy)]
 pub struct PlayerFootstepEvent {
     pub position: Vec3,
+    pub surface: SurfaceKind,
+}
+
+// Both wave start events carry the village health snapshot taken as the wave began
+#[derive(Event, Message, Debug, Clone, Copy)]
+pub struct WaveStartedEvent {
+    #[allow(dead_code)]
+    pub village_health: u32,
 }
 
 #[derive(Event, Message, Debug, Clone, Copy)]
-pub struct WaveStartedEvent;
+pub struct BossWaveStartedEvent {
+    #[allow(dead_code)]
+    pub village_health: u32,
+}
+
+// Last enemy of a wave is gone and the intermission begins
+#[derive(Event, Message, Debug, Clone, Copy)]
+pub struct WaveCompletedEvent;
+
+// The day/night clock crossed into night (`night`) or back to day
+#[derive(Event, Message, Debug, Clone, Copy)]
+pub struct DayNightChangedEvent {
+    pub night: bool,
+}
+
+// One second of the pre-game countdown passed; `remaining` whole seconds are left to show
+#[derive(Event, Message, Debug, Clone, Copy)]
+pub struct CountdownTickEvent {
+    #[allow(dead_code)]
+    pub remaining: u32,
+}
+
+// The run ended: the village fell, or `victory` when `max_waves` were survived
+#[derive(Event, Message, Debug, Clone, Copy)]
+pub struct GameOverEvent {
+    pub victory: bool,
+}
+
+// Five or more Minions bunched up into a swarm around `position`
+#[derive(Event, Message, Debug, Clone, Copy)]
+pub struct SwarmBurstEvent {
+    pub position: Vec3,
+}
 
+// Storm lightning strike; played unspatialized so it reads as a world-wide event
 #[derive(Event, Message, Debug, Clone, Copy)]
-pub struct BossWaveStartedEvent;
+pub struct LightningStrikeEvent;
 
 #[derive(Debug, Clone, Copy)]
 pub enum BuildingActionKind {
@@ -68,6 +198,12 @@ pub struct BuildingActionEvent {
     pub position: Vec3,
 }
 
+#[derive(Event, Message, Debug, Clone, Copy)]
+pub struct ResourceHarvestedEvent {
+    pub kind: HarvestableKind,
+    pub position: Vec3,
+}
+
 // Centralized handles to audio assets we care about
 #[derive(Resource, Default)]
 pub struct AudioAssets {
@@ -76,10 +212,25 @@ pub struct AudioAssets {
     pub wave_start: Handle<KiraAudioSource>,
     pub wave_start_boss: Handle<KiraAudioSource>,
     pub player_footstep_01: Handle<KiraAudioSource>,
+    pub player_footstep_road_01: Handle<KiraAudioSource>,
     pub tower_place: Handle<KiraAudioSource>,
     pub tower_place_invalid: Handle<KiraAudioSource>,
     pub tower_upgrade: Handle<KiraAudioSource>,
     pub tower_sell: Handle<KiraAudioSource>,
+    pub wood_collect_complete: Handle<KiraAudioSource>,
+    pub rock_collect_complete: Handle<KiraAudioSource>,
+    pub lightning_strike: Handle<KiraAudioSource>,
+    pub wave_complete: Handle<KiraAudioSource>,
+    pub danger_heartbeat: Handle<KiraAudioSource>,
+    pub market_trade: Handle<KiraAudioSource>,
+    pub nightfall: Handle<KiraAudioSource>,
+    pub dawn: Handle<KiraAudioSource>,
+    pub minion_swarm: Handle<KiraAudioSource>,
+    pub ambience_peaceful: Handle<KiraAudioSource>,
+    pub ambience_light: Handle<KiraAudioSource>,
+    pub ambience_heavy: Handle<KiraAudioSource>,
+    pub ambience_intense: Handle<KiraAudioSource>,
+    pub countdown_beep: Handle<KiraAudioSource>,
 }
 
 // Marker placed on the active camera used as audio listener
@@ -98,22 +249,69 @@ impl Plugin for GameAudioPlugin {
             .add_audio_channel::<AmbienceChannel>()
             // Volumes and assets
             .init_resource::<AudioVolumes>()
+            .init_resource::<AudioVolumesSmoother>()
             .init_resource::<AudioAssets>()
+            .init_resource::<music::MusicPlaylist>()
+            .init_resource::<music::MusicCrossfadeState>()
+            .init_resource::<ambience::CombatIntensity>()
             .init_resource::<SpatialAudioParams>()
             .init_resource::<ListenerTransform>()
+            .init_resource::<PendingEchoes>()
             // Messages for audio-triggering events
             .add_message::<TowerShotEvent>()
             .add_message::<PlayerFootstepEvent>()
             .add_message::<WaveStartedEvent>()
             .add_message::<BossWaveStartedEvent>()
+            .add_message::<WaveCompletedEvent>()
             .add_message::<BuildingActionEvent>()
+            .add_message::<ResourceHarvestedEvent>()
+            .add_message::<LightningStrikeEvent>()
+            .add_message::<DayNightChangedEvent>()
+            .add_message::<SwarmBurstEvent>()
+            .add_message::<CountdownTickEvent>()
+            .add_message::<GameOverEvent>()
+            // Fade effective volumes toward the configured ones
+            .add_systems(Update, smooth_audio_volumes)
+            .add_systems(
+                Update,
+                apply_ambience_channel_volume.after(smooth_audio_volumes),
+            )
+            .add_systems(Update, apply_sfx_playback_rate)
             // Keep listener transform updated each frame
             .add_systems(Update, update_listener_transform)
+            // Play delayed reverb copies once their delay has elapsed
+            .add_systems(Update, play_pending_echoes)
+            // Thunder for storm lightning strikes
+            .add_systems(Update, play_lightning_strike_sfx)
+            .add_systems(Update, play_wave_complete_sfx)
+            .add_systems(Update, play_player_footstep_sfx)
+            .add_systems(Update, play_danger_heartbeat)
+            .add_systems(Update, play_market_trade_sfx)
+            .add_systems(Update, play_day_night_sfx)
+            .add_systems(Update, play_swarm_burst_sfx)
+            .add_systems(Update, play_countdown_beep_sfx)
+            .add_systems(Update, play_resource_harvested_sfx)
+            // Background music follows the wave phase
+            .add_systems(Update, music::music_system)
+            // Everything but the music holds still on the game over screen
+            .add_systems(OnEnter(GameState::GameOver), pause_game_audio)
+            .add_systems(
+                OnExit(GameState::GameOver),
+                (resume_game_audio, music::end_defeat_music),
+            )
+            // Ambience loop follows how many enemies are alive
+            .add_systems(
+                Update,
+                (
+                    ambience::update_combat_intensity,
+                    ambience::manage_ambience_system,
+                )
+                    .chain(),
+            )
             // Load audio handles at startup
-            .add_systems(Startup, load_audio_assets)
+            .add_systems(Startup, (load_audio_assets, music::load_music_playlist))
             // Observers to react to gameplay messages
             .add_observer(on_tower_shot)
-            .add_observer(on_player_footstep)
             .add_observer(on_wave_started)
             .add_observer(on_boss_wave_started)
             .add_observer(on_building_action);
@@ -125,6 +323,7 @@ impl Plugin for GameAudioPlugin {
 pub struct SpatialAudioParams {
     pub attenuation: f32,          // higher => faster volume falloff
     pub max_audible_distance: f32, // hard clamp to mute beyond this
+    pub reverb_when_inside: bool,  // echo SFX while the listener is inside the town walls
 }
 
 impl Default for SpatialAudioParams {
@@ -132,26 +331,67 @@ impl Default for SpatialAudioParams {
         Self {
             attenuation: 0.08,
             max_audible_distance: 80.0,
+            reverb_when_inside: true,
         }
     }
 }
 
+// Reverb approximation: bevy_kira_audio channels expose no effect sends, so a quieter
+// copy of the sound is replayed shortly after the original.
+const ECHO_DELAY_SECS: f32 = 0.06;
+// -12 dB as a linear amplitude factor
+const ECHO_GAIN: f32 = 0.251;
+
 #[derive(Resource, Default, Clone, Copy)]
-pub struct ListenerTransform(pub Option<GlobalTransform>);
+pub struct ListenerTransform {
+    pub current: Option<GlobalTransform>,
+    /// Listener transform of the previous frame, used to derive its direction of travel.
+    pub previous: Option<GlobalTransform>,
+    pub inside_town: bool,
+}
+
+impl ListenerTransform {
+    /// Unit XZ direction the listener moved in since the previous frame, or zero when still.
+    pub fn xz_velocity_dir(&self) -> Vec3 {
+        match (self.current, self.previous) {
+            (Some(current), Some(previous)) => (current.translation() - previous.translation())
+                .with_y(0.0)
+                .normalize_or_zero(),
+            _ => Vec3::ZERO,
+        }
+    }
+}
 
 fn update_listener_transform(
     q_listener: Query<&GlobalTransform, (With<Camera>, With<AudioListener>)>,
+    tunables: Option<Res<Tunables>>,
     mut listener_tf: ResMut<ListenerTransform>,
 ) {
-    listener_tf.0 = q_listener.iter().next().copied();
+    listener_tf.previous = listener_tf.current;
+    listener_tf.current = q_listener.iter().next().copied();
+    listener_tf.inside_town = match (listener_tf.current, tunables) {
+        (Some(tf), Some(tunables)) => {
+            let half = tunables.town_size * 0.5;
+            let p = tf.translation();
+            p.x.abs() <= half && p.z.abs() <= half
+        }
+        _ => false,
+    };
 }
 
+/// Returns `(volume, pan, echo_volume)` for a sound at `source_world`. `echo_volume` is
+/// non-zero only when the listener is inside town and reverb is enabled.
+///
+/// While the listener moves, sources ahead of it are steered toward the center and sources
+/// behind it further out, by up to `doppler_factor` of the pan.
 pub fn spatialize(
     source_world: Vec3,
-    listener: &GlobalTransform,
+    listener: &ListenerTransform,
     params: SpatialAudioParams,
-) -> (f32, f32) {
-    let listener_translation = listener.translation();
+    doppler_factor: f32,
+) -> (f32, f32, f32) {
+    let listener_gt = listener.current.unwrap_or(GlobalTransform::IDENTITY);
+    let listener_translation = listener_gt.translation();
     let to_source = source_world - listener_translation;
     let distance = to_source.length();
     let volume = if distance >= params.max_audible_distance {
@@ -160,15 +400,94 @@ pub fn spatialize(
         (1.0 / (1.0 + params.attenuation * distance)).clamp(0.0, 1.0)
     };
 
-    let listener_tr = listener.compute_transform();
+    let listener_tr = listener_gt.compute_transform();
     let right = listener_tr.rotation * Vec3::X;
     let dir_norm = if distance > 0.0001 {
         to_source / distance
     } else {
         Vec3::ZERO
     };
-    let pan = dir_norm.dot(right).clamp(-1.0, 1.0);
-    (volume, pan)
+    let approach = listener
+        .xz_velocity_dir()
+        .dot(dir_norm.with_y(0.0).normalize_or_zero());
+    let pan = (dir_norm.dot(right) * (1.0 - doppler_factor * approach)).clamp(-1.0, 1.0);
+    let echo_volume = if listener.inside_town && params.reverb_when_inside {
+        volume * ECHO_GAIN
+    } else {
+        0.0
+    };
+    (volume, pan, echo_volume)
+}
+
+struct PendingEcho {
+    handle: Handle<KiraAudioSource>,
+    volume: f32,
+    pan: f32,
+    delay: Timer,
+}
+
+/// Delayed reverb copies waiting to be played on the SFX channel.
+#[derive(Resource, Default)]
+pub struct PendingEchoes(Vec<PendingEcho>);
+
+impl PendingEchoes {
+    fn push(&mut self, handle: Handle<KiraAudioSource>, volume: f32, pan: f32) {
+        if volume <= 0.0 {
+            return;
+        }
+        self.0.push(PendingEcho {
+            handle,
+            volume,
+            pan,
+            delay: Timer::new(Duration::from_secs_f32(ECHO_DELAY_SECS), TimerMode::Once),
+        });
+    }
+}
+
+fn play_pending_echoes(
+    time: Res<Time<Real>>,
+    sfx: Res<AudioChannel<SfxChannel>>,
+    mut echoes: ResMut<PendingEchoes>,
+) {
+    echoes.0.retain_mut(|echo| {
+        echo.delay.tick(time.delta());
+        if !echo.delay.is_finished() {
+            return true;
+        }
+        sfx.play(echo.handle.clone())
+            .with_volume(echo.volume)
+            .with_panning(echo.pan);
+        false
+    });
+}
+
+/// Everything needed to play a sound at a world position on the SFX channel: distance volume,
+/// pan with doppler steering, and the reverb copy inside town.
+#[derive(SystemParam)]
+pub struct SpatialSfx<'w> {
+    sfx: Res<'w, AudioChannel<SfxChannel>>,
+    volumes: Res<'w, AudioVolumesSmoother>,
+    params: Res<'w, SpatialAudioParams>,
+    listener: Res<'w, ListenerTransform>,
+    tunables: Option<Res<'w, Tunables>>,
+    echoes: ResMut<'w, PendingEchoes>,
+}
+
+impl SpatialSfx<'_> {
+    /// Plays `handle` as heard from the listener, at `position`.
+    pub fn play(&mut self, handle: Handle<KiraAudioSource>, position: Vec3) {
+        let doppler = self
+            .tunables
+            .as_ref()
+            .map_or(C_AUDIO_DOPPLER_FACTOR, |t| t.audio_doppler_factor);
+        let (vol, pan, echo_vol) = spatialize(position, &self.listener, *self.params, doppler);
+        let base = effective_sfx_volume(&self.volumes.current);
+        self.sfx
+            .play(handle.clone())
+            .with_volume(base * vol)
+            .with_panning(pan);
+        self.echoes.push(handle, base * echo_vol, pan);
+    }
 }
 
 fn load_audio_assets(asset_server: Res<AssetServer>, mut assets: ResMut<AudioAssets>) {
@@ -178,10 +497,25 @@ fn load_audio_assets(asset_server: Res<AssetServer>, mut assets: ResMut<AudioAss
     assets.wave_start = sfx::wave_start::load(&asset_server);
     assets.wave_start_boss = sfx::wave_start_boss::load(&asset_server);
     assets.player_footstep_01 = sfx::player_footstep_01::load(&asset_server);
+    assets.player_footstep_road_01 = sfx::player_footstep_road_01::load(&asset_server);
     assets.tower_place = sfx::tower_place::load(&asset_server);
     assets.tower_place_invalid = sfx::tower_place_invalid::load(&asset_server);
     assets.tower_upgrade = sfx::tower_upgrade::load(&asset_server);
     assets.tower_sell = sfx::tower_sell::load(&asset_server);
+    assets.wood_collect_complete = sfx::wood_collect_complete::load(&asset_server);
+    assets.rock_collect_complete = sfx::rock_collect_complete::load(&asset_server);
+    assets.lightning_strike = sfx::lightning_strike::load(&asset_server);
+    assets.wave_complete = sfx::wave_complete::load(&asset_server);
+    assets.danger_heartbeat = sfx::danger_heartbeat::load(&asset_server);
+    assets.market_trade = sfx::market_trade::load(&asset_server);
+    assets.nightfall = sfx::nightfall::load(&asset_server);
+    assets.dawn = sfx::dawn::load(&asset_server);
+    assets.minion_swarm = sfx::minion_swarm::load(&asset_server);
+    assets.ambience_peaceful = sfx::ambience_peaceful::load(&asset_server);
+    assets.ambience_light = sfx::ambience_light::load(&asset_server);
+    assets.ambience_heavy = sfx::ambience_heavy::load(&asset_server);
+    assets.ambience_intense = sfx::ambience_intense::load(&asset_server);
+    assets.countdown_beep = sfx::countdown_beep::load(&asset_server);
 }
 
 fn effective_sfx_volume(volumes: &AudioVolumes) -> f32 {
@@ -190,47 +524,72 @@ fn effective_sfx_volume(volumes: &AudioVolumes) -> f32 {
 
 pub fn on_tower_shot(
     trigger: On<TowerShotEvent>,
-    sfx: Res<AudioChannel<SfxChannel>>,
     assets: Res<AudioAssets>,
-    volumes: Res<AudioVolumes>,
-    params: Res<SpatialAudioParams>,
-    listener_tf: Res<ListenerTransform>,
+    mut spatial: SpatialSfx,
 ) {
     let e = trigger.event();
-    let listener = listener_tf.0.unwrap_or(GlobalTransform::IDENTITY);
-    let (vol, pan) = spatialize(e.position, &listener, *params);
-    let base = effective_sfx_volume(&volumes);
     let handle = match e.kind {
         TowerShotKind::Bow => assets.tower_bow_release.clone(),
         TowerShotKind::Crossbow => assets.tower_crossbow_release.clone(),
+        // No dedicated zap sample yet; the crossbow snap reads well enough
+        TowerShotKind::Lightning => assets.tower_crossbow_release.clone(),
+        // Heavy release; the crossbow snap stands in until a throw sample exists
+        TowerShotKind::Catapult => assets.tower_crossbow_release.clone(),
+        // No blast sample yet either
+        TowerShotKind::Cannon => assets.tower_crossbow_release.clone(),
     };
-    sfx.play(handle).with_volume(base * vol).with_panning(pan);
+    spatial.play(handle, e.position);
 }
 
-pub fn on_player_footstep(
-    trigger: On<PlayerFootstepEvent>,
-    sfx: Res<AudioChannel<SfxChannel>>,
+/// Time left before another footstep may play.
+pub struct FootstepCooldown(Timer);
+
+impl Default for FootstepCooldown {
+    fn default() -> Self {
+        // Starts elapsed so the first step is heard immediately
+        let mut timer = Timer::from_seconds(C_FOOTSTEP_INTERVAL_SECS, TimerMode::Once);
+        timer.finish();
+        Self(timer)
+    }
+}
+
+/// Plays footsteps at most once per `Tunables.footstep_interval_secs`, whatever the event rate.
+fn play_player_footstep_sfx(
+    time: Res<Time>,
+    mut footsteps: MessageReader<PlayerFootstepEvent>,
+    mut cooldown: Local<FootstepCooldown>,
+    tunables: Option<Res<Tunables>>,
     assets: Res<AudioAssets>,
-    volumes: Res<AudioVolumes>,
-    params: Res<SpatialAudioParams>,
-    listener_tf: Res<ListenerTransform>,
+    mut spatial: SpatialSfx,
 ) {
-    let e = trigger.event();
-    let listener = listener_tf.0.unwrap_or(GlobalTransform::IDENTITY);
-    let (vol, pan) = spatialize(e.position, &listener, *params);
-    let base = effective_sfx_volume(&volumes);
-    sfx.play(assets.player_footstep_01.clone())
-        .with_volume(base * vol)
-        .with_panning(pan);
+    cooldown.0.tick(time.delta());
+    // Only the latest step matters; anything before it would be skipped by the cooldown anyway
+    let Some(e) = footsteps.read().last() else {
+        return;
+    };
+    if !cooldown.0.is_finished() {
+        return;
+    }
+    let interval = tunables
+        .as_ref()
+        .map_or(C_FOOTSTEP_INTERVAL_SECS, |t| t.footstep_interval_secs);
+    cooldown.0.set_duration(Duration::from_secs_f32(interval));
+    cooldown.0.reset();
+
+    let handle = match e.surface {
+        SurfaceKind::Grass => assets.player_footstep_01.clone(),
+        SurfaceKind::Road => assets.player_footstep_road_01.clone(),
+    };
+    spatial.play(handle, e.position);
 }
 
 pub fn on_wave_started(
     _trigger: On<WaveStartedEvent>,
     sfx: Res<AudioChannel<SfxChannel>>,
     assets: Res<AudioAssets>,
-    volumes: Res<AudioVolumes>,
+    volumes: Res<AudioVolumesSmoother>,
 ) {
-    let base = effective_sfx_volume(&volumes);
+    let base = effective_sfx_volume(&volumes.current);
     sfx.play(assets.wave_start.clone()).with_volume(base);
 }
 
@@ -238,29 +597,247 @@ pub fn on_boss_wave_started(
     _trigger: On<BossWaveStartedEvent>,
     sfx: Res<AudioChannel<SfxChannel>>,
     assets: Res<AudioAssets>,
-    volumes: Res<AudioVolumes>,
+    volumes: Res<AudioVolumesSmoother>,
 ) {
-    let base = effective_sfx_volume(&volumes);
+    let base = effective_sfx_volume(&volumes.current);
     sfx.play(assets.wave_start_boss.clone()).with_volume(base);
 }
 
+/// Thunder plays at the full SFX volume regardless of where the strike lands.
+fn play_lightning_strike_sfx(
+    mut strikes: MessageReader<LightningStrikeEvent>,
+    sfx: Res<AudioChannel<SfxChannel>>,
+    assets: Res<AudioAssets>,
+    volumes: Res<AudioVolumesSmoother>,
+) {
+    // Simultaneous strikes share a single boom
+    if strikes.read().count() == 0 {
+        return;
+    }
+    let base = effective_sfx_volume(&volumes.current);
+    sfx.play(assets.lightning_strike.clone()).with_volume(base);
+}
+
+/// Fanfare for surviving a wave, at the full SFX volume.
+fn play_wave_complete_sfx(
+    mut completed: MessageReader<WaveCompletedEvent>,
+    sfx: Res<AudioChannel<SfxChannel>>,
+    assets: Res<AudioAssets>,
+    volumes: Res<AudioVolumesSmoother>,
+) {
+    if completed.read().count() == 0 {
+        return;
+    }
+    let base = effective_sfx_volume(&volumes.current);
+    sfx.play(assets.wave_complete.clone()).with_volume(base);
+}
+
+/// Coin clink for market trades, on the UI channel since it answers a menu click.
+fn play_market_trade_sfx(
+    mut transactions: MessageReader<MarketTransactionEvent>,
+    ui: Res<AudioChannel<UiChannel>>,
+    assets: Res<AudioAssets>,
+    volumes: Res<AudioVolumesSmoother>,
+) {
+    if transactions.read().count() == 0 {
+        return;
+    }
+    let base = effective_sfx_volume(&volumes.current);
+    ui.play(assets.market_trade.clone()).with_volume(base);
+}
+
+/// Beep for each second of the pre-game countdown, on the UI channel like the countdown itself.
+fn play_countdown_beep_sfx(
+    mut ticks: MessageReader<CountdownTickEvent>,
+    ui: Res<AudioChannel<UiChannel>>,
+    assets: Res<AudioAssets>,
+    volumes: Res<AudioVolumesSmoother>,
+) {
+    if ticks.read().count() == 0 {
+        return;
+    }
+    let base = effective_sfx_volume(&volumes.current);
+    ui.play(assets.countdown_beep.clone()).with_volume(base);
+}
+
+fn pause_game_audio(
+    sfx: Res<AudioChannel<SfxChannel>>,
+    ui: Res<AudioChannel<UiChannel>>,
+    ambience: Res<AudioChannel<AmbienceChannel>>,
+) {
+    sfx.pause();
+    ui.pause();
+    ambience.pause();
+}
+
+fn resume_game_audio(
+    sfx: Res<AudioChannel<SfxChannel>>,
+    ui: Res<AudioChannel<UiChannel>>,
+    ambience: Res<AudioChannel<AmbienceChannel>>,
+) {
+    sfx.resume();
+    ui.resume();
+    ambience.resume();
+}
+
+fn play_swarm_burst_sfx(
+    mut bursts: MessageReader<SwarmBurstEvent>,
+    assets: Res<AudioAssets>,
+    mut spatial: SpatialSfx,
+) {
+    let Some(e) = bursts.read().last() else {
+        return;
+    };
+    spatial.play(assets.minion_swarm.clone(), e.position);
+}
+
+/// Nightfall and dawn stingers, unspatialized like the wave sounds.
+fn play_day_night_sfx(
+    mut changed: MessageReader<DayNightChangedEvent>,
+    sfx: Res<AudioChannel<SfxChannel>>,
+    assets: Res<AudioAssets>,
+    volumes: Res<AudioVolumesSmoother>,
+) {
+    let Some(e) = changed.read().last() else {
+        return;
+    };
+    let handle = if e.night {
+        assets.nightfall.clone()
+    } else {
+        assets.dawn.clone()
+    };
+    let base = effective_sfx_volume(&volumes.current);
+    sfx.play(handle).with_volume(base);
+}
+
+/// Seconds the danger heartbeat takes to fade fully in or out.
+const DANGER_HEARTBEAT_FADE_SECS: f32 = 0.75;
+
+#[derive(Default)]
+struct DangerHeartbeat {
+    instance: Option<Handle<AudioInstance>>,
+    volume: f32,
+}
+
+/// Loops a heartbeat on the ambience channel while village health is below
+/// `Tunables.danger_threshold`, fading it in and out along with the danger overlay.
+fn play_danger_heartbeat(
+    time: Res<Time>,
+    tunables: Option<Res<Tunables>>,
+    village: Query<&Village>,
+    ambience: Res<AudioChannel<AmbienceChannel>>,
+    assets: Res<AudioAssets>,
+    mut instances: ResMut<Assets<AudioInstance>>,
+    mut heartbeat: Local<DangerHeartbeat>,
+) {
+    let in_danger = match (village.single(), tunables) {
+        (Ok(village), Some(tunables)) => village.health_ratio() < tunables.danger_threshold,
+        _ => false,
+    };
+    let target = if in_danger { 1.0 } else { 0.0 };
+    let step = time.delta_secs() / DANGER_HEARTBEAT_FADE_SECS;
+    heartbeat.volume = move_toward(heartbeat.volume, target, step);
+
+    if heartbeat.volume > 0.0 && heartbeat.instance.is_none() {
+        heartbeat.instance = Some(
+            ambience
+                .play(assets.danger_heartbeat.clone())
+                .looped()
+                .with_volume(0.0)
+                .handle(),
+        );
+    }
+    let Some(handle) = heartbeat.instance.clone() else {
+        return;
+    };
+    let Some(instance) = instances.get_mut(&handle) else {
+        return;
+    };
+    if heartbeat.volume <= 0.0 {
+        instance.stop(AudioTween::default());
+        heartbeat.instance = None;
+    } else {
+        instance.set_decibels(heartbeat.volume, AudioTween::default());
+    }
+}
+
 pub fn on_building_action(
     trigger: On<BuildingActionEvent>,
-    sfx: Res<AudioChannel<SfxChannel>>,
     assets: Res<AudioAssets>,
-    volumes: Res<AudioVolumes>,
-    params: Res<SpatialAudioParams>,
-    listener_tf: Res<ListenerTransform>,
+    mut spatial: SpatialSfx,
 ) {
     let e = trigger.event();
-    let listener = listener_tf.0.unwrap_or(GlobalTransform::IDENTITY);
-    let (vol, pan) = spatialize(e.position, &listener, *params);
-    let base = effective_sfx_volume(&volumes);
     let handle = match e.kind {
         BuildingActionKind::Place => assets.tower_place.clone(),
         BuildingActionKind::Invalid => assets.tower_place_invalid.clone(),
         BuildingActionKind::Upgrade => assets.tower_upgrade.clone(),
         BuildingActionKind::Sell => assets.tower_sell.clone(),
     };
-    sfx.play(handle).with_volume(base * vol).with_panning(pan);
+    spatial.play(handle, e.position);
+}
+
+fn play_resource_harvested_sfx(
+    mut harvested: MessageReader<ResourceHarvestedEvent>,
+    assets: Res<AudioAssets>,
+    mut spatial: SpatialSfx,
+) {
+    for e in harvested.read() {
+        let handle = match e.kind {
+            HarvestableKind::Wood => assets.wood_collect_complete.clone(),
+            HarvestableKind::Rock => assets.rock_collect_complete.clone(),
+        };
+        spatial.play(handle, e.position);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A listener at the origin facing -Z that moved from `from` since the previous frame.
+    fn listener_from(from: Vec3) -> ListenerTransform {
+        ListenerTransform {
+            current: Some(GlobalTransform::IDENTITY),
+            previous: Some(GlobalTransform::from_translation(from)),
+            inside_town: false,
+        }
+    }
+
+    fn pan(source: Vec3, listener: &ListenerTransform, doppler_factor: f32) -> f32 {
+        spatialize(
+            source,
+            listener,
+            SpatialAudioParams::default(),
+            doppler_factor,
+        )
+        .1
+    }
+
+    #[test]
+    fn still_listener_pans_by_direction() {
+        let listener = listener_from(Vec3::ZERO);
+        assert!((pan(Vec3::new(10.0, 0.0, 0.0), &listener, 0.5) - 1.0).abs() < 1e-5);
+        assert!((pan(Vec3::new(-10.0, 0.0, 0.0), &listener, 0.5) + 1.0).abs() < 1e-5);
+        assert!(pan(Vec3::new(0.0, 0.0, -10.0), &listener, 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn moving_listener_steers_pan() {
+        // Ahead and to the right
+        let source = Vec3::new(5.0, 0.0, -5.0);
+        let still = pan(source, &listener_from(Vec3::ZERO), 0.5);
+        let toward = pan(source, &listener_from(Vec3::new(-1.0, 0.0, 1.0)), 0.5);
+        let away = pan(source, &listener_from(Vec3::new(1.0, 0.0, -1.0)), 0.5);
+        assert!(still > 0.0);
+        assert!(toward.abs() < still.abs());
+        assert!(away.abs() > still.abs());
+    }
+
+    #[test]
+    fn zero_doppler_factor_ignores_movement() {
+        let source = Vec3::new(5.0, 0.0, -5.0);
+        let still = pan(source, &listener_from(Vec3::ZERO), 0.0);
+        let moving = pan(source, &listener_from(Vec3::new(-1.0, 0.0, 1.0)), 0.0);
+        assert!((still - moving).abs() < 1e-5);
+    }
 }
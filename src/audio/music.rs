@@ -0,0 +1,121 @@
+use super::util::{AUDIO_MUSIC_DIR, load_first_existing_in};
+use super::{
+    AudioVolumesSmoother, BossWaveStartedEvent, GameOverEvent, MusicChannel, WaveCompletedEvent,
+    WaveStartedEvent,
+};
+use crate::constants::{C_MUSIC_CROSSFADE_SECS, Tunables};
+use bevy::prelude::*;
+use bevy_kira_audio::AudioSource as KiraAudioSource;
+use bevy_kira_audio::prelude::*;
+
+/// Background tracks, loaded from assets/audio/music. Missing files simply stay silent.
+#[derive(Resource, Default)]
+pub struct MusicPlaylist {
+    pub exploration: Handle<KiraAudioSource>,
+    pub combat: Handle<KiraAudioSource>,
+    pub boss: Handle<KiraAudioSource>,
+    pub defeat: Handle<KiraAudioSource>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MusicTrack {
+    /// Intermissions and the time before the first wave.
+    #[default]
+    Exploration,
+    Combat,
+    Boss,
+    /// Game over screen after the village fell.
+    Defeat,
+}
+
+impl MusicPlaylist {
+    fn handle(&self, track: MusicTrack) -> Handle<KiraAudioSource> {
+        match track {
+            MusicTrack::Exploration => self.exploration.clone(),
+            MusicTrack::Combat => self.combat.clone(),
+            MusicTrack::Boss => self.boss.clone(),
+            MusicTrack::Defeat => self.defeat.clone(),
+        }
+    }
+}
+
+/// Progress of the switch between tracks. The music channel plays one track at a time, so a
+/// change fades the current track out, starts the target track silently and fades it back in.
+#[derive(Resource, Default)]
+pub struct MusicCrossfadeState {
+    /// Track playing on the music channel; `None` until the first one starts.
+    pub current: Option<MusicTrack>,
+    pub target: MusicTrack,
+    /// Fade level of the current track, 0..=1, before the master and music volumes.
+    pub fade: f32,
+    /// Channel volume last sent to the audio backend.
+    applied_volume: f32,
+}
+
+pub(super) fn load_music_playlist(
+    asset_server: Res<AssetServer>,
+    mut playlist: ResMut<MusicPlaylist>,
+) {
+    playlist.exploration = load_first_existing_in(&asset_server, AUDIO_MUSIC_DIR, "exploration");
+    playlist.combat = load_first_existing_in(&asset_server, AUDIO_MUSIC_DIR, "combat");
+    playlist.boss = load_first_existing_in(&asset_server, AUDIO_MUSIC_DIR, "boss");
+    playlist.defeat = load_first_existing_in(&asset_server, AUDIO_MUSIC_DIR, "defeat");
+}
+
+/// Picks the track for the wave phase (or the defeat track once the village falls) and drives the
+/// fade between tracks.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn music_system(
+    time: Res<Time<Real>>,
+    mut wave_started: MessageReader<WaveStartedEvent>,
+    mut boss_wave_started: MessageReader<BossWaveStartedEvent>,
+    mut wave_completed: MessageReader<WaveCompletedEvent>,
+    mut game_over: MessageReader<GameOverEvent>,
+    tunables: Option<Res<Tunables>>,
+    volumes: Res<AudioVolumesSmoother>,
+    playlist: Res<MusicPlaylist>,
+    music: Res<AudioChannel<MusicChannel>>,
+    mut state: ResMut<MusicCrossfadeState>,
+) {
+    if wave_completed.read().count() > 0 {
+        state.target = MusicTrack::Exploration;
+    }
+    if wave_started.read().count() > 0 {
+        state.target = MusicTrack::Combat;
+    }
+    if boss_wave_started.read().count() > 0 {
+        state.target = MusicTrack::Boss;
+    }
+    if game_over.read().any(|e| !e.victory) {
+        state.target = MusicTrack::Defeat;
+    }
+
+    let fade_secs = tunables.map_or(C_MUSIC_CROSSFADE_SECS, |t| t.music_crossfade_secs);
+    let step = time.delta_secs() / fade_secs;
+    if state.current == Some(state.target) {
+        state.fade = (state.fade + step).min(1.0);
+    } else if state.current.is_some() && state.fade > 0.0 {
+        state.fade = (state.fade - step).max(0.0);
+    } else {
+        music.stop();
+        music.play(playlist.handle(state.target)).looped();
+        state.current = Some(state.target);
+        state.fade = 0.0;
+        // Force the silent starting volume onto the new track
+        state.applied_volume = -1.0;
+    }
+
+    // Applied every frame so settings changes take effect without waiting for a track change
+    let volume = state.fade * (volumes.current.master * volumes.current.music).clamp(0.0, 1.0);
+    if volume != state.applied_volume {
+        music.set_volume(volume);
+        state.applied_volume = volume;
+    }
+}
+
+/// Leaving the game over screen (restart or main menu) brings back the calm track.
+pub(super) fn end_defeat_music(mut state: ResMut<MusicCrossfadeState>) {
+    if state.target == MusicTrack::Defeat {
+        state.target = MusicTrack::Exploration;
+    }
+}
@@ -1,9 +1,24 @@
+pub mod ambience_heavy;
+pub mod ambience_intense;
+pub mod ambience_light;
+pub mod ambience_peaceful;
+pub mod countdown_beep;
+pub mod danger_heartbeat;
+pub mod dawn;
+pub mod lightning_strike;
+pub mod market_trade;
+pub mod minion_swarm;
+pub mod nightfall;
 pub mod player_footstep_01;
+pub mod player_footstep_road_01;
+pub mod rock_collect_complete;
 pub mod tower_bow_release;
 pub mod tower_crossbow_release;
 pub mod tower_place;
 pub mod tower_place_invalid;
 pub mod tower_sell;
 pub mod tower_upgrade;
+pub mod wave_complete;
 pub mod wave_start;
 pub mod wave_start_boss;
+pub mod wood_collect_complete;
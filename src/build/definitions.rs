@@ -4,6 +4,7 @@ use bevy::prelude::*;
 pub enum BuildCategory {
     Towers,
     Upgrades,
+    Market,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
@@ -24,6 +25,8 @@ pub struct UpgradeDefinition {
     pub gold_cost: u64,
     pub silver_cost: u64,
     pub tower_kind: crate::components::TowerKind,
+    /// Stat raised by one level of this upgrade.
+    pub stat: crate::components::UpgradeableStat,
 }
 
 #[derive(Resource, Default)]
@@ -50,6 +53,24 @@ impl BuildCatalog {
                 display_name: "Crossbow Tower",
                 cost: 20,
             },
+            BuildDefinition {
+                id: BuildDefinitionId("lightning_tower"),
+                category: BuildCategory::Towers,
+                display_name: "Lightning Tower",
+                cost: 30,
+            },
+            BuildDefinition {
+                id: BuildDefinitionId("catapult_tower"),
+                category: BuildCategory::Towers,
+                display_name: "Catapult Tower",
+                cost: 40,
+            },
+            BuildDefinition {
+                id: BuildDefinitionId("cannon_tower"),
+                category: BuildCategory::Towers,
+                display_name: "Cannon Tower",
+                cost: 50,
+            },
         ];
         self.upgrades = vec![
             UpgradeDefinition {
@@ -58,6 +79,7 @@ impl BuildCatalog {
                 gold_cost: 5,
                 silver_cost: 10,
                 tower_kind: crate::components::TowerKind::Bow,
+                stat: crate::components::UpgradeableStat::Damage,
             },
             UpgradeDefinition {
                 id: BuildDefinitionId("crossbow_damage_upgrade"),
@@ -65,6 +87,31 @@ impl BuildCatalog {
                 gold_cost: 10,
                 silver_cost: 20,
                 tower_kind: crate::components::TowerKind::Crossbow,
+                stat: crate::components::UpgradeableStat::Damage,
+            },
+            UpgradeDefinition {
+                id: BuildDefinitionId("lightning_damage_upgrade"),
+                display_name: "Lightning Damage",
+                gold_cost: 10,
+                silver_cost: 20,
+                tower_kind: crate::components::TowerKind::Lightning,
+                stat: crate::components::UpgradeableStat::Damage,
+            },
+            UpgradeDefinition {
+                id: BuildDefinitionId("catapult_damage_upgrade"),
+                display_name: "Catapult Damage",
+                gold_cost: 15,
+                silver_cost: 25,
+                tower_kind: crate::components::TowerKind::Catapult,
+                stat: crate::components::UpgradeableStat::Damage,
+            },
+            UpgradeDefinition {
+                id: BuildDefinitionId("cannon_damage_upgrade"),
+                display_name: "Cannon Damage",
+                gold_cost: 20,
+                silver_cost: 30,
+                tower_kind: crate::components::TowerKind::Cannon,
+                stat: crate::components::UpgradeableStat::Damage,
             },
         ];
     }
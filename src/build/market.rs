@@ -0,0 +1,221 @@
+use bevy::prelude::*;
+
+use crate::audio::{BossWaveStartedEvent, WaveStartedEvent};
+use crate::components::Player;
+use crate::constants::Tunables;
+
+/// Wood received by one "Rock → Wood" trade.
+pub const MARKET_WOOD_LOT: u32 = 10;
+/// Rock received by one "Wood → Rock" trade.
+pub const MARKET_ROCK_LOT: u32 = 5;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum MarketTrade {
+    WoodForRock,
+    RockForWood,
+}
+
+impl MarketTrade {
+    /// Fixed amount the trade delivers.
+    pub fn received(self) -> u32 {
+        match self {
+            MarketTrade::WoodForRock => MARKET_ROCK_LOT,
+            MarketTrade::RockForWood => MARKET_WOOD_LOT,
+        }
+    }
+
+    fn base_rate(self, tunables: &Tunables) -> f32 {
+        match self {
+            MarketTrade::WoodForRock => tunables.market_wood_per_rock,
+            MarketTrade::RockForWood => tunables.market_rock_per_wood,
+        }
+    }
+
+    fn paid_from(self, player: &mut Player) -> &mut u32 {
+        match self {
+            MarketTrade::WoodForRock => &mut player.wood,
+            MarketTrade::RockForWood => &mut player.rock,
+        }
+    }
+
+    fn received_into(self, player: &mut Player) -> &mut u32 {
+        match self {
+            MarketTrade::WoodForRock => &mut player.rock,
+            MarketTrade::RockForWood => &mut player.wood,
+        }
+    }
+
+    /// "10 Wood → 5 Rock" style label for the given price.
+    pub fn label(self, cost: u32) -> String {
+        match self {
+            MarketTrade::WoodForRock => format!("{} Wood → {} Rock", cost, self.received()),
+            MarketTrade::RockForWood => format!("{} Rock → {} Wood", cost, self.received()),
+        }
+    }
+}
+
+/// Trades made at the market during the current wave; every trade makes the next one pricier.
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct MarketState {
+    pub transactions_this_wave: u32,
+}
+
+impl MarketState {
+    /// What `trade` costs right now: the base rate raised by `market_rate_penalty` for every
+    /// trade made this wave, rounded up.
+    pub fn cost(&self, trade: MarketTrade, tunables: &Tunables) -> u32 {
+        let markup = (1.0 + tunables.market_rate_penalty).powi(self.transactions_this_wave as i32);
+        (trade.received() as f32 * trade.base_rate(tunables) * markup).ceil() as u32
+    }
+
+    pub fn can_afford(&self, trade: MarketTrade, player: &Player, tunables: &Tunables) -> bool {
+        let cost = self.cost(trade, tunables);
+        match trade {
+            MarketTrade::WoodForRock => player.wood >= cost,
+            MarketTrade::RockForWood => player.rock >= cost,
+        }
+    }
+
+    /// Performs `trade` on `player` if affordable and returns the amount paid.
+    pub fn execute(
+        &mut self,
+        trade: MarketTrade,
+        player: &mut Player,
+        tunables: &Tunables,
+    ) -> Option<u32> {
+        if !self.can_afford(trade, player, tunables) {
+            return None;
+        }
+        let cost = self.cost(trade, tunables);
+        *trade.paid_from(player) -= cost;
+        let received = trade.received_into(player);
+        *received = received.saturating_add(trade.received());
+        self.transactions_this_wave += 1;
+        Some(cost)
+    }
+}
+
+/// A completed market trade.
+#[derive(Event, Message, Debug, Clone, Copy)]
+pub struct MarketTransactionEvent {
+    pub trade: MarketTrade,
+    pub paid: u32,
+    pub received: u32,
+}
+
+/// Prices go back to the base rates whenever a new wave starts.
+pub fn reset_market_on_wave_start(
+    mut market: ResMut<MarketState>,
+    mut wave_started: MessageReader<WaveStartedEvent>,
+    mut boss_wave_started: MessageReader<BossWaveStartedEvent>,
+) {
+    let started = wave_started.read().count() + boss_wave_started.read().count() > 0;
+    if started && market.transactions_this_wave > 0 {
+        market.transactions_this_wave = 0;
+    }
+}
+
+pub fn log_market_transactions(mut transactions: MessageReader<MarketTransactionEvent>) {
+    for e in transactions.read() {
+        match e.trade {
+            MarketTrade::WoodForRock => info!("Market: {} wood → {} rock", e.paid, e.received),
+            MarketTrade::RockForWood => info!("Market: {} rock → {} wood", e.paid, e.received),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn player(wood: u32, rock: u32) -> Player {
+        Player {
+            wood,
+            rock,
+            silver: 0,
+            gold: 0,
+        }
+    }
+
+    #[test]
+    fn base_prices_match_the_labels() {
+        let tunables = Tunables::default();
+        let market = MarketState::default();
+        let wood_cost = market.cost(MarketTrade::WoodForRock, &tunables);
+        let rock_cost = market.cost(MarketTrade::RockForWood, &tunables);
+        assert_eq!(
+            MarketTrade::WoodForRock.label(wood_cost),
+            "10 Wood → 5 Rock"
+        );
+        assert_eq!(
+            MarketTrade::RockForWood.label(rock_cost),
+            "5 Rock → 10 Wood"
+        );
+    }
+
+    #[test]
+    fn unaffordable_trades_change_nothing() {
+        let tunables = Tunables::default();
+        let mut market = MarketState::default();
+        let mut poor = player(9, 4);
+        assert!(!market.can_afford(MarketTrade::WoodForRock, &poor, &tunables));
+        assert!(!market.can_afford(MarketTrade::RockForWood, &poor, &tunables));
+        assert_eq!(
+            market.execute(MarketTrade::WoodForRock, &mut poor, &tunables),
+            None
+        );
+        assert_eq!((poor.wood, poor.rock), (9, 4));
+        assert_eq!(market.transactions_this_wave, 0);
+
+        let mut exact = player(10, 0);
+        assert!(market.can_afford(MarketTrade::WoodForRock, &exact, &tunables));
+        assert_eq!(
+            market.execute(MarketTrade::WoodForRock, &mut exact, &tunables),
+            Some(10)
+        );
+        assert_eq!((exact.wood, exact.rock), (0, 5));
+    }
+
+    #[test]
+    fn every_trade_this_wave_raises_the_price() {
+        let tunables = Tunables::default();
+        let mut market = MarketState::default();
+        let mut rich = player(1000, 1000);
+        let paid: Vec<u32> = (0..3)
+            .filter_map(|_| market.execute(MarketTrade::WoodForRock, &mut rich, &tunables))
+            .collect();
+        // 10% worse each time, rounded up: 10, 11, then 12.1 becomes 13
+        assert_eq!(paid, vec![10, 11, 13]);
+        // Both directions share the count
+        assert_eq!(market.cost(MarketTrade::RockForWood, &tunables), 7);
+
+        // An affordable trade can become unaffordable as the price climbs
+        let mut eleven = player(11, 0);
+        assert!(!market.can_afford(MarketTrade::WoodForRock, &eleven, &tunables));
+        assert_eq!(
+            market.execute(MarketTrade::WoodForRock, &mut eleven, &tunables),
+            None
+        );
+    }
+
+    #[test]
+    fn prices_reset_when_a_wave_starts() {
+        let mut world = World::new();
+        world.insert_resource(MarketState {
+            transactions_this_wave: 3,
+        });
+        world.init_resource::<Messages<WaveStartedEvent>>();
+        world.init_resource::<Messages<BossWaveStartedEvent>>();
+
+        world.run_system_once(reset_market_on_wave_start).unwrap();
+        assert_eq!(world.resource::<MarketState>().transactions_this_wave, 3);
+
+        world.write_message(BossWaveStartedEvent {
+            village_health: 100,
+        });
+        world.run_system_once(reset_market_on_wave_start).unwrap();
+        assert_eq!(world.resource::<MarketState>().transactions_this_wave, 0);
+    }
+}
@@ -3,10 +3,13 @@ use bevy::prelude::*;
 use crate::components::{GameState, TowerUpgradeConfig, TowerUpgrades};
 
 pub mod definitions;
+pub mod market;
+pub mod placement;
+pub mod radial_menu;
 pub mod theme;
 pub mod ui_menu;
 
-/// Plugin that owns the build menu (Tab) and placement flow.
+/// Plugin that owns the build menu (Tab), the optional radial menu (hold B) and placement flow.
 pub struct BuildPlugin;
 
 impl Plugin for BuildPlugin {
@@ -14,9 +17,12 @@ impl Plugin for BuildPlugin {
         app.init_resource::<ui_menu::BuildMenuState>()
             .init_resource::<definitions::BuildCatalog>()
             .init_resource::<ui_menu::CurrentCategory>()
+            .init_resource::<radial_menu::RadialMenuState>()
             .init_resource::<TowerUpgrades>()
             .init_resource::<TowerUpgradeConfig>()
+            .init_resource::<market::MarketState>()
             .add_message::<ui_menu::ToggleBuildMenu>()
+            .add_message::<market::MarketTransactionEvent>()
             .add_systems(
                 OnEnter(GameState::Playing),
                 definitions::ensure_default_catalog,
@@ -30,7 +36,24 @@ impl Plugin for BuildPlugin {
                     ui_menu::handle_category_buttons,
                     ui_menu::handle_item_selection,
                     ui_menu::handle_upgrade_selection,
+                    ui_menu::update_upgrade_level_displays,
+                    ui_menu::update_tower_limit_displays,
+                    ui_menu::handle_sell_all_button,
+                    ui_menu::handle_sell_all_confirmation,
+                    ui_menu::handle_market_buttons,
+                    ui_menu::update_market_buttons,
+                    market::reset_market_on_wave_start,
+                    market::log_market_transactions,
                 ),
+            )
+            .add_systems(
+                Update,
+                (
+                    radial_menu::radial_menu_input,
+                    radial_menu::animate_radial_segments,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
             );
     }
 }
@@ -0,0 +1,251 @@
+use bevy::prelude::*;
+
+use crate::components::{BuiltTower, RoadPaths, StaticObstacleAABBs, Tower, TowerGhost, TowerKind};
+use crate::constants::Tunables;
+use crate::core::geometry::{aabb_overlap, segment_circle_intersect};
+
+/// Towers already placed in the world, excluding the placement ghost.
+pub type PlacedTowersQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'static Transform, &'static Tower),
+    (With<BuiltTower>, Without<TowerGhost>),
+>;
+
+/// Whether two tower footprints intersect, as axis-aligned boxes on the XZ plane.
+/// Sizes are (width, height, depth); towers that merely touch do not overlap.
+pub fn towers_overlap(
+    pos_a: Vec3,
+    size_a: (f32, f32, f32),
+    pos_b: Vec3,
+    size_b: (f32, f32, f32),
+) -> bool {
+    let half_x = (size_a.0 + size_b.0) * 0.5;
+    let half_z = (size_a.2 + size_b.2) * 0.5;
+    (pos_a.x - pos_b.x).abs() < half_x && (pos_a.z - pos_b.z).abs() < half_z
+}
+
+/// First placed tower whose footprint a `kind` tower at `proposed_pos` would overlap.
+pub fn find_overlapping_tower(
+    proposed_pos: Vec3,
+    kind: TowerKind,
+    towers: &PlacedTowersQuery,
+) -> Option<Entity> {
+    let size = kind.size();
+    towers
+        .iter()
+        .find(|(_, transform, tower)| {
+            towers_overlap(
+                proposed_pos,
+                size,
+                transform.translation,
+                (tower.width, tower.height, tower.depth),
+            )
+        })
+        .map(|(entity, _, _)| entity)
+}
+
+/// Whether a `kind` tower at `proposed_pos` would intersect a wall, the village or the town
+/// square.
+pub fn overlaps_static_obstacle(
+    proposed_pos: Vec3,
+    kind: TowerKind,
+    obstacles: &StaticObstacleAABBs,
+) -> bool {
+    let size = kind.size();
+    let half = Vec3::new(size.0 * 0.5, 0.0, size.2 * 0.5);
+    let footprint = (
+        proposed_pos.with_y(0.0) - half,
+        proposed_pos.with_y(size.1) + half,
+    );
+    obstacles
+        .boxes
+        .iter()
+        .any(|obstacle| aabb_overlap(footprint, *obstacle))
+}
+
+/// Why a tower cannot be built at a spot, beyond range, cost and overlap checks.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PlacementError {
+    OnRoad,
+    InTownSquare,
+    TowerLimitReached,
+}
+
+impl PlacementError {
+    /// Short reason shown on the HUD when a placement is refused.
+    pub fn message(self) -> &'static str {
+        match self {
+            PlacementError::OnRoad => "Can't build on the road",
+            PlacementError::InTownSquare => "Can't build in the town square",
+            PlacementError::TowerLimitReached => "Tower limit reached",
+        }
+    }
+}
+
+/// Most towers of `kind` that can stand at once, or `None` when the kind is unlimited.
+pub fn tower_limit(kind: TowerKind, tunables: &Tunables) -> Option<usize> {
+    match kind {
+        TowerKind::Bow => Some(tunables.max_bow_towers),
+        TowerKind::Crossbow => Some(tunables.max_crossbow_towers),
+        _ => None,
+    }
+}
+
+/// Whether `built` towers of `kind` already use up its limit.
+pub fn tower_limit_reached(kind: TowerKind, built: usize, tunables: &Tunables) -> bool {
+    tower_limit(kind, tunables).is_some_and(|limit| built >= limit)
+}
+
+/// Checks `pos` against the roads (`Tunables.road_block_radius` from any centerline) and the
+/// town square (`Tunables.town_square_block_radius` from its center).
+pub fn placement_error(
+    pos: Vec3,
+    roads: Option<&RoadPaths>,
+    town_square_center: Option<Vec3>,
+    tunables: &Tunables,
+) -> Option<PlacementError> {
+    let on_road = roads.is_some_and(|roads| {
+        roads
+            .roads
+            .iter()
+            .flat_map(|road| road.windows(2))
+            .any(|segment| {
+                segment_circle_intersect(
+                    segment[0].xz(),
+                    segment[1].xz(),
+                    pos.xz(),
+                    tunables.road_block_radius,
+                )
+            })
+    });
+    if on_road {
+        return Some(PlacementError::OnRoad);
+    }
+    let in_town_square = town_square_center.is_some_and(|center| {
+        (pos - center).with_y(0.0).length() < tunables.town_square_block_radius
+    });
+    in_town_square.then_some(PlacementError::InTownSquare)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limit_blocks_once_reached() {
+        let tunables = Tunables {
+            max_bow_towers: 3,
+            max_crossbow_towers: 1,
+            ..Tunables::default()
+        };
+        assert!(!tower_limit_reached(TowerKind::Bow, 0, &tunables));
+        assert!(!tower_limit_reached(TowerKind::Bow, 2, &tunables));
+        assert!(tower_limit_reached(TowerKind::Bow, 3, &tunables));
+        assert!(tower_limit_reached(TowerKind::Bow, 4, &tunables));
+        assert!(!tower_limit_reached(TowerKind::Crossbow, 0, &tunables));
+        assert!(tower_limit_reached(TowerKind::Crossbow, 1, &tunables));
+    }
+
+    #[test]
+    fn zero_limit_blocks_every_tower() {
+        let tunables = Tunables {
+            max_bow_towers: 0,
+            ..Tunables::default()
+        };
+        assert!(tower_limit_reached(TowerKind::Bow, 0, &tunables));
+    }
+
+    #[test]
+    fn unlimited_kinds_never_block() {
+        let tunables = Tunables::default();
+        for kind in TowerKind::ALL {
+            if tower_limit(kind, &tunables).is_none() {
+                assert!(!tower_limit_reached(kind, usize::MAX, &tunables));
+            }
+        }
+        assert_eq!(
+            tower_limit(TowerKind::Bow, &tunables),
+            Some(tunables.max_bow_towers)
+        );
+    }
+
+    /// An L-shaped road: east along Z = 0 to X = 100, then north to Z = 100.
+    fn l_road() -> RoadPaths {
+        RoadPaths {
+            roads: vec![vec![
+                Vec3::new(50.0, 0.0, 0.0),
+                Vec3::new(100.0, 0.0, 0.0),
+                Vec3::new(100.0, 0.0, 100.0),
+            ]],
+        }
+    }
+
+    #[test]
+    fn positions_on_the_road_polyline_are_invalid() {
+        let tunables = Tunables::default();
+        let roads = l_road();
+        let on_road = [
+            Vec3::new(50.0, 0.0, 0.0),
+            Vec3::new(75.0, 0.0, 0.0),
+            Vec3::new(100.0, 0.0, 0.0),
+            Vec3::new(100.0, 0.0, 60.0),
+            // Off the centerline but within the blocked band, at any height
+            Vec3::new(75.0, 5.0, tunables.road_block_radius * 0.9),
+        ];
+        for pos in on_road {
+            assert_eq!(
+                placement_error(pos, Some(&roads), None, &tunables),
+                Some(PlacementError::OnRoad),
+                "{pos}"
+            );
+        }
+    }
+
+    #[test]
+    fn positions_beside_the_road_are_valid() {
+        let tunables = Tunables::default();
+        let roads = l_road();
+        let clear = tunables.road_block_radius + 1.0;
+        for pos in [
+            Vec3::new(75.0, 0.0, clear),
+            Vec3::new(100.0 - clear, 0.0, 60.0),
+            // Past the end of the road
+            Vec3::new(50.0 - clear, 0.0, 0.0),
+        ] {
+            assert_eq!(
+                placement_error(pos, Some(&roads), None, &tunables),
+                None,
+                "{pos}"
+            );
+        }
+        assert_eq!(
+            placement_error(Vec3::new(75.0, 0.0, 0.0), None, None, &tunables),
+            None
+        );
+    }
+
+    #[test]
+    fn town_square_blocks_within_its_radius() {
+        let tunables = Tunables::default();
+        let center = Vec3::new(10.0, 0.0, -10.0);
+        let inside = center + Vec3::X * (tunables.town_square_block_radius - 1.0);
+        let outside = center + Vec3::Z * (tunables.town_square_block_radius + 1.0);
+        assert_eq!(
+            placement_error(inside, None, Some(center), &tunables),
+            Some(PlacementError::InTownSquare)
+        );
+        assert_eq!(
+            placement_error(outside, None, Some(center), &tunables),
+            None
+        );
+        // A road through the square reports the road
+        let roads = RoadPaths {
+            roads: vec![vec![center - Vec3::X * 50.0, center + Vec3::X * 50.0]],
+        };
+        assert_eq!(
+            placement_error(center, Some(&roads), Some(center), &tunables),
+            Some(PlacementError::OnRoad)
+        );
+    }
+}
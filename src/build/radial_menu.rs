@@ -0,0 +1,201 @@
+use bevy::input::keyboard::KeyCode;
+use bevy::prelude::*;
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use super::ui_menu::BuildMenuState;
+use crate::components::{BuildingMode, TowerBuildSelection, TowerKind};
+use crate::constants::Tunables;
+
+/// Tower types offered by the radial menu, clockwise from the top.
+const RADIAL_TOWERS: [TowerKind; 5] = [
+    TowerKind::Bow,
+    TowerKind::Crossbow,
+    TowerKind::Lightning,
+    TowerKind::Catapult,
+    TowerKind::Cannon,
+];
+/// Distance in pixels from the menu center to each segment's center.
+const RADIAL_RADIUS_PX: f32 = 90.0;
+/// Size in pixels of a segment.
+const SEGMENT_SIZE_PX: Vec2 = Vec2::new(110.0, 54.0);
+/// Cursor must move at least this far (pixels) from the center before a segment highlights.
+const DEAD_ZONE_PX: f32 = 24.0;
+/// Scale of segments that aren't highlighted; the highlighted one grows to 1.0.
+const IDLE_SCALE: f32 = 0.8;
+/// Rate at which segment scale eases toward its target (per second).
+const SCALE_EASE_RATE: f32 = 18.0;
+
+/// Open radial menu: the cursor position at activation and the highlighted tower, if any.
+#[derive(Resource, Default)]
+pub struct RadialMenuState {
+    center: Option<Vec2>,
+    highlighted: Option<TowerKind>,
+}
+
+#[derive(Component)]
+pub struct RadialMenuRoot;
+
+#[derive(Component, Clone, Copy)]
+pub struct RadialSegment(pub TowerKind);
+
+/// Hold B to open the radial menu at the cursor; release it over a segment to select that tower.
+#[allow(clippy::too_many_arguments)]
+pub fn radial_menu_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    tunables: Res<Tunables>,
+    windows: Query<&Window>,
+    menu_state: Res<BuildMenuState>,
+    asset_server: Res<AssetServer>,
+    mut radial: ResMut<RadialMenuState>,
+    roots_q: Query<Entity, With<RadialMenuRoot>>,
+    mut selection: ResMut<TowerBuildSelection>,
+    mut building_mode_q: Query<&mut BuildingMode>,
+) {
+    if !tunables.use_radial_menu || matches!(*menu_state, BuildMenuState::Open) {
+        close_radial_menu(&mut commands, &mut radial, &roots_q);
+        return;
+    }
+    let Ok(window) = windows.single() else {
+        return;
+    };
+
+    if keyboard.just_pressed(KeyCode::KeyB)
+        && let Some(cursor) = window.cursor_position()
+    {
+        close_radial_menu(&mut commands, &mut radial, &roots_q);
+        spawn_radial_menu(&mut commands, &asset_server, cursor);
+        radial.center = Some(cursor);
+        return;
+    }
+
+    let Some(center) = radial.center else {
+        return;
+    };
+
+    if keyboard.just_released(KeyCode::KeyB) {
+        if let Some(kind) = radial.highlighted {
+            selection.choice = Some(kind);
+            for mut bm in building_mode_q.iter_mut() {
+                bm.is_active = true;
+            }
+        }
+        close_radial_menu(&mut commands, &mut radial, &roots_q);
+        return;
+    }
+
+    // Highlight the segment whose direction is closest to the cursor's offset from the center
+    radial.highlighted = window
+        .cursor_position()
+        .map(|cursor| cursor - center)
+        .filter(|offset| offset.length() >= DEAD_ZONE_PX)
+        .and_then(|offset| {
+            RADIAL_TOWERS
+                .iter()
+                .enumerate()
+                .map(|(i, kind)| (segment_direction(i).dot(offset.normalize()), *kind))
+                .max_by(|a, b| a.0.total_cmp(&b.0))
+                .map(|(_, kind)| kind)
+        });
+}
+
+/// Eases segment scale toward 1.0 when highlighted and back to the idle scale otherwise.
+pub fn animate_radial_segments(
+    time: Res<Time<Real>>,
+    radial: Res<RadialMenuState>,
+    mut segments: Query<(&RadialSegment, &mut UiTransform, &mut BackgroundColor)>,
+) {
+    let t = 1.0 - (-SCALE_EASE_RATE * time.delta_secs()).exp();
+    for (segment, mut transform, mut background) in segments.iter_mut() {
+        let highlighted = radial.highlighted == Some(segment.0);
+        let target = if highlighted { 1.0 } else { IDLE_SCALE };
+        transform.scale = transform.scale.lerp(Vec2::splat(target), t);
+        background.set_if_neq(BackgroundColor(if highlighted {
+            Color::srgba(0.12, 0.47, 0.95, 0.95)
+        } else {
+            Color::srgba(0.97, 0.975, 0.965, 0.92)
+        }));
+    }
+}
+
+/// Unit screen-space direction of segment `index` (screen Y grows downward, so up is -Y).
+fn segment_direction(index: usize) -> Vec2 {
+    let angle = -FRAC_PI_2 + index as f32 * TAU / RADIAL_TOWERS.len() as f32;
+    Vec2::from_angle(angle)
+}
+
+fn spawn_radial_menu(commands: &mut Commands, asset_server: &AssetServer, center: Vec2) {
+    let font = asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf");
+    commands
+        .spawn((
+            RadialMenuRoot,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                top: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+        ))
+        .with_children(|root| {
+            for (i, kind) in RADIAL_TOWERS.iter().enumerate() {
+                let pos = center + segment_direction(i) * RADIAL_RADIUS_PX - SEGMENT_SIZE_PX * 0.5;
+                let (wood, rock) = kind.cost();
+                let name = kind.name();
+                root.spawn((
+                    RadialSegment(*kind),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(pos.x),
+                        top: Val::Px(pos.y),
+                        width: Val::Px(SEGMENT_SIZE_PX.x),
+                        height: Val::Px(SEGMENT_SIZE_PX.y),
+                        flex_direction: FlexDirection::Column,
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    UiTransform {
+                        scale: Vec2::splat(IDLE_SCALE),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.97, 0.975, 0.965, 0.92)),
+                    BorderColor::all(Color::srgba(0.18, 0.17, 0.19, 1.0)),
+                ))
+                .with_children(|segment| {
+                    segment.spawn((
+                        Text::new(name),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgba(0.08, 0.09, 0.11, 1.0)),
+                    ));
+                    segment.spawn((
+                        Text::new(format!("{}W {}R", wood, rock)),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgba(0.18, 0.17, 0.19, 0.85)),
+                    ));
+                });
+            }
+        });
+}
+
+fn close_radial_menu(
+    commands: &mut Commands,
+    radial: &mut RadialMenuState,
+    roots_q: &Query<Entity, With<RadialMenuRoot>>,
+) {
+    for root in roots_q.iter() {
+        commands.entity(root).try_despawn();
+    }
+    radial.center = None;
+    radial.highlighted = None;
+}
@@ -2,12 +2,16 @@ use bevy::input::keyboard::KeyCode;
 use bevy::prelude::*;
 
 use super::definitions::{BuildCatalog, BuildCategory, BuildDefinitionId};
+use super::market::{MarketState, MarketTrade, MarketTransactionEvent};
+use super::placement::tower_limit;
 use super::theme::{paper_panel, shadow_node};
 use crate::audio::{BuildingActionEvent, BuildingActionKind};
 use crate::components::{
     BuildingMode, BuiltTower, GameState, Player, Tower, TowerBuildSelection, TowerKind,
-    TowerUpgradeConfig, TowerUpgrades, UpgradeableStat,
+    TowerSpecificUpgrade, TowerUpgradeConfig, TowerUpgrades,
 };
+use crate::constants::Tunables;
+use crate::systems::ui::settings::settings_button;
 
 #[derive(Resource, Default, Clone, Copy, Debug, Eq, PartialEq)]
 pub enum BuildMenuState {
@@ -65,6 +69,7 @@ pub fn manage_build_menu_ui(
     content_q: Query<Entity, With<BuildContentRoot>>,
     mut current: ResMut<CurrentCategory>,
     catalog: Res<BuildCatalog>,
+    tunables: Res<Tunables>,
 ) {
     let mut toggled = false;
     for _ in reader.read() {
@@ -142,11 +147,23 @@ pub fn manage_build_menu_ui(
                 BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.0)),
             ))
             .with_children(|col| {
+                if tunables.challenge_mode {
+                    col.spawn((
+                        Text::new("CHALLENGE MODE"),
+                        TextFont {
+                            font: asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(CHALLENGE_BANNER_INK),
+                    ));
+                }
                 let normal_bg = BackgroundColor(Color::srgba(0.98, 0.98, 0.975, 0.9));
                 let border = BorderColor::all(Color::srgba(0.18, 0.17, 0.19, 0.9));
                 for (cat, label) in [
                     (BuildCategory::Towers, "Towers [1]"),
                     (BuildCategory::Upgrades, "Upgrades [2]"),
+                    (BuildCategory::Market, "Market [3]"),
                 ] {
                     col.spawn((
                         Button,
@@ -171,6 +188,18 @@ pub fn manage_build_menu_ui(
                         ));
                     });
                 }
+                // Opens the settings panel on top of the (paused) build menu
+                col.spawn(settings_button()).with_children(|b| {
+                    b.spawn((
+                        Text::new("Settings"),
+                        TextFont {
+                            font: asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgba(0.08, 0.09, 0.11, 1.0)),
+                    ));
+                });
             });
 
             // Right content area placeholder
@@ -194,7 +223,14 @@ pub fn manage_build_menu_ui(
     commands.entity(backdrop).add_child(panel);
 
     if let Some(root) = content_root_entity {
-        build_grid_under(&mut commands, &asset_server, root, &catalog, current.0);
+        build_grid_under(
+            &mut commands,
+            &asset_server,
+            root,
+            &catalog,
+            current.0,
+            tunables.challenge_mode,
+        );
     }
 }
 
@@ -232,6 +268,7 @@ pub fn handle_category_buttons(
     content_root_q: Query<Entity, With<BuildContentRoot>>,
     children_q: Query<&Children>,
     catalog: Res<BuildCatalog>,
+    tunables: Res<Tunables>,
     asset_server: Res<AssetServer>,
     mut commands: Commands,
 ) {
@@ -270,7 +307,14 @@ pub fn handle_category_buttons(
     }
 
     // Rebuild grid
-    build_grid_under(&mut commands, &asset_server, root, &catalog, current.0);
+    build_grid_under(
+        &mut commands,
+        &asset_server,
+        root,
+        &catalog,
+        current.0,
+        tunables.challenge_mode,
+    );
 }
 
 fn build_grid_under(
@@ -279,6 +323,7 @@ fn build_grid_under(
     content_root: Entity,
     catalog: &BuildCatalog,
     current: BuildCategory,
+    challenge_mode: bool,
 ) {
     commands.entity(content_root).with_children(|content| {
         content
@@ -344,8 +389,66 @@ fn build_grid_under(
                                     },
                                     TextColor(Color::srgba(0.18, 0.17, 0.19, 0.85)),
                                 ));
+                                // Towers of this kind already built, filled in by
+                                // `update_tower_limit_displays`
+                                if let Some(kind) = tower_kind_for(def.id) {
+                                    card.spawn((
+                                        Text::new(""),
+                                        TextFont {
+                                            font: asset_server
+                                                .load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                                            font_size: 12.0,
+                                            ..default()
+                                        },
+                                        TextColor(Color::srgba(0.18, 0.17, 0.19, 0.85)),
+                                        TowerLimitDisplay(kind),
+                                    ));
+                                }
                             });
                         }
+                        // Emergency refund of every placed tower, behind a confirmation dialog.
+                        // Challenge mode allows no selling, so the card stays greyed out there.
+                        let (card_bg, label, ink) = if challenge_mode {
+                            (
+                                MARKET_CARD_DISABLED_BG,
+                                "SELL (disabled in challenge mode)",
+                                Color::srgba(0.18, 0.17, 0.19, 0.5),
+                            )
+                        } else {
+                            (
+                                Color::srgba(0.99, 0.93, 0.92, 0.95),
+                                "Sell all towers",
+                                Color::srgba(0.62, 0.12, 0.12, 1.0),
+                            )
+                        };
+                        let mut sell_card = grid.spawn((
+                            Node {
+                                width: Val::Px(120.0),
+                                height: Val::Px(120.0),
+                                padding: UiRect::all(Val::Px(8.0)),
+                                border: UiRect::all(Val::Px(2.0)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            BackgroundColor(card_bg),
+                            BorderColor::all(Color::srgba(0.62, 0.12, 0.12, 0.85)),
+                            SellAllTowersButton,
+                        ));
+                        if !challenge_mode {
+                            sell_card.insert(Button);
+                        }
+                        sell_card.with_children(|card| {
+                            card.spawn((
+                                Text::new(label),
+                                TextFont {
+                                    font: asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                                    font_size: 16.0,
+                                    ..default()
+                                },
+                                TextColor(ink),
+                            ));
+                        });
                     }
                     BuildCategory::Upgrades => {
                         for upgrade in catalog.upgrades.iter() {
@@ -353,7 +456,7 @@ fn build_grid_under(
                                 Button,
                                 Node {
                                     width: Val::Px(120.0),
-                                    height: Val::Px(120.0),
+                                    height: Val::Px(140.0),
                                     padding: UiRect::all(Val::Px(8.0)),
                                     border: UiRect::all(Val::Px(2.0)),
                                     flex_direction: FlexDirection::Column,
@@ -399,6 +502,51 @@ fn build_grid_under(
                                     },
                                     TextColor(Color::srgba(0.18, 0.17, 0.19, 0.85)),
                                 ));
+                                // Level, filled in by update_upgrade_level_displays
+                                card.spawn((
+                                    Text::new(""),
+                                    TextFont {
+                                        font: asset_server
+                                            .load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                                        font_size: 14.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgba(0.18, 0.17, 0.19, 0.85)),
+                                    UpgradeLevelDisplay(upgrade.tower_kind),
+                                ));
+                            });
+                        }
+                    }
+                    BuildCategory::Market => {
+                        for trade in [MarketTrade::WoodForRock, MarketTrade::RockForWood] {
+                            // Label and affordability are filled in by update_market_buttons
+                            grid.spawn((
+                                Button,
+                                Node {
+                                    width: Val::Px(180.0),
+                                    height: Val::Px(80.0),
+                                    padding: UiRect::all(Val::Px(8.0)),
+                                    border: UiRect::all(Val::Px(2.0)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                BackgroundColor(MARKET_CARD_BG),
+                                BorderColor::all(Color::srgba(0.18, 0.17, 0.19, 0.85)),
+                                MarketButton(trade),
+                            ))
+                            .with_children(|card| {
+                                card.spawn((
+                                    Text::new(""),
+                                    TextFont {
+                                        font: asset_server
+                                            .load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                                        font_size: 16.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgba(0.08, 0.09, 0.11, 1.0)),
+                                    MarketTradeLabel(trade),
+                                ));
                             });
                         }
                     }
@@ -410,9 +558,54 @@ fn build_grid_under(
 #[derive(Component, Clone, Copy)]
 pub struct BuildCard(pub BuildDefinitionId);
 
+/// Card in the Towers grid that sells every placed tower after confirmation.
+#[derive(Component)]
+pub struct SellAllTowersButton;
+
+/// "Sell all towers? Y/N" dialog shown over the build menu.
+#[derive(Component)]
+pub struct SellAllConfirmDialog;
+
+/// Yes (`true`) or No (`false`) button of the sell-all dialog.
+#[derive(Component, Clone, Copy)]
+pub struct SellAllConfirmButton(pub bool);
+
+/// Text on a tower card showing how many towers of its kind are built, out of the kind's limit.
+#[derive(Component, Clone, Copy)]
+pub struct TowerLimitDisplay(pub TowerKind);
+
 #[derive(Component, Clone, Copy)]
 pub struct UpgradeCard(pub BuildDefinitionId);
 
+/// Text on an upgrade card showing the purchased level of its tower kind.
+#[derive(Component, Clone, Copy)]
+pub struct UpgradeLevelDisplay(pub TowerKind);
+
+/// Market card for one trade direction. Loses its `Button` while the trade is unaffordable.
+#[derive(Component, Clone, Copy)]
+pub struct MarketButton(pub MarketTrade);
+
+/// Text on a market card showing the trade at its current price.
+#[derive(Component, Clone, Copy)]
+pub struct MarketTradeLabel(pub MarketTrade);
+
+const MARKET_CARD_BG: Color = Color::srgba(0.99, 0.99, 0.985, 0.95);
+const MARKET_CARD_DISABLED_BG: Color = Color::srgba(0.78, 0.78, 0.77, 0.95);
+/// Red of the "CHALLENGE MODE" banner, the same ink as the sell-all card.
+const CHALLENGE_BANNER_INK: Color = Color::srgba(0.62, 0.12, 0.12, 1.0);
+
+/// Tower kind placed by a Towers-category build definition.
+fn tower_kind_for(id: BuildDefinitionId) -> Option<TowerKind> {
+    match id.0 {
+        "bow_tower" => Some(TowerKind::Bow),
+        "crossbow_tower" => Some(TowerKind::Crossbow),
+        "lightning_tower" => Some(TowerKind::Lightning),
+        "catapult_tower" => Some(TowerKind::Catapult),
+        "cannon_tower" => Some(TowerKind::Cannon),
+        _ => None,
+    }
+}
+
 pub fn handle_item_selection(
     mut interactions: Query<(&Interaction, &BuildCard), (Changed<Interaction>, With<Button>)>,
     mut selection: ResMut<TowerBuildSelection>,
@@ -431,12 +624,7 @@ pub fn handle_item_selection(
         }
     }
     if let Some(id) = selected {
-        let tower_kind = match id.0 {
-            "bow_tower" => Some(TowerKind::Bow),
-            "crossbow_tower" => Some(TowerKind::Crossbow),
-            _ => None,
-        };
-        if let Some(kind) = tower_kind {
+        if let Some(kind) = tower_kind_for(id) {
             selection.choice = Some(kind);
             for mut bm in building_mode_q.iter_mut() {
                 bm.is_active = true;
@@ -452,90 +640,478 @@ pub fn handle_item_selection(
     }
 }
 
+/// Buys the clicked upgrade: charges its gold and silver cost, raises the tower kind's level
+/// (up to `TowerUpgradeConfig::max_level`) and applies the new stats to every tower of that kind
+/// already on the map.
+#[allow(clippy::too_many_arguments)]
 pub fn handle_upgrade_selection(
     mut interactions: Query<(&Interaction, &UpgradeCard), (Changed<Interaction>, With<Button>)>,
     catalog: Res<BuildCatalog>,
     mut upgrades: ResMut<TowerUpgrades>,
     upgrade_config: Res<TowerUpgradeConfig>,
+    tunables: Res<Tunables>,
     mut player_query: Query<(&mut Player, &Transform), With<Player>>,
-    mut towers_query: Query<(&mut Tower, &BuiltTower)>,
+    mut towers_query: Query<(Entity, &mut Tower, &BuiltTower)>,
+    specific_upgrades: Query<&TowerSpecificUpgrade>,
     mut building_sfx: MessageWriter<BuildingActionEvent>,
 ) {
     for (interaction, card) in interactions.iter_mut() {
-        if matches!(*interaction, Interaction::Pressed) {
-            // Find the upgrade definition
-            if let Some(upgrade_def) = catalog.upgrades.iter().find(|u| u.id == card.0) {
-                // Check if player can afford it
-                if let Ok((mut player, player_tf)) = player_query.single_mut() {
-                    if player.gold >= upgrade_def.gold_cost
-                        && player.silver >= upgrade_def.silver_cost
-                    {
-                        // Deduct resources
-                        player.gold -= upgrade_def.gold_cost;
-                        player.silver -= upgrade_def.silver_cost;
-
-                        // Apply upgrade
-                        match upgrade_def.tower_kind {
-                            TowerKind::Bow => {
-                                upgrades.bow_damage_level += 1;
-                            }
-                            TowerKind::Crossbow => {
-                                upgrades.crossbow_damage_level += 1;
-                            }
-                        }
+        if !matches!(*interaction, Interaction::Pressed) {
+            continue;
+        }
+        let Some(upgrade_def) = catalog.upgrades.iter().find(|u| u.id == card.0) else {
+            continue;
+        };
+        let Ok((mut player, player_tf)) = player_query.single_mut() else {
+            continue;
+        };
+        let kind = upgrade_def.tower_kind;
+        let affordable =
+            player.gold >= upgrade_def.gold_cost && player.silver >= upgrade_def.silver_cost;
+        if !affordable || upgrades.get_level(kind) >= upgrade_config.max_level {
+            building_sfx.write(BuildingActionEvent {
+                kind: BuildingActionKind::Invalid,
+                position: player_tf.translation,
+            });
+            continue;
+        }
 
-                        // Update all existing towers of this type using declarative config
-                        let level = upgrades.get_level(upgrade_def.tower_kind);
-                        let damage_bonus = upgrade_config.calculate_bonus(
-                            upgrade_def.tower_kind,
-                            UpgradeableStat::Damage,
-                            level,
-                        ) as u32;
-
-                        // Calculate other stat bonuses
-                        let range_bonus = upgrade_config.calculate_bonus(
-                            upgrade_def.tower_kind,
-                            UpgradeableStat::Range,
-                            level,
-                        );
-                        let fire_speed_bonus = upgrade_config.calculate_bonus(
-                            upgrade_def.tower_kind,
-                            UpgradeableStat::FireSpeed,
-                            level,
-                        );
-                        let projectile_speed_bonus = upgrade_config.calculate_bonus(
-                            upgrade_def.tower_kind,
-                            UpgradeableStat::ProjectileSpeed,
-                            level,
-                        );
-
-                        for (mut tower, built) in towers_query.iter_mut() {
-                            if built.kind == upgrade_def.tower_kind {
-                                // Calculate base stats from tower kind
-                                let (base_damage, base_fire_interval, base_projectile_speed) =
-                                    match upgrade_def.tower_kind {
-                                        TowerKind::Bow => (12, 0.7, 60.0),
-                                        TowerKind::Crossbow => (35, 2.4, 140.0),
-                                    };
-
-                                // Apply upgrades
-                                tower.damage = base_damage + damage_bonus;
-                                tower.range += range_bonus;
-                                tower.fire_interval_secs =
-                                    (base_fire_interval - fire_speed_bonus).max(0.1);
-                                tower.projectile_speed =
-                                    base_projectile_speed + projectile_speed_bonus;
-                            }
-                        }
+        player.gold -= upgrade_def.gold_cost;
+        player.silver -= upgrade_def.silver_cost;
+        let level = upgrades.increment_level(kind);
 
-                        // Emit upgrade SFX event at player position
-                        building_sfx.write(BuildingActionEvent {
-                            kind: BuildingActionKind::Upgrade,
-                            position: player_tf.translation,
-                        });
-                    }
+        // Recompute from base stats so repeated purchases never stack a bonus twice, then put
+        // back what was bought for single towers
+        let (stats, range) = upgrade_config.upgraded_stats(kind, level, tunables.tower_range);
+        for (entity, mut tower, built) in towers_query.iter_mut() {
+            if built.kind == kind {
+                tower.damage = stats.damage;
+                tower.range = range;
+                tower.fire_interval_secs = stats.fire_interval_secs;
+                tower.projectile_speed = stats.projectile_speed;
+                for upgrade in specific_upgrades
+                    .iter()
+                    .filter(|upgrade| upgrade.tower_entity == entity)
+                {
+                    upgrade.apply(&mut tower);
                 }
             }
         }
+
+        building_sfx.write(BuildingActionEvent {
+            kind: BuildingActionKind::Upgrade,
+            position: player_tf.translation,
+        });
+    }
+}
+
+/// Shows "Lv N/max" on upgrade cards when they are spawned and after every purchase.
+pub fn update_upgrade_level_displays(
+    upgrades: Res<TowerUpgrades>,
+    upgrade_config: Res<TowerUpgradeConfig>,
+    mut displays: Query<(Ref<UpgradeLevelDisplay>, &mut Text)>,
+) {
+    for (display, mut text) in displays.iter_mut() {
+        if !display.is_added() && !upgrades.is_changed() {
+            continue;
+        }
+        let level = upgrades.get_level(display.0);
+        text.0 = if level >= upgrade_config.max_level {
+            format!("Lv {} (max)", level)
+        } else {
+            format!("Lv {}/{}", level, upgrade_config.max_level)
+        };
+    }
+}
+
+/// Shows "Built: X / Y" on tower cards, or just "Built: X" for kinds without a limit.
+pub fn update_tower_limit_displays(
+    tunables: Res<Tunables>,
+    towers: Query<&BuiltTower>,
+    mut displays: Query<(&TowerLimitDisplay, &mut Text)>,
+) {
+    for (display, mut text) in displays.iter_mut() {
+        let built = towers.iter().filter(|t| t.kind == display.0).count();
+        let label = match tower_limit(display.0, &tunables) {
+            Some(limit) => format!("Built: {} / {}", built, limit),
+            None => format!("Built: {}", built),
+        };
+        if text.0 != label {
+            text.0 = label;
+        }
+    }
+}
+
+/// Executes the clicked market trade at the current price.
+#[allow(clippy::type_complexity)]
+pub fn handle_market_buttons(
+    interactions: Query<(&Interaction, &MarketButton), (Changed<Interaction>, With<Button>)>,
+    mut market: ResMut<MarketState>,
+    tunables: Res<Tunables>,
+    mut player_query: Query<&mut Player>,
+    mut transactions: MessageWriter<MarketTransactionEvent>,
+) {
+    for (interaction, button) in interactions.iter() {
+        if !matches!(*interaction, Interaction::Pressed) {
+            continue;
+        }
+        let Ok(mut player) = player_query.single_mut() else {
+            continue;
+        };
+        if let Some(paid) = market.execute(button.0, &mut player, &tunables) {
+            transactions.write(MarketTransactionEvent {
+                trade: button.0,
+                paid,
+                received: button.0.received(),
+            });
+        }
+    }
+}
+
+/// Keeps market labels at the current price and greys out (removes `Button` from) trades the
+/// player cannot afford.
+pub fn update_market_buttons(
+    market: Res<MarketState>,
+    tunables: Res<Tunables>,
+    player_query: Query<&Player>,
+    mut buttons: Query<(Entity, &MarketButton, Has<Button>, &mut BackgroundColor)>,
+    mut labels: Query<(&MarketTradeLabel, &mut Text, &mut TextColor)>,
+    mut commands: Commands,
+) {
+    let Ok(player) = player_query.single() else {
+        return;
+    };
+    for (entity, button, enabled, mut bg) in buttons.iter_mut() {
+        let affordable = market.can_afford(button.0, player, &tunables);
+        if affordable != enabled {
+            if affordable {
+                commands.entity(entity).insert(Button);
+            } else {
+                commands.entity(entity).remove::<Button>();
+            }
+        }
+        let color = if affordable {
+            MARKET_CARD_BG
+        } else {
+            MARKET_CARD_DISABLED_BG
+        };
+        if bg.0 != color {
+            bg.0 = color;
+        }
+    }
+    for (label, mut text, mut color) in labels.iter_mut() {
+        let value = label.0.label(market.cost(label.0, &tunables));
+        if text.0 != value {
+            text.0 = value;
+        }
+        let ink = if market.can_afford(label.0, player, &tunables) {
+            Color::srgba(0.08, 0.09, 0.11, 1.0)
+        } else {
+            Color::srgba(0.18, 0.17, 0.19, 0.5)
+        };
+        if color.0 != ink {
+            color.0 = ink;
+        }
+    }
+}
+
+/// Opens the sell-all confirmation dialog on top of the build menu.
+pub fn handle_sell_all_button(
+    interactions: Query<&Interaction, (Changed<Interaction>, With<SellAllTowersButton>)>,
+    dialogs_q: Query<(), With<SellAllConfirmDialog>>,
+    roots_q: Query<Entity, With<BuildMenuRoot>>,
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+) {
+    let pressed = interactions
+        .iter()
+        .any(|i| matches!(*i, Interaction::Pressed));
+    if !pressed || !dialogs_q.is_empty() {
+        return;
+    }
+    let Ok(root) = roots_q.single() else {
+        return;
+    };
+
+    let font = asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf");
+    let dialog = commands
+        .spawn((
+            SellAllConfirmDialog,
+            Node {
+                position_type: PositionType::Absolute,
+                padding: UiRect::all(Val::Px(16.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.97, 0.975, 0.965, 1.0)),
+            BorderColor::all(Color::srgba(0.18, 0.17, 0.19, 1.0)),
+            GlobalZIndex(5),
+        ))
+        .with_children(|dialog| {
+            dialog.spawn((
+                Text::new("Sell all towers? Y/N"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(0.08, 0.09, 0.11, 1.0)),
+            ));
+            dialog
+                .spawn(Node {
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                })
+                .with_children(|buttons| {
+                    for (confirm, label) in [(true, "Yes [Y]"), (false, "No [N]")] {
+                        buttons
+                            .spawn((
+                                Button,
+                                Node {
+                                    padding: UiRect::all(Val::Px(10.0)),
+                                    border: UiRect::all(Val::Px(2.0)),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgba(0.98, 0.98, 0.975, 0.9)),
+                                BorderColor::all(Color::srgba(0.18, 0.17, 0.19, 0.9)),
+                                SellAllConfirmButton(confirm),
+                            ))
+                            .with_children(|b| {
+                                b.spawn((
+                                    Text::new(label),
+                                    TextFont {
+                                        font: font.clone(),
+                                        font_size: 18.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgba(0.08, 0.09, 0.11, 1.0)),
+                                ));
+                            });
+                    }
+                });
+        })
+        .id();
+    commands.entity(root).add_child(dialog);
+}
+
+/// Answers the sell-all dialog by button or Y/N key. Confirming despawns every tower, refunds
+/// `TowerKind::sell_refund` for each and closes the build menu.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_sell_all_confirmation(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    interactions: Query<(&Interaction, &SellAllConfirmButton), Changed<Interaction>>,
+    dialogs_q: Query<Entity, With<SellAllConfirmDialog>>,
+    towers_q: Query<(Entity, &Transform, &BuiltTower)>,
+    mut player_q: Query<&mut Player>,
+    mut menu_state: ResMut<BuildMenuState>,
+    roots_q: Query<Entity, With<BuildMenuRoot>>,
+    children_q: Query<&Children>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut building_sfx: MessageWriter<BuildingActionEvent>,
+    mut commands: Commands,
+) {
+    if dialogs_q.is_empty() {
+        return;
+    }
+    let mut answer = interactions
+        .iter()
+        .find(|(i, _)| matches!(*i, Interaction::Pressed))
+        .map(|(_, button)| button.0);
+    if keyboard.just_pressed(KeyCode::KeyY) {
+        answer = Some(true);
+    } else if keyboard.just_pressed(KeyCode::KeyN) {
+        answer = Some(false);
+    }
+    let Some(confirmed) = answer else {
+        return;
+    };
+
+    if !confirmed {
+        for dialog in dialogs_q.iter() {
+            despawn_entity_recursive(&mut commands, dialog, &children_q);
+        }
+        return;
+    }
+
+    // Labels are children and will be automatically despawned with the towers
+    for (entity, transform, _) in towers_q.iter() {
+        commands.entity(entity).despawn();
+        building_sfx.write(BuildingActionEvent {
+            kind: BuildingActionKind::Sell,
+            position: transform.translation,
+        });
+    }
+    let (wood, rock) = total_sell_refund(towers_q.iter().map(|(_, _, built)| built.kind));
+    if let Ok(mut player) = player_q.single_mut() {
+        player.wood = player.wood.saturating_add(wood);
+        player.rock = player.rock.saturating_add(rock);
+    }
+
+    *menu_state = BuildMenuState::Closed;
+    next_state.set(GameState::Playing);
+    for e in roots_q.iter() {
+        despawn_entity_recursive(&mut commands, e, &children_q);
+    }
+}
+
+/// Summed (wood, rock) refund for selling towers of the given kinds, each rounded on its own.
+pub fn total_sell_refund(kinds: impl IntoIterator<Item = TowerKind>) -> (u32, u32) {
+    kinds.into_iter().fold((0, 0), |(wood, rock), kind| {
+        let (w, r) = kind.sell_refund();
+        (wood.saturating_add(w), rock.saturating_add(r))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn tower_limit_display_counts_built_towers() {
+        let mut world = World::new();
+        world.insert_resource(Tunables {
+            max_bow_towers: 4,
+            ..Tunables::default()
+        });
+        world.spawn(BuiltTower {
+            kind: TowerKind::Bow,
+        });
+        world.spawn(BuiltTower {
+            kind: TowerKind::Bow,
+        });
+        world.spawn(BuiltTower {
+            kind: TowerKind::Crossbow,
+        });
+        let bow = world
+            .spawn((TowerLimitDisplay(TowerKind::Bow), Text::default()))
+            .id();
+        let cannon = world
+            .spawn((TowerLimitDisplay(TowerKind::Cannon), Text::default()))
+            .id();
+
+        world.run_system_once(update_tower_limit_displays).unwrap();
+        assert_eq!(world.get::<Text>(bow).unwrap().0, "Built: 2 / 4");
+        assert_eq!(world.get::<Text>(cannon).unwrap().0, "Built: 0");
+    }
+
+    #[test]
+    fn sell_all_refund_rounds_each_tower_down() {
+        // Bow (3, 1) refunds (1, 0); Crossbow (10, 3) refunds (5, 1)
+        let kinds = [TowerKind::Bow, TowerKind::Crossbow, TowerKind::Bow];
+        assert_eq!(total_sell_refund(kinds), (7, 1));
+        assert_eq!(total_sell_refund([]), (0, 0));
+    }
+
+    #[test]
+    fn confirming_sell_all_refunds_and_removes_every_tower() {
+        let mut world = World::new();
+        let mut keyboard = ButtonInput::<KeyCode>::default();
+        keyboard.press(KeyCode::KeyY);
+        world.insert_resource(keyboard);
+        world.insert_resource(BuildMenuState::Open);
+        world.init_resource::<NextState<GameState>>();
+        world.init_resource::<Messages<BuildingActionEvent>>();
+        world.spawn(SellAllConfirmDialog);
+        let player = world
+            .spawn(Player {
+                wood: 2,
+                rock: 0,
+                silver: 0,
+                gold: 0,
+            })
+            .id();
+        let towers: Vec<Entity> = [TowerKind::Bow, TowerKind::Bow, TowerKind::Crossbow]
+            .into_iter()
+            .map(|kind| {
+                world
+                    .spawn((Transform::default(), BuiltTower { kind }))
+                    .id()
+            })
+            .collect();
+
+        world.run_system_once(handle_sell_all_confirmation).unwrap();
+
+        let player = world.get::<Player>(player).unwrap();
+        assert_eq!((player.wood, player.rock), (2 + 7, 1));
+        assert!(towers.iter().all(|tower| world.get_entity(*tower).is_err()));
+        assert_eq!(world.resource::<Messages<BuildingActionEvent>>().len(), 3);
+        assert_eq!(*world.resource::<BuildMenuState>(), BuildMenuState::Closed);
+    }
+
+    #[test]
+    fn buying_two_upgrade_levels_doubles_the_bonus_and_charges_for_both() {
+        let mut world = World::new();
+        let mut catalog = BuildCatalog::default();
+        catalog.ensure_defaults();
+        let upgrade = catalog
+            .upgrades
+            .iter()
+            .find(|u| u.tower_kind == TowerKind::Bow)
+            .unwrap()
+            .clone();
+        world.insert_resource(catalog);
+        world.init_resource::<TowerUpgrades>();
+        world.init_resource::<TowerUpgradeConfig>();
+        world.init_resource::<Tunables>();
+        world.init_resource::<Messages<BuildingActionEvent>>();
+        let player = world
+            .spawn((
+                Player {
+                    gold: 100,
+                    silver: 100,
+                    ..Player::starting()
+                },
+                Transform::default(),
+            ))
+            .id();
+        let base = TowerKind::Bow.stats();
+        let tower = world
+            .spawn((
+                Tower {
+                    range: 10.0,
+                    damage: base.damage,
+                    fire_interval_secs: base.fire_interval_secs,
+                    height: 1.0,
+                    width: 1.0,
+                    depth: 1.0,
+                    projectile_speed: base.projectile_speed,
+                    last_shot: 0.0,
+                },
+                BuiltTower {
+                    kind: TowerKind::Bow,
+                },
+            ))
+            .id();
+        let card = world
+            .spawn((Button, Interaction::Pressed, UpgradeCard(upgrade.id)))
+            .id();
+
+        for _ in 0..2 {
+            // Pressing again marks the interaction changed for the next run
+            *world.get_mut::<Interaction>(card).unwrap() = Interaction::Pressed;
+            world.run_system_once(handle_upgrade_selection).unwrap();
+        }
+
+        assert_eq!(
+            world.resource::<TowerUpgrades>().get_level(TowerKind::Bow),
+            2
+        );
+        let config = world.resource::<TowerUpgradeConfig>();
+        let one = config.calculate_bonus(TowerKind::Bow, upgrade.stat, 1);
+        let two = config.calculate_bonus(TowerKind::Bow, upgrade.stat, 2);
+        assert!(one > 0.0);
+        assert_eq!(two, one * 2.0);
+        assert_eq!(
+            world.get::<Tower>(tower).unwrap().damage,
+            base.damage + two as u32
+        );
+        let player = world.get::<Player>(player).unwrap();
+        assert_eq!(player.gold, 100 - upgrade.gold_cost * 2);
+        assert_eq!(player.silver, 100 - upgrade.silver_cost * 2);
     }
 }
@@ -1,5 +1,28 @@
+use crate::core::grid::ChunkCoord;
 use bevy::prelude::*;
+use std::collections::HashMap;
 
 /// Marker for a chunk root entity (used by chunk loading/unloading systems).
 #[derive(Component, Debug, Clone, Copy)]
 pub struct ChunkRoot;
+
+/// Terrain biome of a chunk. Terrain generation doesn't assign Desert/Snow yet, so every chunk
+/// is currently grassland.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TerrainBiome {
+    #[default]
+    Grassland,
+    Desert,
+    Snow,
+}
+
+/// Biome assigned to each chunk; chunks without an entry are grassland.
+#[derive(Resource, Default)]
+pub struct ChunkBiomes(pub HashMap<ChunkCoord, TerrainBiome>);
+
+impl ChunkBiomes {
+    pub fn biome_of(&self, coord: ChunkCoord) -> TerrainBiome {
+        self.0.get(&coord).copied().unwrap_or_default()
+    }
+}
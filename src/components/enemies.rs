@@ -1,19 +1,79 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component, Serialize, Deserialize)]
 pub enum EnemyKind {
     Minion,
     Zombie,
     Boss,
+    /// Support unit that restores HP of nearby enemies.
+    Healer,
+}
+
+/// Base stats for an enemy kind before wave multipliers are applied.
+#[derive(Debug, Clone, Copy)]
+pub struct EnemyStats {
+    pub health: u32,
+    /// Damage dealt to the village on impact.
+    pub damage: u32,
+    pub speed: f32,
+    /// Cube edge length in world units.
+    pub size: f32,
 }
 
 impl EnemyKind {
-    /// Returns (hp, damage_to_village, speed, cube_size)
-    pub fn stats(self) -> (u32, u32, f32, f32) {
+    pub const ALL: [EnemyKind; 4] = [
+        EnemyKind::Minion,
+        EnemyKind::Zombie,
+        EnemyKind::Boss,
+        EnemyKind::Healer,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            EnemyKind::Minion => "Minion",
+            EnemyKind::Zombie => "Zombie",
+            EnemyKind::Boss => "Boss",
+            EnemyKind::Healer => "Healer",
+        }
+    }
+
+    /// Body color of the enemy's material (Minions may be reskinned by biome).
+    pub fn color(self) -> Color {
+        match self {
+            EnemyKind::Minion => Color::srgb(0.9, 0.1, 0.1),
+            EnemyKind::Zombie => Color::srgb(0.2, 0.8, 0.2),
+            EnemyKind::Boss => Color::srgb(0.6, 0.1, 0.8),
+            EnemyKind::Healer => Color::srgb(0.95, 0.75, 0.2),
+        }
+    }
+
+    pub fn stats(self) -> EnemyStats {
         match self {
-            EnemyKind::Minion => (30, 5, 24.0, 0.8),
-            EnemyKind::Zombie => (50, 10, 18.0, 1.2),
-            EnemyKind::Boss => (100, 50, 12.0, 1.8),
+            EnemyKind::Minion => EnemyStats {
+                health: 30,
+                damage: 5,
+                speed: 24.0,
+                size: 0.8,
+            },
+            EnemyKind::Zombie => EnemyStats {
+                health: 50,
+                damage: 10,
+                speed: 18.0,
+                size: 1.2,
+            },
+            EnemyKind::Boss => EnemyStats {
+                health: 1000,
+                damage: 50,
+                speed: 4.0,
+                size: 2.5,
+            },
+            EnemyKind::Healer => EnemyStats {
+                health: 40,
+                damage: 5,
+                speed: 20.0,
+                size: 1.0,
+            },
         }
     }
 }
@@ -38,3 +98,131 @@ pub struct EnemyHealthBarFill {
     pub owner: Entity,
     pub last_ratio: f32,
 }
+
+/// Shield carried by Boss enemies; soaks up damage before their health is touched.
+/// Removed (with a flash) once `absorb_remaining` reaches zero.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct BossShield {
+    pub absorb_remaining: u32,
+    /// Shield strength at spawn, for the shield bar.
+    pub capacity: u32,
+}
+
+impl BossShield {
+    pub fn new(capacity: u32) -> Self {
+        BossShield {
+            absorb_remaining: capacity,
+            capacity,
+        }
+    }
+
+    /// Absorbs as much of `damage` as the shield has left and returns the rest.
+    pub fn absorb(&mut self, damage: u32) -> u32 {
+        let absorbed = damage.min(self.absorb_remaining);
+        self.absorb_remaining -= absorbed;
+        damage - absorbed
+    }
+}
+
+/// Blue shield bar drawn over a Boss's health bar.
+#[derive(Component)]
+pub struct BossShieldBarFill {
+    pub max_width: f32,
+    pub owner: Entity,
+    pub last_ratio: f32,
+}
+
+/// Healing aura carried by Healer enemies; restores HP of other enemies within `radius`
+/// every time `timer` finishes.
+#[derive(Component)]
+pub struct HealAura {
+    pub radius: f32,
+    pub heal_per_second: f32,
+    pub timer: Timer,
+}
+
+/// Non-leader member of a spawn group. Walks toward its leader's position plus `offset`
+/// (x = right, y = forward in the leader's heading frame) instead of following the road.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct FormationMember {
+    pub leader: Entity,
+    pub offset: Vec2,
+}
+
+/// Minion moving in a group of at least three; its speed is multiplied by `multiplier`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SwarmBoost {
+    pub multiplier: f32,
+}
+
+/// Movement slow; speed is multiplied by `multiplier` while present. A slow from a hit ends
+/// with its `timer`; one without a timer (the village shield's field) lasts until its source
+/// removes it.
+#[derive(Component, Debug, Clone)]
+pub struct SlowEffect {
+    pub multiplier: f32,
+    pub timer: Option<Timer>,
+}
+
+/// Damage over time: `dps` is dealt in steps every time `tick_timer` finishes until `timer` ends.
+#[derive(Component, Debug, Clone)]
+pub struct BurnEffect {
+    pub dps: f32,
+    pub timer: Timer,
+    pub tick_timer: Timer,
+    /// Damage below one whole point carried over to the next tick.
+    pub carry: f32,
+}
+
+/// Enemy cannot move until `timer` ends.
+#[derive(Component, Debug, Clone)]
+pub struct StunEffect {
+    pub timer: Timer,
+}
+
+/// Enemy repelled by the village shield; walks away from the village until the timer ends.
+#[derive(Component)]
+pub struct BouncedBack {
+    pub timer: Timer,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats_tuple(kind: EnemyKind) -> (u32, u32, f32, f32) {
+        let stats = kind.stats();
+        (stats.health, stats.damage, stats.speed, stats.size)
+    }
+
+    #[test]
+    fn each_kind_keeps_its_stats() {
+        assert_eq!(stats_tuple(EnemyKind::Minion), (30, 5, 24.0, 0.8));
+        assert_eq!(stats_tuple(EnemyKind::Zombie), (50, 10, 18.0, 1.2));
+        assert_eq!(stats_tuple(EnemyKind::Boss), (1000, 50, 4.0, 2.5));
+        assert_eq!(stats_tuple(EnemyKind::Healer), (40, 5, 20.0, 1.0));
+    }
+
+    #[test]
+    fn shield_absorbs_damage_until_it_breaks() {
+        let mut shield = BossShield::new(200);
+        assert_eq!(shield.absorb(80), 0);
+        assert_eq!(shield.absorb(80), 0);
+        assert_eq!(
+            shield.absorb(80),
+            40,
+            "only what the shield can't hold passes"
+        );
+        assert_eq!(shield.absorb_remaining, 0);
+        assert_eq!(shield.absorb(80), 80);
+        assert_eq!(shield.capacity, 200);
+    }
+
+    #[test]
+    fn shield_absorbs_an_exact_hit_fully() {
+        let mut shield = BossShield::new(200);
+        assert_eq!(shield.absorb(200), 0);
+        assert_eq!(shield.absorb_remaining, 0);
+        assert_eq!(shield.absorb(0), 0);
+    }
+}
@@ -9,6 +9,57 @@ pub struct Player {
     pub gold: u64,
 }
 
+impl Player {
+    /// Inventory a new run starts with (plenty of everything with the `rich` feature).
+    pub fn starting() -> Self {
+        let amount = if cfg!(feature = "rich") { 1000 } else { 0 };
+        Self {
+            wood: amount,
+            rock: amount,
+            silver: amount as u64,
+            gold: amount as u64,
+        }
+    }
+}
+
+/// Auto-collect state on the player: while `active`, the nearest tree (and rock, with
+/// `Tunables.auto_collect_rocks`) within `radius` is gathered at the normal hold-to-collect pace.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct AutoCollect {
+    pub active: bool,
+    pub radius: f32,
+}
+
+/// Faint ring under the player showing the auto-collect reach while it is active.
+#[derive(Component)]
+pub struct AutoCollectRing {
+    pub material: Handle<StandardMaterial>,
+}
+
+/// Currencies earned from kills, shown under wood and rock in the resource counters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CurrencyKind {
+    Silver,
+    Gold,
+}
+
+impl CurrencyKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            CurrencyKind::Silver => "Silver",
+            CurrencyKind::Gold => "Gold",
+        }
+    }
+
+    /// How much of this currency `player` holds.
+    pub fn amount(self, player: &Player) -> u64 {
+        match self {
+            CurrencyKind::Silver => player.silver,
+            CurrencyKind::Gold => player.gold,
+        }
+    }
+}
+
 /// Marker for the 3D player entity used in the world.
 #[derive(Component)]
 pub struct IsoPlayer;
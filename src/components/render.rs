@@ -3,3 +3,11 @@ use bevy::prelude::*;
 /// Marker for entities that should not be hidden by distance-based culling.
 #[derive(Component)]
 pub struct NoDistanceCull;
+
+/// The ground plane, recolored by the day/night cycle.
+#[derive(Component)]
+pub struct Ground;
+
+/// The directional light standing in for the sun, dimmed by the day/night cycle.
+#[derive(Component)]
+pub struct SunLight;
@@ -1,3 +1,4 @@
+use crate::materials::RoadMaterial;
 use bevy::prelude::*;
 
 /// World-space road paths used for AI/path-following.
@@ -7,9 +8,35 @@ pub struct RoadPaths {
     pub roads: Vec<Vec<Vec3>>,
 }
 
+/// Straight stretch of road between two waypoints, `end` being the one toward the village. Its
+/// patches share `material`, whose scroll speed follows the enemies walking on it.
+#[derive(Component, Debug, Clone)]
+pub struct RoadSegment {
+    pub start: Vec3,
+    pub end: Vec3,
+    pub material: Handle<RoadMaterial>,
+}
+
+/// Small plaza just outside the gate where the roads of a multi-road map merge.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct RoadJunctionPoint {
+    #[allow(dead_code)]
+    pub position: Vec3,
+}
+
 /// Component for entities that follow a given `RoadPaths` entry.
 #[derive(Component, Debug, Clone, Copy)]
 pub struct PathFollower {
     pub road_index: usize,
     pub next_index: usize,
+    /// Progress from the previous waypoint toward `next_index` (0..1), projected onto the segment.
+    pub path_t: f32,
+}
+
+/// Per-enemy path computed at runtime (e.g., rerouted around a newly built tower).
+/// Takes precedence over `PathFollower` while present.
+#[derive(Component, Debug, Clone)]
+pub struct DynamicPath {
+    pub waypoints: Vec<Vec3>,
+    pub next_index: usize,
 }
@@ -4,6 +4,7 @@ use bevy::prelude::*;
 ///
 /// - Menu: main menu and non-gameplay screens
 /// - Playing: active gameplay loop
+/// - GameOver: the run has ended (village fallen or `max_waves` survived)
 #[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
 pub enum GameState {
     Loading,
@@ -11,4 +12,30 @@ pub enum GameState {
     Menu,
     Playing,
     Paused,
+    GameOver,
+}
+
+/// Fast-forward / slow-motion multiplier on gameplay time. It sets the relative speed of
+/// `Time<Virtual>`, so every system reading `Time` runs at it. `+` / `-` step it by `STEP` within
+/// `MIN..=MAX`, `0` resets it.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct GameSpeed {
+    pub multiplier: f32,
+}
+
+impl Default for GameSpeed {
+    fn default() -> Self {
+        Self { multiplier: 1.0 }
+    }
+}
+
+impl GameSpeed {
+    pub const MIN: f32 = 0.25;
+    pub const MAX: f32 = 4.0;
+    pub const STEP: f32 = 0.25;
+
+    /// Moves the multiplier by `steps` of `STEP`, clamped to `MIN..=MAX`.
+    pub fn step(&mut self, steps: i32) {
+        self.multiplier = (self.multiplier + steps as f32 * Self::STEP).clamp(Self::MIN, Self::MAX);
+    }
 }
@@ -1,17 +1,127 @@
+use crate::components::enemies::EnemyKind;
+use crate::constants::Tunables;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Different kinds of towers selectable by the player.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum TowerKind {
     Bow,
     Crossbow,
+    /// Instant hitscan bolt that chains to nearby enemies.
+    Lightning,
+    /// Slow boulder that splashes damage around its impact point.
+    Catapult,
+    /// Heavy shell that splashes high damage around its impact point.
+    Cannon,
+}
+
+/// Base combat stats for a tower kind before upgrades are applied.
+#[derive(Debug, Clone, Copy)]
+pub struct TowerStats {
+    pub damage: u32,
+    pub fire_interval_secs: f32,
+    /// Zero for hitscan towers.
+    pub projectile_speed: f32,
 }
 
 impl TowerKind {
+    pub const ALL: [TowerKind; 5] = [
+        TowerKind::Bow,
+        TowerKind::Crossbow,
+        TowerKind::Lightning,
+        TowerKind::Catapult,
+        TowerKind::Cannon,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            TowerKind::Bow => "Bow",
+            TowerKind::Crossbow => "Crossbow",
+            TowerKind::Lightning => "Lightning",
+            TowerKind::Catapult => "Catapult",
+            TowerKind::Cannon => "Cannon",
+        }
+    }
+
+    pub fn stats(self) -> TowerStats {
+        let (damage, fire_interval_secs, projectile_speed) = match self {
+            // Bow: slower projectiles
+            TowerKind::Bow => (12, 0.7, 60.0),
+            // Crossbow: much faster projectiles
+            TowerKind::Crossbow => (35, 2.4, 140.0),
+            // Lightning: hitscan so projectile speed is unused
+            TowerKind::Lightning => (18, 1.6, 0.0),
+            // Catapult: slow boulders that splash on impact
+            TowerKind::Catapult => (30, 3.2, 28.0),
+            // Cannon: heavy shells, the slowest rate of fire
+            TowerKind::Cannon => (80, 3.5, 30.0),
+        };
+        TowerStats {
+            damage,
+            fire_interval_secs,
+            projectile_speed,
+        }
+    }
+
     pub fn cost(self) -> (u32, u32) {
         match self {
             TowerKind::Bow => (3, 1),
             TowerKind::Crossbow => (10, 3),
+            TowerKind::Lightning => (12, 6),
+            TowerKind::Catapult => (15, 10),
+            TowerKind::Cannon => (20, 14),
+        }
+    }
+
+    /// (wood, rock) returned when a tower of this kind is sold: half its cost, rounded down.
+    pub fn sell_refund(self) -> (u32, u32) {
+        let (wood, rock) = self.cost();
+        (wood / 2, rock / 2)
+    }
+
+    /// (wood, rock) forfeited for a failed placement of this kind: `pct` of its cost, rounded
+    /// down.
+    pub fn misplace_penalty(self, pct: f32) -> (u32, u32) {
+        let (wood, rock) = self.cost();
+        let pct = pct.clamp(0.0, 1.0);
+        ((wood as f32 * pct) as u32, (rock as f32 * pct) as u32)
+    }
+
+    /// (wood, rock) to convert a tower of this kind into `target`: the cost difference plus
+    /// `premium` of it, rounded up. Converting to a cheaper kind is free but refunds nothing.
+    pub fn conversion_cost(self, target: TowerKind, premium: f32) -> (u32, u32) {
+        let (wood, rock) = self.cost();
+        let (target_wood, target_rock) = target.cost();
+        let charge = |diff: u32| (diff as f32 * (1.0 + premium)).ceil() as u32;
+        (
+            charge(target_wood.saturating_sub(wood)),
+            charge(target_rock.saturating_sub(rock)),
+        )
+    }
+
+    /// Radius around the impact point that takes full damage, for towers whose shots splash.
+    pub fn splash_radius(self, tunables: &Tunables) -> Option<f32> {
+        match self {
+            TowerKind::Catapult => Some(tunables.catapult_splash_radius),
+            TowerKind::Cannon => Some(tunables.cannon_splash_radius),
+            TowerKind::Bow | TowerKind::Crossbow | TowerKind::Lightning => None,
+        }
+    }
+
+    /// Absolute (width, height, depth) of the tower body in world units.
+    pub fn size(self) -> (f32, f32, f32) {
+        match self {
+            // Bow: smaller
+            TowerKind::Bow => (1.02, 2.72, 1.02),
+            // Crossbow: bigger
+            TowerKind::Crossbow => (1.38, 3.68, 1.38),
+            // Lightning: slender and tall
+            TowerKind::Lightning => (1.2, 4.2, 1.2),
+            // Catapult: wide and squat
+            TowerKind::Catapult => (1.8, 2.4, 1.8),
+            // Cannon: wide like the Catapult but taller
+            TowerKind::Cannon => (1.8, 3.0, 1.8),
         }
     }
 }
@@ -40,6 +150,55 @@ pub struct BuiltTower {
     pub kind: TowerKind,
 }
 
+/// Enemies this tower has killed, shown in the tower inspection popup.
+#[derive(Component, Copy, Clone, Debug, Default)]
+pub struct TowerKillCount(pub u32);
+
+/// How a tower picks its target among enemies in range.
+#[derive(Component, Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum TargetingMode {
+    /// Shoot the nearest enemy.
+    #[default]
+    Nearest,
+    /// Shoot the enemy furthest away while still in range.
+    Furthest,
+    /// Shoot the enemy with the least HP left; ties go to the nearest.
+    LowestHp,
+    /// Shoot the enemy with the most HP left; ties go to the nearest.
+    HighestHp,
+    /// Prefer high-value targets (Healers) in range, falling back to the nearest enemy.
+    Priority,
+}
+
+impl TargetingMode {
+    pub fn next(self) -> Self {
+        match self {
+            TargetingMode::Nearest => TargetingMode::Furthest,
+            TargetingMode::Furthest => TargetingMode::LowestHp,
+            TargetingMode::LowestHp => TargetingMode::HighestHp,
+            TargetingMode::HighestHp => TargetingMode::Priority,
+            TargetingMode::Priority => TargetingMode::Nearest,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TargetingMode::Nearest => "Nearest",
+            TargetingMode::Furthest => "Furthest",
+            TargetingMode::LowestHp => "Lowest HP",
+            TargetingMode::HighestHp => "Highest HP",
+            TargetingMode::Priority => "Healers first",
+        }
+    }
+}
+
+/// Per-tower lock onto one enemy kind. When set, the tower only considers enemies of that kind
+/// (still ordered by its `TargetingMode`) and falls back to all kinds if none are in range.
+#[derive(Component, Copy, Clone, Debug, Default)]
+pub struct TowerPriorityKind {
+    pub kind: Option<EnemyKind>,
+}
+
 /// Marker for the in-progress tower preview (ghost).
 #[derive(Component)]
 pub struct TowerGhost;
@@ -60,3 +219,23 @@ pub struct TowerDamageLabel {
 /// Marker on the tower entity indicating a damage label has been spawned.
 #[derive(Component)]
 pub struct HasTowerDamageLabel;
+
+/// Marker on built towers inside the camera's view frustum, kept up to date by
+/// `frustum_cull_towers_system`. Only these get their damage label placed each frame.
+#[derive(Component)]
+pub struct FrustumCulledTower;
+
+/// Damage bonus of a tower paired with complementary towers nearby (a Bow with Crossbows, or the
+/// other way round). `damage_multiplier` already includes every stacked partner; `label` names
+/// the combo.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ComboBonus {
+    pub damage_multiplier: f32,
+    pub label: &'static str,
+}
+
+/// Screen-space combo label above `tower_entity`, shown while it has a `ComboBonus`.
+#[derive(Component)]
+pub struct TowerComboLabel {
+    pub tower_entity: Entity,
+}
@@ -7,6 +7,46 @@ pub struct Village {
     pub max_health: u32,
 }
 
+impl Village {
+    /// Remaining health as a fraction of `max_health`.
+    pub fn health_ratio(&self) -> f32 {
+        self.health as f32 / self.max_health.max(1) as f32
+    }
+}
+
+/// Purchasable village shield that blocks enemy damage while active. Usable once per wave.
+#[derive(Resource, Debug)]
+pub struct VillageShield {
+    pub active: bool,
+    pub duration: Timer,
+    /// Wave number in which the shield was last activated.
+    pub last_used_wave: Option<u32>,
+}
+
+impl VillageShield {
+    pub fn new(duration_secs: f32) -> Self {
+        VillageShield {
+            active: false,
+            duration: Timer::from_seconds(duration_secs, TimerMode::Once),
+            last_used_wave: None,
+        }
+    }
+
+    /// Whether the shield can still be activated during `wave`.
+    pub fn is_available(&self, wave: u32) -> bool {
+        !self.active && self.last_used_wave != Some(wave)
+    }
+
+    /// Activates the shield for `duration_secs`, read fresh so tunable changes apply.
+    pub fn activate(&mut self, wave: u32, duration_secs: f32) {
+        self.active = true;
+        self.last_used_wave = Some(wave);
+        self.duration
+            .set_duration(std::time::Duration::from_secs_f32(duration_secs.max(0.0)));
+        self.duration.reset();
+    }
+}
+
 /// Global toggle for building placement mode.
 #[derive(Component)]
 pub struct BuildingMode {
@@ -32,3 +72,41 @@ pub struct TownSquareCenter(pub Vec3);
 
 #[derive(Component)]
 pub struct Wall;
+
+/// Remaining hit points of a wall segment; the wall falls when it reaches zero.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct WallHealth {
+    pub hp: u32,
+}
+
+/// Rubble left where a wall fell, holding what is needed to rebuild it.
+#[derive(Component, Debug, Clone)]
+pub struct WallBreachMarker {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+    /// Transform of the wall that fell.
+    pub wall_transform: Transform,
+    /// World-space (min, max) bounds of the wall that fell, as in `StaticObstacleAABBs`.
+    pub bounds: (Vec3, Vec3),
+    /// Point just inside the town that enemies walk for to get through the breach.
+    pub entry: Vec3,
+}
+
+/// An open breach enemies can walk through instead of the gate.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct WallBreached;
+
+/// Enemy stopped at a wall, hitting it for `Enemy::damage` per second.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct AttackingWall {
+    pub wall: Entity,
+    /// Damage dealt but not yet taken off the wall's whole hit points.
+    pub pending_damage: f32,
+}
+
+/// World-space (min, max) bounds of the walls, village and town square, computed from their
+/// meshes once the town is built. Towers cannot be placed inside any of them.
+#[derive(Resource, Debug, Default)]
+pub struct StaticObstacleAABBs {
+    pub boxes: Vec<(Vec3, Vec3)>,
+}
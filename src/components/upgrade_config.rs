@@ -1,9 +1,10 @@
-use crate::components::towers::TowerKind;
+use crate::components::towers::{TowerKind, TowerStats};
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Stat types that can be upgraded.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum UpgradeableStat {
     Damage,
     Range,
@@ -11,6 +12,18 @@ pub enum UpgradeableStat {
     ProjectileSpeed,
 }
 
+impl UpgradeableStat {
+    /// Lowercase name for UI text, e.g. "+5 damage".
+    pub fn label(self) -> &'static str {
+        match self {
+            UpgradeableStat::Damage => "damage",
+            UpgradeableStat::Range => "range",
+            UpgradeableStat::FireSpeed => "fire speed",
+            UpgradeableStat::ProjectileSpeed => "projectile speed",
+        }
+    }
+}
+
 /// Configuration for how upgrades affect a tower's stats.
 ///
 /// Each field represents the bonus per upgrade level.
@@ -85,10 +98,15 @@ pub enum UpgradeMode {
     Linear(TowerUpgradeBonuses),
 }
 
+/// Upgrade levels that can be bought per tower type unless configured otherwise.
+const DEFAULT_MAX_LEVEL: u32 = 5;
+
 /// Resource that stores upgrade bonus configurations for each tower type.
 #[derive(Resource)]
 pub struct TowerUpgradeConfig {
     configs: HashMap<TowerKind, UpgradeMode>,
+    /// Highest upgrade level that can be bought for any tower type.
+    pub max_level: u32,
 }
 
 impl Default for TowerUpgradeConfig {
@@ -119,12 +137,53 @@ impl Default for TowerUpgradeConfig {
             ),
         );
 
-        Self { configs }
+        configs.insert(
+            TowerKind::Lightning,
+            UpgradeMode::Linear(
+                TowerUpgradeBonuses::new()
+                    .with_damage(6)
+                    .with_range(0.0)
+                    .with_fire_speed(0.0)
+                    .with_projectile_speed(0.0),
+            ),
+        );
+
+        configs.insert(
+            TowerKind::Catapult,
+            UpgradeMode::Linear(
+                TowerUpgradeBonuses::new()
+                    .with_damage(8)
+                    .with_range(0.0)
+                    .with_fire_speed(0.0)
+                    .with_projectile_speed(0.0),
+            ),
+        );
+
+        configs.insert(
+            TowerKind::Cannon,
+            UpgradeMode::Linear(
+                TowerUpgradeBonuses::new()
+                    .with_damage(15)
+                    .with_range(0.0)
+                    .with_fire_speed(0.0)
+                    .with_projectile_speed(0.0),
+            ),
+        );
+
+        Self {
+            configs,
+            max_level: DEFAULT_MAX_LEVEL,
+        }
     }
 }
 
 impl TowerUpgradeConfig {
-    // Removed unused constructors and setters; use Default and direct config map if needed
+    /// Replaces the per-level bonuses of `tower_kind` with linear `bonuses`.
+    #[allow(dead_code)]
+    pub fn set_bonuses(&mut self, tower_kind: TowerKind, bonuses: TowerUpgradeBonuses) {
+        self.configs
+            .insert(tower_kind, UpgradeMode::Linear(bonuses));
+    }
 
     /// Calculate the bonus for a specific stat at the specified upgrade level.
     /// Works with linear scaling.
@@ -136,6 +195,25 @@ impl TowerUpgradeConfig {
             })
             .unwrap_or(0.0)
     }
+
+    /// Stats of a `tower_kind` tower at upgrade `level`, and its range given the un-upgraded
+    /// `base_range`.
+    pub fn upgraded_stats(
+        &self,
+        tower_kind: TowerKind,
+        level: u32,
+        base_range: f32,
+    ) -> (TowerStats, f32) {
+        let base = tower_kind.stats();
+        let bonus = |stat| self.calculate_bonus(tower_kind, stat, level);
+        let stats = TowerStats {
+            damage: base.damage + bonus(UpgradeableStat::Damage) as u32,
+            fire_interval_secs: (base.fire_interval_secs - bonus(UpgradeableStat::FireSpeed))
+                .max(0.1),
+            projectile_speed: base.projectile_speed + bonus(UpgradeableStat::ProjectileSpeed),
+        };
+        (stats, base_range + bonus(UpgradeableStat::Range))
+    }
 }
 
 // Removed unused setup_upgrade_config helper and related docs
@@ -1,4 +1,5 @@
-use crate::components::towers::TowerKind;
+use crate::components::towers::{Tower, TowerKind};
+use crate::components::upgrade_config::UpgradeableStat;
 // Removed unused imports of upgrade configuration helpers
 use bevy::prelude::*;
 
@@ -7,6 +8,9 @@ use bevy::prelude::*;
 pub struct TowerUpgrades {
     pub bow_damage_level: u32,
     pub crossbow_damage_level: u32,
+    pub lightning_damage_level: u32,
+    pub catapult_damage_level: u32,
+    pub cannon_damage_level: u32,
 }
 
 impl TowerUpgrades {
@@ -15,6 +19,46 @@ impl TowerUpgrades {
         match kind {
             TowerKind::Bow => self.bow_damage_level,
             TowerKind::Crossbow => self.crossbow_damage_level,
+            TowerKind::Lightning => self.lightning_damage_level,
+            TowerKind::Catapult => self.catapult_damage_level,
+            TowerKind::Cannon => self.cannon_damage_level,
+        }
+    }
+
+    /// Raise the upgrade level of a tower type by one and return the new level.
+    pub fn increment_level(&mut self, kind: TowerKind) -> u32 {
+        let level = match kind {
+            TowerKind::Bow => &mut self.bow_damage_level,
+            TowerKind::Crossbow => &mut self.crossbow_damage_level,
+            TowerKind::Lightning => &mut self.lightning_damage_level,
+            TowerKind::Catapult => &mut self.catapult_damage_level,
+            TowerKind::Cannon => &mut self.cannon_damage_level,
+        };
+        *level += 1;
+        *level
+    }
+}
+
+/// One upgrade bought for a single tower from its inspection card, on top of the kind-wide
+/// `TowerUpgrades` levels. Carried by the star icon shown above the tower (a child of it), so the
+/// upgrade goes away with the tower.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TowerSpecificUpgrade {
+    pub tower_entity: Entity,
+    pub bonus: f32,
+    pub stat: UpgradeableStat,
+}
+
+impl TowerSpecificUpgrade {
+    /// Adds the bonus to `tower`'s stats the same way a kind-wide upgrade level does.
+    pub fn apply(&self, tower: &mut Tower) {
+        match self.stat {
+            UpgradeableStat::Damage => tower.damage += self.bonus as u32,
+            UpgradeableStat::Range => tower.range += self.bonus,
+            UpgradeableStat::FireSpeed => {
+                tower.fire_interval_secs = (tower.fire_interval_secs - self.bonus).max(0.1)
+            }
+            UpgradeableStat::ProjectileSpeed => tower.projectile_speed += self.bonus,
         }
     }
 }
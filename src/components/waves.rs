@@ -1,12 +1,19 @@
 use crate::components::EnemyKind;
 use crate::constants::Tunables;
-use crate::waves::rules::{Multipliers, WavePlan, WaveRules};
+use crate::core::geometry::compute_wave_formation;
+use crate::core::rng::derive_seed;
+use crate::waves::rules::{Multipliers, WavePlan, WaveRules, WaveSchedule};
 use bevy::prelude::*;
 use bevy::time::TimerMode;
 use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::time::Duration;
 
+/// Waves with fewer enemies than this spawn them one at a time instead of in formations.
+pub const FORMATION_MIN_WAVE_SIZE: u32 = 5;
+/// Spacing between formation slots in world units.
+pub const FORMATION_SPACING: f32 = 2.5;
+
 /// Wave phase used by wave progression systems.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WavePhase {
@@ -14,6 +21,49 @@ pub enum WavePhase {
     Spawning,
 }
 
+/// An overlay that takes the player's attention and holds the wave timers while it is open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseReason {
+    PriorityPicker,
+    Settings,
+    GameOver,
+}
+
+impl PauseReason {
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// While any reason is present, the wave intermission and enemy spawn timers stop advancing.
+/// Each overlay adds and removes only its own reason, so closing one of two open overlays keeps
+/// the timers paused.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WaveTimerPaused {
+    reasons: u8,
+}
+
+impl WaveTimerPaused {
+    pub fn is_paused(&self) -> bool {
+        self.reasons != 0
+    }
+
+    /// Adds `reason` while `paused`, removes it otherwise.
+    pub fn set(&mut self, reason: PauseReason, paused: bool) {
+        if paused {
+            self.reasons |= reason.bit();
+        } else {
+            self.reasons &= !reason.bit();
+        }
+    }
+}
+
+/// Present when the game was launched with a `challenge-<name>` seed.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct ChallengeMode {
+    pub name: &'static str,
+}
+
 /// Global wave state resource tracking timers and counts.
 #[derive(Resource, Debug)]
 pub struct WaveState {
@@ -25,6 +75,15 @@ pub struct WaveState {
     pub enemies_spawned: u32,
     pub spawn_queue: VecDeque<EnemyKind>,
     pub current_multipliers: HashMap<EnemyKind, Multipliers>,
+    /// Extra HP multiplier for the current wave based on the player's tower count.
+    pub enemy_hp_modifier: f32,
+    /// Village health when the current wave started, to spot a perfect defense.
+    pub health_at_wave_start: u32,
+    /// Slot offsets of the current wave's spawn groups (see `compute_wave_formation`); empty
+    /// when the wave is too small for formations.
+    pub formation_offsets: Vec<Vec3>,
+    /// Spawn groups released this wave; each one takes the next road in turn.
+    pub groups_spawned: u32,
 }
 
 impl WaveState {
@@ -44,6 +103,10 @@ impl WaveState {
             enemies_spawned: 0,
             spawn_queue: VecDeque::new(),
             current_multipliers: HashMap::new(),
+            enemy_hp_modifier: 1.0,
+            health_at_wave_start: 0,
+            formation_offsets: Vec::new(),
+            groups_spawned: 0,
         }
     }
 
@@ -57,36 +120,62 @@ impl WaveState {
         self.phase = WavePhase::Spawning;
         // Build from rules
         let plan = rules.plan(self.current_wave, tunables, seed_mode);
-        self.spawn_queue.clear();
-        for k in plan.enemies.iter().copied() {
-            self.spawn_queue.push_back(k);
-        }
+        self.queue_enemies(tunables, &plan.enemies);
 
         self.enemies_to_spawn = self.spawn_queue.len() as u32;
         self.enemies_spawned = 0;
+        self.groups_spawned = 0;
         self.current_multipliers.clear();
         self.current_multipliers
             .extend(plan.multipliers.into_iter());
         self.spawn_timer
             .set_duration(Duration::from_secs_f32(tunables.enemy_spawn_interval_secs));
         self.spawn_timer.reset();
+        self.plan_formation(tunables);
     }
 
     pub fn start_next_wave_from_plan(&mut self, tunables: &Tunables, plan: WavePlan) {
         self.current_wave += 1;
         self.phase = WavePhase::Spawning;
-        self.spawn_queue.clear();
-        for k in plan.enemies.iter().copied() {
-            self.spawn_queue.push_back(k);
-        }
+        self.queue_enemies(tunables, &plan.enemies);
         self.enemies_to_spawn = self.spawn_queue.len() as u32;
         self.enemies_spawned = 0;
+        self.groups_spawned = 0;
         self.current_multipliers.clear();
         self.current_multipliers
             .extend(plan.multipliers.into_iter());
         self.spawn_timer
             .set_duration(Duration::from_secs_f32(tunables.enemy_spawn_interval_secs));
         self.spawn_timer.reset();
+        self.plan_formation(tunables);
+    }
+
+    /// Fills the spawn queue with the planned enemies, resized and remixed by the difficulty curve.
+    fn queue_enemies(&mut self, tunables: &Tunables, planned: &[EnemyKind]) {
+        self.spawn_queue.clear();
+        self.spawn_queue
+            .extend(scaled_wave(tunables, self.current_wave, planned));
+    }
+
+    /// Lays out this wave's spawn groups once, at wave start.
+    fn plan_formation(&mut self, tunables: &Tunables) {
+        self.formation_offsets.clear();
+        if self.enemies_to_spawn >= FORMATION_MIN_WAVE_SIZE {
+            // Salted apart from the per-enemy spawn point seeds
+            let seed = derive_seed(tunables.world_seed, self.current_wave as u64, u64::MAX);
+            self.formation_offsets = compute_wave_formation(
+                tunables.wave_formation,
+                tunables.formation_size,
+                FORMATION_SPACING,
+                seed,
+            );
+        }
+    }
+
+    /// Recomputes `enemy_hp_modifier` from the number of towers placed at wave start.
+    pub fn apply_tower_scaling(&mut self, tower_count: u32, tunables: &Tunables) {
+        let extra = tower_count.saturating_sub(tunables.tower_scaling_threshold);
+        self.enemy_hp_modifier = 1.0 + extra as f32 * tunables.hp_per_extra_tower.max(0.0);
     }
 
     pub fn start_intermission(&mut self, duration_secs: f32) {
@@ -103,6 +192,42 @@ impl WaveState {
         }
     }
 
+    /// The plan `wave_progression` will use for the next wave: the precomputed schedule when
+    /// available, else the rules directly.
+    pub fn upcoming_plan(
+        &self,
+        tunables: &Tunables,
+        rules: &WaveRules,
+        schedule: Option<&WaveSchedule>,
+        seed_mode: Option<u64>,
+    ) -> WavePlan {
+        let wave = self.current_wave + 1;
+        match schedule.and_then(|s| s.plans.get((wave - 1) as usize)) {
+            Some(plan) => plan.clone(),
+            None => rules.plan(wave, tunables, seed_mode),
+        }
+    }
+
+    /// Kinds and counts of the enemies the next wave will spawn from `plan` (see
+    /// `upcoming_plan`), in `EnemyKind::ALL` order and without absent kinds.
+    pub fn upcoming_composition(
+        &self,
+        tunables: &Tunables,
+        plan: &WavePlan,
+    ) -> Vec<(EnemyKind, u32)> {
+        let enemies = scaled_wave(tunables, self.current_wave + 1, &plan.enemies);
+        EnemyKind::ALL
+            .iter()
+            .map(|kind| (*kind, enemies.iter().filter(|k| *k == kind).count() as u32))
+            .filter(|(_, count)| *count > 0)
+            .collect()
+    }
+
+    /// Rough difficulty of the next wave: tier 1 for waves 1-4, tier 2 for waves 5-9 and so on.
+    pub fn upcoming_difficulty_tier(&self) -> u32 {
+        (self.current_wave + 1) / 5 + 1
+    }
+
     pub fn remaining_intermission_secs(&self) -> f32 {
         self.intermission_timer.remaining_secs()
     }
@@ -119,3 +244,78 @@ impl WaveState {
             .unwrap_or_default()
     }
 }
+
+/// `planned` after the difficulty curve's count and composition scaling for `wave`. Seeded from
+/// the world seed so the wave preview and the wave itself agree.
+fn scaled_wave(tunables: &Tunables, wave: u32, planned: &[EnemyKind]) -> Vec<EnemyKind> {
+    // Salted apart from the formation and spawn point seeds
+    let seed = derive_seed(tunables.world_seed, wave as u64, u64::MAX - 1);
+    tunables
+        .difficulty_curve
+        .interpolate(wave)
+        .apply_to_wave(planned, seed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Wave state in the intermission before `wave`.
+    fn before_wave(tunables: &Tunables, wave: u32) -> WaveState {
+        let mut state = WaveState::new(tunables);
+        state.current_wave = wave - 1;
+        state
+    }
+
+    fn composition(wave: u32) -> (WavePlan, Vec<(EnemyKind, u32)>) {
+        let tunables = Tunables::default();
+        let state = before_wave(&tunables, wave);
+        let plan = state.upcoming_plan(&tunables, &WaveRules::default(), None, Some(7));
+        let composition = state.upcoming_composition(&tunables, &plan);
+        (plan, composition)
+    }
+
+    #[test]
+    fn upcoming_composition_lists_enemies_for_waves_1_5_and_10() {
+        for wave in [1, 5, 10] {
+            let (_, composition) = composition(wave);
+            assert!(!composition.is_empty(), "wave {} has no enemies", wave);
+            assert!(composition.iter().all(|(_, count)| *count > 0));
+        }
+    }
+
+    #[test]
+    fn boss_waves_include_the_boss() {
+        // The default rules bring a boss every 10th wave
+        for wave in [10, 20] {
+            let (plan, composition) = composition(wave);
+            assert!(plan.is_boss);
+            assert!(composition.contains(&(EnemyKind::Boss, 1)));
+        }
+        for wave in [1, 5, 9, 11] {
+            let (plan, composition) = composition(wave);
+            assert!(!plan.is_boss);
+            assert!(composition.iter().all(|(kind, _)| *kind != EnemyKind::Boss));
+        }
+    }
+
+    #[test]
+    fn upcoming_composition_follows_enemy_kind_order() {
+        let (_, composition) = composition(10);
+        let order: Vec<usize> = composition
+            .iter()
+            .map(|(kind, _)| EnemyKind::ALL.iter().position(|k| k == kind).unwrap())
+            .collect();
+        assert!(order.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn difficulty_tier_rises_every_five_waves() {
+        let tunables = Tunables::default();
+        let tiers: Vec<u32> = [1, 4, 5, 9, 10, 15]
+            .into_iter()
+            .map(|wave| before_wave(&tunables, wave).upcoming_difficulty_tier())
+            .collect();
+        assert_eq!(tiers, vec![1, 1, 2, 2, 3, 4]);
+    }
+}
@@ -1,14 +1,40 @@
+use crate::core::geometry::FormationType;
+use crate::waves::difficulty::DifficultyCurve;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 // App/window
 pub const C_WINDOW_TITLE: &str = "Village Defender v0.1";
 pub const C_WINDOW_RESOLUTION: (u32, u32) = (1920, 1080);
+pub const C_AUTO_SCREENSHOT: bool = false;
+pub const C_MAX_SCREENSHOTS: usize = 50;
+pub const C_VOLUME_LERP_SPEED: f32 = 3.3;
+pub const C_MUSIC_CROSSFADE_SECS: f32 = 2.0;
+pub const C_FOOTSTEP_INTERVAL_SECS: f32 = 0.35;
+pub const C_AUDIO_DOPPLER_FACTOR: f32 = 0.15;
+pub const C_USE_RADIAL_MENU: bool = false;
+pub const C_LEADERBOARD_URL: &str = "";
+pub const C_LEADERBOARD_NAME: &str = "";
 
 // Camera and lighting
 pub const C_CAMERA_OFFSET_X: f32 = 0.0;
 pub const C_CAMERA_OFFSET_Y: f32 = 80.0;
 pub const C_CAMERA_OFFSET_Z: f32 = 50.0;
+pub const C_CAMERA_FOV_DEGREES: f32 = 45.0;
 pub const C_LIGHT_ILLUMINANCE: f32 = 10000.0;
+pub const C_NIGHT_ILLUMINANCE: f32 = 500.0;
+pub const C_DAY_NIGHT_PHASE_PER_WAVE: f32 = 0.1;
+pub const C_DAY_NIGHT_RESET_WAVE: u32 = 20;
+pub const C_NIGHT_SPAWN_MULTIPLIER: f32 = 1.5;
+pub const C_CAMERA_ZOOM_MIN: f32 = 0.5;
+pub const C_CAMERA_ZOOM_MAX: f32 = 1.5;
+pub const C_CAMERA_ZOOM_SPEED: f32 = 8.0;
+pub const C_CAMERA_FOLLOW_SPEED: f32 = 10.0;
+pub const C_SPECTATOR_CAMERA_SPEED: f32 = 2.0;
+pub const C_ENABLE_EDGE_SCROLL: bool = true;
+pub const C_EDGE_SCROLL_MARGIN: f32 = 16.0;
+pub const C_EDGE_SCROLL_SPEED: f32 = 80.0;
+pub const C_CAMERA_PAN_LIMIT: f32 = 250.0;
 
 // World
 pub const C_GROUND_SIZE: f32 = 1000.0;
@@ -19,6 +45,11 @@ pub const C_GATE_WIDTH: f32 = 20.0;
 pub const C_SQUARE_SIZE: f32 = 60.0;
 pub const C_GROUND_COLOR_SRGB: (f32, f32, f32) = (0.2, 0.3, 0.2);
 pub const C_ROAD_WIDTH: f32 = 5.0;
+pub const C_NUM_ROADS: usize = 1;
+pub const C_ROAD_TILE_SCALE: f32 = 1.0;
+pub const C_ROAD_SCROLL_SPEED: f32 = 0.05;
+pub const C_ROAD_SCROLL_SPEED_PER_ENEMY: f32 = 0.1;
+pub const C_TRAFFIC_THRESHOLD_SECS: f32 = 10.0;
 // Chunking & world seed
 pub const C_WORLD_SEED: u64 = 0xC0FFEE_u64;
 pub const C_CHUNK_SIZE: f32 = 1024.0;
@@ -32,23 +63,94 @@ pub const C_PLAYER_SPEED: f32 = 80.0;
 
 // Village/base
 pub const C_VILLAGE_HEALTH: u32 = 200;
+pub const C_DANGER_THRESHOLD: f32 = 0.25;
 pub const C_VILLAGE_COLLISION_RADIUS: f32 = 8.0;
+pub const C_SHIELD_DURATION_SECS: f32 = 10.0;
+pub const C_SHIELD_GOLD_COST: u64 = 1;
+pub const C_SHIELD_BOUNCE_SECS: f32 = 1.0;
+pub const C_SHIELD_SLOW_RADIUS: f32 = 20.0;
+pub const C_SHIELD_SLOW_FACTOR: f32 = 0.5;
+
+// Walls
+pub const C_WALL_HEALTH: u32 = 300;
+pub const C_WALL_ATTACK_RANGE: f32 = 2.0;
+pub const C_WALL_REPAIR_WOOD_COST: u32 = 150;
+pub const C_WALL_REPAIR_RADIUS: f32 = 15.0;
+
+// Market
+pub const C_MARKET_WOOD_PER_ROCK: f32 = 2.0;
+pub const C_MARKET_ROCK_PER_WOOD: f32 = 0.5;
+pub const C_MARKET_RATE_PENALTY: f32 = 0.1;
 
 // Enemies
 pub const C_ENEMY_SPAWN_INTERVAL_SECS: f32 = 1.0;
+pub const C_PATHFIND_TOWER_CLEARANCE: f32 = 4.0;
+pub const C_PATHFINDING_CELL_SIZE: f32 = 2.0;
+pub const C_HEALER_AURA_RADIUS: f32 = 10.0;
+pub const C_HEALER_HEAL_PER_SECOND: f32 = 4.0;
+pub const C_BOSS_SHIELD_ABSORB: u32 = 200;
+pub const C_BOSS_POISON_CLOUD_RADIUS: f32 = 8.0;
+pub const C_BOSS_POISON_CLOUD_DPS: f32 = 6.0;
+pub const C_BOSS_POISON_CLOUD_SECS: f32 = 6.0;
+pub const C_FORMATION_SIZE: u32 = 3;
+pub const C_FORMATION_BREAK_DISTANCE: f32 = 12.0;
+pub const C_WAVE_FORMATION: FormationType = FormationType::V;
+pub const C_MINION_GROUP_RADIUS: f32 = 6.0;
+pub const C_MINION_SWARM_SPEED_BONUS: f32 = 1.3;
+pub const C_WAYPOINT_ARRIVAL_RADIUS: f32 = 4.0;
+pub const C_ENEMY_TURN_SPEED: f32 = 8.0;
 
 // Waves
+pub const C_PRE_GAME_COUNTDOWN_SECS: f32 = 5.0;
 pub const C_WAVE_INITIAL_DELAY_SECS: f32 = 20.0;
 pub const C_WAVE_INTERMISSION_SECS: f32 = 3.0;
+pub const C_PERFECT_WAVE_WOOD_BONUS: u32 = 20;
+pub const C_PERFECT_WAVE_ROCK_BONUS: u32 = 10;
+pub const C_WAVE_COMPLETION_SILVER_BONUS: u64 = 5;
 pub const C_WAVE_BASE_ENEMY_COUNT: u32 = 10;
 pub const C_WAVE_ENEMY_INCREMENT: u32 = 2;
+pub const C_WAVE_BOSS_EVERY: u32 = 5;
+pub const C_MAX_WAVES: u32 = 0;
+pub const C_CHALLENGE_MODE: bool = false;
+pub const C_CHALLENGE_MISPLACE_PENALTY_PCT: f32 = 0.0;
+pub const C_SHOW_WAVE_PROGRESS_BAR: bool = true;
+pub const C_SHOW_CHOKE_ADVISOR: bool = false;
+pub const C_TOWER_SCALING_THRESHOLD: u32 = 10;
+pub const C_HP_PER_EXTRA_TOWER: f32 = 0.05;
 
 // Towers
 pub const C_TOWER_RANGE: f32 = 30.0;
+pub const C_ROAD_BLOCK_RADIUS: f32 = 4.0;
+pub const C_TOWN_SQUARE_BLOCK_RADIUS: f32 = 25.0;
+pub const C_CONVERSION_PREMIUM: f32 = 0.25;
 pub const C_TOWER_SPAWN_EFFECT_DURATION_SECS: f32 = 0.3;
 pub const C_PROJECTILE_HIT_RADIUS: f32 = 1.4;
 pub const C_PROJECTILE_LIFETIME_SECS: f32 = 5.0;
+pub const C_PROJECTILE_TRAIL_INTERVAL_SECS: f32 = 0.03;
+pub const C_MAX_TRAIL_PARTICLES: u32 = 300;
+pub const C_SPATIAL_GRID_CELL_SIZE: f32 = 10.0;
+pub const C_WIND_PROJECTILE_INFLUENCE: f32 = 1.0;
+pub const C_BOW_WIND_FACTOR: f32 = 1.0;
+pub const C_CROSSBOW_WIND_FACTOR: f32 = 0.35;
+pub const C_LIGHTNING_CHAIN_COUNT: u32 = 3;
+pub const C_LIGHTNING_CHAIN_DAMAGE_FALLOFF: f32 = 0.25;
+pub const C_LIGHTNING_CHAIN_RADIUS: f32 = 12.0;
+pub const C_LIGHTNING_ARC_FADE_SECS: f32 = 0.15;
+pub const C_CATAPULT_SPLASH_RADIUS: f32 = 4.0;
+pub const C_CANNON_SPLASH_RADIUS: f32 = 4.0;
+pub const C_ARROW_SLOW_CHANCE: f32 = 0.2;
+pub const C_ARROW_SLOW_SECS: f32 = 0.5;
+pub const C_ARROW_SLOW_MULTIPLIER: f32 = 0.6;
+pub const C_LIGHTNING_STRIKE_INTERVAL_SECS: f32 = 12.0;
+pub const C_LIGHTNING_STRIKE_RADIUS: f32 = 5.0;
+pub const C_LIGHTNING_STRIKE_DAMAGE: u32 = 30;
+pub const C_WIND_MAX_SPEED: f32 = 4.0;
+pub const C_WIND_CHANGE_INTERVAL_SECS: f32 = 30.0;
 pub const C_MAX_BUILD_DISTANCE: f32 = 50.0;
+pub const C_MAX_BOW_TOWERS: usize = 10;
+pub const C_MAX_CROSSBOW_TOWERS: usize = 5;
+pub const C_PLACEMENT_GRID_SNAP: bool = false;
+pub const C_GRID_SNAP_SIZE: f32 = 2.0;
 pub const C_RING_INNER_RATIO: f32 = 0.92;
 pub const C_IMPACT_EFFECT_DURATION_SECS: f32 = 0.2;
 pub const C_DAMAGE_NUMBER_LIFETIME_SECS: f32 = 0.56;
@@ -56,6 +158,10 @@ pub const C_DAMAGE_NUMBER_SPAWN_HEIGHT: f32 = 0.0;
 pub const C_DAMAGE_NUMBER_FONT_SIZE: f32 = 16.0;
 pub const C_ENEMY_FLASH_DURATION_SECS: f32 = 0.20;
 pub const C_ENEMY_FADE_OUT_DURATION_SECS: f32 = 0.6;
+pub const C_LABEL_CULL_DISTANCE: f32 = 250.0;
+pub const C_COMBO_DETECTION_RADIUS: f32 = 12.0;
+pub const C_COMBO_DAMAGE_BONUS: f32 = 0.2;
+pub const C_COMBO_MAX_STACKS: u32 = 3;
 // Deprecated explosion/pre-explosion settings removed
 
 // Projectile trail removed (no trail rendering)
@@ -65,6 +171,8 @@ pub const C_HEALTH_BAR_WIDTH: f32 = 4.0;
 pub const C_HEALTH_BAR_HEIGHT: f32 = 0.5;
 pub const C_HEALTH_BAR_FILL_HEIGHT: f32 = 0.4;
 pub const C_HEALTH_BAR_OFFSET_Y: f32 = 4.2;
+pub const C_HEALTH_BAR_LOD_DISTANCE: f32 = 40.0;
+pub const C_HEALTH_BAR_LOD_NEAR_DISTANCE: f32 = 28.0;
 
 // Resources placement
 pub const C_TREE_WOOD_MIN: u32 = 20;
@@ -72,31 +180,99 @@ pub const C_TREE_WOOD_MAX: u32 = 60;
 pub const C_TREE_SIZE: (f32, f32, f32) = (1.4, 3.2, 1.4);
 pub const C_ROCK_SIZE: (f32, f32, f32) = (1.0, 0.8, 1.0);
 pub const C_TOWN_RESOURCE_EXCLUSION_RADIUS: f32 = 100.0;
+pub const C_ROAD_TREE_CLEARANCE: f32 = C_ROAD_WIDTH * 0.5 + 3.0;
+pub const C_TREE_CENTER_THINNING: f32 = 0.5;
+pub const C_AUTO_COLLECT_RADIUS: f32 = 12.0;
+pub const C_AUTO_COLLECT_ROCKS: bool = false;
 
 /// Tunable values that control the game. Insert this as a Bevy resource to tweak gameplay,
 /// visuals, and pacing without touching system code. Values are read at runtime by systems.
-#[derive(Resource, Clone)]
+/// Serializable so development builds can hot-reload overrides from `td/tunables.toml`.
+#[derive(Resource, Clone, Serialize, Deserialize)]
 pub struct Tunables {
     /// Overall town square dimension (town_size x town_size)
     pub town_size: f32,
     /// Title of the primary window. Changing this requires a restart/run.
+    #[serde(skip)]
     pub window_title: &'static str,
     /// Window resolution in pixels (width, height). Changing this requires a restart/run.
     pub window_resolution: (u32, u32),
+    /// Save a screenshot after each wave to td/screenshots (enable with --screenshots).
+    pub auto_screenshot: bool,
+    /// Maximum automatic screenshots kept on disk; the oldest are deleted beyond this.
+    pub max_screenshots: usize,
+    /// Rate (volume units per second) at which audio volume changes fade toward their new value.
+    pub volume_lerp_speed: f32,
+    /// Seconds the music takes to fade out the old track, and again to fade in the new one.
+    pub music_crossfade_secs: f32,
+    /// Minimum seconds between two player footstep sounds.
+    pub footstep_interval_secs: f32,
+    /// How strongly the listener's direction of travel steers the pan of spatial sounds
+    /// (0.0 disables it, 0.15 = up to 15% of the pan).
+    pub audio_doppler_factor: f32,
+    /// Hold B to pick a tower from a radial menu around the cursor (the Tab drawer stays
+    /// available).
+    pub use_radial_menu: bool,
+    /// Online leaderboard endpoint (plain http://) used by the `online` feature; empty disables it.
+    pub leaderboard_url: String,
+    /// Name shown next to your score on the online leaderboard. Scores are only submitted once
+    /// this is set.
+    pub leaderboard_name: String,
 
     /// Camera offset from the player in world units (X, Y, Z). Larger Y/Z pulls the camera back.
     pub camera_offset: Vec3,
+    /// Vertical field of view of the camera, in degrees.
+    pub camera_fov_degrees: f32,
+    /// Multiplier on the camera offset's Y and Z when fully zoomed in with the mouse wheel.
+    pub camera_zoom_min: f32,
+    /// Multiplier on the camera offset's Y and Z when fully zoomed out with the mouse wheel.
+    pub camera_zoom_max: f32,
+    /// How quickly the camera eases into a new zoom level (higher is snappier).
+    pub camera_zoom_speed: f32,
+    /// How quickly the camera eases toward its target (the player or the panned point).
+    pub camera_follow_speed: f32,
+    /// How fast the spectator camera closes in on the busiest fight (fraction of the distance
+    /// per second).
+    pub spectator_camera_speed: f32,
+    /// Pan the camera by holding the cursor at a window edge.
+    pub enable_edge_scroll: bool,
+    /// Distance in logical pixels from a window edge at which edge scrolling starts.
+    pub edge_scroll_margin: f32,
+    /// Edge scrolling speed in world units per second.
+    pub edge_scroll_speed: f32,
+    /// Farthest the edge-scrolled camera may look from the village center, in world units (XZ).
+    pub camera_pan_limit: f32,
     /// Directional light illuminance (lux-like units). Higher is brighter.
     pub light_illuminance: f32,
+    /// Directional light illuminance at full night.
+    pub night_illuminance: f32,
+    /// How far each completed wave moves the day/night clock toward night (1.0 is full night).
+    pub day_night_phase_per_wave: f32,
+    /// The clock returns to day after every this many waves (0 never resets it).
+    pub day_night_reset_wave: u32,
+    /// Enemy spawn rate multiplier at night.
+    pub night_spawn_multiplier: f32,
 
     /// Ground plane dimension (size x size) in world units.
     pub ground_size: f32,
     /// Ground base color (linear sRGB).
     pub ground_color: Color,
+    /// Roads leading enemies to the gate (1 to 3). With more than one, they fan out from a
+    /// junction just outside the gate and enemies spawn at the far end of any of them.
+    pub num_roads: usize,
     /// Road strip width in world units.
     pub road_width: f32,
+    /// Surface tiles per road patch (patches are about 3 units long).
+    pub road_tile_scale: f32,
+    /// Tiles per second road surfaces scroll toward the village with no enemies on them.
+    pub road_scroll_speed: f32,
+    /// Extra scroll speed per enemy walking on a road segment.
+    pub road_scroll_speed_per_enemy: f32,
+    /// Seconds of enemy presence in a road cell before a worn-dirt decal appears (x3 for a rut).
+    pub traffic_threshold_secs: f32,
 
     /// Deterministic world seed for procedural content.
+    #[serde(skip)]
     pub world_seed: u64,
     /// Size of a single world chunk (square on XZ plane).
     pub chunk_size: f32,
@@ -134,24 +310,116 @@ pub struct Tunables {
 
     /// Maximum health for the village/base.
     pub village_health: u32,
+    /// Village health fraction below which the screen pulses red and a heartbeat plays.
+    pub danger_threshold: f32,
     /// Collision radius around the village center for enemy impacts.
     pub village_collision_radius: f32,
+    /// Seconds the purchased village shield blocks enemy damage.
+    pub shield_duration_secs: f32,
+    /// Gold cost of activating the village shield (once per wave).
+    pub shield_gold_cost: u64,
+    /// Seconds an enemy walks back after bouncing off the shield.
+    pub shield_bounce_secs: f32,
+    /// Radius around the village in which the active shield slows enemies.
+    pub shield_slow_radius: f32,
+    /// Speed multiplier for enemies inside the shield's slow field.
+    pub shield_slow_factor: f32,
+
+    /// Hit points of each perimeter wall segment.
+    pub wall_health: u32,
+    /// Distance from a wall within which an enemy outside the town stops to attack it.
+    pub wall_attack_range: f32,
+    /// Wood spent to rebuild a breached wall.
+    pub wall_repair_wood_cost: u32,
+    /// How close the player has to stand to a breach to repair it.
+    pub wall_repair_radius: f32,
+
+    /// Wood paid per rock bought at the market.
+    pub market_wood_per_rock: f32,
+    /// Rock paid per wood bought at the market.
+    pub market_rock_per_wood: f32,
+    /// Price increase per market trade within a wave (0.1 = +10% each); resets at wave start.
+    pub market_rate_penalty: f32,
 
     /// Seconds between enemy spawns.
     pub enemy_spawn_interval_secs: f32,
     /// Radius of the ring used for random enemy spawns when roads are unavailable.
     pub enemy_spawn_ring_distance: f32,
+    /// Radius of a Healer's aura that restores HP of nearby enemies (world units).
+    pub healer_aura_radius: f32,
+    /// HP per second a Healer restores to each enemy inside its aura.
+    pub healer_heal_per_second: f32,
+    /// Damage a Boss's shield absorbs before its health is touched (0 spawns Bosses unshielded).
+    pub boss_shield_absorb: u32,
+    /// Radius of the poison cloud a dead Boss leaves behind.
+    pub boss_poison_cloud_radius: f32,
+    /// Damage per second the cloud deals to every enemy inside it; overlapping clouds add up.
+    pub boss_poison_cloud_dps: f32,
+    /// How long the cloud lingers, in seconds.
+    pub boss_poison_cloud_secs: f32,
+    /// Enemies spawned together as one formation group (1 disables formations).
+    pub formation_size: u32,
+    /// Distance from its leader beyond which a formation member breaks off and walks alone.
+    pub formation_break_distance: f32,
+    /// Shape of each spawn group in waves of at least five enemies.
+    pub wave_formation: FormationType,
+    /// Distance within which Minions count each other as one group.
+    pub minion_group_radius: f32,
+    /// Speed multiplier of Minions in a group of three or more.
+    pub minion_swarm_speed_bonus: f32,
+    /// Distance from a road waypoint at which enemies start easing toward the one after it.
+    pub waypoint_arrival_radius: f32,
+    /// How quickly enemies turn to face their direction of travel (higher is snappier).
+    pub enemy_turn_speed: f32,
+    /// Clearance around a newly built tower that triggers (and is kept by) enemy re-pathing.
+    pub pathfind_tower_clearance: f32,
+    /// Cell size of the occupancy grid enemies re-path on (world units); smaller is finer but
+    /// slower.
+    pub pathfinding_cell_size: f32,
+    /// Seconds of the on-screen countdown after loading, before the player can move or build and
+    /// the first wave's timer starts (0 skips it).
+    pub pre_game_countdown_secs: f32,
     /// Seconds before the first wave begins.
     pub wave_initial_delay_secs: f32,
     /// Seconds between waves after the first.
     pub wave_intermission_secs: f32,
+    /// Wood awarded for finishing a wave without the village taking damage.
+    pub perfect_wave_wood_bonus: u32,
+    /// Rock awarded for finishing a wave without the village taking damage.
+    pub perfect_wave_rock_bonus: u32,
+    /// Silver awarded for every finished wave.
+    pub wave_completion_silver_bonus: u64,
     /// Base number of enemies spawned during the first wave.
     pub wave_base_enemy_count: u32,
     /// Number of additional enemies added per wave.
     pub wave_enemy_increment: u32,
+    /// Every Nth wave is a boss wave.
+    pub wave_boss_every: u32,
+    /// Enemy health, speed, count and composition scaling by wave, on top of the wave rules.
+    pub difficulty_curve: DifficultyCurve,
+    /// Surviving this many waves wins the run; 0 plays on until the village falls.
+    pub max_waves: u32,
+    /// Challenge rules: no tower selling, and bests go to a separate highscore table.
+    pub challenge_mode: bool,
+    /// Share of a tower's cost lost on a failed placement (0 disables the penalty).
+    pub challenge_misplace_penalty_pct: f32,
+    /// Show the wave progress bar under the wave counter.
+    pub show_wave_progress_bar: bool,
+    /// Show the choke point advisor debug overlay (toggled with Ctrl+H).
+    pub show_choke_advisor: bool,
+    /// Towers allowed before enemies gain extra HP at wave start.
+    pub tower_scaling_threshold: u32,
+    /// Extra enemy HP fraction per tower above the scaling threshold (0.05 = +5%).
+    pub hp_per_extra_tower: f32,
 
     /// Tower attack range in world units.
     pub tower_range: f32,
+    /// Towers cannot be placed closer than this to a road centerline.
+    pub road_block_radius: f32,
+    /// Towers cannot be placed closer than this to the town square center.
+    pub town_square_block_radius: f32,
+    /// Extra fraction charged on top of the cost difference when converting a tower (0.25 = +25%).
+    pub conversion_premium: f32,
     // Tower mesh dimensions removed; sizes are per-kind
     // Costs are per-tower and hardcoded in systems
     /// Seconds for the tower spawn ring effect.
@@ -160,8 +428,56 @@ pub struct Tunables {
     pub projectile_hit_radius: f32,
     /// Maximum projectile lifetime before self-despawn.
     pub projectile_lifetime_secs: f32,
+    /// Seconds of flight between two trail particles of a projectile.
+    pub projectile_trail_interval_secs: f32,
+    /// Most trail particles alive at once; projectiles stop leaving trails beyond it.
+    pub max_trail_particles: u32,
+    /// Cell size of the enemy spatial hash towers look targets up in.
+    pub spatial_grid_cell_size: f32,
+    /// Global scale for wind drift applied to flying projectiles (0 disables drift).
+    pub wind_projectile_influence: f32,
+    /// Wind drift multiplier for light Bow arrows.
+    pub bow_wind_factor: f32,
+    /// Wind drift multiplier for heavy Crossbow bolts.
+    pub crossbow_wind_factor: f32,
+    /// Additional enemies a lightning bolt arcs to after its first hit.
+    pub lightning_chain_count: u32,
+    /// Fraction of damage lost on each lightning arc (0.25 = each arc deals 75% of the previous).
+    pub lightning_chain_damage_falloff: f32,
+    /// Maximum distance a lightning arc can jump between enemies (world units).
+    pub lightning_chain_radius: f32,
+    /// Seconds for a lightning arc visual to fade out.
+    pub lightning_arc_fade_secs: f32,
+    /// Radius around a Catapult boulder's impact point that takes its full damage (world units).
+    pub catapult_splash_radius: f32,
+    /// Radius around a Cannon shell's impact point that takes its full damage (world units).
+    pub cannon_splash_radius: f32,
+    /// Chance (0..=1) that an arrow or bolt hit slows its target.
+    pub arrow_slow_chance: f32,
+    /// Seconds the slow from an arrow hit lasts.
+    pub arrow_slow_secs: f32,
+    /// Speed multiplier for enemies slowed by an arrow hit.
+    pub arrow_slow_multiplier: f32,
+    /// Seconds between random lightning strikes while a storm is blowing.
+    pub lightning_interval_secs: f32,
+    /// Radius of a storm lightning strike's area damage (world units).
+    pub lightning_radius: f32,
+    /// Damage a storm lightning strike deals to every enemy in its radius. Towers are immune.
+    pub lightning_damage: u32,
+    /// Maximum wind speed in world units/second.
+    pub wind_max_speed: f32,
+    /// Seconds between picking a new target wind the current wind eases toward.
+    pub wind_change_interval_secs: f32,
     /// Maximum distance from the player to place a building.
     pub max_build_distance: f32,
+    /// Most Bow towers that can stand at once.
+    pub max_bow_towers: usize,
+    /// Most Crossbow towers that can stand at once.
+    pub max_crossbow_towers: usize,
+    /// Snap tower placement to a world grid of `grid_snap_size` cells (toggled with G).
+    pub placement_grid_snap: bool,
+    /// Cell size of the placement grid, in world units.
+    pub grid_snap_size: f32,
     /// Inner radius ratio for ring meshes (0..1).
     pub ring_inner_ratio: f32,
     /// Duration of the radial impact flash effect.
@@ -176,6 +492,14 @@ pub struct Tunables {
     pub enemy_flash_duration_secs: f32,
     /// Duration of the enemy fade-out on death.
     pub enemy_fade_out_duration_secs: f32,
+    /// Tower labels farther than this from the camera are hidden and not repositioned.
+    pub label_cull_distance: f32,
+    /// Distance within which a Bow and a Crossbow tower form a combo.
+    pub combo_detection_radius: f32,
+    /// Damage bonus per combo partner (0.2 = +20%).
+    pub combo_damage_bonus: f32,
+    /// Most combo partners whose bonuses stack on one tower (0 disables combos).
+    pub combo_max_stacks: u32,
     // Deprecated explosion/pre-explosion tunables removed
 
     // Projectile trail settings removed
@@ -187,6 +511,11 @@ pub struct Tunables {
     pub health_bar_fill_height: f32,
     /// Vertical offset above the unit for health bar placement.
     pub health_bar_offset_y: f32,
+    /// Distance from the camera focus point beyond which enemy health bars are hidden.
+    pub health_bar_lod_distance: f32,
+    /// Distance from the camera focus within which enemy health bars are re-checked every frame;
+    /// bars between this and `health_bar_lod_distance` only update on a slower tick.
+    pub health_bar_lod_near_distance: f32,
 
     /// Minimum wood per tree.
     pub tree_wood_min: u32,
@@ -198,6 +527,14 @@ pub struct Tunables {
     pub rock_size: Vec3,
     /// Radius around town square where resources should be excluded.
     pub town_resource_exclusion_radius: f32,
+    /// Trees closer than this to a road's center line are removed.
+    pub road_tree_clearance: f32,
+    /// Share of trees removed at the map center, easing to none at the edge of the ground.
+    pub tree_center_thinning: f32,
+    /// Reach of auto-collect (toggled with C) around the player, in world units.
+    pub auto_collect_radius: f32,
+    /// Whether auto-collect also gathers rocks; otherwise it only fells trees.
+    pub auto_collect_rocks: bool,
 }
 
 impl Default for Tunables {
@@ -207,10 +544,33 @@ impl Default for Tunables {
             // App/window
             window_title: C_WINDOW_TITLE,
             window_resolution: C_WINDOW_RESOLUTION,
+            auto_screenshot: C_AUTO_SCREENSHOT,
+            max_screenshots: C_MAX_SCREENSHOTS,
+            volume_lerp_speed: C_VOLUME_LERP_SPEED,
+            music_crossfade_secs: C_MUSIC_CROSSFADE_SECS,
+            footstep_interval_secs: C_FOOTSTEP_INTERVAL_SECS,
+            audio_doppler_factor: C_AUDIO_DOPPLER_FACTOR,
+            use_radial_menu: C_USE_RADIAL_MENU,
+            leaderboard_url: C_LEADERBOARD_URL.to_string(),
+            leaderboard_name: C_LEADERBOARD_NAME.to_string(),
 
             // Camera and lighting
             camera_offset: Vec3::new(C_CAMERA_OFFSET_X, C_CAMERA_OFFSET_Y, C_CAMERA_OFFSET_Z),
+            camera_fov_degrees: C_CAMERA_FOV_DEGREES,
+            camera_zoom_min: C_CAMERA_ZOOM_MIN,
+            camera_zoom_max: C_CAMERA_ZOOM_MAX,
+            camera_zoom_speed: C_CAMERA_ZOOM_SPEED,
+            camera_follow_speed: C_CAMERA_FOLLOW_SPEED,
+            spectator_camera_speed: C_SPECTATOR_CAMERA_SPEED,
+            enable_edge_scroll: C_ENABLE_EDGE_SCROLL,
+            edge_scroll_margin: C_EDGE_SCROLL_MARGIN,
+            edge_scroll_speed: C_EDGE_SCROLL_SPEED,
+            camera_pan_limit: C_CAMERA_PAN_LIMIT,
             light_illuminance: C_LIGHT_ILLUMINANCE,
+            night_illuminance: C_NIGHT_ILLUMINANCE,
+            day_night_phase_per_wave: C_DAY_NIGHT_PHASE_PER_WAVE,
+            day_night_reset_wave: C_DAY_NIGHT_RESET_WAVE,
+            night_spawn_multiplier: C_NIGHT_SPAWN_MULTIPLIER,
 
             // World
             ground_size: C_GROUND_SIZE,
@@ -219,7 +579,12 @@ impl Default for Tunables {
                 C_GROUND_COLOR_SRGB.1,
                 C_GROUND_COLOR_SRGB.2,
             ),
+            num_roads: C_NUM_ROADS,
             road_width: C_ROAD_WIDTH,
+            road_tile_scale: C_ROAD_TILE_SCALE,
+            road_scroll_speed: C_ROAD_SCROLL_SPEED,
+            road_scroll_speed_per_enemy: C_ROAD_SCROLL_SPEED_PER_ENEMY,
+            traffic_threshold_secs: C_TRAFFIC_THRESHOLD_SECS,
             world_seed: C_WORLD_SEED,
             chunk_size: C_CHUNK_SIZE,
             chunks_active_radius: C_CHUNKS_ACTIVE_RADIUS,
@@ -242,22 +607,94 @@ impl Default for Tunables {
 
             // Village/base
             village_health: C_VILLAGE_HEALTH,
+            danger_threshold: C_DANGER_THRESHOLD,
             village_collision_radius: C_VILLAGE_COLLISION_RADIUS,
+            shield_duration_secs: C_SHIELD_DURATION_SECS,
+            shield_gold_cost: C_SHIELD_GOLD_COST,
+            shield_bounce_secs: C_SHIELD_BOUNCE_SECS,
+            shield_slow_radius: C_SHIELD_SLOW_RADIUS,
+            shield_slow_factor: C_SHIELD_SLOW_FACTOR,
+
+            // Walls
+            wall_health: C_WALL_HEALTH,
+            wall_attack_range: C_WALL_ATTACK_RANGE,
+            wall_repair_wood_cost: C_WALL_REPAIR_WOOD_COST,
+            wall_repair_radius: C_WALL_REPAIR_RADIUS,
+
+            // Market
+            market_wood_per_rock: C_MARKET_WOOD_PER_ROCK,
+            market_rock_per_wood: C_MARKET_ROCK_PER_WOOD,
+            market_rate_penalty: C_MARKET_RATE_PENALTY,
 
             // Enemies
             enemy_spawn_interval_secs: C_ENEMY_SPAWN_INTERVAL_SECS,
             enemy_spawn_ring_distance: C_TOWN_SIZE / 2.0 + 100.0,
+            healer_aura_radius: C_HEALER_AURA_RADIUS,
+            healer_heal_per_second: C_HEALER_HEAL_PER_SECOND,
+            boss_shield_absorb: C_BOSS_SHIELD_ABSORB,
+            boss_poison_cloud_radius: C_BOSS_POISON_CLOUD_RADIUS,
+            boss_poison_cloud_dps: C_BOSS_POISON_CLOUD_DPS,
+            boss_poison_cloud_secs: C_BOSS_POISON_CLOUD_SECS,
+            formation_size: C_FORMATION_SIZE,
+            formation_break_distance: C_FORMATION_BREAK_DISTANCE,
+            wave_formation: C_WAVE_FORMATION,
+            minion_group_radius: C_MINION_GROUP_RADIUS,
+            minion_swarm_speed_bonus: C_MINION_SWARM_SPEED_BONUS,
+            waypoint_arrival_radius: C_WAYPOINT_ARRIVAL_RADIUS,
+            enemy_turn_speed: C_ENEMY_TURN_SPEED,
+            pathfind_tower_clearance: C_PATHFIND_TOWER_CLEARANCE,
+            pathfinding_cell_size: C_PATHFINDING_CELL_SIZE,
+            pre_game_countdown_secs: C_PRE_GAME_COUNTDOWN_SECS,
             wave_initial_delay_secs: C_WAVE_INITIAL_DELAY_SECS,
             wave_intermission_secs: C_WAVE_INTERMISSION_SECS,
+            perfect_wave_wood_bonus: C_PERFECT_WAVE_WOOD_BONUS,
+            perfect_wave_rock_bonus: C_PERFECT_WAVE_ROCK_BONUS,
+            wave_completion_silver_bonus: C_WAVE_COMPLETION_SILVER_BONUS,
             wave_base_enemy_count: C_WAVE_BASE_ENEMY_COUNT,
             wave_enemy_increment: C_WAVE_ENEMY_INCREMENT,
+            wave_boss_every: C_WAVE_BOSS_EVERY,
+            difficulty_curve: DifficultyCurve::default(),
+            max_waves: C_MAX_WAVES,
+            challenge_mode: C_CHALLENGE_MODE,
+            challenge_misplace_penalty_pct: C_CHALLENGE_MISPLACE_PENALTY_PCT,
+            show_wave_progress_bar: C_SHOW_WAVE_PROGRESS_BAR,
+            show_choke_advisor: C_SHOW_CHOKE_ADVISOR,
+            tower_scaling_threshold: C_TOWER_SCALING_THRESHOLD,
+            hp_per_extra_tower: C_HP_PER_EXTRA_TOWER,
 
             // Towers
             tower_range: C_TOWER_RANGE,
+            road_block_radius: C_ROAD_BLOCK_RADIUS,
+            town_square_block_radius: C_TOWN_SQUARE_BLOCK_RADIUS,
+            conversion_premium: C_CONVERSION_PREMIUM,
             tower_spawn_effect_duration_secs: C_TOWER_SPAWN_EFFECT_DURATION_SECS,
             projectile_hit_radius: C_PROJECTILE_HIT_RADIUS,
             projectile_lifetime_secs: C_PROJECTILE_LIFETIME_SECS,
+            projectile_trail_interval_secs: C_PROJECTILE_TRAIL_INTERVAL_SECS,
+            max_trail_particles: C_MAX_TRAIL_PARTICLES,
+            spatial_grid_cell_size: C_SPATIAL_GRID_CELL_SIZE,
+            wind_projectile_influence: C_WIND_PROJECTILE_INFLUENCE,
+            bow_wind_factor: C_BOW_WIND_FACTOR,
+            crossbow_wind_factor: C_CROSSBOW_WIND_FACTOR,
+            lightning_chain_count: C_LIGHTNING_CHAIN_COUNT,
+            lightning_chain_damage_falloff: C_LIGHTNING_CHAIN_DAMAGE_FALLOFF,
+            lightning_chain_radius: C_LIGHTNING_CHAIN_RADIUS,
+            lightning_arc_fade_secs: C_LIGHTNING_ARC_FADE_SECS,
+            catapult_splash_radius: C_CATAPULT_SPLASH_RADIUS,
+            cannon_splash_radius: C_CANNON_SPLASH_RADIUS,
+            arrow_slow_chance: C_ARROW_SLOW_CHANCE,
+            arrow_slow_secs: C_ARROW_SLOW_SECS,
+            arrow_slow_multiplier: C_ARROW_SLOW_MULTIPLIER,
+            lightning_interval_secs: C_LIGHTNING_STRIKE_INTERVAL_SECS,
+            lightning_radius: C_LIGHTNING_STRIKE_RADIUS,
+            lightning_damage: C_LIGHTNING_STRIKE_DAMAGE,
+            wind_max_speed: C_WIND_MAX_SPEED,
+            wind_change_interval_secs: C_WIND_CHANGE_INTERVAL_SECS,
             max_build_distance: C_MAX_BUILD_DISTANCE,
+            max_bow_towers: C_MAX_BOW_TOWERS,
+            max_crossbow_towers: C_MAX_CROSSBOW_TOWERS,
+            placement_grid_snap: C_PLACEMENT_GRID_SNAP,
+            grid_snap_size: C_GRID_SNAP_SIZE,
             ring_inner_ratio: C_RING_INNER_RATIO,
             impact_effect_duration_secs: C_IMPACT_EFFECT_DURATION_SECS,
             damage_number_lifetime_secs: C_DAMAGE_NUMBER_LIFETIME_SECS,
@@ -265,6 +702,10 @@ impl Default for Tunables {
             damage_number_font_size: C_DAMAGE_NUMBER_FONT_SIZE,
             enemy_flash_duration_secs: C_ENEMY_FLASH_DURATION_SECS,
             enemy_fade_out_duration_secs: C_ENEMY_FADE_OUT_DURATION_SECS,
+            label_cull_distance: C_LABEL_CULL_DISTANCE,
+            combo_detection_radius: C_COMBO_DETECTION_RADIUS,
+            combo_damage_bonus: C_COMBO_DAMAGE_BONUS,
+            combo_max_stacks: C_COMBO_MAX_STACKS,
 
             // Projectile trail removed
 
@@ -273,6 +714,8 @@ impl Default for Tunables {
             health_bar_height: C_HEALTH_BAR_HEIGHT,
             health_bar_fill_height: C_HEALTH_BAR_FILL_HEIGHT,
             health_bar_offset_y: C_HEALTH_BAR_OFFSET_Y,
+            health_bar_lod_distance: C_HEALTH_BAR_LOD_DISTANCE,
+            health_bar_lod_near_distance: C_HEALTH_BAR_LOD_NEAR_DISTANCE,
 
             // Resources
             tree_wood_min: C_TREE_WOOD_MIN,
@@ -280,6 +723,321 @@ impl Default for Tunables {
             tree_size: Vec3::new(C_TREE_SIZE.0, C_TREE_SIZE.1, C_TREE_SIZE.2),
             rock_size: Vec3::new(C_ROCK_SIZE.0, C_ROCK_SIZE.1, C_ROCK_SIZE.2),
             town_resource_exclusion_radius: C_TOWN_RESOURCE_EXCLUSION_RADIUS,
+            road_tree_clearance: C_ROAD_TREE_CLEARANCE,
+            tree_center_thinning: C_TREE_CENTER_THINNING,
+            auto_collect_radius: C_AUTO_COLLECT_RADIUS,
+            auto_collect_rocks: C_AUTO_COLLECT_ROCKS,
+        }
+    }
+}
+
+/// Checks every tunable against its sane range so typos fail loudly at startup instead of as
+/// mysterious gameplay bugs. Returns one message per violation.
+pub fn validate_tunables(tunables: &Tunables) -> Result<(), Vec<String>> {
+    let t = tunables;
+    let mut errors = Vec::new();
+
+    let positive = [
+        // Durations
+        ("shield_duration_secs", t.shield_duration_secs),
+        ("shield_bounce_secs", t.shield_bounce_secs),
+        ("enemy_spawn_interval_secs", t.enemy_spawn_interval_secs),
+        ("wave_initial_delay_secs", t.wave_initial_delay_secs),
+        ("wave_intermission_secs", t.wave_intermission_secs),
+        (
+            "tower_spawn_effect_duration_secs",
+            t.tower_spawn_effect_duration_secs,
+        ),
+        ("projectile_lifetime_secs", t.projectile_lifetime_secs),
+        (
+            "projectile_trail_interval_secs",
+            t.projectile_trail_interval_secs,
+        ),
+        ("spatial_grid_cell_size", t.spatial_grid_cell_size),
+        ("lightning_arc_fade_secs", t.lightning_arc_fade_secs),
+        ("lightning_interval_secs", t.lightning_interval_secs),
+        ("wind_change_interval_secs", t.wind_change_interval_secs),
+        ("impact_effect_duration_secs", t.impact_effect_duration_secs),
+        ("damage_number_lifetime_secs", t.damage_number_lifetime_secs),
+        ("enemy_flash_duration_secs", t.enemy_flash_duration_secs),
+        ("arrow_slow_secs", t.arrow_slow_secs),
+        ("traffic_threshold_secs", t.traffic_threshold_secs),
+        ("boss_poison_cloud_secs", t.boss_poison_cloud_secs),
+        (
+            "enemy_fade_out_duration_secs",
+            t.enemy_fade_out_duration_secs,
+        ),
+        // Rates, sizes and distances
+        ("volume_lerp_speed", t.volume_lerp_speed),
+        ("music_crossfade_secs", t.music_crossfade_secs),
+        ("camera_zoom_min", t.camera_zoom_min),
+        ("camera_zoom_speed", t.camera_zoom_speed),
+        ("camera_follow_speed", t.camera_follow_speed),
+        ("spectator_camera_speed", t.spectator_camera_speed),
+        ("edge_scroll_speed", t.edge_scroll_speed),
+        ("camera_pan_limit", t.camera_pan_limit),
+        ("footstep_interval_secs", t.footstep_interval_secs),
+        ("town_size", t.town_size),
+        ("ground_size", t.ground_size),
+        ("road_width", t.road_width),
+        ("road_tile_scale", t.road_tile_scale),
+        ("chunk_size", t.chunk_size),
+        ("wall_thickness", t.wall_thickness),
+        ("wall_height", t.wall_height),
+        ("gate_width", t.gate_width),
+        ("wall_attack_range", t.wall_attack_range),
+        ("wall_repair_radius", t.wall_repair_radius),
+        ("plaza_short_side", t.plaza_short_side),
+        ("plaza_aspect", t.plaza_aspect),
+        ("player_speed", t.player_speed),
+        ("village_collision_radius", t.village_collision_radius),
+        ("shield_slow_radius", t.shield_slow_radius),
+        ("auto_collect_radius", t.auto_collect_radius),
+        ("night_spawn_multiplier", t.night_spawn_multiplier),
+        ("market_wood_per_rock", t.market_wood_per_rock),
+        ("market_rock_per_wood", t.market_rock_per_wood),
+        ("enemy_spawn_ring_distance", t.enemy_spawn_ring_distance),
+        ("healer_aura_radius", t.healer_aura_radius),
+        ("boss_poison_cloud_radius", t.boss_poison_cloud_radius),
+        ("formation_break_distance", t.formation_break_distance),
+        ("minion_group_radius", t.minion_group_radius),
+        ("minion_swarm_speed_bonus", t.minion_swarm_speed_bonus),
+        ("waypoint_arrival_radius", t.waypoint_arrival_radius),
+        ("enemy_turn_speed", t.enemy_turn_speed),
+        ("pathfinding_cell_size", t.pathfinding_cell_size),
+        ("tower_range", t.tower_range),
+        ("projectile_hit_radius", t.projectile_hit_radius),
+        ("lightning_chain_radius", t.lightning_chain_radius),
+        ("catapult_splash_radius", t.catapult_splash_radius),
+        ("cannon_splash_radius", t.cannon_splash_radius),
+        ("lightning_radius", t.lightning_radius),
+        ("wind_max_speed", t.wind_max_speed),
+        ("max_build_distance", t.max_build_distance),
+        ("grid_snap_size", t.grid_snap_size),
+        ("damage_number_font_size", t.damage_number_font_size),
+        ("label_cull_distance", t.label_cull_distance),
+        ("camera_fov_degrees", t.camera_fov_degrees),
+        ("combo_detection_radius", t.combo_detection_radius),
+        ("health_bar_width", t.health_bar_width),
+        ("health_bar_height", t.health_bar_height),
+        ("health_bar_fill_height", t.health_bar_fill_height),
+        ("health_bar_lod_distance", t.health_bar_lod_distance),
+        (
+            "health_bar_lod_near_distance",
+            t.health_bar_lod_near_distance,
+        ),
+    ];
+    for (name, value) in positive {
+        if value.is_nan() || value <= 0.0 {
+            errors.push(format!("{} must be > 0.0 (got {})", name, value));
+        }
+    }
+
+    let non_negative = [
+        ("pre_game_countdown_secs", t.pre_game_countdown_secs),
+        ("road_scroll_speed", t.road_scroll_speed),
+        ("road_scroll_speed_per_enemy", t.road_scroll_speed_per_enemy),
+        ("healer_heal_per_second", t.healer_heal_per_second),
+        ("boss_poison_cloud_dps", t.boss_poison_cloud_dps),
+        ("pathfind_tower_clearance", t.pathfind_tower_clearance),
+        ("edge_scroll_margin", t.edge_scroll_margin),
+        ("base_clearance_from_wall", t.base_clearance_from_wall),
+        ("gate_corner_margin", t.gate_corner_margin),
+        ("plaza_gap_from_base", t.plaza_gap_from_base),
+        ("road_tree_clearance", t.road_tree_clearance),
+        ("road_block_radius", t.road_block_radius),
+        ("town_square_block_radius", t.town_square_block_radius),
+        ("hp_per_extra_tower", t.hp_per_extra_tower),
+        ("conversion_premium", t.conversion_premium),
+        ("market_rate_penalty", t.market_rate_penalty),
+        ("wind_projectile_influence", t.wind_projectile_influence),
+        ("bow_wind_factor", t.bow_wind_factor),
+        ("crossbow_wind_factor", t.crossbow_wind_factor),
+        ("light_illuminance", t.light_illuminance),
+        ("night_illuminance", t.night_illuminance),
+        ("combo_damage_bonus", t.combo_damage_bonus),
+        ("day_night_phase_per_wave", t.day_night_phase_per_wave),
+        (
+            "town_resource_exclusion_radius",
+            t.town_resource_exclusion_radius,
+        ),
+    ];
+    for (name, value) in non_negative {
+        if value.is_nan() || value < 0.0 {
+            errors.push(format!("{} must be >= 0.0 (got {})", name, value));
+        }
+    }
+
+    let ratios = [
+        ("danger_threshold", t.danger_threshold),
+        (
+            "challenge_misplace_penalty_pct",
+            t.challenge_misplace_penalty_pct,
+        ),
+        ("audio_doppler_factor", t.audio_doppler_factor),
+        ("shield_slow_factor", t.shield_slow_factor),
+        ("arrow_slow_chance", t.arrow_slow_chance),
+        ("arrow_slow_multiplier", t.arrow_slow_multiplier),
+        ("tree_center_thinning", t.tree_center_thinning),
+        (
+            "lightning_chain_damage_falloff",
+            t.lightning_chain_damage_falloff,
+        ),
+    ];
+    for (name, value) in ratios {
+        if !(0.0..=1.0).contains(&value) {
+            errors.push(format!("{} must be in [0.0, 1.0] (got {})", name, value));
+        }
+    }
+    if t.ring_inner_ratio <= 0.0 || t.ring_inner_ratio >= 1.0 || t.ring_inner_ratio.is_nan() {
+        errors.push(format!(
+            "ring_inner_ratio must be in (0.0, 1.0) (got {})",
+            t.ring_inner_ratio
+        ));
+    }
+
+    let counts = [
+        ("village_health", t.village_health),
+        ("wall_health", t.wall_health),
+        ("wave_base_enemy_count", t.wave_base_enemy_count),
+        ("wave_boss_every", t.wave_boss_every),
+        ("formation_size", t.formation_size),
+        ("lightning_damage", t.lightning_damage),
+        ("tree_wood_min", t.tree_wood_min),
+    ];
+    for (name, value) in counts {
+        if value == 0 {
+            errors.push(format!("{} must be > 0", name));
+        }
+    }
+    errors.extend(t.difficulty_curve.validate());
+    if t.tree_wood_min > t.tree_wood_max {
+        errors.push(format!(
+            "tree_wood_min ({}) must not exceed tree_wood_max ({})",
+            t.tree_wood_min, t.tree_wood_max
+        ));
+    }
+    if t.health_bar_lod_near_distance > t.health_bar_lod_distance {
+        errors.push(format!(
+            "health_bar_lod_near_distance ({}) must not exceed health_bar_lod_distance ({})",
+            t.health_bar_lod_near_distance, t.health_bar_lod_distance
+        ));
+    }
+    if t.camera_zoom_max.is_nan() || t.camera_zoom_max < t.camera_zoom_min {
+        errors.push(format!(
+            "camera_zoom_max ({}) must not be below camera_zoom_min ({})",
+            t.camera_zoom_max, t.camera_zoom_min
+        ));
+    }
+    for (name, size) in [("tree_size", t.tree_size), ("rock_size", t.rock_size)] {
+        if size.is_nan() || size.cmple(Vec3::ZERO).any() {
+            errors.push(format!(
+                "{} must be > 0.0 on every axis (got {})",
+                name, size
+            ));
         }
     }
+    let offsets = [
+        ("damage_number_spawn_height", t.damage_number_spawn_height),
+        ("health_bar_offset_y", t.health_bar_offset_y),
+    ];
+    for (name, value) in offsets {
+        if !value.is_finite() {
+            errors.push(format!("{} must be finite (got {})", name, value));
+        }
+    }
+    if !t.camera_offset.is_finite() {
+        errors.push(format!(
+            "camera_offset must be finite (got {})",
+            t.camera_offset
+        ));
+    }
+    if t.gate_width >= t.town_size {
+        errors.push(format!(
+            "gate_width ({}) must be below town_size ({})",
+            t.gate_width, t.town_size
+        ));
+    }
+    if t.base_clearance_from_wall >= t.town_size * 0.5 {
+        errors.push(format!(
+            "base_clearance_from_wall ({}) must be below half of town_size ({})",
+            t.base_clearance_from_wall, t.town_size
+        ));
+    }
+    if t.window_resolution.0 == 0 || t.window_resolution.1 == 0 {
+        errors.push(format!(
+            "window_resolution must be non-zero (got {:?})",
+            t.window_resolution
+        ));
+    }
+    if t.chunks_active_radius < 1 || t.chunks_hysteresis < 0 {
+        errors.push(format!(
+            "chunks_active_radius must be >= 1 and chunks_hysteresis >= 0 (got {} and {})",
+            t.chunks_active_radius, t.chunks_hysteresis
+        ));
+    }
+    if !(1..=3).contains(&t.num_roads) {
+        errors.push(format!("num_roads must be 1, 2 or 3 (got {})", t.num_roads));
+    }
+    if t.chunks_loads_per_frame == 0 || t.chunks_unloads_per_frame == 0 {
+        errors.push("chunks_loads_per_frame and chunks_unloads_per_frame must be > 0".to_string());
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The errors `validate_tunables` reports after `change` is applied to the defaults.
+    fn errors_after(change: impl FnOnce(&mut Tunables)) -> Vec<String> {
+        let mut tunables = Tunables::default();
+        change(&mut tunables);
+        validate_tunables(&tunables).err().unwrap_or_default()
+    }
+
+    #[test]
+    fn defaults_are_valid() {
+        assert_eq!(validate_tunables(&Tunables::default()), Ok(()));
+    }
+
+    #[test]
+    fn zero_duration_is_rejected() {
+        assert_eq!(
+            errors_after(|t| t.projectile_lifetime_secs = 0.0),
+            vec!["projectile_lifetime_secs must be > 0.0 (got 0)".to_string()]
+        );
+        assert_eq!(errors_after(|t| t.tower_range = f32::NAN).len(), 1);
+    }
+
+    #[test]
+    fn negative_amount_is_rejected() {
+        assert_eq!(
+            errors_after(|t| t.road_scroll_speed = -1.0),
+            vec!["road_scroll_speed must be >= 0.0 (got -1)".to_string()]
+        );
+        assert!(errors_after(|t| t.road_scroll_speed = 0.0).is_empty());
+    }
+
+    #[test]
+    fn ratio_outside_unit_range_is_rejected() {
+        assert_eq!(
+            errors_after(|t| t.arrow_slow_chance = 1.5),
+            vec!["arrow_slow_chance must be in [0.0, 1.0] (got 1.5)".to_string()]
+        );
+        assert!(errors_after(|t| t.arrow_slow_chance = 1.0).is_empty());
+    }
+
+    #[test]
+    fn zero_count_is_rejected() {
+        assert_eq!(
+            errors_after(|t| t.wave_boss_every = 0),
+            vec!["wave_boss_every must be > 0".to_string()]
+        );
+    }
 }
@@ -1,4 +1,7 @@
 use bevy::prelude::*;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+use std::f32::consts::TAU;
 
 /// Compute the minimum distance (in XZ) from a point to a polyline.
 pub fn distance_to_polyline_xz(point: Vec3, path: &[Vec3]) -> f32 {
@@ -48,3 +51,347 @@ pub fn direction_xz(from: Vec3, to: Vec3) -> Vec3 {
     let v = Vec3::new(to.x - from.x, 0.0, to.z - from.z);
     v.normalize_or_zero()
 }
+
+/// Angle in radians (0 when straight on) by which an XZ path going `a` → `b` → `c` turns at `b`.
+/// Degenerate segments count as straight.
+pub fn turn_angle_xz(a: Vec3, b: Vec3, c: Vec3) -> f32 {
+    let incoming = direction_xz(a, b);
+    let outgoing = direction_xz(b, c);
+    if incoming == Vec3::ZERO || outgoing == Vec3::ZERO {
+        return 0.0;
+    }
+    incoming.dot(outgoing).clamp(-1.0, 1.0).acos()
+}
+
+/// Point on `a0`-`a1` where it crosses `b0`-`b1` on the XZ plane, or `None` if they don't.
+/// Touching counts as crossing; parallel segments never cross.
+pub fn segment_intersection_xz(a0: Vec3, a1: Vec3, b0: Vec3, b1: Vec3) -> Option<Vec3> {
+    let r = a1.xz() - a0.xz();
+    let s = b1.xz() - b0.xz();
+    let denom = r.perp_dot(s);
+    if denom.abs() <= f32::EPSILON {
+        return None;
+    }
+    let offset = b0.xz() - a0.xz();
+    let t = offset.perp_dot(s) / denom;
+    let u = offset.perp_dot(r) / denom;
+    if !(0.0..=1.0).contains(&t) || !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    Some(a0.lerp(a1, t))
+}
+
+/// Whether two axis-aligned boxes given as (min, max) corners intersect.
+/// Boxes that merely touch do not overlap.
+pub fn aabb_overlap(a: (Vec3, Vec3), b: (Vec3, Vec3)) -> bool {
+    a.0.cmplt(b.1).all() && b.0.cmplt(a.1).all()
+}
+
+/// Indices `(x, z)` of every `cell_size` grid cell (cell `(x, z)` spans `[x, x + 1) * cell_size`
+/// on each axis, as `grid::world_to_cell`) whose square overlaps the circle of `radius` around
+/// `center`. Cells that only touch the circle's edge count.
+pub fn circle_grid_cells(
+    center: Vec2,
+    radius: f32,
+    cell_size: f32,
+) -> impl Iterator<Item = (i32, i32)> {
+    let radius = radius.max(0.0);
+    // One cell further down when the circle ends exactly on a cell edge, so the cell below that
+    // edge, which touches it, is visited too
+    let min = ((center - Vec2::splat(radius)) / cell_size)
+        .ceil()
+        .as_ivec2()
+        - IVec2::ONE;
+    let max = ((center + Vec2::splat(radius)) / cell_size)
+        .floor()
+        .as_ivec2();
+    (min.y..=max.y)
+        .flat_map(move |z| (min.x..=max.x).map(move |x| (x, z)))
+        .filter(move |&(x, z)| {
+            // Nearest point of the cell to the center decides; the bounding box alone would
+            // also take in the corners of the square around the circle
+            let cell_min = Vec2::new(x as f32, z as f32) * cell_size;
+            let nearest = center.clamp(cell_min, cell_min + Vec2::splat(cell_size));
+            nearest.distance_squared(center) <= radius * radius
+        })
+}
+
+/// Whether the segment `a`-`b` passes within `radius` of `center` (touching counts). A
+/// zero-length segment is treated as the point `a`.
+pub fn segment_circle_intersect(a: Vec2, b: Vec2, center: Vec2, radius: f32) -> bool {
+    let ab = b - a;
+    let ab_len2 = ab.length_squared();
+    let closest = if ab_len2 <= f32::EPSILON {
+        a
+    } else {
+        a + ab * ((center - a).dot(ab) / ab_len2).clamp(0.0, 1.0)
+    };
+    closest.distance_squared(center) <= radius * radius
+}
+
+/// Shape a wave's spawn groups take around their road entrance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FormationType {
+    /// Side by side across the road.
+    Line,
+    /// Wedge with its point toward the village.
+    #[default]
+    V,
+    /// Evenly spaced ring.
+    Circle,
+    /// Scattered over a disc, seeded per wave.
+    Random,
+}
+
+/// Slot offsets of a `count`-enemy formation `spacing` apart, centered on the origin in the
+/// group's heading frame: +X is to the right and -Z points toward the village. Slot 0 leads; for
+/// `Random` the layout is fixed by `seed`.
+pub fn compute_wave_formation(
+    formation: FormationType,
+    count: u32,
+    spacing: f32,
+    seed: u64,
+) -> Vec<Vec3> {
+    let n = count as f32;
+    let mut offsets: Vec<Vec3> = match formation {
+        FormationType::Line => (0..count)
+            .map(|i| Vec3::new((i as f32 - (n - 1.0) * 0.5) * spacing, 0.0, 0.0))
+            .collect(),
+        FormationType::V => (0..count)
+            .map(|i| {
+                let row = i.div_ceil(2) as f32;
+                let side = if i % 2 == 1 { -1.0 } else { 1.0 };
+                Vec3::new(side * spacing * row, 0.0, spacing * row)
+            })
+            .collect(),
+        FormationType::Circle => {
+            // Ring just wide enough to keep neighbors `spacing` apart along it
+            let radius = (spacing * n / TAU).max(spacing);
+            (0..count)
+                .map(|i| {
+                    let angle = TAU * i as f32 / n;
+                    Vec3::new(angle.sin() * radius, 0.0, -angle.cos() * radius)
+                })
+                .collect()
+        }
+        FormationType::Random => {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let radius = spacing * n.sqrt();
+            (0..count)
+                .map(|_| {
+                    let r = radius * rng.random::<f32>().sqrt();
+                    let angle = rng.random::<f32>() * TAU;
+                    Vec3::new(angle.sin() * r, 0.0, -angle.cos() * r)
+                })
+                .collect()
+        }
+    };
+    if !offsets.is_empty() {
+        let centroid = offsets.iter().copied().sum::<Vec3>() / n;
+        for offset in &mut offsets {
+            *offset -= centroid;
+        }
+    }
+    offsets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted_cells(center: Vec2, radius: f32, cell_size: f32) -> Vec<(i32, i32)> {
+        let mut cells: Vec<_> = circle_grid_cells(center, radius, cell_size).collect();
+        cells.sort();
+        cells
+    }
+
+    #[test]
+    fn circle_on_cell_corner_takes_all_four_cells() {
+        assert_eq!(
+            sorted_cells(Vec2::new(10.0, 10.0), 1.0, 10.0),
+            vec![(0, 0), (0, 1), (1, 0), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn circle_on_cell_edge_takes_both_sides() {
+        assert_eq!(
+            sorted_cells(Vec2::new(10.0, 5.0), 1.0, 10.0),
+            vec![(0, 0), (1, 0)]
+        );
+    }
+
+    #[test]
+    fn zero_radius_takes_only_the_containing_cells() {
+        assert_eq!(sorted_cells(Vec2::new(5.0, 5.0), 0.0, 10.0), vec![(0, 0)]);
+        assert_eq!(
+            sorted_cells(Vec2::new(-5.0, 15.0), 0.0, 10.0),
+            vec![(-1, 1)]
+        );
+        // A point on a corner touches every cell around it
+        assert_eq!(sorted_cells(Vec2::ZERO, 0.0, 10.0).len(), 4);
+    }
+
+    #[test]
+    fn large_radius_matches_brute_force_and_skips_box_corners() {
+        let center = Vec2::new(3.0, -2.0);
+        let radius = 27.0;
+        let cell_size = 5.0;
+        let cells = sorted_cells(center, radius, cell_size);
+
+        let mut expected = Vec::new();
+        for z in -20..=20 {
+            for x in -20..=20 {
+                let min = Vec2::new(x as f32, z as f32) * cell_size;
+                let nearest = center.clamp(min, min + Vec2::splat(cell_size));
+                if nearest.distance(center) <= radius {
+                    expected.push((x, z));
+                }
+            }
+        }
+        expected.sort();
+        assert_eq!(cells, expected);
+        assert!(cells.len() > 50);
+        // The bounding square's corner cell lies outside the circle
+        let corner = ((center - Vec2::splat(radius)) / cell_size)
+            .floor()
+            .as_ivec2();
+        assert!(!cells.contains(&(corner.x, corner.y)));
+    }
+
+    #[test]
+    fn segment_circle_intersect_hits_and_misses() {
+        let a = Vec2::new(-10.0, 0.0);
+        let b = Vec2::new(10.0, 0.0);
+        assert!(segment_circle_intersect(a, b, Vec2::new(0.0, 2.0), 2.0));
+        assert!(!segment_circle_intersect(a, b, Vec2::new(0.0, 2.1), 2.0));
+        // Past the end the distance is to the endpoint, not the infinite line
+        assert!(!segment_circle_intersect(a, b, Vec2::new(12.0, 0.0), 1.5));
+        assert!(segment_circle_intersect(a, b, Vec2::new(11.0, 0.0), 1.5));
+    }
+
+    #[test]
+    fn zero_length_segment_is_a_point() {
+        let p = Vec2::new(1.0, 1.0);
+        assert!(segment_circle_intersect(p, p, Vec2::new(4.0, 5.0), 5.0));
+        assert!(!segment_circle_intersect(p, p, Vec2::new(4.0, 5.0), 4.9));
+    }
+
+    #[test]
+    fn crossing_segments_meet_at_their_crossing_point() {
+        let hit = segment_intersection_xz(
+            Vec3::new(-1.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.5, 0.0, -1.0),
+            Vec3::new(0.5, 0.0, 1.0),
+        );
+        assert_eq!(hit, Some(Vec3::new(0.5, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn crossing_ignores_height() {
+        let hit = segment_intersection_xz(
+            Vec3::new(0.0, 2.0, -1.0),
+            Vec3::new(0.0, 2.0, 1.0),
+            Vec3::new(-1.0, 9.0, 0.0),
+            Vec3::new(1.0, 9.0, 0.0),
+        );
+        assert_eq!(hit, Some(Vec3::new(0.0, 2.0, 0.0)));
+    }
+
+    #[test]
+    fn touching_segments_cross_but_parallel_and_apart_ones_do_not() {
+        let touching = segment_intersection_xz(
+            Vec3::ZERO,
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 1.0),
+        );
+        assert_eq!(touching, Some(Vec3::new(1.0, 0.0, 0.0)));
+
+        let parallel = segment_intersection_xz(
+            Vec3::ZERO,
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(2.0, 0.0, 1.0),
+        );
+        assert_eq!(parallel, None);
+
+        let collinear = segment_intersection_xz(
+            Vec3::ZERO,
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(3.0, 0.0, 0.0),
+        );
+        assert_eq!(collinear, None);
+
+        let short_of_each_other = segment_intersection_xz(
+            Vec3::ZERO,
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, -1.0),
+            Vec3::new(2.0, 0.0, 1.0),
+        );
+        assert_eq!(short_of_each_other, None);
+    }
+
+    #[test]
+    fn line_formation_spreads_across_the_road() {
+        let offsets = compute_wave_formation(FormationType::Line, 3, 2.0, 0);
+        assert_eq!(
+            offsets,
+            vec![
+                Vec3::new(-2.0, 0.0, 0.0),
+                Vec3::ZERO,
+                Vec3::new(2.0, 0.0, 0.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn v_formation_points_toward_the_village() {
+        let offsets = compute_wave_formation(FormationType::V, 5, 1.5, 0);
+        let leader = offsets[0];
+        // The leader is the slot furthest along -Z, with the wings trailing behind it
+        assert!(offsets[1..].iter().all(|o| o.z > leader.z));
+        assert!(leader.x.abs() < 1e-5);
+        // Each row pairs one slot to either side
+        assert!((offsets[1].x + offsets[2].x).abs() < 1e-5);
+        assert!((offsets[1].z - offsets[2].z).abs() < 1e-5);
+    }
+
+    #[test]
+    fn circle_formation_spaces_slots_evenly() {
+        let offsets = compute_wave_formation(FormationType::Circle, 8, 2.0, 0);
+        let radius = offsets[0].length();
+        let gap = offsets[0].distance(offsets[1]);
+        for (i, offset) in offsets.iter().enumerate() {
+            assert!((offset.length() - radius).abs() < 1e-4);
+            let next = offsets[(i + 1) % offsets.len()];
+            assert!((offset.distance(next) - gap).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn random_formation_depends_only_on_the_seed() {
+        let a = compute_wave_formation(FormationType::Random, 6, 2.0, 42);
+        let b = compute_wave_formation(FormationType::Random, 6, 2.0, 42);
+        let c = compute_wave_formation(FormationType::Random, 6, 2.0, 43);
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn formations_are_centered_on_the_origin() {
+        for formation in [
+            FormationType::Line,
+            FormationType::V,
+            FormationType::Circle,
+            FormationType::Random,
+        ] {
+            let offsets = compute_wave_formation(formation, 7, 1.0, 7);
+            assert_eq!(offsets.len(), 7);
+            assert!(offsets.iter().copied().sum::<Vec3>().length() < 1e-4);
+        }
+        assert!(compute_wave_formation(FormationType::V, 0, 1.0, 0).is_empty());
+    }
+}
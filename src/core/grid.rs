@@ -1,5 +1,6 @@
+use crate::core::geometry::circle_grid_cells;
 use bevy::prelude::*;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct ChunkCoord {
@@ -68,3 +69,138 @@ pub fn adjacent_chunks(center: ChunkCoord) -> HashSet<ChunkCoord> {
     });
     set
 }
+
+/// Convert a world-space position to the XZ cell of a square grid with cells of `cell_size`.
+pub fn world_to_cell(pos: Vec3, cell_size: f32) -> IVec2 {
+    IVec2::new(
+        (pos.x / cell_size).floor() as i32,
+        (pos.z / cell_size).floor() as i32,
+    )
+}
+
+/// World-space center of a grid cell at height `y`.
+pub fn cell_center(cell: IVec2, cell_size: f32, y: f32) -> Vec3 {
+    Vec3::new(
+        (cell.x as f32 + 0.5) * cell_size,
+        y,
+        (cell.y as f32 + 0.5) * cell_size,
+    )
+}
+
+/// Spatial hash bucketing items by the XZ grid cell they stand in, so range lookups only touch
+/// the cells that overlap the search circle. Rebuilt from scratch each frame.
+#[derive(Resource, Debug)]
+pub struct SpatialGrid<T: Send + Sync + 'static> {
+    pub cell_size: f32,
+    cells: HashMap<IVec2, Vec<T>>,
+}
+
+impl<T: Send + Sync + 'static> SpatialGrid<T> {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Empty every cell. Buckets filled since the last clear keep their allocations for the next
+    /// rebuild; ones that stayed empty are dropped, so cells nobody stands in don't pile up.
+    pub fn clear(&mut self) {
+        self.cells.retain(|_, bucket| {
+            let used = !bucket.is_empty();
+            bucket.clear();
+            used
+        });
+    }
+
+    pub fn insert(&mut self, pos: Vec3, item: T) {
+        self.cells
+            .entry(world_to_cell(pos, self.cell_size))
+            .or_default()
+            .push(item);
+    }
+
+    /// Items in every cell overlapping the XZ circle around `center`. Callers still need an
+    /// exact distance check.
+    pub fn query_radius(&self, center: Vec3, radius: f32) -> impl Iterator<Item = &T> {
+        circle_grid_cells(center.xz(), radius, self.cell_size)
+            .filter_map(|(x, z)| self.cells.get(&IVec2::new(x, z)))
+            .flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 100 enemies spread over a 10x10 lattice, 10 units apart, centred on the origin.
+    fn enemy_lattice() -> Vec<(usize, Vec3)> {
+        (0..100)
+            .map(|i| {
+                let x = (i % 10) as f32 * 10.0 - 45.0;
+                let z = (i / 10) as f32 * 10.0 - 45.0;
+                (i, Vec3::new(x, 0.0, z))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn query_radius_finds_everything_a_full_scan_does() {
+        let enemies = enemy_lattice();
+        let mut grid = SpatialGrid::new(8.0);
+        for &(id, pos) in &enemies {
+            grid.insert(pos, id);
+        }
+        for (center, radius) in [
+            (Vec3::ZERO, 12.0),
+            (Vec3::new(-45.0, 0.0, -45.0), 15.0),
+            (Vec3::new(31.0, 3.0, -7.5), 22.0),
+            (Vec3::new(200.0, 0.0, 0.0), 10.0),
+        ] {
+            let mut from_grid: Vec<usize> = grid
+                .query_radius(center, radius)
+                .copied()
+                .filter(|&id| enemies[id].1.xz().distance(center.xz()) <= radius)
+                .collect();
+            from_grid.sort();
+            let full_scan: Vec<usize> = enemies
+                .iter()
+                .filter(|(_, pos)| pos.xz().distance(center.xz()) <= radius)
+                .map(|&(id, _)| id)
+                .collect();
+            assert_eq!(from_grid, full_scan);
+        }
+    }
+
+    #[test]
+    fn tower_range_query_touches_a_fraction_of_100_enemies() {
+        let mut grid = SpatialGrid::new(8.0);
+        for (id, pos) in enemy_lattice() {
+            grid.insert(pos, id);
+        }
+        // A tower in the middle of the field with a typical 15 unit range
+        let candidates = grid.query_radius(Vec3::new(5.0, 0.0, 5.0), 15.0).count();
+        assert!(candidates > 0);
+        assert!(candidates <= 25, "{} candidates out of 100", candidates);
+    }
+
+    #[test]
+    fn clear_drops_cells_left_empty() {
+        let mut grid = SpatialGrid::new(5.0);
+        grid.insert(Vec3::new(1.0, 0.0, 1.0), 1);
+        grid.insert(Vec3::new(51.0, 0.0, 1.0), 2);
+        grid.clear();
+        assert_eq!(grid.query_radius(Vec3::ZERO, 100.0).count(), 0);
+        assert_eq!(grid.cells.len(), 2);
+
+        // Only the first enemy is still around; the cell the second one left goes away
+        grid.insert(Vec3::new(2.0, 0.0, 2.0), 1);
+        grid.clear();
+        grid.clear();
+        assert!(grid.cells.is_empty());
+
+        grid.insert(Vec3::new(2.0, 0.0, 2.0), 1);
+        grid.clear();
+        assert_eq!(grid.cells.len(), 1);
+    }
+}
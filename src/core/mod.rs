@@ -1,8 +1,12 @@
-//! Core, pure utilities: geometry, grid math, RNG helpers.
+//! Core, pure utilities: geometry, grid math, grid pathfinding, RNG helpers, run statistics,
+//! input replays.
 //! These modules avoid Bevy ECS and can be unit-tested in isolation.
 
 pub mod geometry;
 pub mod grid;
+pub mod pathfinding;
 pub mod paths;
+pub mod replay;
 pub mod rng;
+pub mod stats;
 pub mod world;
@@ -0,0 +1,275 @@
+use bevy::prelude::*;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::core::grid::{cell_center, world_to_cell};
+
+/// Upper bound on cells expanded by one search, so an unreachable goal cannot stall a task.
+pub const MAX_EXPANDED_CELLS: usize = 20_000;
+/// How many cells out an enemy standing in a blocked area looks for walkable ground.
+pub const MAX_ESCAPE_CELLS: i32 = 8;
+
+// Step costs scaled by 10 so diagonals stay integral (10 * sqrt(2) ~= 14)
+const STRAIGHT_COST: u32 = 10;
+const DIAGONAL_COST: u32 = 14;
+
+/// XZ grid marking the cells blocked by obstacles (towers). Every other cell is walkable.
+#[derive(Debug, Clone, Default)]
+pub struct OccupancyGrid {
+    pub cell_size: f32,
+    pub blocked: HashSet<IVec2>,
+}
+
+impl OccupancyGrid {
+    /// Blocks every cell whose center lies within `radius` of one of `obstacles`.
+    pub fn from_obstacles(obstacles: &[Vec3], radius: f32, cell_size: f32) -> Self {
+        let mut blocked = HashSet::new();
+        let reach = (radius / cell_size).ceil() as i32;
+        for &obstacle in obstacles {
+            let center = world_to_cell(obstacle, cell_size);
+            let flat = Vec2::new(obstacle.x, obstacle.z);
+            for dz in -reach..=reach {
+                for dx in -reach..=reach {
+                    let cell = center + IVec2::new(dx, dz);
+                    let c = cell_center(cell, cell_size, 0.0);
+                    if Vec2::new(c.x, c.z).distance(flat) < radius {
+                        blocked.insert(cell);
+                    }
+                }
+            }
+        }
+        OccupancyGrid { cell_size, blocked }
+    }
+
+    pub fn is_blocked(&self, pos: Vec3) -> bool {
+        self.blocked.contains(&world_to_cell(pos, self.cell_size))
+    }
+
+    /// Whether the straight segment `a`-`b` crosses a blocked cell (sampled every half cell).
+    pub fn segment_blocked(&self, a: Vec3, b: Vec3) -> bool {
+        let steps = (a.distance(b) / (self.cell_size * 0.5)).ceil().max(1.0) as usize;
+        (0..=steps).any(|i| self.is_blocked(a.lerp(b, i as f32 / steps as f32)))
+    }
+}
+
+/// A* over the 8-connected grid from `start` to `goal`. Returns waypoints from `start` to `goal`
+/// (collinear steps merged), or `None` if no path exists within `MAX_EXPANDED_CELLS`.
+/// The goal cell is always walkable. An enemy starting inside a blocked area first steps to the
+/// nearest walkable cell (within `MAX_ESCAPE_CELLS`) and is routed from there.
+pub fn find_path_xz(grid: &OccupancyGrid, start: Vec3, goal: Vec3) -> Option<Vec<Vec3>> {
+    let start_cell = world_to_cell(start, grid.cell_size);
+    let goal_cell = world_to_cell(goal, grid.cell_size);
+    let walkable = |cell: IVec2| cell == goal_cell || !grid.blocked.contains(&cell);
+    let search_start = if walkable(start_cell) {
+        start_cell
+    } else {
+        nearest_walkable_cell(start, start_cell, grid.cell_size, walkable)?
+    };
+    // Octile distance, admissible for 8-connected moves
+    let heuristic = |cell: IVec2| {
+        let d = (cell - goal_cell).abs();
+        STRAIGHT_COST * d.x.max(d.y) as u32 + (DIAGONAL_COST - STRAIGHT_COST) * d.x.min(d.y) as u32
+    };
+
+    let mut open = BinaryHeap::new();
+    let mut came_from: HashMap<IVec2, IVec2> = HashMap::new();
+    let mut cost: HashMap<IVec2, u32> = HashMap::new();
+    if search_start != start_cell {
+        // The escape step leads the path, so the enemy walks out before turning
+        came_from.insert(search_start, start_cell);
+    }
+    cost.insert(search_start, 0);
+    open.push(Reverse((
+        heuristic(search_start),
+        search_start.x,
+        search_start.y,
+    )));
+
+    let mut expanded = 0;
+    while let Some(Reverse((_, x, z))) = open.pop() {
+        let cell = IVec2::new(x, z);
+        if cell == goal_cell {
+            return Some(reconstruct(&came_from, cell, start, goal, grid.cell_size));
+        }
+        expanded += 1;
+        if expanded > MAX_EXPANDED_CELLS {
+            return None;
+        }
+        let base = cost[&cell];
+        for dz in -1..=1 {
+            for dx in -1..=1 {
+                if dx == 0 && dz == 0 {
+                    continue;
+                }
+                let next = cell + IVec2::new(dx, dz);
+                if !walkable(next) {
+                    continue;
+                }
+                let diagonal = dx != 0 && dz != 0;
+                // No corner cutting past a blocked neighbour
+                if diagonal
+                    && (!walkable(cell + IVec2::new(dx, 0)) || !walkable(cell + IVec2::new(0, dz)))
+                {
+                    continue;
+                }
+                let step = if diagonal {
+                    DIAGONAL_COST
+                } else {
+                    STRAIGHT_COST
+                };
+                let next_cost = base + step;
+                if cost.get(&next).is_some_and(|&c| c <= next_cost) {
+                    continue;
+                }
+                cost.insert(next, next_cost);
+                came_from.insert(next, cell);
+                open.push(Reverse((next_cost + heuristic(next), next.x, next.y)));
+            }
+        }
+    }
+    None
+}
+
+/// Walkable cell closest to `start` within `MAX_ESCAPE_CELLS` rings around `start_cell`.
+fn nearest_walkable_cell(
+    start: Vec3,
+    start_cell: IVec2,
+    cell_size: f32,
+    walkable: impl Fn(IVec2) -> bool,
+) -> Option<IVec2> {
+    (1..=MAX_ESCAPE_CELLS).find_map(|ring| {
+        (-ring..=ring)
+            .flat_map(|dz| (-ring..=ring).map(move |dx| IVec2::new(dx, dz)))
+            .filter(|offset| offset.x.abs() == ring || offset.y.abs() == ring)
+            .map(|offset| start_cell + offset)
+            .filter(|&cell| walkable(cell))
+            .min_by(|&a, &b| {
+                let distance = |cell| cell_center(cell, cell_size, start.y).distance_squared(start);
+                distance(a).total_cmp(&distance(b))
+            })
+    })
+}
+
+fn reconstruct(
+    came_from: &HashMap<IVec2, IVec2>,
+    goal_cell: IVec2,
+    start: Vec3,
+    goal: Vec3,
+    cell_size: f32,
+) -> Vec<Vec3> {
+    let mut cells = vec![goal_cell];
+    let mut current = goal_cell;
+    while let Some(&prev) = came_from.get(&current) {
+        cells.push(prev);
+        current = prev;
+    }
+    cells.reverse();
+
+    let mut path = vec![start];
+    // Interior cells only, and only where the direction changes
+    for i in 1..cells.len().saturating_sub(1) {
+        if cells[i] - cells[i - 1] != cells[i + 1] - cells[i] {
+            path.push(cell_center(cells[i], cell_size, start.y));
+        }
+    }
+    path.push(goal);
+    path
+}
+
+/// Replaces every leg of `path` that crosses a blocked cell with an A* route around it.
+/// Interior waypoints inside blocked cells are dropped; legs with no route are kept as-is.
+pub fn reroute_path_xz(path: &[Vec3], grid: &OccupancyGrid) -> Vec<Vec3> {
+    let Some((&first, rest)) = path.split_first() else {
+        return Vec::new();
+    };
+    let last_index = rest.len().saturating_sub(1);
+    let kept: Vec<Vec3> = std::iter::once(first)
+        .chain(
+            rest.iter()
+                .enumerate()
+                .filter(|(i, p)| *i == last_index || !grid.is_blocked(**p))
+                .map(|(_, p)| *p),
+        )
+        .collect();
+
+    let mut out = vec![first];
+    for leg in kept.windows(2) {
+        let (a, b) = (leg[0], leg[1]);
+        match grid
+            .segment_blocked(a, b)
+            .then(|| find_path_xz(grid, a, b))
+            .flatten()
+        {
+            Some(route) => out.extend(route.into_iter().skip(1)),
+            None => out.push(b),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Blocked column at cell x = 0 from z = -20 to 20, on a 1-unit grid.
+    fn wall_grid() -> OccupancyGrid {
+        OccupancyGrid {
+            cell_size: 1.0,
+            blocked: (-20..=20).map(|z| IVec2::new(0, z)).collect(),
+        }
+    }
+
+    fn path_length(path: &[Vec3]) -> f32 {
+        path.windows(2).map(|leg| leg[0].distance(leg[1])).sum()
+    }
+
+    #[test]
+    fn path_goes_around_a_tower_on_the_road() {
+        let grid = OccupancyGrid::from_obstacles(&[Vec3::ZERO], 2.5, 1.0);
+        let start = Vec3::new(-10.0, 0.0, 0.2);
+        let goal = Vec3::new(10.0, 0.0, 0.2);
+        assert!(grid.segment_blocked(start, goal));
+
+        let path = find_path_xz(&grid, start, goal).expect("open ground around the tower");
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&goal));
+        for leg in path.windows(2) {
+            assert!(
+                !grid.segment_blocked(leg[0], leg[1]),
+                "{:?} crosses the tower",
+                leg
+            );
+        }
+    }
+
+    #[test]
+    fn start_inside_a_wall_steps_out_without_crossing_it() {
+        let grid = wall_grid();
+        // Just inside the wall's left half; the goal is on the far side
+        let start = Vec3::new(0.2, 0.0, 0.5);
+        let goal = Vec3::new(10.0, 0.0, 0.5);
+        let path = find_path_xz(&grid, start, goal).expect("the wall can be walked around");
+
+        // Out to the nearest free cell (x = -1), then all the way round an end of the wall
+        assert!(path[1].x < 0.0);
+        for leg in path[1..].windows(2) {
+            assert!(
+                !grid.segment_blocked(leg[0], leg[1]),
+                "{:?} crosses the wall",
+                leg
+            );
+        }
+        assert!(path_length(&path) > 40.0);
+    }
+
+    #[test]
+    fn start_buried_too_deep_has_no_path() {
+        let grid = OccupancyGrid {
+            cell_size: 1.0,
+            blocked: (-20..=20)
+                .flat_map(|z| (-20..=20).map(move |x| IVec2::new(x, z)))
+                .collect(),
+        };
+        assert!(find_path_xz(&grid, Vec3::new(0.5, 0.0, 0.5), Vec3::new(30.0, 0.0, 0.5)).is_none());
+    }
+}
@@ -1,5 +1,10 @@
+use crate::core::geometry::segment_intersection_xz;
 use bevy::prelude::*;
 use rand::{Rng, rngs::StdRng};
+use std::fmt;
+
+/// Waypoints closer than this are merged; no road segment is shorter.
+const MIN_SEGMENT_LENGTH: f32 = 0.01;
 
 #[derive(Debug, Clone, Copy)]
 pub enum RoadPattern {
@@ -120,6 +125,85 @@ pub fn generate_road_pattern(
     }
 }
 
+/// Generates road waypoints between two points, with no ECS involvement.
+/// Endpoints are forced exactly onto `start` and `end` (at ground level) so the road
+/// connects cleanly. Returns `None` for degenerate input where both points coincide on XZ.
+pub fn generate_road_waypoints(
+    start: Vec3,
+    end: Vec3,
+    width: f32,
+    rng: &mut StdRng,
+) -> Option<Vec<Vec3>> {
+    if Vec2::new(end.x - start.x, end.z - start.z).length_squared() <= f32::EPSILON {
+        return None;
+    }
+
+    let mut waypoints = generate_road_pattern(start, end, width, rng)?;
+
+    // Enforce exact endpoints to guarantee clean connections to the town square
+    if let Some(first) = waypoints.first_mut() {
+        *first = Vec3::new(start.x, 0.0, start.z);
+    }
+    if let Some(last) = waypoints.last_mut() {
+        *last = Vec3::new(end.x, 0.0, end.z);
+    }
+
+    Some(waypoints)
+}
+
+/// Why a road path could not be repaired.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PathValidationError {
+    /// Fewer than two distinct waypoints were left.
+    TooFewPoints { remaining: usize },
+}
+
+impl fmt::Display for PathValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathValidationError::TooFewPoints { remaining } => {
+                write!(
+                    f,
+                    "road path has {} distinct waypoint(s), needs 2",
+                    remaining
+                )
+            }
+        }
+    }
+}
+
+/// Cleans up a road path in place: merges waypoints within `MIN_SEGMENT_LENGTH` of the previous
+/// one (dropping duplicates and zero-length segments) and cuts out every loop where the path
+/// crosses itself, joining it at the crossing point. The endpoints are kept. Fails when fewer than
+/// two distinct waypoints remain.
+pub fn validate_and_repair_road_paths(path: &mut Vec<Vec3>) -> Result<(), PathValidationError> {
+    path.dedup_by(|b, a| a.distance(*b) <= MIN_SEGMENT_LENGTH);
+
+    // O(n²) per pass; every cut removes at least one waypoint, so this terminates
+    'search: loop {
+        for i in 0..path.len().saturating_sub(1) {
+            // Adjacent segments share a waypoint and always "touch"
+            for j in i + 2..path.len() - 1 {
+                if let Some(crossing) =
+                    segment_intersection_xz(path[i], path[i + 1], path[j], path[j + 1])
+                {
+                    path.splice(i + 1..=j, [crossing]);
+                    continue 'search;
+                }
+            }
+        }
+        break;
+    }
+    path.dedup_by(|b, a| a.distance(*b) <= MIN_SEGMENT_LENGTH);
+
+    if path.len() < 2 {
+        return Err(PathValidationError::TooFewPoints {
+            remaining: path.len(),
+        });
+    }
+    Ok(())
+}
+
 /// Generates points on a cubic Bezier curve.
 pub fn generate_bezier_curve(
     p0: Vec3,
@@ -159,3 +243,98 @@ pub fn segment_patch_tiling(
     let patch_len = seg_len / patch_count as f32;
     Some((patch_count, patch_len, forward, yaw))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn xz(points: &[(f32, f32)]) -> Vec<Vec3> {
+        points.iter().map(|&(x, z)| Vec3::new(x, 0.0, z)).collect()
+    }
+
+    #[test]
+    fn valid_path_is_left_alone() {
+        let mut path = xz(&[(0.0, 0.0), (5.0, 0.0), (5.0, 5.0), (10.0, 5.0)]);
+        let original = path.clone();
+        assert_eq!(validate_and_repair_road_paths(&mut path), Ok(()));
+        assert_eq!(path, original);
+    }
+
+    #[test]
+    fn duplicate_waypoints_are_merged() {
+        let mut path = xz(&[
+            (0.0, 0.0),
+            (0.0, 0.0),
+            (5.0, 0.0),
+            (5.005, 0.0),
+            (10.0, 0.0),
+        ]);
+        assert_eq!(validate_and_repair_road_paths(&mut path), Ok(()));
+        assert_eq!(path, xz(&[(0.0, 0.0), (5.0, 0.0), (10.0, 0.0)]));
+    }
+
+    #[test]
+    fn no_segment_is_shorter_than_the_minimum() {
+        let mut path = xz(&[
+            (0.0, 0.0),
+            (0.004, 0.0),
+            (0.008, 0.0),
+            (3.0, 0.0),
+            (3.0, 4.0),
+        ]);
+        assert_eq!(validate_and_repair_road_paths(&mut path), Ok(()));
+        assert!(
+            path.windows(2)
+                .all(|pair| pair[0].distance(pair[1]) > MIN_SEGMENT_LENGTH)
+        );
+        assert_eq!(path.first(), Some(&Vec3::ZERO));
+        assert_eq!(path.last(), Some(&Vec3::new(3.0, 0.0, 4.0)));
+    }
+
+    #[test]
+    fn self_crossing_loop_is_cut_at_the_crossing() {
+        // Goes right, doubles back up and left, then down across its own first segment
+        let mut path = xz(&[
+            (0.0, 0.0),
+            (10.0, 0.0),
+            (10.0, 5.0),
+            (5.0, 5.0),
+            (5.0, -5.0),
+            (5.0, -10.0),
+        ]);
+        assert_eq!(validate_and_repair_road_paths(&mut path), Ok(()));
+        assert_eq!(
+            path,
+            xz(&[(0.0, 0.0), (5.0, 0.0), (5.0, -5.0), (5.0, -10.0)])
+        );
+        for i in 0..path.len() - 1 {
+            for j in i + 2..path.len() - 1 {
+                assert_eq!(
+                    segment_intersection_xz(path[i], path[i + 1], path[j], path[j + 1]),
+                    None
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn fewer_than_two_distinct_points_is_an_error() {
+        let mut single = xz(&[(1.0, 1.0)]);
+        assert_eq!(
+            validate_and_repair_road_paths(&mut single),
+            Err(PathValidationError::TooFewPoints { remaining: 1 })
+        );
+
+        let mut collapsed = xz(&[(1.0, 1.0), (1.001, 1.0), (1.0, 1.002)]);
+        assert_eq!(
+            validate_and_repair_road_paths(&mut collapsed),
+            Err(PathValidationError::TooFewPoints { remaining: 1 })
+        );
+
+        let mut empty = Vec::new();
+        assert_eq!(
+            validate_and_repair_road_paths(&mut empty),
+            Err(PathValidationError::TooFewPoints { remaining: 0 })
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use crate::components::TowerKind;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A tower placed by the player during a recorded frame.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct ReplayPlacement {
+    pub position: Vec3,
+    pub kind: TowerKind,
+}
+
+/// Player inputs of one `Playing` frame.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ReplayFrame {
+    pub delta_secs: f32,
+    /// Normalized XZ movement direction; zero while standing still.
+    pub movement: Vec2,
+    pub placements: Vec<ReplayPlacement>,
+    /// Composition seed of a wave that started this frame (`None` if unseeded or no wave started).
+    pub wave_seed: Option<u64>,
+}
+
+/// Everything needed to replay a run: its world seed and the recorded frames.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ReplayFile {
+    pub seed: u64,
+    pub frames: Vec<ReplayFrame>,
+}
+
+impl ReplayFile {
+    pub fn encode(&self) -> Result<Vec<u8>, bincode::error::EncodeError> {
+        bincode::serde::encode_to_vec(self, bincode::config::standard())
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, bincode::error::DecodeError> {
+        bincode::serde::decode_from_slice(bytes, bincode::config::standard()).map(|(file, _)| file)
+    }
+}
+
+/// Collects the inputs of every `Playing` frame while `enabled` (`--record-replay`).
+#[derive(Resource, Debug, Default)]
+pub struct ReplayRecorder {
+    pub enabled: bool,
+    pub frames: Vec<ReplayFrame>,
+    current: ReplayFrame,
+}
+
+impl ReplayRecorder {
+    pub fn new(enabled: bool) -> Self {
+        ReplayRecorder {
+            enabled,
+            ..default()
+        }
+    }
+
+    pub fn record_movement(&mut self, delta_secs: f32, direction: Vec2) {
+        if self.enabled {
+            self.current.delta_secs = delta_secs;
+            self.current.movement = direction;
+        }
+    }
+
+    pub fn record_placement(&mut self, position: Vec3, kind: TowerKind) {
+        if self.enabled {
+            self.current
+                .placements
+                .push(ReplayPlacement { position, kind });
+        }
+    }
+
+    pub fn record_wave_seed(&mut self, seed: Option<u64>) {
+        if self.enabled {
+            self.current.wave_seed = seed;
+        }
+    }
+
+    /// Closes the current frame and starts recording the next one.
+    pub fn end_frame(&mut self) {
+        if self.enabled {
+            self.frames.push(std::mem::take(&mut self.current));
+        }
+    }
+
+    /// Drops everything recorded so far, for a run started over.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+        self.current = ReplayFrame::default();
+    }
+
+    pub fn to_file(&self, seed: u64) -> ReplayFile {
+        ReplayFile {
+            seed,
+            frames: self.frames.clone(),
+        }
+    }
+}
+
+/// Present only when launched with `--replay=PATH`, until the recording runs out: recorded frames
+/// fed to the movement and building systems in place of hardware input.
+#[derive(Resource, Debug, Default)]
+pub struct ReplayPlayback {
+    pub frames: Vec<ReplayFrame>,
+    pub frame_index: usize,
+}
+
+impl ReplayPlayback {
+    pub fn new(file: ReplayFile) -> Self {
+        ReplayPlayback {
+            frames: file.frames,
+            frame_index: 0,
+        }
+    }
+
+    /// Frame to replay now; `None` once the recording has run out.
+    pub fn current(&self) -> Option<&ReplayFrame> {
+        self.frames.get(self.frame_index)
+    }
+
+    pub fn advance(&mut self) {
+        self.frame_index = (self.frame_index + 1).min(self.frames.len());
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.frame_index >= self.frames.len()
+    }
+}
@@ -0,0 +1,197 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Running totals for the current run, shown on the run summary when it ends.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct RunStats {
+    pub enemies_killed: u64,
+    pub total_damage_dealt: u64,
+    pub towers_built: u32,
+    pub resources_collected: u64,
+    /// Virtual clock seconds at which the run started. The virtual clock stands still while the
+    /// game is paused or in the menu, so that time is not part of the run. Not saved, since it
+    /// only means something to the session that set it.
+    #[serde(skip)]
+    pub run_start_time: f64,
+    pub waves_survived: u32,
+    /// Wave that `wave_kills` counts for.
+    pub kills_wave: u32,
+    /// Enemies killed during `kills_wave`.
+    pub wave_kills: u32,
+    /// Played under challenge rules (`Tunables::challenge_mode`).
+    pub challenge_mode: bool,
+}
+
+impl RunStats {
+    /// Counts a kill towards the run total and towards `wave`.
+    pub fn record_kill(&mut self, wave: u32) {
+        self.enemies_killed += 1;
+        if self.kills_wave != wave {
+            self.kills_wave = wave;
+            self.wave_kills = 0;
+        }
+        self.wave_kills += 1;
+    }
+
+    /// Enemies killed during `wave`, or 0 if no kill has been recorded for it.
+    pub fn kills_in_wave(&self, wave: u32) -> u32 {
+        if self.kills_wave == wave {
+            self.wave_kills
+        } else {
+            0
+        }
+    }
+
+    pub fn record_damage(&mut self, amount: u32) {
+        self.total_damage_dealt += amount as u64;
+    }
+
+    pub fn record_tower_built(&mut self) {
+        self.towers_built += 1;
+    }
+
+    pub fn record_resources(&mut self, amount: u32) {
+        self.resources_collected += amount as u64;
+    }
+
+    pub fn record_wave_survived(&mut self) {
+        self.waves_survived += 1;
+    }
+
+    /// Seconds since `run_start_time`, never negative.
+    pub fn run_time_secs(&self, now: f64) -> f64 {
+        (now - self.run_start_time).max(0.0)
+    }
+}
+
+/// Best value reached for each stat across all runs of one mode, persisted to
+/// `td/highscore.json`.
+/// Every field is tracked on its own, so the bests may come from different runs.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+#[serde(default)]
+pub struct BestRunStats {
+    pub enemies_killed: u64,
+    pub total_damage_dealt: u64,
+    pub towers_built: u32,
+    pub resources_collected: u64,
+    pub run_time_secs: f64,
+    pub waves_survived: u32,
+}
+
+impl BestRunStats {
+    /// Raises every best that `run` beat and returns the names of those stats.
+    pub fn merge(&mut self, run: &RunStats, run_time_secs: f64) -> Vec<&'static str> {
+        let mut beaten = Vec::new();
+        if run.enemies_killed > self.enemies_killed {
+            self.enemies_killed = run.enemies_killed;
+            beaten.push("Enemies killed");
+        }
+        if run.total_damage_dealt > self.total_damage_dealt {
+            self.total_damage_dealt = run.total_damage_dealt;
+            beaten.push("Damage dealt");
+        }
+        if run.towers_built > self.towers_built {
+            self.towers_built = run.towers_built;
+            beaten.push("Towers built");
+        }
+        if run.resources_collected > self.resources_collected {
+            self.resources_collected = run.resources_collected;
+            beaten.push("Resources collected");
+        }
+        if run_time_secs > self.run_time_secs {
+            self.run_time_secs = run_time_secs;
+            beaten.push("Run time");
+        }
+        if run.waves_survived > self.waves_survived {
+            self.waves_survived = run.waves_survived;
+            beaten.push("Waves survived");
+        }
+        beaten
+    }
+}
+
+/// Contents of `td/highscore.json`: separate bests for normal and challenge runs. The normal
+/// bests stay at the top level, so files written before challenge mode still load.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+pub struct HighScores {
+    #[serde(flatten)]
+    pub normal: BestRunStats,
+    #[serde(default)]
+    pub challenge: BestRunStats,
+}
+
+impl HighScores {
+    /// The bests that a run in the given mode competes against.
+    pub fn for_mode(&mut self, challenge_mode: bool) -> &mut BestRunStats {
+        if challenge_mode {
+            &mut self.challenge
+        } else {
+            &mut self.normal
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_accumulate_into_the_totals() {
+        let mut stats = RunStats::default();
+        stats.record_kill(1);
+        stats.record_kill(1);
+        stats.record_damage(30);
+        stats.record_damage(12);
+        stats.record_tower_built();
+        stats.record_resources(5);
+        stats.record_resources(7);
+        stats.record_wave_survived();
+        assert_eq!(stats.enemies_killed, 2);
+        assert_eq!(stats.total_damage_dealt, 42);
+        assert_eq!(stats.towers_built, 1);
+        assert_eq!(stats.resources_collected, 12);
+        assert_eq!(stats.waves_survived, 1);
+    }
+
+    #[test]
+    fn wave_kills_restart_with_each_wave() {
+        let mut stats = RunStats::default();
+        stats.record_kill(1);
+        stats.record_kill(1);
+        stats.record_kill(2);
+        assert_eq!(stats.kills_in_wave(1), 0);
+        assert_eq!(stats.kills_in_wave(2), 1);
+        assert_eq!(stats.enemies_killed, 3);
+    }
+
+    #[test]
+    fn run_time_is_never_negative() {
+        let stats = RunStats {
+            run_start_time: 10.0,
+            ..default()
+        };
+        assert_eq!(stats.run_time_secs(25.5), 15.5);
+        assert_eq!(stats.run_time_secs(4.0), 0.0);
+    }
+
+    #[test]
+    fn merge_keeps_only_beaten_bests() {
+        let mut best = BestRunStats {
+            enemies_killed: 10,
+            waves_survived: 5,
+            ..default()
+        };
+        let run = RunStats {
+            enemies_killed: 8,
+            total_damage_dealt: 100,
+            waves_survived: 6,
+            ..default()
+        };
+        let beaten = best.merge(&run, 0.0);
+        assert_eq!(beaten, vec!["Damage dealt", "Waves survived"]);
+        assert_eq!(best.enemies_killed, 10);
+        assert_eq!(best.total_damage_dealt, 100);
+        assert_eq!(best.waves_survived, 6);
+    }
+}
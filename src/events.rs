@@ -9,9 +9,29 @@ pub struct ResourceCollected {
 
 // WoodCollected removed; use ResourceCollected
 
+/// Request to switch the player's auto-collect on or off.
+#[derive(Event, Message, Debug)]
+pub struct ToggleAutoCollect;
+
 #[derive(Event, Message, Debug)]
 pub struct TowerBuilt {
     pub position: Vec3,
+    pub kind: crate::components::TowerKind,
+}
+
+/// Request to replace `tower` with a tower of `target_kind` at the same spot.
+#[derive(Event, Message, Debug)]
+pub struct ConvertTower {
+    pub tower: Entity,
+    pub target_kind: crate::components::TowerKind,
+}
+
+/// A tower placement click that was refused for `reason`.
+#[derive(Event, Message, Debug)]
+pub struct PlacementRejected {
+    pub reason: crate::build::placement::PlacementError,
+    #[allow(dead_code)]
+    pub position: Vec3,
 }
 
 #[derive(Event, Message, Debug)]
@@ -22,6 +42,25 @@ pub struct EnemySpawned {
 #[derive(Event, Message, Debug)]
 pub struct EnemyKilled {
     pub position: Vec3,
+    /// Tower whose shot landed the killing blow, if any.
+    pub tower: Option<Entity>,
+}
+
+#[derive(Event, Message, Debug)]
+pub struct VillageDamaged {
+    pub amount: u32,
+    pub remaining: u32,
+}
+
+/// Rewards paid out when a wave is finished; `perfect` when the village took no damage.
+#[derive(Event, Message, Debug)]
+pub struct WaveCompletionBonusEvent {
+    #[allow(dead_code)]
+    pub wave: u32,
+    pub perfect: bool,
+    pub wood: u32,
+    pub rock: u32,
+    pub silver: u64,
 }
 
 #[derive(Event, Message, Debug)]
@@ -29,3 +68,10 @@ pub struct DamageDealt {
     pub amount: u32,
     pub position: Vec3,
 }
+
+/// A breached wall was rebuilt with wood; `position` is where the breach was.
+#[derive(Event, Message, Debug)]
+pub struct WallRepaired {
+    #[allow(dead_code)]
+    pub position: Vec3,
+}
@@ -1,6 +1,7 @@
 // Public library interface for integration tests and shared code
 
 pub mod audio;
+pub mod build;
 pub mod components;
 pub mod constants;
 pub mod core;
@@ -8,6 +9,7 @@ pub mod entities;
 pub mod events;
 pub mod materials;
 pub mod random_policy;
+pub mod save;
 pub mod setup;
 pub mod splash;
 pub mod systems;
@@ -12,53 +12,114 @@ mod entities;
 mod events;
 mod materials;
 mod random_policy;
+mod run_code;
+mod save;
 mod setup;
 mod splash;
 mod systems;
 mod utils;
 mod waves;
 
+use crate::core::grid::SpatialGrid;
+use crate::core::replay::{ReplayFile, ReplayPlayback, ReplayRecorder};
 use build::BuildPlugin;
 use components::*;
-use constants::Tunables;
+use constants::{Tunables, validate_tunables};
 use events::*;
 use materials::*;
-use random_policy::RandomizationPolicy;
+use random_policy::{RandomizationPolicy, load_policy};
+use run_code::{parse_run_code_arg, save_run_code_on_close, update_run_code_text};
 use setup::*;
 use splash::SplashPlugin;
-use systems::camera::camera_system;
+use systems::accessibility::AccessibilityPlugin;
+use systems::camera::{
+    SpectatorCameraState, camera_system, camera_zoom_system, spectator_toggle_input,
+};
+use systems::celebration::{celebration_particle_system, wave_complete_celebration};
+use systems::choke_advisor::ChokePointAdvisorPlugin;
 use systems::chunks::ChunkPlugin;
 use systems::combat::assets::{CombatVfxAssets, init_combat_vfx_assets};
-use systems::combat::enemy::{enemy_spawning, face_enemy_health_bars, update_enemy_health_bars};
+use systems::combat::enemy::{
+    enemy_spawning, face_enemy_health_bars, healer_aura_system, health_bar_lod_system,
+    minion_swarm_system, update_boss_shield_bars, update_enemy_health_bars,
+};
+use systems::combat::lightning_strikes::{
+    lightning_flash_system, lightning_strike_system, lightning_telegraph_system,
+};
 use systems::combat::projectiles::{
-    damage_dealt_spawn_text_system, enemy_fade_out_system, enemy_flash_system,
-    ephemeral_text_despawn_system, impact_effect_system, projectile_system, tower_shooting,
+    boss_shield_break_system, burn_tick_system, damage_dealt_spawn_text_system,
+    enemy_fade_out_system, enemy_flash_system, enemy_frozen_visual_system,
+    ephemeral_text_despawn_system, impact_effect_system, lightning_arc_system, poison_cloud_system,
+    projectile_system, projectile_trail_system, status_effect_timers_system, tower_shooting,
+    trail_particle_system, update_spatial_grid, zombie_resurrection_system,
 };
 use systems::combat::towers::{
-    cleanup_tower_damage_labels, tower_building, tower_damage_label_spawner,
-    tower_damage_label_system, tower_selling_click, tower_spawn_effect_system,
-    update_tower_damage_labels,
+    TowerPriorityPicker, convert_tower_system, count_tower_kills, frustum_cull_towers_system,
+    tower_building, tower_combo_detector, tower_combo_label_system,
+    tower_convert_option_interaction, tower_cooldown_indicator_system, tower_damage_label_spawner,
+    tower_damage_label_system, tower_hover_tooltip_system, tower_priority_picker_interaction,
+    tower_priority_picker_open, tower_selling_click, tower_spawn_effect_system,
+    tower_targeting_toggle_click, update_tower_damage_labels, update_tower_range_rings,
 };
-use systems::input::{handle_game_input, handle_menu_input, pause_toggle_input};
-use systems::movement::{enemy_movement, player_movement};
+use systems::countdown::{PreGameCountdown, PreGameCountdownPlugin};
+use systems::day_night::DayNightPlugin;
+use systems::input::{
+    apply_game_speed, game_speed_input, handle_game_input, handle_menu_input, pause_toggle_input,
+    pause_virtual_time, resume_virtual_time, save_load_input,
+};
+use systems::movement::{enemy_movement, formation_leader_promotion_system, player_movement};
+use systems::pathfinding::{poll_pathfind_tasks_system, spawn_pathfind_tasks_system};
+use systems::replay::{
+    GhostReplay, RunActionLog, RunCodeText, advance_replay_playback, finish_replay_frame,
+    ghost_replay_system, record_tower_placements, replay_playback_placements, save_replay_on_close,
+};
+#[cfg(debug_assertions)]
+use systems::replay::{spawn_replay_frame_index_text, update_replay_frame_index_text};
 use systems::resource_passes::{
     ResourcePassesPlugin, RocksAlongRoadPassPlugin, TownSquareExclusionPassPlugin,
+    TrafficDecalSystem, TreeDensityPassPlugin,
 };
+use systems::road_surface::road_material_params_system;
+use systems::screenshots::{WaveEndScreenshot, wave_end_screenshot_system};
+use systems::spawn_indicators::{manage_spawn_indicators_system, spawn_indicator_label_system};
 use systems::tree_collection::{
+    apply_auto_collect_toggle, auto_collect, auto_collect_ring_system, auto_collect_toggle_input,
     hold_to_collect, resource_collected_spawn_text_system, resource_number_system,
 };
+#[cfg(debug_assertions)]
+use systems::tunables_reload::{TunablesHotReload, tunables_hot_reload_system};
 use systems::ui::collect_bar::{CollectUiState, manage_collect_bar_ui};
+use systems::ui::grid_overlay::GridOverlayPlugin;
 use systems::ui::hud::{
-    spawn_game_speed_indicator, spawn_resource_counters, spawn_village_health_bar, spawn_wave_hud,
-    update_currency_counters, update_game_speed_indicator, update_resource_counters,
-    update_wave_hud, village_health_hud,
+    danger_overlay_system, show_placement_errors, spawn_danger_overlay, spawn_game_speed_indicator,
+    spawn_placement_error_text, spawn_resource_counters, spawn_spectator_watermark,
+    spawn_village_health_bar, spawn_wave_hud, spawn_wind_indicator, toast_fade_system,
+    update_enemy_scaling_warning, update_game_speed_indicator, update_kill_counter,
+    update_resource_counters, update_spectator_watermark, update_wave_hud, update_wind_indicator,
+    village_health_hud, wave_completion_bonus_toast, wave_kill_summary_toast,
 };
+use systems::ui::inspect::TowerInspectionPlugin;
+use systems::ui::minimap::MinimapPlugin;
 use systems::ui::observers::{
     on_enemy_killed, on_enemy_spawned, on_resource_collected, on_tower_built,
 };
+use systems::ui::run_summary::RunSummaryPlugin;
+use systems::ui::settings::SettingsMenuPlugin;
+use systems::ui::tooltip::TooltipPlugin;
 use systems::ui::warmup::warm_ui_pipelines;
+use systems::ui::wave_preview::{spawn_wave_preview_panel, update_wave_preview};
+use systems::ui::wiki::WikiPanelPlugin;
+use systems::village_shield::{
+    shield_button_interaction, shield_slow_field_system, spawn_shield_button, tick_village_shield,
+    update_shield_button_visibility, update_shield_dome,
+};
+use systems::wall_breach::WallBreachPlugin;
 use systems::waves::wave_progression;
+#[cfg(debug_assertions)]
+use systems::waves::{SkipToWave, skip_to_wave_system, skip_wave_input};
+use systems::wind::{WindState, wind_update_system};
 use systems::window::force_exit_on_close;
+use utils::paths::app_data_dir;
 use waves::rules::{WaveRules, WaveSchedule};
 // Frame time graph (Bevy 0.17 dev tools)
 #[cfg(feature = "devtools")]
@@ -67,15 +128,32 @@ use bevy::diagnostic::FrameTimeDiagnosticsPlugin;
 use rand::Rng;
 use std::fs;
 use std::io::Write;
-use std::path::PathBuf;
 
 fn main() {
+    // A --run-code reproduces a shared run: its seed and challenge win over --seed and placements
+    // are replayed.
+    let run_code = parse_run_code_arg();
+
     // Determine the world seed for this run: allow --seed override, otherwise randomize.
-    let launch_seed = determine_launch_seed();
+    // A challenge-<name> seed also selects that challenge's predefined overrides.
+    let (launch_seed, challenge, replay) = match &run_code {
+        Some(code) => (code.seed, code.challenge, None),
+        None => determine_launch_seed(),
+    };
 
     // Start from default tunables, then inject the dynamic seed before the app/plugins read it.
     let mut tunables = Tunables::default();
     tunables.world_seed = launch_seed;
+    if std::env::args().skip(1).any(|arg| arg == "--screenshots") {
+        tunables.auto_screenshot = true;
+    }
+    // Saved policy (td/policy.toml), with --no-seed-* flags unseeding parts of it for this run
+    let mut policy = load_policy();
+    apply_policy_flags(&mut policy);
+    if let Some(challenge) = &challenge {
+        println!("[td] Challenge mode: {}", challenge.name);
+        challenge.apply(&mut tunables);
+    }
 
     // Persist the used seed so we can reproduce a given world later if needed.
     persist_seed_to_app_data(launch_seed);
@@ -85,22 +163,32 @@ fn main() {
     let wave_rules: WaveRules = wave_rules! {
       defaults {
         count = linear(tunables.wave_base_enemy_count, + tunables.wave_enemy_increment);
-        health = exp(1.05);
+        health = const(1.0);
         damage = linear(1.0, + 0.02);
         speed  = const(1.0);
-        composition = weights { EnemyKind::Minion: 0.6, EnemyKind::Zombie: 0.4 };
-        boss_every = 10;
+        composition = weights { EnemyKind::Minion: 0.55, EnemyKind::Zombie: 0.35, EnemyKind::Healer: 0.1 };
+        boss_every = tunables.wave_boss_every;
       }
-      every(10) { boss; }
+      every(tunables.wave_boss_every) { boss; }
     };
     // Precompute wave schedule once during loading. Adjust count as needed.
     let wave_schedule: WaveSchedule =
         WaveSchedule::precompute(300, &wave_rules, &tunables, tunables.world_seed);
+    if let Some(challenge) = challenge {
+        app.insert_resource(ChallengeMode {
+            name: challenge.name,
+        });
+    }
     app.insert_resource(tunables.clone())
         .insert_resource(WaveState::new(&tunables))
+        .init_resource::<WaveTimerPaused>()
+        .init_resource::<GameSpeed>()
+        .init_resource::<SpectatorCameraState>()
+        .insert_resource(VillageShield::new(tunables.shield_duration_secs))
+        .insert_resource(WindState::new(&tunables))
         .insert_resource(wave_rules)
         .insert_resource(CombatVfxAssets::default())
-        .insert_resource(RandomizationPolicy::default())
+        .insert_resource(policy)
         .insert_resource(wave_schedule)
         .add_plugins((DefaultPlugins
             .set(WindowPlugin {
@@ -123,19 +211,71 @@ fn main() {
         .add_plugins((
             MaterialPlugin::<ProjectileMaterial>::default(),
             MaterialPlugin::<ImpactMaterial>::default(),
+            MaterialPlugin::<ShieldMaterial>::default(),
+            MaterialPlugin::<RoadMaterial>::default(),
         ))
         .add_plugins(ChunkPlugin)
         .add_plugins(ResourcePassesPlugin)
         .add_plugins(RocksAlongRoadPassPlugin)
         .add_plugins(TownSquareExclusionPassPlugin)
+        .add_plugins(TreeDensityPassPlugin)
+        .add_plugins(TrafficDecalSystem)
         .add_plugins(SplashPlugin)
         .add_plugins(BuildPlugin)
+        .add_plugins(WikiPanelPlugin)
+        .add_plugins(MinimapPlugin)
+        .add_plugins(SettingsMenuPlugin)
+        .add_plugins(RunSummaryPlugin)
+        .add_plugins(TowerInspectionPlugin)
+        .add_plugins(PreGameCountdownPlugin)
+        .add_plugins(GridOverlayPlugin)
+        .add_plugins(TooltipPlugin)
+        .add_plugins(ChokePointAdvisorPlugin)
+        .add_plugins(WallBreachPlugin)
+        .add_plugins(DayNightPlugin)
         .add_plugins(FrameTimeDiagnosticsPlugin::default());
 
-    // Dev tools (frame time graph) only in devtools feature
+    // Dev tools (frame time graph, road path overlay) only in devtools feature
     #[cfg(feature = "devtools")]
     {
-        app.add_plugins(FrameTimeGraphPlugin);
+        app.add_plugins(FrameTimeGraphPlugin)
+            .add_plugins(systems::ui::policy_editor::PolicyEditorPlugin)
+            .add_plugins(systems::path_debug::PathDebugPlugin);
+    }
+
+    // Spoken announcements of key game events for screen reader users
+    if std::env::args().skip(1).any(|arg| arg == "--accessibility") {
+        app.add_plugins(AccessibilityPlugin);
+    }
+
+    // Global leaderboard submission/fetch on game over
+    #[cfg(feature = "online")]
+    {
+        app.add_plugins(systems::online_leaderboard::OnlineLeaderboardPlugin);
+    }
+
+    // Live-tune gameplay values from td/tunables.toml in development builds
+    #[cfg(debug_assertions)]
+    {
+        app.init_resource::<TunablesHotReload>()
+            .add_systems(Update, tunables_hot_reload_system);
+    }
+
+    // Wave skipping for testing: the HUD button / Shift+N, and --skip-to-wave=N at startup
+    #[cfg(debug_assertions)]
+    {
+        if let Some(waves) = parse_skip_to_wave_arg() {
+            app.insert_resource(SkipToWave(waves));
+        }
+        app.add_systems(
+            Update,
+            (
+                skip_to_wave_system
+                    .run_if(in_state(GameState::Playing).and(resource_exists::<SkipToWave>)),
+                skip_wave_input.run_if(in_state(GameState::Playing)),
+            )
+                .before(wave_progression),
+        );
     }
 
     // Add explicit exit handling and the rest of the systems/plugins
@@ -146,23 +286,37 @@ fn main() {
         .insert_resource(CurrentCollectProgress::default())
         .insert_resource(CollectUiState::default())
         .insert_resource(TowerBuildSelection::default())
+        .init_resource::<StaticObstacleAABBs>()
         .add_message::<ResourceCollected>()
+        .add_message::<ToggleAutoCollect>()
         .add_message::<TowerBuilt>()
+        .add_message::<ConvertTower>()
+        .add_message::<PlacementRejected>()
         .add_message::<EnemySpawned>()
         .add_message::<EnemyKilled>()
+        .add_message::<VillageDamaged>()
+        .add_message::<WallRepaired>()
         .add_message::<DamageDealt>()
+        .add_message::<WaveCompletionBonusEvent>()
         .add_message::<bevy::window::WindowCloseRequested>()
         .add_message::<AppExit>()
         .add_systems(
             OnEnter(GameState::Loading),
             (
                 setup,
+                compute_static_obstacle_aabbs.after(setup),
                 init_combat_vfx_assets,
                 warm_ui_pipelines,
                 spawn_village_health_bar,
+                spawn_shield_button,
                 spawn_resource_counters,
                 spawn_wave_hud,
+                spawn_wave_preview_panel,
                 spawn_game_speed_indicator,
+                spawn_wind_indicator,
+                spawn_danger_overlay,
+                spawn_placement_error_text,
+                spawn_spectator_watermark,
             ),
         )
         .add_systems(Update, handle_menu_input.run_if(in_state(GameState::Menu)))
@@ -178,8 +332,29 @@ fn main() {
             Update,
             pause_toggle_input.run_if(in_state(GameState::Paused)),
         )
-        .add_systems(Update, player_movement.run_if(in_state(GameState::Playing)))
-        .add_systems(Update, tower_building.run_if(in_state(GameState::Playing)))
+        .add_systems(Update, save_load_input.run_if(in_state(GameState::Playing)))
+        .add_systems(
+            Update,
+            game_speed_input.run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(Update, apply_game_speed.after(game_speed_input))
+        .add_systems(OnEnter(GameState::Paused), pause_virtual_time)
+        .add_systems(OnExit(GameState::Paused), resume_virtual_time)
+        .add_systems(OnEnter(GameState::Menu), pause_virtual_time)
+        .add_systems(OnExit(GameState::Menu), resume_virtual_time)
+        .add_systems(
+            Update,
+            player_movement
+                .run_if(in_state(GameState::Playing).and(not(resource_exists::<PreGameCountdown>))),
+        )
+        .add_systems(
+            Update,
+            tower_building.run_if(
+                in_state(GameState::Playing)
+                    .and(not(resource_exists::<ReplayPlayback>))
+                    .and(not(resource_exists::<PreGameCountdown>)),
+            ),
+        )
         .add_systems(
             Update,
             tower_damage_label_spawner.run_if(in_state(GameState::Playing)),
@@ -190,14 +365,106 @@ fn main() {
         )
         .add_systems(
             Update,
-            wave_progression.run_if(in_state(GameState::Playing)),
+            wave_progression
+                .run_if(in_state(GameState::Playing).and(not(resource_exists::<PreGameCountdown>))),
+        )
+        .add_systems(
+            Update,
+            enemy_spawning
+                .run_if(in_state(GameState::Playing).and(not(resource_exists::<PreGameCountdown>))),
+        )
+        .add_systems(
+            Update,
+            manage_spawn_indicators_system.run_if(in_state(GameState::Playing)),
         )
-        .add_systems(Update, enemy_spawning.run_if(in_state(GameState::Playing)))
         .add_systems(Update, enemy_movement.run_if(in_state(GameState::Playing)))
-        .add_systems(Update, tower_shooting.run_if(in_state(GameState::Playing)))
         .add_systems(
             Update,
-            tower_spawn_effect_system.run_if(in_state(GameState::Playing)),
+            formation_leader_promotion_system
+                .before(enemy_movement)
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            (
+                shield_button_interaction,
+                tick_village_shield,
+                update_shield_button_visibility,
+                update_shield_dome,
+                shield_slow_field_system.after(tick_village_shield),
+            )
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            road_material_params_system.run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            (spawn_pathfind_tasks_system, poll_pathfind_tasks_system)
+                .run_if(in_state(GameState::Playing)),
+        )
+        .insert_resource(SpatialGrid::<Entity>::new(tunables.spatial_grid_cell_size))
+        .add_systems(
+            Update,
+            (update_spatial_grid, tower_shooting)
+                .chain()
+                .run_if(in_state(GameState::Playing)),
+        )
+        .init_resource::<WaveEndScreenshot>()
+        .add_systems(
+            Update,
+            wave_end_screenshot_system.run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            (
+                tower_targeting_toggle_click,
+                tower_hover_tooltip_system,
+                healer_aura_system,
+                minion_swarm_system,
+                tower_combo_detector,
+                count_tower_kills,
+            )
+                .run_if(in_state(GameState::Playing)),
+        )
+        .init_resource::<TowerPriorityPicker>()
+        .add_systems(
+            Update,
+            (
+                tower_priority_picker_open,
+                tower_priority_picker_interaction,
+                tower_convert_option_interaction,
+                convert_tower_system.after(tower_convert_option_interaction),
+            )
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            wind_update_system.run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            (
+                lightning_strike_system.after(wind_update_system),
+                lightning_telegraph_system,
+                lightning_flash_system,
+            )
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            (
+                tower_spawn_effect_system,
+                tower_cooldown_indicator_system,
+                update_tower_range_rings,
+            )
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            (wave_complete_celebration, celebration_particle_system)
+                .run_if(in_state(GameState::Playing)),
         )
         .add_systems(
             Update,
@@ -205,8 +472,17 @@ fn main() {
                 projectile_system,
                 damage_dealt_spawn_text_system,
                 enemy_fade_out_system,
+                zombie_resurrection_system,
+                poison_cloud_system,
                 impact_effect_system,
+                lightning_arc_system,
+                projectile_trail_system,
+                trail_particle_system,
                 enemy_flash_system,
+                enemy_frozen_visual_system,
+                burn_tick_system,
+                boss_shield_break_system,
+                status_effect_timers_system,
             )
                 .run_if(in_state(GameState::Playing)),
         )
@@ -214,9 +490,11 @@ fn main() {
         .add_systems(
             PostUpdate,
             (
-                tower_damage_label_system.after(camera_system),
+                frustum_cull_towers_system.after(camera_system),
+                tower_damage_label_system.after(frustum_cull_towers_system),
+                spawn_indicator_label_system.after(camera_system),
+                tower_combo_label_system.after(camera_system),
                 update_tower_damage_labels,
-                cleanup_tower_damage_labels,
             )
                 .run_if(in_state(GameState::Playing)),
         )
@@ -230,15 +508,32 @@ fn main() {
             PostUpdate,
             camera_system.run_if(in_state(GameState::Playing)),
         )
+        .add_systems(
+            Update,
+            (
+                camera_zoom_system,
+                spectator_toggle_input,
+                update_spectator_watermark,
+            )
+                .run_if(in_state(GameState::Playing)),
+        )
         // HUD systems
         .add_systems(
             Update,
             (
                 village_health_hud,
                 update_resource_counters,
-                update_currency_counters,
                 update_wave_hud,
+                update_wave_preview,
+                update_enemy_scaling_warning,
+                update_wind_indicator,
                 manage_collect_bar_ui,
+                danger_overlay_system,
+                show_placement_errors,
+                update_kill_counter,
+                wave_kill_summary_toast,
+                wave_completion_bonus_toast,
+                toast_fade_system,
             )
                 .run_if(in_state(GameState::Playing)),
         )
@@ -251,15 +546,28 @@ fn main() {
         .add_systems(
             Update,
             (
+                health_bar_lod_system,
                 face_enemy_health_bars.run_if(bevy::time::common_conditions::on_timer(
                     std::time::Duration::from_millis(33),
                 )),
                 update_enemy_health_bars,
+                update_boss_shield_bars,
             )
                 .run_if(in_state(GameState::Playing)),
         )
         // Tree collection system
         .add_systems(Update, hold_to_collect.run_if(in_state(GameState::Playing)))
+        .add_systems(
+            Update,
+            (
+                auto_collect_toggle_input,
+                apply_auto_collect_toggle,
+                auto_collect,
+                auto_collect_ring_system,
+            )
+                .chain()
+                .run_if(in_state(GameState::Playing)),
+        )
         // Resource collection number systems
         .add_systems(
             Update,
@@ -274,53 +582,200 @@ fn main() {
         // Window close handling - use force exit for immediate termination
         .add_systems(Update, force_exit_on_close);
 
+    app.insert_resource(RunActionLog::default())
+        .init_resource::<RunCodeText>()
+        .add_systems(
+            Update,
+            (record_tower_placements, update_run_code_text)
+                .chain()
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(Update, save_run_code_on_close.before(force_exit_on_close));
+
+    // Replays record player input with --record-replay and play it back with --replay=PATH
+    let record_replay =
+        replay.is_none() && std::env::args().skip(1).any(|arg| arg == "--record-replay");
+    app.insert_resource(ReplayRecorder::new(record_replay))
+        .add_systems(
+            Last,
+            finish_replay_frame.run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(Update, save_replay_on_close.before(force_exit_on_close));
+    if let Some(replay) = replay {
+        app.insert_resource(ReplayPlayback::new(replay))
+            .insert_resource(RandomizationPolicy::all_seeded())
+            .add_systems(
+                Update,
+                replay_playback_placements
+                    .run_if(in_state(GameState::Playing).and(resource_exists::<ReplayPlayback>)),
+            )
+            .add_systems(
+                Last,
+                advance_replay_playback
+                    .run_if(in_state(GameState::Playing).and(resource_exists::<ReplayPlayback>)),
+            );
+        #[cfg(debug_assertions)]
+        {
+            app.add_systems(OnEnter(GameState::Loading), spawn_replay_frame_index_text)
+                .add_systems(
+                    Update,
+                    update_replay_frame_index_text.run_if(resource_exists::<ReplayPlayback>),
+                );
+        }
+    }
+    if let Some(code) = run_code {
+        println!(
+            "[td] Ghost replay: {} recorded placements (difficulty preset {})",
+            code.placements.len(),
+            code.difficulty
+        );
+        app.insert_resource(GhostReplay {
+            pending: code.placements.into_iter().collect(),
+        })
+        .add_systems(
+            Update,
+            ghost_replay_system
+                .run_if(in_state(GameState::Playing).and(resource_exists::<GhostReplay>)),
+        );
+    }
+
+    // Fail fast on out-of-range values instead of debugging their symptoms in-game
+    if let Err(errors) = validate_tunables(&tunables) {
+        panic!("Invalid tunables:\n  - {}", errors.join("\n  - "));
+    }
+
     app.run();
 }
 
 // Default font is specified per-usage in UI systems to ensure explicit control.
 
 /// Parse command-line arguments for an explicit seed, otherwise generate a random one.
-fn determine_launch_seed() -> u64 {
-    // Accept either --seed=NUMBER or --seed NUMBER
+/// A `challenge-<name>` seed also returns the matching challenge configuration.
+/// `--replay=PATH` loads a recorded replay, whose seed takes precedence over `--seed`.
+fn determine_launch_seed() -> (u64, Option<ChallengeConfig>, Option<ReplayFile>) {
+    // Accept either --flag=VALUE or --flag VALUE
+    let mut seed_values = Vec::new();
+    let mut replay_path = None;
     let mut args = std::env::args().skip(1);
-    let mut pending_seed_flag = false;
     while let Some(arg) = args.next() {
-        if pending_seed_flag {
-            if let Ok(value) = arg.parse::<u64>() {
-                return value;
-            }
-            // If malformed, ignore and continue to random seed
-            pending_seed_flag = false;
-            continue;
+        if let Some(rest) = arg.strip_prefix("--seed=") {
+            seed_values.push(rest.to_string());
+        } else if arg == "--seed" {
+            seed_values.extend(args.next());
+        } else if let Some(rest) = arg.strip_prefix("--replay=") {
+            replay_path = Some(rest.to_string());
+        } else if arg == "--replay" {
+            replay_path = args.next();
         }
+    }
 
-        if let Some(rest) = arg.strip_prefix("--seed=") {
-            if let Ok(value) = rest.parse::<u64>() {
-                return value;
+    if let Some(path) = replay_path {
+        match fs::read(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| ReplayFile::decode(&bytes).map_err(|e| e.to_string()))
+        {
+            Ok(replay) => {
+                println!(
+                    "[td] Replaying {} frames from {} (seed {})",
+                    replay.frames.len(),
+                    path,
+                    replay.seed
+                );
+                return (replay.seed, None, Some(replay));
             }
-        } else if arg == "--seed" {
-            pending_seed_flag = true;
+            Err(e) => eprintln!("[td] Warning: ignoring unreadable replay {:?}: {}", path, e),
         }
     }
 
+    for value in seed_values {
+        if let Ok(seed) = value.parse::<u64>() {
+            return (seed, None, None);
+        }
+        if let Some((seed, challenge)) = parse_challenge_seed(&value) {
+            return (seed, Some(challenge), None);
+        }
+        // If malformed, ignore and continue to random seed
+    }
+
     // No explicit seed provided: generate a random 64-bit seed
     let seed: u64 = rand::rng().random();
     println!("[td] Launching with random world seed: {}", seed);
-    seed
+    (seed, None, None)
+}
+
+/// `--no-seed-road`, `--no-seed-layout` and `--no-seed-resources` turn off seeding of road
+/// generation, town layout and resource placement (chunk content and resource passes).
+fn apply_policy_flags(policy: &mut RandomizationPolicy) {
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--no-seed-road" => policy.road_generation_seeded = false,
+            "--no-seed-layout" => policy.town_layout_seeded = false,
+            "--no-seed-resources" => {
+                policy.chunk_content_seeded = false;
+                policy.resource_rules_seeded = false;
+            }
+            _ => continue,
+        }
+        println!("[td] {}: seeding disabled for this run", arg);
+    }
+}
+
+/// Predefined extreme configuration selected by a `challenge-<name>` seed.
+#[derive(Debug, Clone, Copy)]
+struct ChallengeConfig {
+    name: &'static str,
+    /// Multiplies both the first wave's enemy count and the per-wave increment.
+    enemy_count_multiplier: u32,
+    boss_every: u32,
+    /// Resource nodes are kept out of this radius around the town.
+    town_resource_exclusion_radius: f32,
+}
+
+impl ChallengeConfig {
+    fn apply(&self, tunables: &mut Tunables) {
+        tunables.wave_base_enemy_count *= self.enemy_count_multiplier;
+        tunables.wave_enemy_increment *= self.enemy_count_multiplier;
+        tunables.wave_boss_every = self.boss_every;
+        tunables.town_resource_exclusion_radius = self.town_resource_exclusion_radius;
+        tunables.challenge_mode = true;
+        tunables.challenge_misplace_penalty_pct = 0.5;
+    }
+}
+
+const CHALLENGES: [ChallengeConfig; 1] = [ChallengeConfig {
+    name: "neverending",
+    enemy_count_multiplier: 2,
+    boss_every: 3,
+    town_resource_exclusion_radius: 250.0,
+}];
+
+/// Map a `challenge-<name>` seed argument to a fixed world seed and its challenge.
+/// The world seed is a hash of the name, so every player gets the same layout.
+fn parse_challenge_seed(arg: &str) -> Option<(u64, ChallengeConfig)> {
+    let name = arg.strip_prefix("challenge-")?;
+    let Some(challenge) = CHALLENGES
+        .iter()
+        .find(|c| c.name.eq_ignore_ascii_case(name))
+    else {
+        eprintln!("[td] Warning: unknown challenge {:?}", name);
+        return None;
+    };
+    // FNV-1a
+    let seed = challenge
+        .name
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+    Some((seed, *challenge))
 }
 
 /// Save the seed into the platform-specific app data directory under td/seed.txt.
 fn persist_seed_to_app_data(seed: u64) {
-    // Prefer a standard data dir; fall back to current dir if unavailable.
-    let base_dir: PathBuf = match dirs_next::data_dir() {
-        Some(p) => p,
-        None => match std::env::current_dir() {
-            Ok(p) => p,
-            Err(_) => return, // Give up quietly if we can't determine any directory
-        },
+    // Give up quietly if we can't determine any directory
+    let Some(dir) = app_data_dir() else {
+        return;
     };
-
-    let dir = base_dir.join("td");
     let file_path = dir.join("seed.txt");
 
     if let Err(e) = fs::create_dir_all(&dir) {
@@ -351,3 +806,29 @@ fn persist_seed_to_app_data(seed: u64) {
         }
     }
 }
+
+/// Parse `--skip-to-wave N` / `--skip-to-wave=N`. Invalid values are reported and ignored.
+#[cfg(debug_assertions)]
+fn parse_skip_to_wave_arg() -> Option<u32> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let raw = if let Some(rest) = arg.strip_prefix("--skip-to-wave=") {
+            rest.to_string()
+        } else if arg == "--skip-to-wave" {
+            args.next()?
+        } else {
+            continue;
+        };
+        match raw.parse::<u32>() {
+            Ok(waves) => return Some(waves),
+            Err(_) => {
+                eprintln!(
+                    "[td] Warning: ignoring invalid --skip-to-wave value {:?}",
+                    raw
+                );
+                return None;
+            }
+        }
+    }
+    None
+}
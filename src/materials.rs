@@ -76,4 +76,86 @@ impl Material for ImpactMaterial {
     }
 }
 
+/// Uniform data for the village shield dome.
+#[derive(Clone, Copy, ShaderType, Default, Debug)]
+pub struct ShieldMaterialUniform {
+    pub color: Vec4,
+    pub time: f32,
+    pub _pad: Vec3,
+}
+
+/// Pulsing translucent dome with a hexagonal grid pattern.
+#[derive(Asset, AsBindGroup, TypePath, Debug, Clone)]
+pub struct ShieldMaterial {
+    #[uniform(0)]
+    pub data: ShieldMaterialUniform,
+}
+
+impl ShieldMaterial {
+    pub fn new(color: Color) -> Self {
+        let linear = color.to_linear();
+        let rgba = linear.to_f32_array();
+        ShieldMaterial {
+            data: ShieldMaterialUniform {
+                color: Vec4::from_array(rgba),
+                time: 0.0,
+                _pad: Vec3::ZERO,
+            },
+        }
+    }
+}
+
+impl Material for ShieldMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/shield.wgsl".into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode {
+        AlphaMode::Blend
+    }
+}
+
+/// Uniform data for road surfaces.
+#[derive(Clone, Copy, ShaderType, Default, Debug)]
+pub struct RoadMaterialUniform {
+    pub base_color: Vec4,
+    /// Tiles per second the surface currently scrolls at.
+    pub scroll_speed: f32,
+    /// Tiles per road patch.
+    pub tile_scale: f32,
+    /// Scroll offset in tiles, added to `scroll_speed * globals.time`; shifted on the CPU when
+    /// the speed changes so the pattern doesn't jump.
+    pub scroll: f32,
+    pub _pad: f32,
+}
+
+/// Lit road surface whose tiles scroll along the patch's local +X, toward the village.
+#[derive(Asset, AsBindGroup, TypePath, Debug, Clone)]
+pub struct RoadMaterial {
+    #[uniform(0)]
+    pub data: RoadMaterialUniform,
+}
+
+impl RoadMaterial {
+    pub fn new(color: Color, tile_scale: f32) -> Self {
+        let linear = color.to_linear();
+        let rgba = linear.to_f32_array();
+        RoadMaterial {
+            data: RoadMaterialUniform {
+                base_color: Vec4::from_array(rgba),
+                scroll_speed: 0.0,
+                tile_scale,
+                scroll: 0.0,
+                _pad: 0.0,
+            },
+        }
+    }
+}
+
+impl Material for RoadMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/road.wgsl".into()
+    }
+}
+
 // Explosion material removed
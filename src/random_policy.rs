@@ -1,12 +1,21 @@
+use crate::utils::paths::app_data_dir;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+const POLICY_FILE_NAME: &str = "policy.toml";
 
 /// Centralized toggles for which systems should be deterministic (seeded)
 /// versus non-deterministic (fresh random each run/event).
-#[derive(Resource, Debug, Clone, Copy)]
+/// Read once at startup from `td/policy.toml` (see `load_policy`) when that file exists.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct RandomizationPolicy {
     /// Whether wave composition (mix and order of enemies) is seeded.
     pub wave_composition_seeded: bool,
-    /// Whether enemy spawn entrance selection / ring angle is seeded.
+    /// Whether the ring angle of enemies spawned without roads is seeded (roads are taken in
+    /// turn).
     pub enemy_spawn_selection_seeded: bool,
     /// Whether town layout decisions (gate side, offsets, etc.) are seeded.
     pub town_layout_seeded: bool,
@@ -18,8 +27,9 @@ pub struct RandomizationPolicy {
     pub resource_rules_seeded: bool,
 }
 
-impl Default for RandomizationPolicy {
-    fn default() -> Self {
+impl RandomizationPolicy {
+    /// Every system seeded, as required for replay playback to be deterministic.
+    pub fn all_seeded() -> Self {
         RandomizationPolicy {
             wave_composition_seeded: true,
             enemy_spawn_selection_seeded: true,
@@ -29,4 +39,70 @@ impl Default for RandomizationPolicy {
             resource_rules_seeded: true,
         }
     }
+
+    /// Every toggle as (field name, value), in declaration order.
+    #[cfg(feature = "devtools")]
+    pub fn fields(&self) -> [(&'static str, bool); 6] {
+        [
+            ("wave_composition_seeded", self.wave_composition_seeded),
+            (
+                "enemy_spawn_selection_seeded",
+                self.enemy_spawn_selection_seeded,
+            ),
+            ("town_layout_seeded", self.town_layout_seeded),
+            ("road_generation_seeded", self.road_generation_seeded),
+            ("chunk_content_seeded", self.chunk_content_seeded),
+            ("resource_rules_seeded", self.resource_rules_seeded),
+        ]
+    }
+
+    /// The toggle at `index` in `fields()` order.
+    #[cfg(feature = "devtools")]
+    pub fn field_mut(&mut self, index: usize) -> Option<&mut bool> {
+        match index {
+            0 => Some(&mut self.wave_composition_seeded),
+            1 => Some(&mut self.enemy_spawn_selection_seeded),
+            2 => Some(&mut self.town_layout_seeded),
+            3 => Some(&mut self.road_generation_seeded),
+            4 => Some(&mut self.chunk_content_seeded),
+            5 => Some(&mut self.resource_rules_seeded),
+            _ => None,
+        }
+    }
+}
+
+impl Default for RandomizationPolicy {
+    fn default() -> Self {
+        RandomizationPolicy::all_seeded()
+    }
+}
+
+fn policy_path() -> Option<PathBuf> {
+    app_data_dir().map(|dir| dir.join(POLICY_FILE_NAME))
+}
+
+/// The policy saved in `td/policy.toml`, or the default when there is no (readable) file.
+pub fn load_policy() -> RandomizationPolicy {
+    let Some(path) = policy_path() else {
+        return RandomizationPolicy::default();
+    };
+    let Ok(text) = fs::read_to_string(&path) else {
+        return RandomizationPolicy::default();
+    };
+    toml::from_str(&text).unwrap_or_else(|e| {
+        eprintln!("[td] Warning: ignoring unreadable {:?}: {}", path, e);
+        RandomizationPolicy::default()
+    })
+}
+
+/// Writes `policy` to `td/policy.toml`, to be picked up by the next launch.
+#[cfg(feature = "devtools")]
+pub fn save_policy(policy: &RandomizationPolicy) -> Result<PathBuf, String> {
+    let path = policy_path().ok_or("no app data directory")?;
+    let text = toml::to_string(policy).map_err(|e| e.to_string())?;
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&path, text))
+        .map_err(|e| e.to_string())?;
+    Ok(path)
 }
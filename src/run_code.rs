@@ -0,0 +1,288 @@
+//! Run codes: shareable runs, passed back in with `--run-code` to replay their tower placements.
+
+use crate::components::{ChallengeMode, TowerKind};
+use crate::constants::Tunables;
+use crate::systems::replay::{RecordedPlacement, RunActionLog, RunCodeText};
+use crate::utils::paths::app_data_dir;
+use crate::{CHALLENGES, ChallengeConfig};
+use bevy::prelude::*;
+use std::fs;
+
+/// Shareable run description: world seed, difficulty preset, challenge, and the player's tower
+/// placements. Encoded as URL-safe base64 with a trailing checksum.
+pub struct RunCode {
+    pub seed: u64,
+    /// Difficulty preset id; only the default preset (0) exists so far.
+    pub difficulty: u8,
+    /// Challenge the run was played in, whose overrides (and `challenge_mode`) the replay applies.
+    pub challenge: Option<ChallengeConfig>,
+    pub placements: Vec<RecordedPlacement>,
+}
+
+/// Only codes of this version decode.
+const RUN_CODE_VERSION: u8 = 2;
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+fn encode_run_code(code: &RunCode) -> String {
+    let count = code.placements.len().min(u16::MAX as usize);
+    let mut bytes = Vec::with_capacity(16 + count * 7);
+    bytes.push(RUN_CODE_VERSION);
+    bytes.extend_from_slice(&code.seed.to_le_bytes());
+    bytes.push(code.difficulty);
+    bytes.push(challenge_to_byte(code.challenge));
+    bytes.extend_from_slice(&(count as u16).to_le_bytes());
+    for p in code.placements.iter().take(count) {
+        let wave = p.wave.min(u16::MAX as u32) as u16;
+        let x = p.cell.x.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+        let z = p.cell.y.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+        bytes.extend_from_slice(&wave.to_le_bytes());
+        bytes.push(tower_kind_to_byte(p.kind));
+        bytes.extend_from_slice(&x.to_le_bytes());
+        bytes.extend_from_slice(&z.to_le_bytes());
+    }
+    let checksum = fnv1a32(&bytes);
+    bytes.extend_from_slice(&checksum.to_le_bytes());
+    base64_encode(&bytes)
+}
+
+fn decode_run_code(code: &str) -> Option<RunCode> {
+    let bytes = base64_decode(code.trim())?;
+    let (body, checksum) = bytes.split_at_checked(bytes.len().checked_sub(4)?)?;
+    if fnv1a32(body).to_le_bytes() != checksum {
+        return None;
+    }
+
+    let mut cursor = body;
+    let version = take_bytes(&mut cursor, 1)?[0];
+    if version != RUN_CODE_VERSION {
+        return None;
+    }
+    let seed = u64::from_le_bytes(take_bytes(&mut cursor, 8)?.try_into().ok()?);
+    let difficulty = take_bytes(&mut cursor, 1)?[0];
+    let challenge = challenge_from_byte(take_bytes(&mut cursor, 1)?[0])?;
+    let count = u16::from_le_bytes(take_bytes(&mut cursor, 2)?.try_into().ok()?);
+    let mut placements = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let wave = u16::from_le_bytes(take_bytes(&mut cursor, 2)?.try_into().ok()?);
+        let kind = tower_kind_from_byte(take_bytes(&mut cursor, 1)?[0])?;
+        let x = i16::from_le_bytes(take_bytes(&mut cursor, 2)?.try_into().ok()?);
+        let z = i16::from_le_bytes(take_bytes(&mut cursor, 2)?.try_into().ok()?);
+        placements.push(RecordedPlacement {
+            wave: wave as u32,
+            cell: IVec2::new(x as i32, z as i32),
+            kind,
+        });
+    }
+    Some(RunCode {
+        seed,
+        difficulty,
+        challenge,
+        placements,
+    })
+}
+
+/// 0 for no challenge, else one past the challenge's index in `CHALLENGES`.
+fn challenge_to_byte(challenge: Option<ChallengeConfig>) -> u8 {
+    challenge
+        .and_then(|challenge| CHALLENGES.iter().position(|c| c.name == challenge.name))
+        .map_or(0, |index| index as u8 + 1)
+}
+
+/// Inverse of `challenge_to_byte`; `None` for a byte naming no known challenge.
+fn challenge_from_byte(byte: u8) -> Option<Option<ChallengeConfig>> {
+    match byte {
+        0 => Some(None),
+        _ => CHALLENGES.get(byte as usize - 1).copied().map(Some),
+    }
+}
+
+fn take_bytes<'a>(cursor: &mut &'a [u8], n: usize) -> Option<&'a [u8]> {
+    let (head, rest) = cursor.split_at_checked(n)?;
+    *cursor = rest;
+    Some(head)
+}
+
+fn tower_kind_to_byte(kind: TowerKind) -> u8 {
+    match kind {
+        TowerKind::Bow => 0,
+        TowerKind::Crossbow => 1,
+        TowerKind::Lightning => 2,
+        TowerKind::Catapult => 3,
+        TowerKind::Cannon => 4,
+    }
+}
+
+fn tower_kind_from_byte(byte: u8) -> Option<TowerKind> {
+    match byte {
+        0 => Some(TowerKind::Bow),
+        1 => Some(TowerKind::Crossbow),
+        2 => Some(TowerKind::Lightning),
+        3 => Some(TowerKind::Catapult),
+        4 => Some(TowerKind::Cannon),
+        _ => None,
+    }
+}
+
+fn fnv1a32(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811C_9DC5_u32, |hash, b| {
+        (hash ^ *b as u32).wrapping_mul(0x0100_0193)
+    })
+}
+
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc, (i, b)| acc | (*b as u32) << (16 - 8 * i));
+        // Emit one sextet per 6 bits of input (no padding)
+        for i in 0..=chunk.len() {
+            out.push(BASE64_ALPHABET[((n >> (18 - 6 * i)) & 0x3F) as usize] as char);
+        }
+    }
+    out
+}
+
+fn base64_decode(text: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(text.len() * 3 / 4);
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    for c in text.bytes() {
+        let value = BASE64_ALPHABET.iter().position(|a| *a == c)? as u32;
+        acc = (acc << 6) | value;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+            acc &= (1 << bits) - 1;
+        }
+    }
+    Some(out)
+}
+
+/// Parse `--run-code CODE` / `--run-code=CODE`. Malformed codes are reported and ignored.
+pub fn parse_run_code_arg() -> Option<RunCode> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let raw = if let Some(rest) = arg.strip_prefix("--run-code=") {
+            rest.to_string()
+        } else if arg == "--run-code" {
+            args.next()?
+        } else {
+            continue;
+        };
+        match decode_run_code(&raw) {
+            Some(code) => return Some(code),
+            None => {
+                eprintln!("[td] Warning: ignoring invalid run code {:?}", raw);
+                return None;
+            }
+        }
+    }
+    None
+}
+
+/// This session's run code: world seed, challenge and every placement logged so far.
+fn session_run_code(
+    tunables: &Tunables,
+    challenge: Option<&ChallengeMode>,
+    log: &RunActionLog,
+) -> String {
+    encode_run_code(&RunCode {
+        seed: tunables.world_seed,
+        difficulty: 0,
+        challenge: challenge
+            .and_then(|mode| CHALLENGES.iter().find(|c| c.name == mode.name).copied()),
+        placements: log.placements.clone(),
+    })
+}
+
+/// Re-encodes the run code the game over screen copies whenever a placement is logged.
+pub fn update_run_code_text(
+    tunables: Res<Tunables>,
+    challenge: Option<Res<ChallengeMode>>,
+    log: Res<RunActionLog>,
+    mut text: ResMut<RunCodeText>,
+) {
+    if !log.is_changed() && !text.0.is_empty() {
+        return;
+    }
+    text.0 = session_run_code(&tunables, challenge.as_deref(), &log);
+}
+
+/// Writes this session's run code to td/run_code.txt when the window is closed.
+pub fn save_run_code_on_close(
+    mut close_events: MessageReader<bevy::window::WindowCloseRequested>,
+    tunables: Res<Tunables>,
+    challenge: Option<Res<ChallengeMode>>,
+    log: Res<RunActionLog>,
+) {
+    if close_events.read().next().is_none() {
+        return;
+    }
+    let code = session_run_code(&tunables, challenge.as_deref(), &log);
+    println!("[td] Run code: {}", code);
+
+    let Some(dir) = app_data_dir() else {
+        return;
+    };
+    let file_path = dir.join("run_code.txt");
+    if let Err(e) = fs::create_dir_all(&dir).and_then(|_| fs::write(&file_path, &code)) {
+        eprintln!(
+            "[td] Warning: failed to write run code to {:?}: {}",
+            file_path, e
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_code_round_trips_with_its_challenge() {
+        let code = RunCode {
+            seed: 0xDEAD_BEEF,
+            difficulty: 0,
+            challenge: Some(CHALLENGES[0]),
+            placements: vec![RecordedPlacement {
+                wave: 3,
+                cell: IVec2::new(-12, 40),
+                kind: TowerKind::Cannon,
+            }],
+        };
+        let decoded = decode_run_code(&encode_run_code(&code)).expect("valid run code");
+        assert_eq!(decoded.seed, code.seed);
+        assert_eq!(decoded.challenge.map(|c| c.name), Some(CHALLENGES[0].name));
+        assert_eq!(decoded.placements, code.placements);
+
+        let mut tunables = Tunables::default();
+        decoded.challenge.unwrap().apply(&mut tunables);
+        assert!(tunables.challenge_mode);
+    }
+
+    #[test]
+    fn run_code_without_challenge_decodes_to_none() {
+        let code = RunCode {
+            seed: 7,
+            difficulty: 0,
+            challenge: None,
+            placements: Vec::new(),
+        };
+        let decoded = decode_run_code(&encode_run_code(&code)).expect("valid run code");
+        assert!(decoded.challenge.is_none());
+    }
+
+    #[test]
+    fn older_run_code_versions_are_rejected() {
+        let mut bytes = vec![1];
+        bytes.extend_from_slice(&42u64.to_le_bytes());
+        bytes.push(0);
+        bytes.extend_from_slice(&0u16.to_le_bytes());
+        let checksum = fnv1a32(&bytes);
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+        assert!(decode_run_code(&base64_encode(&bytes)).is_none());
+    }
+}
@@ -0,0 +1,456 @@
+//! Quick save/load of a running game to `td/save.json` in the app data directory.
+//!
+//! Only the state a player would miss is stored: inventory, wave progress, placed towers with their
+//! upgrades, the village's health and the run's stats and action log. Enemies are not saved; a wave
+//! interrupted by saving is replayed after loading.
+
+use crate::components::{
+    BuiltTower, Enemy, Player, Tower, TowerGhost, TowerKind, TowerSpecificUpgrade,
+    TowerUpgradeConfig, UpgradeableStat, Village, WavePhase, WaveState,
+};
+use crate::constants::Tunables;
+use crate::core::stats::RunStats;
+use crate::systems::combat::towers::restore_tower;
+use crate::systems::replay::{RecordedPlacement, RunActionLog};
+use crate::systems::ui::inspect::{spawn_upgrade_star, upgrade_star_assets};
+use crate::utils::paths::app_data_dir;
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+const SAVE_FILE_NAME: &str = "save.json";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SaveState {
+    pub player: PlayerSave,
+    pub wave: WaveSave,
+    pub towers: Vec<TowerSave>,
+    pub village_health: u32,
+    pub village_max_health: u32,
+    /// Missing from saves written before stats were saved.
+    #[serde(default)]
+    pub stats: RunStats,
+    /// Run time played before the save, since `RunStats::run_start_time` is not saved.
+    #[serde(default)]
+    pub run_time_secs: f64,
+    #[serde(default)]
+    pub placements: Vec<RecordedPlacement>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct PlayerSave {
+    pub wood: u32,
+    pub rock: u32,
+    pub silver: u64,
+    pub gold: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct WaveSave {
+    pub current_wave: u32,
+    /// Whether `current_wave` was still being fought when the game was saved.
+    pub in_progress: bool,
+    pub intermission_remaining_secs: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TowerSave {
+    pub kind: TowerKind,
+    pub translation: Vec3,
+    /// Stats with `upgrades` already applied.
+    pub range: f32,
+    pub damage: u32,
+    pub fire_interval_secs: f32,
+    pub projectile_speed: f32,
+    /// Upgrades bought for this tower only, in the order they were bought.
+    #[serde(default)]
+    pub upgrades: Vec<TowerUpgradeSave>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct TowerUpgradeSave {
+    pub stat: UpgradeableStat,
+    pub bonus: f32,
+}
+
+impl From<&Player> for PlayerSave {
+    fn from(player: &Player) -> Self {
+        PlayerSave {
+            wood: player.wood,
+            rock: player.rock,
+            silver: player.silver,
+            gold: player.gold,
+        }
+    }
+}
+
+fn save_path() -> Result<PathBuf, String> {
+    app_data_dir()
+        .map(|dir| dir.join(SAVE_FILE_NAME))
+        .ok_or_else(|| "no app data directory".to_string())
+}
+
+impl SaveState {
+    /// Snapshot of the saveable state in `world`.
+    pub fn capture(world: &World) -> Result<Self, String> {
+        let player = world
+            .try_query::<&Player>()
+            .and_then(|mut q| q.iter(world).next().map(PlayerSave::from))
+            .ok_or("no player to save")?;
+        let (village_health, village_max_health) = world
+            .try_query::<&Village>()
+            .and_then(|mut q| q.iter(world).next().map(|v| (v.health, v.max_health)))
+            .ok_or("no village to save")?;
+        let wave_state = world
+            .get_resource::<WaveState>()
+            .ok_or("no wave state to save")?;
+        let specific_upgrades: Vec<TowerSpecificUpgrade> = world
+            .try_query::<&TowerSpecificUpgrade>()
+            .map(|mut q| q.iter(world).copied().collect())
+            .unwrap_or_default();
+        let towers = world
+            .try_query::<(Entity, &Transform, &Tower, &BuiltTower)>()
+            .map(|mut q| {
+                // Not a query filter: that would find nothing while `TowerGhost` is unregistered
+                q.iter(world)
+                    .filter(|(entity, ..)| world.get::<TowerGhost>(*entity).is_none())
+                    .map(|(entity, transform, tower, built)| TowerSave {
+                        kind: built.kind,
+                        translation: transform.translation,
+                        range: tower.range,
+                        damage: tower.damage,
+                        fire_interval_secs: tower.fire_interval_secs,
+                        projectile_speed: tower.projectile_speed,
+                        upgrades: specific_upgrades
+                            .iter()
+                            .filter(|u| u.tower_entity == entity)
+                            .map(|u| TowerUpgradeSave {
+                                stat: u.stat,
+                                bonus: u.bonus,
+                            })
+                            .collect(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let stats = world
+            .get_resource::<RunStats>()
+            .cloned()
+            .unwrap_or_default();
+        let now = world
+            .get_resource::<Time<Virtual>>()
+            .map_or(0.0, |time| time.elapsed_secs_f64());
+        let placements = world
+            .get_resource::<RunActionLog>()
+            .map(|log| log.placements.clone())
+            .unwrap_or_default();
+
+        Ok(SaveState {
+            player,
+            wave: WaveSave {
+                current_wave: wave_state.current_wave,
+                in_progress: wave_state.phase == WavePhase::Spawning,
+                intermission_remaining_secs: wave_state.remaining_intermission_secs(),
+            },
+            towers,
+            village_health,
+            village_max_health,
+            run_time_secs: stats.run_time_secs(now),
+            stats,
+            placements,
+        })
+    }
+
+    /// Puts this save into `world`: existing towers and enemies are despawned and the saved towers
+    /// are restored with their saved stats and upgrades. Restored towers write no `TowerBuilt`, so
+    /// they are not counted as built again; the run stats and action log come from the save.
+    #[allow(clippy::type_complexity)]
+    pub fn restore(&self, world: &mut World) {
+        let doomed: Vec<Entity> = world
+            .query_filtered::<Entity, Or<(With<BuiltTower>, With<Enemy>)>>()
+            .iter(world)
+            .collect();
+        for entity in doomed {
+            world.despawn(entity);
+        }
+
+        let mut spawner: SystemState<(
+            Commands,
+            ResMut<Assets<Mesh>>,
+            ResMut<Assets<StandardMaterial>>,
+            Res<Tunables>,
+            Res<TowerUpgradeConfig>,
+        )> = SystemState::new(world);
+        {
+            let (mut commands, mut meshes, mut materials, tunables, upgrade_config) =
+                spawner.get_mut(world);
+            let star_assets = upgrade_star_assets(&mut meshes, &mut materials);
+            for saved in &self.towers {
+                let tower = restore_tower(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    &tunables,
+                    saved.kind,
+                    saved.translation.with_y(0.0),
+                    saved.damage,
+                    saved.fire_interval_secs,
+                    saved.projectile_speed,
+                    saved.range,
+                );
+                for (slot, upgrade) in saved.upgrades.iter().enumerate() {
+                    spawn_upgrade_star(
+                        &mut commands,
+                        TowerSpecificUpgrade {
+                            tower_entity: tower,
+                            bonus: upgrade.bonus,
+                            stat: upgrade.stat,
+                        },
+                        star_assets.clone(),
+                        slot as u32,
+                        upgrade_config.max_level,
+                        saved.kind.size().1,
+                    );
+                }
+            }
+        }
+        spawner.apply(world);
+
+        if let Ok(mut player) = world.query::<&mut Player>().single_mut(world) {
+            player.wood = self.player.wood;
+            player.rock = self.player.rock;
+            player.silver = self.player.silver;
+            player.gold = self.player.gold;
+        }
+        if let Ok(mut village) = world.query::<&mut Village>().single_mut(world) {
+            village.health = self.village_health;
+            village.max_health = self.village_max_health;
+        }
+
+        let now = world
+            .get_resource::<Time<Virtual>>()
+            .map_or(0.0, |time| time.elapsed_secs_f64());
+        world.insert_resource(RunStats {
+            run_start_time: now - self.run_time_secs,
+            ..self.stats.clone()
+        });
+        world.insert_resource(RunActionLog {
+            placements: self.placements.clone(),
+        });
+
+        let intermission_secs = world.resource::<Tunables>().wave_intermission_secs;
+        let mut wave_state = world.resource_mut::<WaveState>();
+        wave_state.spawn_queue.clear();
+        wave_state.enemies_to_spawn = 0;
+        wave_state.enemies_spawned = 0;
+        if self.wave.in_progress {
+            // Its enemies were not saved, so the interrupted wave starts over
+            wave_state.current_wave = self.wave.current_wave.saturating_sub(1);
+            wave_state.start_intermission(intermission_secs);
+        } else {
+            wave_state.current_wave = self.wave.current_wave;
+            wave_state.start_intermission(self.wave.intermission_remaining_secs);
+        }
+    }
+}
+
+/// Writes the current game to `td/save.json` and returns the file path.
+pub fn save_game(world: &World) -> Result<PathBuf, String> {
+    let state = SaveState::capture(world)?;
+    let json = serde_json::to_string_pretty(&state).map_err(|e| e.to_string())?;
+    let path = save_path()?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    fs::write(&path, json).map_err(|e| e.to_string())?;
+    Ok(path)
+}
+
+/// Replaces the running game with the one in `td/save.json`.
+pub fn load_game(world: &mut World) -> Result<(), String> {
+    let json = fs::read_to_string(save_path()?).map_err(|e| e.to_string())?;
+    let state: SaveState = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+    state.restore(world);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::TowerBuilt;
+
+    #[test]
+    fn save_state_round_trips_through_json() {
+        let state = SaveState {
+            player: PlayerSave {
+                wood: 12,
+                rock: 7,
+                silver: 340,
+                gold: 5,
+            },
+            wave: WaveSave {
+                current_wave: 9,
+                in_progress: true,
+                intermission_remaining_secs: 3.25,
+            },
+            towers: vec![
+                TowerSave {
+                    kind: TowerKind::Bow,
+                    translation: Vec3::new(4.0, 1.5, -8.0),
+                    range: 12.5,
+                    damage: 8,
+                    fire_interval_secs: 0.75,
+                    projectile_speed: 30.0,
+                    upgrades: vec![
+                        TowerUpgradeSave {
+                            stat: UpgradeableStat::Damage,
+                            bonus: 3.0,
+                        },
+                        TowerUpgradeSave {
+                            stat: UpgradeableStat::Range,
+                            bonus: 1.5,
+                        },
+                    ],
+                },
+                TowerSave {
+                    kind: TowerKind::Crossbow,
+                    translation: Vec3::new(-2.5, 1.5, 6.0),
+                    range: 18.0,
+                    damage: 20,
+                    fire_interval_secs: 1.5,
+                    projectile_speed: 45.0,
+                    upgrades: Vec::new(),
+                },
+            ],
+            village_health: 80,
+            village_max_health: 100,
+            stats: RunStats {
+                enemies_killed: 41,
+                towers_built: 2,
+                waves_survived: 8,
+                ..default()
+            },
+            run_time_secs: 312.5,
+            placements: vec![
+                RecordedPlacement {
+                    wave: 0,
+                    cell: IVec2::new(4, -8),
+                    kind: TowerKind::Bow,
+                },
+                RecordedPlacement {
+                    wave: 6,
+                    cell: IVec2::new(-2, 6),
+                    kind: TowerKind::Crossbow,
+                },
+            ],
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        let loaded: SaveState = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, state);
+    }
+
+    fn world_with_player_and_village() -> World {
+        let mut world = World::new();
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.init_resource::<Messages<TowerBuilt>>();
+        world.init_resource::<Time<Virtual>>();
+        world.init_resource::<RunStats>();
+        world.init_resource::<RunActionLog>();
+        world.insert_resource(Tunables::default());
+        world.insert_resource(TowerUpgradeConfig::default());
+        world.insert_resource(WaveState::new(&Tunables::default()));
+        world.spawn(Player::starting());
+        world.spawn(Village {
+            health: 100,
+            max_health: 100,
+        });
+        world
+    }
+
+    #[test]
+    fn loading_restores_towers_upgrades_and_run_stats_without_rebuilding() {
+        let mut world = world_with_player_and_village();
+        let mut saved_towers = SystemState::<(
+            Commands,
+            ResMut<Assets<Mesh>>,
+            ResMut<Assets<StandardMaterial>>,
+            Res<Tunables>,
+        )>::new(&mut world);
+        {
+            let (mut commands, mut meshes, mut materials, tunables) =
+                saved_towers.get_mut(&mut world);
+            let tower = restore_tower(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                &tunables,
+                TowerKind::Bow,
+                Vec3::new(4.0, 0.0, -8.0),
+                11,
+                0.75,
+                30.0,
+                14.0,
+            );
+            commands.spawn(TowerSpecificUpgrade {
+                tower_entity: tower,
+                bonus: 3.0,
+                stat: UpgradeableStat::Damage,
+            });
+        }
+        saved_towers.apply(&mut world);
+        {
+            let mut stats = world.resource_mut::<RunStats>();
+            stats.towers_built = 1;
+            stats.enemies_killed = 17;
+        }
+        world
+            .resource_mut::<RunActionLog>()
+            .placements
+            .push(RecordedPlacement {
+                wave: 0,
+                cell: IVec2::new(4, -8),
+                kind: TowerKind::Bow,
+            });
+        let state = SaveState::capture(&world).unwrap();
+        assert_eq!(
+            state.towers[0].upgrades,
+            vec![TowerUpgradeSave {
+                stat: UpgradeableStat::Damage,
+                bonus: 3.0,
+            }]
+        );
+
+        let mut loaded = world_with_player_and_village();
+        state.restore(&mut loaded);
+
+        let towers: Vec<(Entity, u32, f32)> = loaded
+            .query::<(Entity, &Tower)>()
+            .iter(&loaded)
+            .map(|(entity, tower)| (entity, tower.damage, tower.range))
+            .collect();
+        assert_eq!(towers.len(), 1);
+        let (tower, damage, range) = towers[0];
+        assert_eq!(damage, 11);
+        assert_eq!(range, 14.0);
+        let upgrades: Vec<TowerSpecificUpgrade> = loaded
+            .query::<&TowerSpecificUpgrade>()
+            .iter(&loaded)
+            .copied()
+            .collect();
+        assert_eq!(upgrades.len(), 1);
+        assert_eq!(upgrades[0].tower_entity, tower);
+        assert_eq!(upgrades[0].stat, UpgradeableStat::Damage);
+
+        assert!(loaded.resource::<Messages<TowerBuilt>>().is_empty());
+        let stats = loaded.resource::<RunStats>();
+        assert_eq!(stats.towers_built, 1);
+        assert_eq!(stats.enemies_killed, 17);
+        assert_eq!(
+            loaded.resource::<RunActionLog>().placements,
+            state.placements
+        );
+    }
+}
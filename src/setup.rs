@@ -1,43 +1,83 @@
 use crate::audio::AudioListener;
 use crate::components::*;
 use crate::constants::Tunables;
-use crate::core::paths::{generate_road_pattern, segment_patch_tiling};
+use crate::core::paths::{
+    generate_road_waypoints, segment_patch_tiling, validate_and_repair_road_paths,
+};
 use crate::core::world::{ExitSide, choose_exit_side, gate_lateral_offset};
+use crate::materials::RoadMaterial;
 use crate::random_policy::RandomizationPolicy;
-use crate::systems::camera::CameraSettings;
-use crate::systems::combat::assets::EnemyHealthBarAssets;
+use crate::systems::camera::{CameraSettings, CameraTarget, CameraZoomState};
+use crate::systems::combat::assets::{
+    BiomeMaterialCache, EnemyHealthBarAssets, FrozenMaterialCache,
+};
+use crate::systems::combat::towers::build_ring_mesh;
+use bevy::camera::primitives::MeshAabb;
 use bevy::prelude::*;
 use rand::{Rng, SeedableRng, rngs::StdRng};
 
 // ExitSide, choose_exit_side, gate_lateral_offset moved to core::world
 
-/// Generates and spawns a road mesh between two points; returns the path waypoints.
+/// Distance from the gate, outward, at which the roads of a multi-road map merge.
+const ROAD_JUNCTION_DISTANCE: f32 = 30.0;
+/// Total angle (radians) the outer roads of a multi-road map fan out over.
+const ROAD_FAN_ANGLE: f32 = 1.75;
+
+/// Generates and spawns a road mesh between two points; returns the path waypoints, repaired by
+/// `validate_and_repair_road_paths`, or `None` if no usable road came out.
 fn generate_and_spawn_road(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
-    material: Handle<StandardMaterial>,
+    road_materials: &mut ResMut<Assets<RoadMaterial>>,
+    road_style: &RoadMaterial,
     start: Vec3,
     end: Vec3,
     width: f32,
     rng: &mut StdRng,
 ) -> Option<Vec<Vec3>> {
-    let mut waypoints = generate_road_pattern(start, end, width, rng)?;
-
-    // Enforce exact endpoints to guarantee clean connections to the town square
-    if let Some(first) = waypoints.first_mut() {
-        *first = Vec3::new(start.x, 0.0, start.z);
-    }
-    if let Some(last) = waypoints.last_mut() {
-        *last = Vec3::new(end.x, 0.0, end.z);
+    let mut waypoints = generate_road_waypoints(start, end, width, rng)?;
+    if let Err(e) = validate_and_repair_road_paths(&mut waypoints) {
+        warn!("Dropping road from {:?} to {:?}: {}", start, end, e);
+        return None;
     }
+    spawn_road_patches(
+        commands,
+        meshes,
+        road_materials,
+        road_style,
+        &waypoints,
+        width,
+    );
+    Some(waypoints)
+}
 
-    // Spawn road segments as multiple short patches for a tiled look (cosmetic only)
-    let mut last = waypoints[0];
+/// Spawns road segments as multiple short patches for a tiled look. Each segment gets its own
+/// copy of `road_style` so its scroll speed can follow the enemies on it; waypoints run toward the
+/// village, which is the direction the patches' local +X (and their scrolling) points.
+fn spawn_road_patches(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    road_materials: &mut ResMut<Assets<RoadMaterial>>,
+    road_style: &RoadMaterial,
+    waypoints: &[Vec3],
+    width: f32,
+) {
+    let Some(&first) = waypoints.first() else {
+        return;
+    };
+    let mut last = first;
     for &current in waypoints.iter().skip(1) {
         if let Some((patch_count, patch_len, forward, yaw)) =
             segment_patch_tiling(last, current, 3.0)
         {
-            let rotation = Quat::from_rotation_y(yaw);
+            let material = road_materials.add(road_style.clone());
+            commands.spawn(RoadSegment {
+                start: last,
+                end: current,
+                material: material.clone(),
+            });
+            // `yaw` turns +X toward +Z, the opposite sense of a rotation about +Y
+            let rotation = Quat::from_rotation_y(-yaw);
             for i in 0..patch_count {
                 let center_offset = (i as f32 + 0.5) * patch_len;
                 let mid = last + forward * center_offset;
@@ -55,8 +95,78 @@ fn generate_and_spawn_road(
         }
         last = current;
     }
+}
 
-    Some(waypoints)
+/// Fans `num_roads` roads out from a junction just outside the gate, each starting
+/// `enemy_spawn_ring_distance` from the town center, and joins them to the road from the gate to
+/// the town square. Returns the full path of every road, outer end first.
+#[allow(clippy::too_many_arguments)]
+fn spawn_outer_roads(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    road_materials: &mut ResMut<Assets<RoadMaterial>>,
+    road_style: &RoadMaterial,
+    tunables: &Tunables,
+    gate_center: Vec3,
+    side_normal: Vec3,
+    inner_road: &[Vec3],
+    rng: &mut StdRng,
+) -> Vec<Vec<Vec3>> {
+    let junction = gate_center + side_normal * ROAD_JUNCTION_DISTANCE;
+    // Shared stretch from the junction through the gate
+    spawn_road_patches(
+        commands,
+        meshes,
+        road_materials,
+        road_style,
+        &[junction, gate_center],
+        tunables.road_width,
+    );
+    let plaza_size = tunables.road_width * 3.0;
+    commands.spawn((
+        RoadJunctionPoint { position: junction },
+        Mesh3d(
+            meshes.add(
+                Plane3d::default()
+                    .mesh()
+                    .size(plaza_size, plaza_size)
+                    .build(),
+            ),
+        ),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgb(0.2, 0.19, 0.18),
+            perceptual_roughness: 1.0,
+            metallic: 0.0,
+            ..default()
+        })),
+        // Just above the road patches so the plaza covers their ends
+        Transform::from_xyz(junction.x, 0.013, junction.z)
+            .with_rotation(Quat::from_rotation_y(side_normal.z.atan2(side_normal.x))),
+    ));
+
+    let approach = (tunables.enemy_spawn_ring_distance - tunables.town_size * 0.5).max(0.0);
+    let count = tunables.num_roads;
+    (0..count)
+        .filter_map(|k| {
+            let angle = ((k as f32 + 0.5) / count as f32 - 0.5) * ROAD_FAN_ANGLE;
+            let start = junction + Quat::from_rotation_y(angle) * side_normal * approach;
+            let mut road = generate_and_spawn_road(
+                commands,
+                meshes,
+                road_materials,
+                road_style,
+                start,
+                junction,
+                tunables.road_width,
+                rng,
+            )?;
+            road.extend(inner_road.iter().copied());
+            // The joined road may double up at the junction or cross itself
+            validate_and_repair_road_paths(&mut road).ok()?;
+            Some(road)
+        })
+        .collect()
 }
 
 /// Generates a random road path (straight, curved, snake) between two points.
@@ -68,6 +178,7 @@ pub fn setup(
     _asset_server: Res<AssetServer>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    mut road_materials: ResMut<Assets<RoadMaterial>>,
     tunables: Res<Tunables>,
     policy: Res<RandomizationPolicy>,
 ) {
@@ -75,10 +186,19 @@ pub fn setup(
     commands.insert_resource(CameraSettings {
         offset: tunables.camera_offset,
     });
+    // A new game starts at the default zoom
+    commands.insert_resource(CameraZoomState::default());
+    commands.insert_resource(CameraTarget::default());
     commands.insert_resource(EnemyHealthBarAssets::default());
+    commands.insert_resource(BiomeMaterialCache::new(&mut materials));
+    commands.insert_resource(FrozenMaterialCache::new(&mut materials));
 
     commands.spawn((
         Camera3d::default(),
+        Projection::Perspective(PerspectiveProjection {
+            fov: tunables.camera_fov_degrees.to_radians(),
+            ..default()
+        }),
         // Initial camera pose will be overridden by camera_system every frame based on settings
         Transform::from_xyz(
             tunables.camera_offset.x,
@@ -96,6 +216,7 @@ pub fn setup(
             ..default()
         },
         Transform::from_xyz(10.0, 20.0, 10.0).looking_at(Vec3::ZERO, Vec3::Y),
+        SunLight,
     ));
 
     let ground_mesh = meshes.add(
@@ -116,6 +237,7 @@ pub fn setup(
         MeshMaterial3d(ground_mat),
         Transform::IDENTITY,
         NoDistanceCull,
+        Ground,
     ));
 
     // Perimeter walls and seeded exit gate
@@ -163,6 +285,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(half, h2, z),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             let bottom_len = (lateral - tunables.gate_width * 0.5 - (-half)).max(0.0);
@@ -178,6 +303,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(half, h2, z),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             // Other full walls
@@ -192,6 +320,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(0.0, h2, -half),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             {
@@ -205,6 +336,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(0.0, h2, half),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             {
@@ -218,6 +352,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(-half, h2, 0.0),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             Vec3::new(half, 0.0, lateral)
@@ -237,6 +374,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(-half, h2, z),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             let bottom_len = (lateral - tunables.gate_width * 0.5 - (-half)).max(0.0);
@@ -252,6 +392,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(-half, h2, z),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             // Other full walls
@@ -266,6 +409,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(0.0, h2, -half),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             {
@@ -279,6 +425,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(0.0, h2, half),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             {
@@ -292,6 +441,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(half, h2, 0.0),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             Vec3::new(-half, 0.0, lateral)
@@ -311,6 +463,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(x, h2, -half),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             let left_len = (lateral - tunables.gate_width * 0.5 - (-half)).max(0.0);
@@ -326,6 +481,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(x, h2, -half),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             // Other full walls
@@ -340,6 +498,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(0.0, h2, half),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             {
@@ -353,6 +514,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(-half, h2, 0.0),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             {
@@ -366,6 +530,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(half, h2, 0.0),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             Vec3::new(lateral, 0.0, -half)
@@ -385,6 +552,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(x, h2, half),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             let left_len = (lateral - tunables.gate_width * 0.5 - (-half)).max(0.0);
@@ -400,6 +570,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(x, h2, half),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             // Other full walls
@@ -414,6 +587,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(0.0, h2, -half),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             {
@@ -427,6 +603,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(-half, h2, 0.0),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             {
@@ -440,6 +619,9 @@ pub fn setup(
                     MeshMaterial3d(wall_mat.clone()),
                     Transform::from_xyz(half, h2, 0.0),
                     Wall,
+                    WallHealth {
+                        hp: tunables.wall_health,
+                    },
                 ));
             }
             Vec3::new(lateral, 0.0, half)
@@ -454,13 +636,8 @@ pub fn setup(
         ..default()
     });
 
-    // Roads material
-    let road_mat = materials.add(StandardMaterial {
-        base_color: Color::srgb(0.15, 0.15, 0.15),
-        perceptual_roughness: 1.0,
-        metallic: 0.0,
-        ..default()
-    });
+    // Roads material, copied per road segment
+    let road_style = RoadMaterial::new(Color::srgb(0.15, 0.15, 0.15), tunables.road_tile_scale);
 
     let road_width = tunables.road_width;
 
@@ -518,32 +695,37 @@ pub fn setup(
         metallic: 0.0,
         ..default()
     });
-    let _player = commands
+    let player = commands
         .spawn((
             Mesh3d(player_mesh),
             MeshMaterial3d(player_mat),
             Transform::from_xyz(plaza_center.x, 2.0, plaza_center.z),
             IsoPlayer,
-            Player {
-                #[cfg(feature = "rich")]
-                wood: 1000,
-                #[cfg(not(feature = "rich"))]
-                wood: 0,
-                #[cfg(feature = "rich")]
-                rock: 1000,
-                #[cfg(not(feature = "rich"))]
-                rock: 0,
-                #[cfg(feature = "rich")]
-                silver: 1000,
-                #[cfg(not(feature = "rich"))]
-                silver: 0,
-                #[cfg(feature = "rich")]
-                gold: 1000,
-                #[cfg(not(feature = "rich"))]
-                gold: 0,
+            Player::starting(),
+            AutoCollect {
+                active: false,
+                radius: tunables.auto_collect_radius,
             },
         ))
         .id();
+    // Auto-collect reach on the ground under the player, shown while auto-collect is on
+    let auto_collect_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.4, 0.8, 0.2, 0.0),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        cull_mode: None,
+        ..default()
+    });
+    commands.entity(player).with_child((
+        AutoCollectRing {
+            material: auto_collect_material.clone(),
+        },
+        Mesh3d(meshes.add(build_ring_mesh(tunables.auto_collect_radius, 0.95, 64))),
+        MeshMaterial3d(auto_collect_material),
+        // The player box is centered 2.0 above the ground
+        Transform::from_xyz(0.0, -1.9, 0.0),
+        Visibility::Hidden,
+    ));
 
     // Spawn village (base) near opposite wall
     let village_mesh = meshes.add(Cuboid::new(8.0, 6.0, 8.0)); // Big block
@@ -576,13 +758,30 @@ pub fn setup(
     if let Some(road) = generate_and_spawn_road(
         &mut commands,
         &mut meshes,
-        road_mat.clone(),
+        &mut road_materials,
+        &road_style,
         gate_center,
         plaza_center,
         road_width,
         &mut road_rng,
     ) {
-        commands.insert_resource(RoadPaths { roads: vec![road] });
+        let roads = if tunables.num_roads > 1 {
+            spawn_outer_roads(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                &mut road_materials,
+                &road_style,
+                &tunables,
+                gate_center,
+                side_normal,
+                &road,
+                &mut road_rng,
+            )
+        } else {
+            vec![road]
+        };
+        commands.insert_resource(RoadPaths { roads });
     }
 
     // Trees and rocks are now spawned by the chunking system per active chunk
@@ -591,3 +790,36 @@ pub fn setup(
     commands.spawn(BuildingMode { is_active: false });
     commands.spawn(SellingMode { is_active: false });
 }
+
+/// Precomputes the world-space bounds of the town's solid structures from their actual meshes,
+/// for the tower placement check. Runs right after `setup` has spawned them.
+#[allow(clippy::type_complexity)]
+pub fn compute_static_obstacle_aabbs(
+    mut commands: Commands,
+    meshes: Res<Assets<Mesh>>,
+    obstacles_q: Query<(&Mesh3d, &Transform), Or<(With<Wall>, With<Village>, With<TownSquare>)>>,
+) {
+    let boxes = obstacles_q
+        .iter()
+        .filter_map(|(mesh, transform)| mesh_world_bounds(meshes.get(&mesh.0)?, transform))
+        .collect();
+    commands.insert_resource(StaticObstacleAABBs { boxes });
+}
+
+/// World-space (min, max) bounds of `mesh` placed at `transform`. Rotated boxes (the plaza) are
+/// enclosed by the bounds of their transformed corners.
+pub fn mesh_world_bounds(mesh: &Mesh, transform: &Transform) -> Option<(Vec3, Vec3)> {
+    let aabb = mesh.compute_aabb()?;
+    let (min, max) = (Vec3::from(aabb.min()), Vec3::from(aabb.max()));
+    let corners = (0..8).map(|i| {
+        transform.transform_point(Vec3::new(
+            if i & 1 == 0 { min.x } else { max.x },
+            if i & 2 == 0 { min.y } else { max.y },
+            if i & 4 == 0 { min.z } else { max.z },
+        ))
+    });
+    Some(corners.fold(
+        (Vec3::splat(f32::INFINITY), Vec3::splat(f32::NEG_INFINITY)),
+        |(lo, hi), p| (lo.min(p), hi.max(p)),
+    ))
+}
@@ -101,6 +101,7 @@ fn queue_preloads(
         asset_server.load_untyped("shaders/impact.wgsl"),
         asset_server.load_untyped("shaders/explosion.wgsl"),
         asset_server.load_untyped("shaders/trail.wgsl"),
+        asset_server.load_untyped("shaders/shield.wgsl"),
     ];
 
     commands.insert_resource(LoadingAssets {
@@ -0,0 +1,167 @@
+use crate::audio::WaveStartedEvent;
+use crate::components::WaveState;
+use crate::events::{EnemyKilled, EnemySpawned, TowerBuilt, VillageDamaged};
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use std::process::{Child, Command};
+
+/// Minimum seconds between two spoken announcements.
+const ANNOUNCE_INTERVAL_SECS: f32 = 1.0;
+/// Announcements waiting beyond this are dropped (oldest first) so speech never lags far behind.
+const MAX_PENDING_ANNOUNCEMENTS: usize = 4;
+
+/// Speaks key game events through the platform's text-to-speech for screen reader users.
+/// Enabled with the `--accessibility` flag.
+pub struct AccessibilityPlugin;
+
+impl Plugin for AccessibilityPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Announcer>().add_systems(
+            Update,
+            (
+                queue_announcements,
+                speak_announcements.after(queue_announcements),
+            ),
+        );
+    }
+}
+
+/// Rate-limited queue of announcements, spoken one at a time.
+#[derive(Resource)]
+pub struct Announcer {
+    pending: VecDeque<String>,
+    cooldown: Timer,
+    /// Speech process of the announcement being spoken; the next one waits until it exits.
+    speaking: Option<Child>,
+}
+
+impl Default for Announcer {
+    fn default() -> Self {
+        let mut cooldown = Timer::from_seconds(ANNOUNCE_INTERVAL_SECS, TimerMode::Once);
+        // Allow the first announcement immediately
+        cooldown.tick(cooldown.duration());
+        Announcer {
+            pending: VecDeque::new(),
+            cooldown,
+            speaking: None,
+        }
+    }
+}
+
+impl Announcer {
+    pub fn announce(&mut self, text: impl Into<String>) {
+        let text = text.into();
+        // Repeated events (e.g. a burst of spawns) collapse into a single announcement
+        if self.pending.contains(&text) {
+            return;
+        }
+        if self.pending.len() >= MAX_PENDING_ANNOUNCEMENTS {
+            self.pending.pop_front();
+        }
+        self.pending.push_back(text);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn queue_announcements(
+    mut announcer: ResMut<Announcer>,
+    wave_state: Res<WaveState>,
+    mut spawned: MessageReader<EnemySpawned>,
+    mut village_damaged: MessageReader<VillageDamaged>,
+    mut wave_started: MessageReader<WaveStartedEvent>,
+    mut tower_built: MessageReader<TowerBuilt>,
+    mut killed: MessageReader<EnemyKilled>,
+) {
+    for _ in wave_started.read() {
+        announcer.announce(format!("Wave {} started", wave_state.current_wave));
+    }
+    for e in village_damaged.read() {
+        announcer.announce(format!(
+            "Village took {} damage, {} HP remaining",
+            e.amount, e.remaining
+        ));
+    }
+    for e in tower_built.read() {
+        announcer.announce(format!("{} tower placed", e.kind.name()));
+    }
+    for e in spawned.read() {
+        announcer.announce(format!(
+            "Enemy approaching from {}",
+            compass_direction(e.position)
+        ));
+    }
+    if killed.read().count() > 0 {
+        announcer.announce("Enemy eliminated");
+    }
+}
+
+fn speak_announcements(time: Res<Time<Real>>, mut announcer: ResMut<Announcer>) {
+    announcer.cooldown.tick(time.delta());
+    if let Some(child) = announcer.speaking.as_mut() {
+        // Reaps the finished process so it does not linger as a zombie
+        match child.try_wait() {
+            Ok(None) => return,
+            Ok(Some(_)) => {}
+            Err(e) => warn!("Accessibility announcement could not be awaited: {}", e),
+        }
+        announcer.speaking = None;
+    }
+    if !announcer.cooldown.is_finished() {
+        return;
+    }
+    let Some(text) = announcer.pending.pop_front() else {
+        return;
+    };
+    announcer.speaking = speak(&text);
+    announcer.cooldown.reset();
+}
+
+/// Compass direction of a world position as seen from the village at the origin (north is -Z).
+fn compass_direction(position: Vec3) -> &'static str {
+    const NAMES: [&str; 8] = [
+        "north",
+        "north-east",
+        "east",
+        "south-east",
+        "south",
+        "south-west",
+        "west",
+        "north-west",
+    ];
+    // Angle clockwise from north
+    let angle = position
+        .x
+        .atan2(-position.z)
+        .rem_euclid(std::f32::consts::TAU);
+    let sector = (angle / (std::f32::consts::TAU / 8.0)).round() as usize % 8;
+    NAMES[sector]
+}
+
+/// Hands `text` to the OS speech synthesizer without blocking the game and returns the process
+/// speaking it, which exits once it is done.
+fn speak(text: &str) -> Option<Child> {
+    let mut command = if cfg!(target_os = "macos") {
+        let mut c = Command::new("say");
+        c.arg(text);
+        c
+    } else if cfg!(target_os = "windows") {
+        let script = format!(
+            "Add-Type -AssemblyName System.Speech; \
+             (New-Object System.Speech.Synthesis.SpeechSynthesizer).Speak('{}')",
+            text.replace('\'', "''")
+        );
+        let mut c = Command::new("powershell");
+        c.args(["-NoProfile", "-Command", &script]);
+        c
+    } else {
+        // Speech Dispatcher is what Orca and most Linux screen readers use; --wait keeps it
+        // running until the text has been spoken
+        let mut c = Command::new("spd-say");
+        c.args(["--wait", text]);
+        c
+    };
+    command
+        .spawn()
+        .inspect_err(|e| warn!("Accessibility announcement failed: {}", e))
+        .ok()
+}
@@ -1,24 +1,260 @@
 use crate::components::*;
+use crate::constants::Tunables;
+use bevy::input::mouse::AccumulatedMouseScroll;
 use bevy::prelude::*;
 
+/// Change of the zoom target per mouse wheel notch.
+const ZOOM_STEP: f32 = 0.1;
+
 /// Settings for camera offset relative to the player.
 #[derive(Resource, Clone)]
 pub struct CameraSettings {
     pub offset: Vec3,
 }
 
-/// Positions the 3D camera at a fixed offset from the player and looks at the player.
+/// Mouse wheel zoom, 0.0 (closest, `camera_zoom_min`) to 1.0 (farthest, `camera_zoom_max`).
+/// `current_zoom` eases toward `target_zoom`, which the wheel moves in steps.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct CameraZoomState {
+    pub current_zoom: f32,
+    pub target_zoom: f32,
+}
+
+impl Default for CameraZoomState {
+    fn default() -> Self {
+        // Halfway, which keeps the configured camera offset with the default zoom range
+        Self {
+            current_zoom: 0.5,
+            target_zoom: 0.5,
+        }
+    }
+}
+
+/// Zooms with the mouse wheel by scaling the Y and Z of `Tunables.camera_offset` between
+/// `camera_zoom_min` and `camera_zoom_max`. Wheel input over a hovered UI node is left to the UI
+/// (e.g. the minimap's own zoom).
+pub fn camera_zoom_system(
+    time: Res<Time<Real>>,
+    scroll: Res<AccumulatedMouseScroll>,
+    ui_hover: Query<&Interaction, With<Node>>,
+    tunables: Res<Tunables>,
+    mut zoom: ResMut<CameraZoomState>,
+    mut settings: ResMut<CameraSettings>,
+) {
+    let over_ui = ui_hover.iter().any(|i| *i != Interaction::None);
+    if scroll.delta.y != 0.0 && !over_ui {
+        // Wheel up moves the camera in
+        zoom.target_zoom = (zoom.target_zoom - scroll.delta.y.signum() * ZOOM_STEP).clamp(0.0, 1.0);
+    }
+
+    // Exponential damping: frame-rate independent easing toward the target
+    let blend = 1.0 - (-tunables.camera_zoom_speed * time.delta_secs()).exp();
+    zoom.current_zoom += (zoom.target_zoom - zoom.current_zoom) * blend;
+    if (zoom.target_zoom - zoom.current_zoom).abs() < 1e-4 {
+        zoom.current_zoom = zoom.target_zoom;
+    }
+
+    let scale = tunables
+        .camera_zoom_min
+        .lerp(tunables.camera_zoom_max, zoom.current_zoom);
+    let base = tunables.camera_offset;
+    let offset = Vec3::new(base.x, base.y * scale, base.z * scale);
+    if settings.offset != offset {
+        settings.offset = offset;
+    }
+}
+
+/// Whether the camera tracks the player or a point panned with the screen edges.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CameraMode {
+    #[default]
+    FollowPlayer,
+    FreePan,
+}
+
+/// Point the camera looks at. `focus` eases toward `target` at `Tunables.camera_follow_speed`;
+/// `target` is the player in `FollowPlayer` mode and the edge-scrolled point in `FreePan` mode.
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub struct CameraTarget {
+    pub mode: CameraMode,
+    pub target: Vec3,
+    pub focus: Vec3,
+}
+
+/// Spectator mode (Ctrl+S): the camera follows the busiest fight instead of the player.
+/// `target` is the cluster center being watched and `velocity` the smoothed focus velocity.
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub struct SpectatorCameraState {
+    pub active: bool,
+    pub target: Vec3,
+    pub velocity: Vec3,
+}
+
+/// Seconds since its last shot under which a tower counts as fighting.
+const SPECTATOR_RECENT_SHOT_SECS: f32 = 0.5;
+/// Radius of a spectator activity cluster in world units.
+const SPECTATOR_CLUSTER_RADIUS: f32 = 20.0;
+
+/// Ctrl+S toggles spectator mode; any movement key pressed without Ctrl ends it.
+pub fn spectator_toggle_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut spectator: ResMut<SpectatorCameraState>,
+) {
+    let ctrl = keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    if ctrl && keyboard.just_pressed(KeyCode::KeyS) {
+        spectator.active = !spectator.active;
+        return;
+    }
+    let moving = keyboard.any_just_pressed([
+        KeyCode::KeyW,
+        KeyCode::KeyA,
+        KeyCode::KeyS,
+        KeyCode::KeyD,
+        KeyCode::ArrowUp,
+        KeyCode::ArrowDown,
+        KeyCode::ArrowLeft,
+        KeyCode::ArrowRight,
+    ]);
+    if spectator.active && moving && !ctrl {
+        spectator.active = false;
+    }
+}
+
+/// Center of the densest group of `points`: the neighbors within `SPECTATOR_CLUSTER_RADIUS` of
+/// the point with the most of them, averaged. `None` when there are no points.
+fn busiest_cluster_center(points: &[Vec3]) -> Option<Vec3> {
+    let radius_sq = SPECTATOR_CLUSTER_RADIUS * SPECTATOR_CLUSTER_RADIUS;
+    let neighbors = |center: Vec3| {
+        points
+            .iter()
+            .filter(move |p| (**p - center).with_y(0.0).length_squared() <= radius_sq)
+    };
+    let busiest = points
+        .iter()
+        .max_by_key(|center| neighbors(**center).count())?;
+    let (sum, count) = neighbors(*busiest).fold((Vec3::ZERO, 0), |(sum, n), p| (sum + *p, n + 1));
+    Some(sum / count as f32)
+}
+
+/// Screen-space pan direction while the cursor is within `margin` of a window edge
+/// (x right, y down), or `None` when it is not at an edge.
+fn edge_scroll_direction(window: &Window, cursor: Vec2, margin: f32) -> Option<Vec2> {
+    let size = window.size();
+    let axis = |pos: f32, len: f32| {
+        if pos <= margin {
+            -1.0
+        } else if pos >= len - margin {
+            1.0
+        } else {
+            0.0
+        }
+    };
+    let dir = Vec2::new(axis(cursor.x, size.x), axis(cursor.y, size.y));
+    (dir != Vec2::ZERO).then(|| dir.normalize())
+}
+
+/// Whether the cursor is back in the middle half of the window, which ends free panning.
+fn cursor_centered(window: &Window, cursor: Vec2) -> bool {
+    let size = window.size();
+    let from_center = (cursor - size * 0.5).abs();
+    from_center.x <= size.x * 0.25 && from_center.y <= size.y * 0.25
+}
+
+/// Positions the 3D camera at a fixed offset from its focus point and looks at it. The focus
+/// follows the player, or pans while the cursor rests at a window edge (up to
+/// `camera_pan_limit` from the village) until the cursor returns to the middle of the screen.
+#[allow(clippy::too_many_arguments)]
 pub fn camera_system(
+    time: Res<Time<Real>>,
+    tunables: Res<Tunables>,
+    windows: Query<&Window>,
     mut camera_query: Query<&mut Transform, With<Camera3d>>,
     player_query: Query<&Transform, (With<Player>, Without<Camera3d>)>,
+    village_query: Query<&Transform, (With<Village>, Without<Camera3d>)>,
     settings: Res<CameraSettings>,
+    mut camera_target: ResMut<CameraTarget>,
+    mut spectator: ResMut<SpectatorCameraState>,
+    enemies: Query<&GlobalTransform, With<Enemy>>,
+    towers: Query<(&GlobalTransform, &Tower)>,
 ) {
-    if let Ok(mut camera_transform) = camera_query.single_mut()
-        && let Ok(player_transform) = player_query.single()
+    let Ok(mut camera_transform) = camera_query.single_mut() else {
+        return;
+    };
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    let player_pos = player_transform.translation;
+    let dt = time.delta_secs();
+
+    // Spectating overrides following and panning while enemies are around; during intermission
+    // the camera falls through to the player
+    if spectator.active {
+        let mut activity: Vec<Vec3> = enemies.iter().map(|t| t.translation()).collect();
+        if !activity.is_empty() {
+            activity.extend(
+                towers
+                    .iter()
+                    .filter(|(_, tower)| tower.last_shot < SPECTATOR_RECENT_SHOT_SECS)
+                    .map(|(t, _)| t.translation()),
+            );
+        }
+        if let Some(center) = busiest_cluster_center(&activity) {
+            spectator.target = center.with_y(player_pos.y);
+            let target = &mut *camera_target;
+            target.target = spectator.target;
+            let blend = (tunables.spectator_camera_speed * dt).min(1.0);
+            let focus = target.focus.lerp(spectator.target, blend);
+            if dt > 0.0 {
+                spectator.velocity = (focus - target.focus) / dt;
+            }
+            target.focus = focus;
+            camera_transform.translation = target.focus + settings.offset;
+            camera_transform.look_at(target.focus, Vec3::Y);
+            return;
+        }
+    }
+    spectator.velocity = Vec3::ZERO;
+
+    let cursor = windows
+        .single()
+        .ok()
+        .filter(|window| window.focused)
+        .and_then(|window| window.cursor_position().map(|cursor| (window, cursor)));
+    let edge_dir = cursor
+        .filter(|_| tunables.enable_edge_scroll)
+        .and_then(|(window, cursor)| {
+            edge_scroll_direction(window, cursor, tunables.edge_scroll_margin)
+        });
+
+    let target = &mut *camera_target;
+    if let Some(dir) = edge_dir {
+        if target.mode == CameraMode::FollowPlayer {
+            target.mode = CameraMode::FreePan;
+            target.target = target.focus;
+        }
+        // Screen up looks away from the camera, which sits on the +Z side of its focus
+        target.target += Vec3::new(dir.x, 0.0, dir.y) * tunables.edge_scroll_speed * dt;
+        let center = village_query
+            .iter()
+            .next()
+            .map_or(Vec3::ZERO, |village| village.translation);
+        let offset = (target.target - center).with_y(0.0);
+        target.target = center + offset.clamp_length_max(tunables.camera_pan_limit);
+        target.target.y = player_pos.y;
+    } else if target.mode == CameraMode::FreePan
+        && (!tunables.enable_edge_scroll
+            || cursor.is_some_and(|(window, cursor)| cursor_centered(window, cursor)))
     {
-        let player_pos = player_transform.translation;
-        let offset = settings.offset;
-        camera_transform.translation = player_pos + offset;
-        camera_transform.look_at(player_pos, Vec3::Y);
+        target.mode = CameraMode::FollowPlayer;
+    }
+    if target.mode == CameraMode::FollowPlayer {
+        target.target = player_pos;
     }
+
+    // Exponential damping: frame-rate independent easing toward the target
+    let blend = 1.0 - (-tunables.camera_follow_speed * dt).exp();
+    target.focus += (target.target - target.focus) * blend;
+
+    camera_transform.translation = target.focus + settings.offset;
+    camera_transform.look_at(target.focus, Vec3::Y);
 }
@@ -0,0 +1,117 @@
+use crate::audio::WaveCompletedEvent;
+use crate::components::TownCenter;
+use bevy::pbr::MeshMaterial3d;
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+/// Particles launched per celebration (inclusive range).
+const PARTICLE_COUNT_MIN: u32 = 20;
+const PARTICLE_COUNT_MAX: u32 = 30;
+/// Seconds each particle lives while fading out.
+const PARTICLE_LIFETIME_SECS: f32 = 1.5;
+/// Downward acceleration applied to particles (world units per second squared).
+const GRAVITY: f32 = 22.0;
+/// Height above the town center the fountain erupts from.
+const LAUNCH_HEIGHT: f32 = 3.0;
+const PARTICLE_COLORS: [Color; 3] = [
+    Color::srgb(1.0, 0.82, 0.2),
+    Color::srgb(0.35, 0.9, 0.4),
+    Color::srgb(1.0, 1.0, 1.0),
+];
+
+/// Small quad thrown up by the wave-complete fountain; falls under gravity while fading.
+#[derive(Component)]
+pub struct CelebrationParticle {
+    velocity: Vec3,
+    spin: Vec3,
+    timer: Timer,
+    material: Handle<StandardMaterial>,
+}
+
+/// One-shot fountain of colorful quads over the town center when a wave is survived.
+pub fn wave_complete_celebration(
+    mut commands: Commands,
+    mut completed: MessageReader<WaveCompletedEvent>,
+    town_q: Query<&Transform, With<TownCenter>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut quad_mesh: Local<Option<Handle<Mesh>>>,
+) {
+    if completed.read().count() == 0 {
+        return;
+    }
+    let origin = town_q
+        .single()
+        .map(|tf| tf.translation)
+        .unwrap_or(Vec3::ZERO)
+        + Vec3::Y * LAUNCH_HEIGHT;
+    let mesh = quad_mesh
+        .get_or_insert_with(|| meshes.add(Rectangle::new(0.35, 0.35)))
+        .clone();
+
+    let count =
+        PARTICLE_COUNT_MIN + rand::random::<u32>() % (PARTICLE_COUNT_MAX - PARTICLE_COUNT_MIN + 1);
+    for _ in 0..count {
+        let heading = rand::random::<f32>() * TAU;
+        let horizontal = 2.0 + rand::random::<f32>() * 4.0;
+        let velocity = Vec3::new(
+            heading.cos() * horizontal,
+            14.0 + rand::random::<f32>() * 6.0,
+            heading.sin() * horizontal,
+        );
+        let spin = Vec3::new(
+            rand::random::<f32>() - 0.5,
+            rand::random::<f32>() - 0.5,
+            rand::random::<f32>() - 0.5,
+        ) * 12.0;
+        let color = PARTICLE_COLORS[rand::random::<u32>() as usize % PARTICLE_COLORS.len()];
+        let material = materials.add(StandardMaterial {
+            base_color: color,
+            emissive: LinearRgba::from(color) * 0.6,
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            cull_mode: None,
+            ..default()
+        });
+        commands.spawn((
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::from_translation(origin),
+            GlobalTransform::default(),
+            Visibility::default(),
+            CelebrationParticle {
+                velocity,
+                spin,
+                timer: Timer::from_seconds(PARTICLE_LIFETIME_SECS, TimerMode::Once),
+                material,
+            },
+        ));
+    }
+}
+
+/// Moves celebration particles along their arcs and fades them out.
+pub fn celebration_particle_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut particles: Query<(Entity, &mut CelebrationParticle, &mut Transform)>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut particle, mut transform) in particles.iter_mut() {
+        particle.timer.tick(time.delta());
+        particle.velocity.y -= GRAVITY * dt;
+        transform.translation += particle.velocity * dt;
+        let spin = particle.spin * dt;
+        transform.rotate(Quat::from_euler(EulerRot::XYZ, spin.x, spin.y, spin.z));
+
+        if let Some(mat) = materials.get_mut(&particle.material) {
+            mat.base_color
+                .set_alpha(particle.timer.fraction_remaining());
+        }
+
+        if particle.timer.just_finished() {
+            materials.remove(particle.material.id());
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,353 @@
+//! Choke point advisor, a debug overlay (Ctrl+H or `Tunables::show_choke_advisor`): marks the
+//! spots along the roads where towers do the most good and how much of each is already covered.
+//!
+//! Sharp bends and stretches where two roads run close together are the candidates. Each marker
+//! is labelled with the share of road around it that built towers already reach, and dims as that
+//! share grows.
+
+use crate::components::{BuiltTower, GameState, RoadPaths, Tower};
+use crate::constants::Tunables;
+use crate::core::geometry::{distance_to_polyline_xz, turn_angle_xz};
+use crate::utils::camera as cam_utils;
+use bevy::prelude::*;
+
+const FONT_PATH: &str = "fonts/Nova_Mono/NovaMono-Regular.ttf";
+const MARKER_COLOR: Color = Color::srgb(1.0, 0.85, 0.1);
+/// Height above the road at which markers hover.
+const MARKER_HEIGHT: f32 = 3.0;
+/// Bends sharper than this (radians, ~20°) count as choke points.
+const MIN_TURN_ANGLE: f32 = 0.35;
+/// Two roads closer than this count as a choke point.
+const ROAD_PROXIMITY: f32 = 25.0;
+/// Roads closer than this run along the same track; a stretch they share is not a choke point.
+const SHARED_ROAD_DISTANCE: f32 = 1.0;
+/// Candidates closer together than this are merged, keeping the stronger one.
+const MERGE_DISTANCE: f32 = 20.0;
+const MAX_CHOKE_POINTS: usize = 12;
+/// Spacing of the road samples used to estimate coverage.
+const COVERAGE_SAMPLE_STEP: f32 = 2.0;
+/// Lowest marker opacity, reached at full coverage.
+const MIN_MARKER_ALPHA: f32 = 0.25;
+
+pub struct ChokePointAdvisorPlugin;
+
+impl Plugin for ChokePointAdvisorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                choke_advisor_toggle_input,
+                manage_choke_markers,
+                update_choke_coverage,
+                animate_choke_markers,
+                position_choke_labels,
+            )
+                .chain()
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// Suggested tower spot; `coverage` is the share (0..1) of nearby road that towers already reach.
+#[derive(Component)]
+pub struct ChokePointMarker {
+    pub base_position: Vec3,
+    pub coverage: f32,
+    material: Handle<StandardMaterial>,
+}
+
+/// Screen-space coverage label following a `ChokePointMarker`.
+#[derive(Component)]
+pub struct ChokePointLabel {
+    pub marker: Entity,
+}
+
+/// Ctrl+H shows or hides the advisor.
+pub fn choke_advisor_toggle_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut tunables: ResMut<Tunables>,
+) {
+    let ctrl = keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    if ctrl && keyboard.just_pressed(KeyCode::KeyH) {
+        tunables.show_choke_advisor = !tunables.show_choke_advisor;
+    }
+}
+
+/// Candidate choke points as (position, score), strongest first. Bends score by how sharply the
+/// road turns, close roads by how close they get; stretches two roads share don't count.
+pub fn find_choke_points(roads: &[Vec<Vec3>]) -> Vec<(Vec3, f32)> {
+    let mut candidates = Vec::new();
+    for (index, road) in roads.iter().enumerate() {
+        for window in road.windows(3) {
+            let angle = turn_angle_xz(window[0], window[1], window[2]);
+            if angle >= MIN_TURN_ANGLE {
+                candidates.push((window[1], angle / std::f32::consts::FRAC_PI_2));
+            }
+        }
+        for (other_index, other) in roads.iter().enumerate() {
+            if other_index == index {
+                continue;
+            }
+            for &point in road {
+                let distance = distance_to_polyline_xz(point, other);
+                if (SHARED_ROAD_DISTANCE..ROAD_PROXIMITY).contains(&distance) {
+                    candidates.push((point, 1.0 - distance / ROAD_PROXIMITY));
+                }
+            }
+        }
+    }
+    candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    let mut chosen: Vec<(Vec3, f32)> = Vec::new();
+    for (position, score) in candidates {
+        let taken = chosen
+            .iter()
+            .any(|(p, _)| (*p - position).with_y(0.0).length() < MERGE_DISTANCE);
+        if !taken {
+            chosen.push((position, score));
+        }
+        if chosen.len() == MAX_CHOKE_POINTS {
+            break;
+        }
+    }
+    chosen
+}
+
+/// Share of the road samples within `radius` of `center` that at least one tower reaches.
+fn coverage_at(center: Vec3, radius: f32, roads: &[Vec<Vec3>], towers: &[(Vec3, f32)]) -> f32 {
+    let (mut total, mut covered) = (0u32, 0u32);
+    for road in roads {
+        for segment in road.windows(2) {
+            let length = (segment[1] - segment[0]).with_y(0.0).length();
+            let steps = (length / COVERAGE_SAMPLE_STEP).ceil().max(1.0) as u32;
+            for step in 0..steps {
+                let sample = segment[0].lerp(segment[1], step as f32 / steps as f32);
+                if (sample - center).with_y(0.0).length() > radius {
+                    continue;
+                }
+                total += 1;
+                let reached = towers
+                    .iter()
+                    .any(|(tower, range)| (sample - *tower).with_y(0.0).length() <= *range);
+                if reached {
+                    covered += 1;
+                }
+            }
+        }
+    }
+    if total == 0 {
+        0.0
+    } else {
+        covered as f32 / total as f32
+    }
+}
+
+/// Spawns markers and labels when the advisor is switched on (or the roads change) and removes
+/// them when it is switched off.
+#[allow(clippy::too_many_arguments)]
+pub fn manage_choke_markers(
+    mut commands: Commands,
+    tunables: Res<Tunables>,
+    roads: Option<Res<RoadPaths>>,
+    markers: Query<Entity, With<ChokePointMarker>>,
+    labels: Query<Entity, With<ChokePointLabel>>,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let roads = roads.filter(|_| tunables.show_choke_advisor);
+    let stale = roads.as_ref().is_none_or(|roads| roads.is_changed());
+    if stale {
+        for entity in markers.iter().chain(labels.iter()) {
+            commands.entity(entity).try_despawn();
+        }
+    }
+    let Some(roads) = roads else {
+        return;
+    };
+    if !stale && !markers.is_empty() {
+        return;
+    }
+
+    let mesh = meshes.add(Rhombus::new(1.6, 2.4));
+    for (position, _) in find_choke_points(&roads.roads) {
+        let material = materials.add(StandardMaterial {
+            base_color: MARKER_COLOR,
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            cull_mode: None,
+            ..default()
+        });
+        let base_position = Vec3::new(position.x, MARKER_HEIGHT, position.z);
+        let marker = commands
+            .spawn((
+                ChokePointMarker {
+                    base_position,
+                    coverage: 0.0,
+                    material: material.clone(),
+                },
+                Mesh3d(mesh.clone()),
+                MeshMaterial3d(material),
+                Transform::from_translation(base_position),
+            ))
+            .id();
+        commands.spawn((
+            ChokePointLabel { marker },
+            Text::new("0%"),
+            TextFont {
+                font: asset_server.load(FONT_PATH),
+                font_size: 15.0,
+                ..default()
+            },
+            TextColor(MARKER_COLOR),
+            Node {
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            Visibility::Hidden,
+            Pickable::IGNORE,
+        ));
+    }
+}
+
+/// Re-estimates coverage for new markers and whenever a tower is built or removed.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn update_choke_coverage(
+    tunables: Res<Tunables>,
+    roads: Option<Res<RoadPaths>>,
+    towers: Query<(&Transform, &Tower), With<BuiltTower>>,
+    added_towers: Query<(), Added<BuiltTower>>,
+    new_markers: Query<(), Added<ChokePointMarker>>,
+    mut markers: Query<&mut ChokePointMarker>,
+    mut labels: Query<(&ChokePointLabel, &mut Text)>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut last_tower_count: Local<usize>,
+) {
+    let Some(roads) = roads else {
+        return;
+    };
+    let tower_count = towers.iter().count();
+    let towers_changed = tower_count != *last_tower_count || !added_towers.is_empty();
+    *last_tower_count = tower_count;
+    if !towers_changed && new_markers.is_empty() {
+        return;
+    }
+
+    let tower_reach: Vec<(Vec3, f32)> = towers
+        .iter()
+        .map(|(tf, tower)| (tf.translation, tower.range))
+        .collect();
+    for mut marker in markers.iter_mut() {
+        marker.coverage = coverage_at(
+            marker.base_position,
+            tunables.tower_range,
+            &roads.roads,
+            &tower_reach,
+        );
+        if let Some(mat) = materials.get_mut(&marker.material) {
+            let alpha = 1.0 - (1.0 - MIN_MARKER_ALPHA) * marker.coverage;
+            mat.base_color = MARKER_COLOR.with_alpha(alpha);
+        }
+    }
+    for (label, mut text) in labels.iter_mut() {
+        if let Ok(marker) = markers.get(label.marker) {
+            text.0 = format!("{:.0}%", marker.coverage * 100.0);
+        }
+    }
+}
+
+fn animate_choke_markers(time: Res<Time>, mut markers: Query<(&ChokePointMarker, &mut Transform)>) {
+    let t = time.elapsed_secs();
+    let pulse = 0.5 + 0.5 * (t * 3.0).sin();
+    for (marker, mut transform) in markers.iter_mut() {
+        transform.translation = marker.base_position + Vec3::Y * (0.3 * pulse);
+        transform.scale = Vec3::splat(1.0 + 0.25 * pulse);
+        transform.rotation = Quat::from_rotation_y(t * 1.2);
+    }
+}
+
+/// Places each coverage label just under its marker in screen space.
+fn position_choke_labels(
+    windows: Query<&Window>,
+    cam_q: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    markers: Query<&ChokePointMarker>,
+    mut labels: Query<(&ChokePointLabel, &mut Node, &mut Visibility)>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Ok((camera, cam_tf)) = cam_q.single() else {
+        return;
+    };
+    for (label, mut node, mut visibility) in labels.iter_mut() {
+        let Ok(marker) = markers.get(label.marker) else {
+            continue;
+        };
+        let world_pos = marker.base_position - Vec3::Y * 1.5;
+        let logical = cam_utils::is_on_screen_ndc(camera, cam_tf, world_pos, 0.0)
+            .then(|| cam_utils::world_to_viewport_logical(camera, cam_tf, window, world_pos))
+            .flatten();
+        let Some(logical) = logical else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        visibility.set_if_neq(Visibility::Visible);
+        node.left = Val::Px(logical.x - 14.0);
+        node.top = Val::Px(logical.y + 8.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn xz(points: &[(f32, f32)]) -> Vec<Vec3> {
+        points.iter().map(|&(x, z)| Vec3::new(x, 0.0, z)).collect()
+    }
+
+    #[test]
+    fn sharp_bend_is_a_choke_point() {
+        let roads = vec![xz(&[(0.0, 0.0), (50.0, 0.0), (50.0, 50.0)])];
+        let chokes = find_choke_points(&roads);
+        assert_eq!(chokes.len(), 1);
+        assert_eq!(chokes[0].0, Vec3::new(50.0, 0.0, 0.0));
+        assert!((chokes[0].1 - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn straight_far_apart_roads_have_no_choke_points() {
+        let roads = vec![
+            xz(&[(0.0, 0.0), (50.0, 0.0), (100.0, 0.0)]),
+            xz(&[(0.0, 100.0), (50.0, 100.0), (100.0, 100.0)]),
+        ];
+        assert!(find_choke_points(&roads).is_empty());
+    }
+
+    #[test]
+    fn roads_running_close_together_are_choke_points() {
+        let roads = vec![
+            xz(&[(0.0, 0.0), (50.0, 0.0), (100.0, 0.0)]),
+            xz(&[(0.0, 10.0), (50.0, 10.0), (100.0, 10.0)]),
+        ];
+        let chokes = find_choke_points(&roads);
+        assert!(!chokes.is_empty());
+        assert!(chokes.iter().all(|(_, score)| (*score - 0.6).abs() < 1e-4));
+    }
+
+    #[test]
+    fn shared_road_stretch_is_not_a_choke_point() {
+        // Both roads leave the town along the same track, then split straight away from each other
+        let shared = [(0.0, 0.0), (30.0, 0.0), (60.0, 0.0)];
+        let mut north = shared.to_vec();
+        north.push((60.0, -100.0));
+        let mut south = shared.to_vec();
+        south.push((60.0, 100.0));
+        let roads = vec![xz(&north), xz(&south)];
+
+        let chokes = find_choke_points(&roads);
+        // Only the split itself, where both roads turn sharply
+        assert_eq!(chokes.len(), 1);
+        assert_eq!(chokes[0].0, Vec3::new(60.0, 0.0, 0.0));
+        assert!((chokes[0].1 - 1.0).abs() < 1e-4);
+    }
+}
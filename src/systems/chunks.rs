@@ -1,6 +1,7 @@
 use crate::components::TownSquareCenter;
 use crate::components::{
-    ChunkRoot, Harvestable, HarvestableKind, NoDistanceCull, Player, Rock, RockSize, Tree, TreeSize,
+    ChunkBiomes, ChunkRoot, Harvestable, HarvestableKind, NoDistanceCull, Player, Rock, RockSize,
+    Tree, TreeSize,
 };
 use crate::constants::Tunables;
 use crate::core::rng as core_rng;
@@ -70,6 +71,7 @@ impl Plugin for ChunkPlugin {
         }
 
         app.init_resource::<LoadedChunks>()
+            .init_resource::<ChunkBiomes>()
             .insert_resource(PlayerChunk(ChunkCoord { x: 0, z: 0 }))
             .insert_resource(ChunkHudState {
                 enabled: true,
@@ -390,6 +392,10 @@ fn update_chunk_hud_text(
 }
 
 fn chunk_config_shortcuts(input: Res<ButtonInput<KeyCode>>, mut cfg: ResMut<ChunkConfig>) {
+    // Held Shift keeps these apart from quick save (F5) and load (F9)
+    if !input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        return;
+    }
     // Active radius: F8(-), F9(+)
     if input.just_pressed(KeyCode::F8) {
         cfg.active_radius = (cfg.active_radius - 1).max(1);
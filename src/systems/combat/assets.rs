@@ -1,4 +1,5 @@
-use bevy::math::primitives::{Circle, Rectangle, Sphere};
+use crate::components::TerrainBiome;
+use bevy::math::primitives::{Circle, Cuboid, Rectangle, Sphere};
 use bevy::prelude::*;
 
 /// Cached meshes/materials for enemy health bars to avoid reallocations.
@@ -8,6 +9,7 @@ pub struct EnemyHealthBarAssets {
     background_material: Option<Handle<StandardMaterial>>,
     fill_material: Option<Handle<StandardMaterial>>,
     border_material: Option<Handle<StandardMaterial>>,
+    shield_material: Option<Handle<StandardMaterial>>,
 }
 
 impl EnemyHealthBarAssets {
@@ -52,6 +54,24 @@ impl EnemyHealthBarAssets {
             .clone()
     }
 
+    pub fn shield_material(
+        &mut self,
+        materials: &mut Assets<StandardMaterial>,
+    ) -> Handle<StandardMaterial> {
+        self.shield_material
+            .get_or_insert_with(|| {
+                materials.add(StandardMaterial {
+                    base_color: Color::srgba(0.2, 0.55, 1.0, 1.0),
+                    emissive: Color::srgb(0.25, 0.6, 1.0).into(),
+                    alpha_mode: AlphaMode::Opaque,
+                    unlit: true,
+                    cull_mode: None,
+                    ..default()
+                })
+            })
+            .clone()
+    }
+
     pub fn border_material(
         &mut self,
         materials: &mut Assets<StandardMaterial>,
@@ -71,11 +91,68 @@ impl EnemyHealthBarAssets {
     }
 }
 
+/// Template enemy skins per terrain biome. Enemies get their own copy of the template since
+/// hit flashes and fade-outs mutate the material in place.
+#[derive(Resource)]
+pub struct BiomeMaterialCache {
+    pub grass_mat: Handle<StandardMaterial>,
+    pub desert_mat: Handle<StandardMaterial>,
+    pub snow_mat: Handle<StandardMaterial>,
+}
+
+impl BiomeMaterialCache {
+    pub fn new(materials: &mut Assets<StandardMaterial>) -> Self {
+        let mut skin = |color: Color| {
+            materials.add(StandardMaterial {
+                base_color: color,
+                perceptual_roughness: 0.7,
+                metallic: 0.0,
+                ..default()
+            })
+        };
+        BiomeMaterialCache {
+            grass_mat: skin(Color::srgb(0.9, 0.1, 0.1)),
+            desert_mat: skin(Color::srgb(0.82, 0.68, 0.45)),
+            snow_mat: skin(Color::srgb(0.55, 0.62, 0.72)),
+        }
+    }
+
+    pub fn for_biome(&self, biome: TerrainBiome) -> &Handle<StandardMaterial> {
+        match biome {
+            TerrainBiome::Grassland => &self.grass_mat,
+            TerrainBiome::Desert => &self.desert_mat,
+            TerrainBiome::Snow => &self.snow_mat,
+        }
+    }
+}
+
+/// Shared icy material worn by slowed enemies in place of their own.
+#[derive(Resource)]
+pub struct FrozenMaterialCache {
+    pub material: Handle<StandardMaterial>,
+}
+
+impl FrozenMaterialCache {
+    pub fn new(materials: &mut Assets<StandardMaterial>) -> Self {
+        FrozenMaterialCache {
+            material: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.62, 0.82, 0.95),
+                perceptual_roughness: 0.35,
+                metallic: 0.3,
+                emissive: LinearRgba::rgb(0.08, 0.2, 0.35),
+                ..default()
+            }),
+        }
+    }
+}
+
 /// Shared meshes used by projectile/impact/explosion effects.
 #[derive(Resource, Default)]
 pub struct CombatVfxAssets {
     projectile_mesh: Option<Handle<Mesh>>,
     impact_mesh: Option<Handle<Mesh>>,
+    lightning_arc_mesh: Option<Handle<Mesh>>,
+    trail_particle_mesh: Option<Handle<Mesh>>,
     projectile_white_material: Option<Handle<StandardMaterial>>,
 }
 
@@ -92,6 +169,20 @@ impl CombatVfxAssets {
             .clone()
     }
 
+    /// Unit cube stretched along Y between hit positions for lightning arcs.
+    pub fn lightning_arc_mesh(&mut self, meshes: &mut Assets<Mesh>) -> Handle<Mesh> {
+        self.lightning_arc_mesh
+            .get_or_insert_with(|| meshes.add(Mesh::from(Cuboid::new(1.0, 1.0, 1.0))))
+            .clone()
+    }
+
+    /// Tiny sphere left behind by flying projectiles.
+    pub fn trail_particle_mesh(&mut self, meshes: &mut Assets<Mesh>) -> Handle<Mesh> {
+        self.trail_particle_mesh
+            .get_or_insert_with(|| meshes.add(Mesh::from(Sphere::new(0.08))))
+            .clone()
+    }
+
     // explosion mesh removed
 
     pub fn projectile_white_material(
@@ -120,6 +211,14 @@ impl CombatVfxAssets {
         self.impact_mesh.clone()
     }
 
+    pub fn lightning_arc_mesh_handle(&self) -> Option<Handle<Mesh>> {
+        self.lightning_arc_mesh.clone()
+    }
+
+    pub fn trail_particle_mesh_handle(&self) -> Option<Handle<Mesh>> {
+        self.trail_particle_mesh.clone()
+    }
+
     pub fn projectile_white_material_handle(&self) -> Option<Handle<StandardMaterial>> {
         self.projectile_white_material.clone()
     }
@@ -137,5 +236,7 @@ pub fn init_combat_vfx_assets(
 ) {
     let _ = vfx_assets.projectile_mesh(&mut meshes);
     let _ = vfx_assets.impact_mesh(&mut meshes);
+    let _ = vfx_assets.lightning_arc_mesh(&mut meshes);
+    let _ = vfx_assets.trail_particle_mesh(&mut meshes);
     let _ = vfx_assets.projectile_white_material(&mut materials);
 }
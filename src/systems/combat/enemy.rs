@@ -1,19 +1,29 @@
-use super::assets::EnemyHealthBarAssets;
+use super::assets::{BiomeMaterialCache, EnemyHealthBarAssets};
+use super::projectiles::{EnemyFadeOut, EnemyFrozenVisual};
+use crate::audio::SwarmBurstEvent;
 use crate::components::{
-    Enemy, EnemyHealthBarFill, EnemyHealthBarRoot, EnemyKind, PathFollower, RoadPaths, WavePhase,
-    WaveState,
+    BossShield, BossShieldBarFill, ChunkBiomes, Enemy, EnemyHealthBarFill, EnemyHealthBarRoot,
+    EnemyKind, FormationMember, HealAura, PathFollower, RoadPaths, SwarmBoost, WavePhase,
+    WaveState, WaveTimerPaused,
 };
 use crate::constants::Tunables;
+use crate::core::geometry::direction_xz;
+use crate::core::grid::world_to_chunk;
 use crate::core::rng::derive_seed;
 use crate::events::EnemySpawned;
 use crate::random_policy::RandomizationPolicy;
+use crate::systems::camera::CameraTarget;
 use crate::systems::chunks::WorldSeed;
+use crate::systems::day_night::DayNightCycle;
+use crate::waves::difficulty::DifficultyParams;
 use bevy::math::primitives::Cuboid;
 use bevy::pbr::MeshMaterial3d;
 use bevy::prelude::*;
 use std::f32::consts::PI;
 use std::time::Duration;
 
+/// Seconds between Healer aura pulses.
+const HEAL_TICK_SECS: f32 = 0.5;
 /// Spawns enemies at intervals on road entrances or at a fallback ring.
 #[allow(clippy::too_many_arguments)]
 pub fn enemy_spawning(
@@ -28,8 +38,12 @@ pub fn enemy_spawning(
     mut wave_state: ResMut<WaveState>,
     seed: Res<WorldSeed>,
     policy: Res<RandomizationPolicy>,
+    biome_materials: Res<BiomeMaterialCache>,
+    chunk_biomes: Res<ChunkBiomes>,
+    timer_paused: Res<WaveTimerPaused>,
+    day_night: Res<DayNightCycle>,
 ) {
-    if wave_state.phase != WavePhase::Spawning {
+    if wave_state.phase != WavePhase::Spawning || timer_paused.is_paused() {
         return;
     }
 
@@ -37,146 +51,373 @@ pub fn enemy_spawning(
         return;
     }
 
-    if wave_state.spawn_timer.duration()
-        != Duration::from_secs_f32(tunables.enemy_spawn_interval_secs)
-    {
-        wave_state
-            .spawn_timer
-            .set_duration(Duration::from_secs_f32(tunables.enemy_spawn_interval_secs));
+    // Night spawns enemies faster
+    let interval = Duration::from_secs_f32(
+        tunables.enemy_spawn_interval_secs / day_night.spawn_rate_multiplier(&tunables),
+    );
+    if wave_state.spawn_timer.duration() != interval {
+        wave_state.spawn_timer.set_duration(interval);
     }
 
     wave_state.spawn_timer.tick(time.delta());
     if wave_state.spawn_timer.just_finished() {
-        let (spawn_pos, road_index) = if policy.enemy_spawn_selection_seeded {
-            let derived = derive_seed(
-                seed.0,
-                wave_state.current_wave as u64,
-                wave_state.enemies_spawned as u64,
-            );
-            select_seeded_spawn_point(&roads, &tunables, derived)
-        } else {
-            select_random_spawn_point(&roads, &tunables)
-        };
-
-        // Determine which enemy to spawn next
-        if let Some(kind) = wave_state.spawn_queue.pop_front() {
-            let (base_hp, base_dmg, base_spd, size) = kind.stats();
-            let mul = wave_state.multiplier_for(kind);
-            let hp = (base_hp as f32 * mul.hp).round().max(1.0) as u32;
-            let dmg = (base_dmg as f32 * mul.dmg).round().max(1.0) as u32;
-            let spd = base_spd * mul.spd;
-            let half_h = size * 0.5;
-            let color = match kind {
-                EnemyKind::Minion => Color::srgb(0.9, 0.1, 0.1),
-                EnemyKind::Zombie => Color::srgb(0.2, 0.8, 0.2),
-                EnemyKind::Boss => Color::srgb(0.6, 0.1, 0.8),
+        // Groups take the roads in turn so every entrance sees traffic; without roads they
+        // appear on a ring around the village
+        let road_entry = roads
+            .as_ref()
+            .filter(|roads| !roads.roads.is_empty())
+            .map(|roads| {
+                let ri = wave_state.groups_spawned as usize % roads.roads.len();
+                (roads.roads[ri][0].with_y(0.0), Some(ri))
+            });
+        let (spawn_pos, road_index) = road_entry.unwrap_or_else(|| {
+            let angle = if policy.enemy_spawn_selection_seeded {
+                let derived = derive_seed(
+                    seed.0,
+                    wave_state.current_wave as u64,
+                    wave_state.enemies_spawned as u64,
+                );
+                seeded_ring_angle(derived)
+            } else {
+                rand::random::<f32>() * 2.0 * PI
             };
+            let distance = tunables.enemy_spawn_ring_distance;
+            (
+                Vec3::new(angle.cos() * distance, 0.0, angle.sin() * distance),
+                None,
+            )
+        });
+        wave_state.groups_spawned += 1;
 
-            let e_mesh = meshes.add(Cuboid::new(size, size, size));
-            let e_mat = materials.add(StandardMaterial {
-                base_color: color,
-                perceptual_roughness: 0.7,
-                metallic: 0.0,
-                ..default()
-            });
+        // Initial heading of the group: along the first road segment, else toward the village
+        let heading = road_index
+            .and_then(|ri| roads.as_ref()?.roads.get(ri).cloned())
+            .filter(|road| road.len() > 1)
+            .map(|road| direction_xz(road[0], road[1]))
+            .unwrap_or_else(|| direction_xz(spawn_pos, Vec3::ZERO));
+        let right = heading.cross(Vec3::Y);
 
-            let enemy_entity = commands
-                .spawn((
-                    Mesh3d(e_mesh),
-                    MeshMaterial3d(e_mat),
-                    Transform::from_translation(Vec3::new(spawn_pos.x, half_h, spawn_pos.z)),
-                    Visibility::default(),
-                    InheritedVisibility::default(),
-                    kind,
-                    Enemy {
-                        health: hp,
-                        max_health: hp,
-                        speed: spd,
-                        damage: dmg,
-                    },
-                    match road_index {
-                        Some(ri) => PathFollower {
-                            road_index: ri,
-                            next_index: 1,
-                        },
-                        None => PathFollower {
-                            road_index: 0,
-                            next_index: 0,
-                        },
-                    },
-                ))
-                .id();
+        let difficulty = tunables
+            .difficulty_curve
+            .interpolate(wave_state.current_wave);
+
+        // Minions take on the skin of the biome their road entry point lies in
+        let biome = chunk_biomes.biome_of(world_to_chunk(spawn_pos, tunables.chunk_size));
+        let minion_skin = materials.get(biome_materials.for_biome(biome)).cloned();
 
-            attach_health_bar(
+        // Spawn a whole formation group at once; the first enemy leads the rest. Small waves
+        // have no formation and come one at a time.
+        let offsets = wave_state.formation_offsets.clone();
+        let leader_offset = offsets.first().copied().unwrap_or(Vec3::ZERO);
+        let mut leader: Option<Entity> = None;
+        for slot in 0..offsets.len().max(1) {
+            if wave_state.enemies_spawned >= wave_state.enemies_to_spawn {
+                break;
+            }
+            let Some(kind) = wave_state.spawn_queue.pop_front() else {
+                break;
+            };
+            // Slot offsets point -Z toward the village; turn them into the group's heading
+            let local = offsets.get(slot).copied().unwrap_or(Vec3::ZERO);
+            let position = spawn_pos + right * local.x - heading * local.z;
+            let enemy_entity = spawn_enemy(
                 &mut commands,
-                enemy_entity,
                 &mut meshes,
                 &mut materials,
                 &mut health_bar_assets,
                 &tunables,
+                &wave_state,
+                &difficulty,
+                kind,
+                position,
+                road_index,
+                minion_skin.as_ref(),
             );
+            match leader {
+                None => leader = Some(enemy_entity),
+                Some(leader) => {
+                    let relative = local - leader_offset;
+                    let offset = Vec2::new(relative.x, -relative.z);
+                    commands
+                        .entity(enemy_entity)
+                        .insert(FormationMember { leader, offset });
+                }
+            }
 
-            enemy_events.write(EnemySpawned {
-                position: spawn_pos,
-            });
+            enemy_events.write(EnemySpawned { position });
             wave_state.enemies_spawned += 1;
         }
     }
 }
 
-fn select_seeded_spawn_point(
-    roads: &Option<Res<RoadPaths>>,
+#[allow(clippy::too_many_arguments)]
+fn spawn_enemy(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    health_bar_assets: &mut ResMut<EnemyHealthBarAssets>,
     tunables: &Tunables,
-    derived_seed: u64,
-) -> (Vec3, Option<usize>) {
-    if let Some(roads) = roads
-        && !roads.roads.is_empty()
-    {
-        use rand::{Rng, SeedableRng, rngs::StdRng};
-        let mut rng =
-            StdRng::seed_from_u64(derive_seed(derived_seed, 0x01, roads.roads.len() as u64));
-        let mut ri = (rng.random::<f32>() * roads.roads.len() as f32).floor() as usize;
-        if ri >= roads.roads.len() {
-            ri = roads.roads.len() - 1;
-        }
-        let wp = &roads.roads[ri][0];
-        return (Vec3::new(wp.x, 0.0, wp.z), Some(ri));
+    wave_state: &WaveState,
+    difficulty: &DifficultyParams,
+    kind: EnemyKind,
+    spawn_pos: Vec3,
+    road_index: Option<usize>,
+    minion_skin: Option<&StandardMaterial>,
+) -> Entity {
+    let base = kind.stats();
+    let mul = wave_state.multiplier_for(kind);
+    let hp =
+        (base.health as f32 * mul.hp * difficulty.health_multiplier * wave_state.enemy_hp_modifier)
+            .round()
+            .max(1.0) as u32;
+    let dmg = (base.damage as f32 * mul.dmg).round().max(1.0) as u32;
+    let spd = base.speed * mul.spd * difficulty.speed_multiplier;
+    let half_h = base.size * 0.5;
+    let color = kind.color();
+
+    let e_mesh = meshes.add(Cuboid::new(base.size, base.size, base.size));
+    // Each enemy owns its material since hit flashes and fade-outs mutate it
+    let e_mat = match (kind, minion_skin) {
+        (EnemyKind::Minion, Some(skin)) => materials.add(skin.clone()),
+        _ => materials.add(StandardMaterial {
+            base_color: color,
+            perceptual_roughness: 0.7,
+            metallic: 0.0,
+            ..default()
+        }),
+    };
+
+    let enemy_entity = commands
+        .spawn((
+            Mesh3d(e_mesh),
+            MeshMaterial3d(e_mat),
+            Transform::from_translation(Vec3::new(spawn_pos.x, half_h, spawn_pos.z)),
+            Visibility::default(),
+            InheritedVisibility::default(),
+            kind,
+            Enemy {
+                health: hp,
+                max_health: hp,
+                speed: spd,
+                damage: dmg,
+            },
+            match road_index {
+                Some(ri) => PathFollower {
+                    road_index: ri,
+                    next_index: 1,
+                    path_t: 0.0,
+                },
+                None => PathFollower {
+                    road_index: 0,
+                    next_index: 0,
+                    path_t: 0.0,
+                },
+            },
+        ))
+        .id();
+
+    if kind == EnemyKind::Healer {
+        commands.entity(enemy_entity).insert(HealAura {
+            radius: tunables.healer_aura_radius,
+            heal_per_second: tunables.healer_heal_per_second,
+            timer: Timer::from_seconds(HEAL_TICK_SECS, TimerMode::Repeating),
+        });
+        attach_healer_cross(commands, enemy_entity, meshes, materials, base.size);
     }
 
+    let shielded = kind == EnemyKind::Boss && tunables.boss_shield_absorb > 0;
+    if shielded {
+        commands
+            .entity(enemy_entity)
+            .insert(BossShield::new(tunables.boss_shield_absorb));
+    }
+
+    attach_health_bar(
+        commands,
+        enemy_entity,
+        meshes,
+        materials,
+        health_bar_assets,
+        tunables,
+        shielded,
+    );
+
+    enemy_entity
+}
+
+/// Angle (radians) of a ring spawn point, drawn from `derived_seed`.
+fn seeded_ring_angle(derived_seed: u64) -> f32 {
     use rand::{Rng, SeedableRng, rngs::StdRng};
     let mut rng = StdRng::seed_from_u64(derive_seed(derived_seed, 0x02, 0));
-    let angle = rng.random::<f32>() * 2.0 * PI;
-    let distance = tunables.enemy_spawn_ring_distance;
-    (
-        Vec3::new(angle.cos() * distance, 0.0, angle.sin() * distance),
-        None,
-    )
+    rng.random::<f32>() * 2.0 * PI
 }
 
-fn select_random_spawn_point(
-    roads: &Option<Res<RoadPaths>>,
-    tunables: &Tunables,
-) -> (Vec3, Option<usize>) {
-    if let Some(roads) = roads
-        && !roads.roads.is_empty()
-    {
-        let mut ri = (rand::random::<f32>() * roads.roads.len() as f32).floor() as usize;
-        if ri >= roads.roads.len() {
-            ri = roads.roads.len() - 1;
+// (No other helpers)
+
+/// White cross on top of a Healer so it reads as a support unit at a glance.
+fn attach_healer_cross(
+    commands: &mut Commands,
+    enemy_entity: Entity,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    size: f32,
+) {
+    let arm_long = size * 0.8;
+    let arm_short = size * 0.22;
+    let thickness = 0.04;
+    let cross_mat = materials.add(StandardMaterial {
+        base_color: Color::WHITE,
+        emissive: Color::WHITE.into(),
+        unlit: true,
+        ..default()
+    });
+    let top = Vec3::Y * (size * 0.5 + thickness * 0.5);
+
+    commands.entity(enemy_entity).with_children(|enemy_parent| {
+        enemy_parent.spawn((
+            Mesh3d(meshes.add(Cuboid::new(arm_long, thickness, arm_short))),
+            MeshMaterial3d(cross_mat.clone()),
+            Transform::from_translation(top),
+        ));
+        enemy_parent.spawn((
+            Mesh3d(meshes.add(Cuboid::new(arm_short, thickness, arm_long))),
+            MeshMaterial3d(cross_mat),
+            Transform::from_translation(top),
+        ));
+    });
+}
+
+/// Healers periodically restore HP of every other (non-Healer) enemy inside their aura.
+pub fn healer_aura_system(
+    time: Res<Time>,
+    mut healers: Query<(&Transform, &mut HealAura)>,
+    mut enemies: Query<(&Transform, &mut Enemy, &EnemyKind), Without<HealAura>>,
+) {
+    for (healer_tf, mut aura) in healers.iter_mut() {
+        aura.timer.tick(time.delta());
+        if !aura.timer.just_finished() {
+            continue;
+        }
+        let amount = (aura.heal_per_second * aura.timer.duration().as_secs_f32()).round() as u32;
+        if amount == 0 {
+            continue;
+        }
+        let radius_sq = aura.radius * aura.radius;
+        for (transform, mut enemy, kind) in enemies.iter_mut() {
+            // Dying enemies stay dead; healers don't heal each other
+            if *kind == EnemyKind::Healer || enemy.health == 0 {
+                continue;
+            }
+            if healer_tf
+                .translation
+                .distance_squared(transform.translation)
+                > radius_sq
+            {
+                continue;
+            }
+            enemy.health = (enemy.health + amount).min(enemy.max_health);
         }
-        let wp = &roads.roads[ri][0];
-        return (Vec3::new(wp.x, 0.0, wp.z), Some(ri));
     }
+}
 
-    let angle = rand::random::<f32>() * 2.0 * PI;
-    let distance = tunables.enemy_spawn_ring_distance;
-    (
-        Vec3::new(angle.cos() * distance, 0.0, angle.sin() * distance),
-        None,
-    )
+/// Seconds between Minion swarm regroupings.
+const SWARM_TICK_SECS: f32 = 0.1;
+/// Minions (counting itself) a Minion needs around it to get the swarm speed boost.
+const SWARM_MIN_GROUP: usize = 3;
+/// Group size that announces a swarm with `SwarmBurstEvent`.
+const SWARM_BURST_GROUP: usize = 5;
+/// Glow added to a boosted Minion's own material.
+const SWARM_GLOW: LinearRgba = LinearRgba::rgb(0.25, 0.2, 0.1);
+
+/// For each position, how many of `positions` (itself included) lie within `radius` on XZ.
+pub fn neighbor_counts(positions: &[Vec3], radius: f32) -> Vec<usize> {
+    let radius_sq = radius * radius;
+    positions
+        .iter()
+        .map(|a| {
+            positions
+                .iter()
+                .filter(|b| (**b - *a).with_y(0.0).length_squared() <= radius_sq)
+                .count()
+        })
+        .collect()
 }
 
-// (No other helpers)
+/// Every `SWARM_TICK_SECS`, gives Minions in a group of `SWARM_MIN_GROUP` or more a `SwarmBoost`
+/// (and a faint glow) and takes it away from the rest. A group of `SWARM_BURST_GROUP` forming
+/// is announced once until it breaks up again.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn minion_swarm_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    tunables: Res<Tunables>,
+    minions: Query<(
+        Entity,
+        &Transform,
+        &EnemyKind,
+        Has<SwarmBoost>,
+        Has<EnemyFadeOut>,
+        &MeshMaterial3d<StandardMaterial>,
+        Option<&EnemyFrozenVisual>,
+    )>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut bursts: MessageWriter<SwarmBurstEvent>,
+    mut tick: Local<Option<Timer>>,
+    mut swarming: Local<bool>,
+) {
+    let tick =
+        tick.get_or_insert_with(|| Timer::from_seconds(SWARM_TICK_SECS, TimerMode::Repeating));
+    if !tick.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    // Dying Minions neither count nor keep their boost
+    let (grouped, dying): (Vec<_>, Vec<_>) = minions
+        .iter()
+        .filter(|(_, _, kind, ..)| **kind == EnemyKind::Minion)
+        .partition(|(_, _, _, _, fading, ..)| !*fading);
+    let positions: Vec<Vec3> = grouped.iter().map(|(_, tf, ..)| tf.translation).collect();
+    let counts = neighbor_counts(&positions, tunables.minion_group_radius);
+
+    let in_group = counts.iter().map(|count| *count >= SWARM_MIN_GROUP);
+    let updates = grouped
+        .iter()
+        .zip(in_group)
+        .chain(dying.iter().map(|minion| (minion, false)));
+    for ((entity, _, _, boosted, _, material, frozen), boost) in updates {
+        if *boosted == boost {
+            continue;
+        }
+        if boost {
+            commands.entity(*entity).insert(SwarmBoost {
+                multiplier: tunables.minion_swarm_speed_bonus,
+            });
+        } else {
+            commands.entity(*entity).remove::<SwarmBoost>();
+        }
+        // Only ever the Minion's own material, never the shared frozen one
+        let handle = frozen.map_or(&material.0, |frozen| &frozen.original_material);
+        if let Some(mat) = materials.get_mut(handle) {
+            mat.emissive = if boost { SWARM_GLOW } else { LinearRgba::BLACK };
+        }
+    }
+
+    let burst = counts
+        .iter()
+        .enumerate()
+        .filter(|(_, count)| **count >= SWARM_BURST_GROUP)
+        .max_by_key(|(_, count)| **count);
+    match burst {
+        Some((index, _)) if !*swarming => {
+            *swarming = true;
+            bursts.write(SwarmBurstEvent {
+                position: positions[index],
+            });
+        }
+        None => *swarming = false,
+        _ => {}
+    }
+}
 
 fn attach_health_bar(
     commands: &mut Commands,
@@ -185,6 +426,7 @@ fn attach_health_bar(
     materials: &mut ResMut<Assets<StandardMaterial>>,
     health_bar_assets: &mut ResMut<EnemyHealthBarAssets>,
     tunables: &Tunables,
+    shielded: bool,
 ) {
     let quad_mesh = health_bar_assets.mesh(meshes);
     let background_mat = health_bar_assets.background_material(materials);
@@ -252,6 +494,28 @@ fn attach_health_bar(
                         last_ratio: 1.0,
                     },
                 ));
+
+                // Shield (blue) drawn over the fill until it breaks
+                if shielded {
+                    bar.spawn((
+                        Mesh3d(quad_mesh.clone()),
+                        MeshMaterial3d(health_bar_assets.shield_material(materials)),
+                        Transform {
+                            translation: Vec3::new(0.0, 0.0, -0.003),
+                            scale: Vec3::new(
+                                tunables.health_bar_width,
+                                tunables.health_bar_fill_height,
+                                1.0,
+                            ),
+                            ..default()
+                        },
+                        BossShieldBarFill {
+                            max_width: tunables.health_bar_width,
+                            owner: enemy_entity,
+                            last_ratio: 1.0,
+                        },
+                    ));
+                }
             });
     });
 }
@@ -279,9 +543,73 @@ pub fn update_enemy_health_bars(
     }
 }
 
+/// Shrinks each Boss shield bar with its shield and removes it once the shield is gone.
+pub fn update_boss_shield_bars(
+    mut commands: Commands,
+    shields: Query<&BossShield>,
+    mut fill_query: Query<(Entity, &mut BossShieldBarFill, &mut Transform)>,
+) {
+    for (entity, mut fill, mut transform) in fill_query.iter_mut() {
+        let ratio = shields
+            .get(fill.owner)
+            .map(|shield| shield.absorb_remaining as f32 / shield.capacity.max(1) as f32)
+            .unwrap_or(0.0)
+            .clamp(0.0, 1.0);
+        if ratio <= 0.0 {
+            commands.entity(entity).try_despawn();
+            continue;
+        }
+        if (ratio - fill.last_ratio).abs() > 0.001 {
+            fill.last_ratio = ratio;
+            let width = fill.max_width * ratio;
+            transform.scale.x = width;
+            transform.translation.x = -fill.max_width * 0.5 + width * 0.5;
+        }
+    }
+}
+
+/// Seconds between visibility updates for health bars outside `health_bar_lod_near_distance`.
+const HEALTH_BAR_LOD_TICK_SECS: f32 = 0.1;
+
+/// Hides enemy health bars farther than `Tunables.health_bar_lod_distance` from the camera's
+/// focus point on the ground (the camera itself hangs far above it). Bars within
+/// `health_bar_lod_near_distance` update every frame, the rest every `HEALTH_BAR_LOD_TICK_SECS`.
+pub fn health_bar_lod_system(
+    time: Res<Time>,
+    tunables: Res<Tunables>,
+    camera_target: Option<Res<CameraTarget>>,
+    mut bars: Query<(&GlobalTransform, &mut Visibility), With<EnemyHealthBarRoot>>,
+    mut tick: Local<Option<Timer>>,
+) {
+    let Some(camera_target) = camera_target else {
+        return;
+    };
+    let tick = tick
+        .get_or_insert_with(|| Timer::from_seconds(HEALTH_BAR_LOD_TICK_SECS, TimerMode::Repeating));
+    let full_update = tick.tick(time.delta()).just_finished();
+
+    let focus = camera_target.focus;
+    let near_sq = tunables.health_bar_lod_near_distance.powi(2);
+    let far_sq = tunables.health_bar_lod_distance.powi(2);
+    for (bar_tf, mut visibility) in bars.iter_mut() {
+        let distance_sq = bar_tf.translation().distance_squared(focus);
+        if distance_sq <= near_sq {
+            visibility.set_if_neq(Visibility::Inherited);
+        } else if full_update {
+            visibility.set_if_neq(if distance_sq > far_sq {
+                Visibility::Hidden
+            } else {
+                Visibility::Inherited
+            });
+        }
+    }
+}
+
+/// Turns visible health bars toward the camera; bars hidden by `health_bar_lod_system` are skipped.
 pub fn face_enemy_health_bars(
     camera_query: Query<&GlobalTransform, With<Camera3d>>,
-    mut bars: Query<(&mut Transform, &GlobalTransform), With<EnemyHealthBarRoot>>,
+    mut bars: Query<(&mut Transform, &ChildOf, &Visibility), With<EnemyHealthBarRoot>>,
+    enemies: Query<&Transform, (With<Enemy>, Without<EnemyHealthBarRoot>)>,
 ) {
     let Ok(camera_tf) = camera_query.single() else {
         return;
@@ -290,9 +618,153 @@ pub fn face_enemy_health_bars(
     let forward = camera_tf.forward();
     let yaw = forward.x.atan2(forward.z);
 
-    for (mut transform, _) in bars.iter_mut() {
-        transform.rotation = Quat::from_rotation_y(yaw);
+    for (mut transform, child_of, visibility) in bars.iter_mut() {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+        // Cancel the enemy's own facing so the bar stays aligned with the camera
+        let parent_rotation = enemies
+            .get(child_of.parent())
+            .map(|tf| tf.rotation)
+            .unwrap_or(Quat::IDENTITY);
+        transform.rotation = parent_rotation.inverse() * Quat::from_rotation_y(yaw);
     }
 }
 
 // Removed unused positioning/cleanup systems for health bars
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    /// World with the camera focused on the origin and a health bar at each of `distances` on +X.
+    fn lod_world(distances: &[f32]) -> (World, Vec<Entity>) {
+        let mut world = World::new();
+        world.insert_resource(Tunables::default());
+        world.insert_resource(CameraTarget::default());
+        world.init_resource::<Time>();
+        let bars = distances
+            .iter()
+            .map(|distance| {
+                world
+                    .spawn((
+                        EnemyHealthBarRoot,
+                        GlobalTransform::from_translation(Vec3::new(*distance, 1.0, 0.0)),
+                        Visibility::Visible,
+                    ))
+                    .id()
+            })
+            .collect();
+        (world, bars)
+    }
+
+    fn visibility(world: &World, bar: Entity) -> Visibility {
+        *world.get::<Visibility>(bar).unwrap()
+    }
+
+    #[test]
+    fn bars_beyond_the_lod_distance_are_hidden() {
+        let tunables = Tunables::default();
+        let near = tunables.health_bar_lod_near_distance * 0.5;
+        let between =
+            (tunables.health_bar_lod_near_distance + tunables.health_bar_lod_distance) * 0.5;
+        let far = tunables.health_bar_lod_distance + 5.0;
+        let (mut world, bars) = lod_world(&[near, between, far]);
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(HEALTH_BAR_LOD_TICK_SECS));
+        world.run_system_once(health_bar_lod_system).unwrap();
+
+        assert_eq!(visibility(&world, bars[0]), Visibility::Inherited);
+        assert_eq!(visibility(&world, bars[1]), Visibility::Inherited);
+        assert_eq!(visibility(&world, bars[2]), Visibility::Hidden);
+    }
+
+    #[test]
+    fn only_near_bars_update_between_ticks() {
+        let tunables = Tunables::default();
+        let near = tunables.health_bar_lod_near_distance * 0.5;
+        let far = tunables.health_bar_lod_distance + 5.0;
+        let (mut world, bars) = lod_world(&[near, far]);
+        world.run_system_once(health_bar_lod_system).unwrap();
+
+        assert_eq!(visibility(&world, bars[0]), Visibility::Inherited);
+        assert_eq!(visibility(&world, bars[1]), Visibility::Visible);
+    }
+
+    #[test]
+    fn neighbor_counts_include_the_minion_itself() {
+        let positions = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            // Height is ignored
+            Vec3::new(0.0, 9.0, -2.5),
+            Vec3::new(50.0, 0.0, 0.0),
+        ];
+        assert_eq!(neighbor_counts(&positions, 3.0), vec![3, 2, 2, 1]);
+        assert_eq!(neighbor_counts(&positions, 100.0), vec![4, 4, 4, 4]);
+        assert!(neighbor_counts(&[], 3.0).is_empty());
+    }
+
+    fn swarm_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(Tunables::default());
+        world.init_resource::<Time>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.init_resource::<Messages<SwarmBurstEvent>>();
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(SWARM_TICK_SECS));
+        world
+    }
+
+    fn spawn_kind(world: &mut World, kind: EnemyKind, position: Vec3) -> Entity {
+        world
+            .spawn((
+                kind,
+                Transform::from_translation(position),
+                MeshMaterial3d::<StandardMaterial>::default(),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn only_minions_in_a_group_of_three_are_boosted() {
+        let mut world = swarm_world();
+        let group: Vec<Entity> = [0.0, 1.0, 2.0]
+            .into_iter()
+            .map(|x| spawn_kind(&mut world, EnemyKind::Minion, Vec3::new(x, 0.0, 0.0)))
+            .collect();
+        let straggler = spawn_kind(&mut world, EnemyKind::Minion, Vec3::new(40.0, 0.0, 0.0));
+        // A Zombie amid the group neither counts nor gets boosted
+        let zombie = spawn_kind(&mut world, EnemyKind::Zombie, Vec3::new(1.0, 0.0, 1.0));
+        let pair: Vec<Entity> = [-40.0, -41.0]
+            .into_iter()
+            .map(|x| spawn_kind(&mut world, EnemyKind::Minion, Vec3::new(x, 0.0, 0.0)))
+            .collect();
+
+        world.run_system_once(minion_swarm_system).unwrap();
+
+        let boosted = |world: &World, entity: Entity| world.entity(entity).contains::<SwarmBoost>();
+        assert!(group.iter().all(|minion| boosted(&world, *minion)));
+        assert!(!boosted(&world, straggler));
+        assert!(!boosted(&world, zombie));
+        assert!(pair.iter().all(|minion| !boosted(&world, *minion)));
+        let multiplier = world.get::<SwarmBoost>(group[0]).unwrap().multiplier;
+        assert_eq!(multiplier, Tunables::default().minion_swarm_speed_bonus);
+        assert!(world.resource::<Messages<SwarmBurstEvent>>().is_empty());
+    }
+
+    #[test]
+    fn a_group_of_five_announces_a_swarm() {
+        let mut world = swarm_world();
+        for x in 0..5 {
+            spawn_kind(&mut world, EnemyKind::Minion, Vec3::new(x as f32, 0.0, 0.0));
+        }
+
+        world.run_system_once(minion_swarm_system).unwrap();
+        assert_eq!(world.resource::<Messages<SwarmBurstEvent>>().len(), 1);
+    }
+}
@@ -0,0 +1,294 @@
+use super::projectiles::{
+    EnemyFadeOut, EnemyFrozenVisual, EnemyHitFlash, ZombieResurrection, handle_projectile_hit,
+};
+use crate::audio::LightningStrikeEvent;
+use crate::components::{BossShield, Enemy, EnemyKind};
+use crate::constants::Tunables;
+use crate::core::rng::derive_seed;
+use crate::events::{DamageDealt, EnemyKilled};
+use crate::systems::chunks::WorldSeed;
+use crate::systems::wind::WindState;
+use bevy::pbr::MeshMaterial3d;
+use bevy::prelude::*;
+use bevy::time::TimerMode;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::time::Duration;
+
+/// Seconds between the telegraph beam appearing and the strike landing.
+const TELEGRAPH_SECS: f32 = 0.5;
+/// Height the telegraph beam starts descending from.
+const TELEGRAPH_START_HEIGHT: f32 = 40.0;
+/// Length of the descending telegraph beam.
+const TELEGRAPH_BEAM_LENGTH: f32 = 12.0;
+/// Seconds for the impact flash to expand and fade out.
+const FLASH_SECS: f32 = 0.35;
+/// Random enemies sampled when looking for the densest cluster to strike.
+const CLUSTER_SAMPLES: usize = 8;
+/// Half-extent of the area around the village struck when no enemies are alive.
+const FALLBACK_HALF_EXTENT: f32 = 40.0;
+/// Salt mixed into the world seed for strike positions, keyed by the strike count.
+const STRIKE_SEED_SALT: u64 = 0x1160;
+
+/// Descending beam marking where a lightning strike is about to land.
+#[derive(Component)]
+pub struct LightningTelegraph {
+    timer: Timer,
+    target: Vec3,
+    material: Handle<StandardMaterial>,
+}
+
+/// Bright sphere that expands and fades where a lightning strike landed.
+#[derive(Component)]
+pub struct LightningFlash {
+    timer: Timer,
+    radius: f32,
+    material: Handle<StandardMaterial>,
+}
+
+/// While a storm is blowing, telegraphs a lightning strike every `lightning_interval_secs`.
+#[allow(clippy::too_many_arguments)]
+pub fn lightning_strike_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    tunables: Res<Tunables>,
+    wind: Res<WindState>,
+    seed: Res<WorldSeed>,
+    enemies: Query<&GlobalTransform, (With<Enemy>, Without<EnemyFadeOut>)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut strike_timer: Local<Option<Timer>>,
+    mut strikes: Local<u64>,
+) {
+    let interval = Duration::from_secs_f32(tunables.lightning_interval_secs.max(0.1));
+    let timer = strike_timer.get_or_insert_with(|| Timer::new(interval, TimerMode::Repeating));
+    // Keep the interval in sync with Tunables in case they change at runtime
+    if timer.duration() != interval {
+        timer.set_duration(interval);
+    }
+
+    if !wind.is_storm(&tunables) {
+        timer.reset();
+        return;
+    }
+    timer.tick(time.delta());
+    if !timer.just_finished() {
+        return;
+    }
+
+    let positions: Vec<Vec3> = enemies.iter().map(|tf| tf.translation()).collect();
+    *strikes += 1;
+    let mut rng = StdRng::seed_from_u64(derive_seed(seed.0, STRIKE_SEED_SALT, *strikes));
+    let target = pick_strike_position(&positions, tunables.lightning_radius, &mut rng);
+
+    let material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.85, 0.9, 1.0, 0.0),
+        emissive: LinearRgba::rgb(6.0, 6.5, 8.0),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    });
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(0.3, TELEGRAPH_BEAM_LENGTH, 0.3))),
+        MeshMaterial3d(material.clone()),
+        Transform::from_translation(target + Vec3::Y * TELEGRAPH_START_HEIGHT),
+        GlobalTransform::default(),
+        Visibility::default(),
+        LightningTelegraph {
+            timer: Timer::from_seconds(TELEGRAPH_SECS, TimerMode::Once),
+            target,
+            material,
+        },
+    ));
+}
+
+/// Lowers telegraph beams toward the ground and resolves the strike when they arrive.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn lightning_telegraph_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    tunables: Res<Tunables>,
+    mut telegraphs: Query<(Entity, &mut LightningTelegraph, &mut Transform)>,
+    enemy_pose_query: Query<(Entity, &GlobalTransform), (With<Enemy>, Without<EnemyFadeOut>)>,
+    mut enemy_hit_query: Query<
+        (
+            &mut Enemy,
+            &MeshMaterial3d<StandardMaterial>,
+            Option<&mut EnemyHitFlash>,
+            Option<&EnemyFrozenVisual>,
+            Option<&mut BossShield>,
+            Option<&EnemyKind>,
+            Has<ZombieResurrection>,
+        ),
+        With<Enemy>,
+    >,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+    mut damage_dealt_events: MessageWriter<DamageDealt>,
+    mut enemy_killed_events: MessageWriter<EnemyKilled>,
+    mut strike_events: MessageWriter<LightningStrikeEvent>,
+) {
+    for (entity, mut telegraph, mut transform) in telegraphs.iter_mut() {
+        telegraph.timer.tick(time.delta());
+        let duration = telegraph.timer.duration().as_secs_f32().max(f32::EPSILON);
+        let progress = (telegraph.timer.elapsed().as_secs_f32() / duration).clamp(0.0, 1.0);
+
+        // Beam bottom descends from the sky to the ground while brightening
+        let bottom = TELEGRAPH_START_HEIGHT * (1.0 - progress);
+        transform.translation = telegraph.target + Vec3::Y * (bottom + TELEGRAPH_BEAM_LENGTH * 0.5);
+        if let Some(mat) = standard_materials.get_mut(&telegraph.material) {
+            mat.base_color.set_alpha(0.3 + 0.7 * progress);
+        }
+
+        if !telegraph.timer.just_finished() {
+            continue;
+        }
+        let target = telegraph.target;
+        commands.entity(entity).despawn();
+
+        // Only enemies are struck; towers are immune
+        let radius_sq = tunables.lightning_radius * tunables.lightning_radius;
+        for (enemy_entity, enemy_tf) in enemy_pose_query.iter() {
+            let pos = enemy_tf.translation();
+            let dx = pos.x - target.x;
+            let dz = pos.z - target.z;
+            if dx * dx + dz * dz > radius_sq {
+                continue;
+            }
+            handle_projectile_hit(
+                &mut commands,
+                enemy_entity,
+                tunables.lightning_damage,
+                pos,
+                None,
+                &mut enemy_hit_query,
+                &mut standard_materials,
+                &tunables,
+                &mut enemy_killed_events,
+            );
+            damage_dealt_events.write(DamageDealt {
+                amount: tunables.lightning_damage,
+                position: pos + Vec3::new(0.0, tunables.damage_number_spawn_height, 0.0),
+            });
+        }
+
+        spawn_lightning_flash(
+            &mut commands,
+            &mut meshes,
+            &mut standard_materials,
+            target,
+            tunables.lightning_radius,
+        );
+        strike_events.write(LightningStrikeEvent);
+    }
+}
+
+fn spawn_lightning_flash(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    position: Vec3,
+    radius: f32,
+) {
+    let material = materials.add(StandardMaterial {
+        base_color: Color::srgba(1.0, 1.0, 1.0, 0.95),
+        emissive: LinearRgba::rgb(10.0, 10.0, 12.0),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    });
+    commands.spawn((
+        Mesh3d(meshes.add(Sphere::new(1.0))),
+        MeshMaterial3d(material.clone()),
+        Transform::from_translation(position).with_scale(Vec3::splat(0.5)),
+        GlobalTransform::default(),
+        Visibility::default(),
+        LightningFlash {
+            timer: Timer::from_seconds(FLASH_SECS, TimerMode::Once),
+            radius,
+            material,
+        },
+    ));
+}
+
+/// Expands and fades lightning impact flashes, mirroring `impact_effect_system`.
+pub fn lightning_flash_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut flashes: Query<(Entity, &mut LightningFlash, &mut Transform)>,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (entity, mut flash, mut transform) in flashes.iter_mut() {
+        flash.timer.tick(time.delta());
+        let duration = flash.timer.duration().as_secs_f32().max(f32::EPSILON);
+        let progress = (flash.timer.elapsed().as_secs_f32() / duration).clamp(0.0, 1.0);
+        // Expands almost instantly, then lingers while fading
+        let grow = 1.0 - (1.0 - progress).powi(4);
+        transform.scale = Vec3::splat(0.5 + grow * flash.radius);
+
+        if let Some(mat) = standard_materials.get_mut(&flash.material) {
+            mat.base_color.set_alpha(0.95 * (1.0 - progress));
+        }
+
+        if flash.timer.just_finished() {
+            standard_materials.remove(flash.material.id());
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Picks a ground point biased toward enemy clusters: samples a few enemies and strikes the one
+/// with the most neighbours within `radius`. Falls back to a random point around the village.
+fn pick_strike_position(enemies: &[Vec3], radius: f32, rng: &mut StdRng) -> Vec3 {
+    if enemies.is_empty() {
+        let x = (rng.random::<f32>() * 2.0 - 1.0) * FALLBACK_HALF_EXTENT;
+        let z = (rng.random::<f32>() * 2.0 - 1.0) * FALLBACK_HALF_EXTENT;
+        return Vec3::new(x, 0.0, z);
+    }
+
+    let radius_sq = radius * radius;
+    let mut best = enemies[0];
+    let mut best_neighbours = 0;
+    for _ in 0..CLUSTER_SAMPLES.min(enemies.len()) {
+        let candidate = enemies[rng.random_range(0..enemies.len())];
+        let neighbours = enemies
+            .iter()
+            .filter(|other| other.distance_squared(candidate) <= radius_sq)
+            .count();
+        if neighbours > best_neighbours {
+            best = candidate;
+            best_neighbours = neighbours;
+        }
+    }
+    Vec3::new(best.x, 0.0, best.z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strike_lands_on_the_densest_cluster() {
+        let mut enemies = vec![Vec3::new(30.0, 0.0, 30.0)];
+        enemies.extend((0..7).map(|i| Vec3::new(-10.0 + i as f32 * 0.1, 1.0, 5.0)));
+        let mut rng = StdRng::seed_from_u64(3);
+        let target = pick_strike_position(&enemies, 2.0, &mut rng);
+        assert!((target.x + 10.0).abs() < 1.0 && (target.z - 5.0).abs() < 1e-4);
+        assert_eq!(target.y, 0.0);
+    }
+
+    #[test]
+    fn same_seed_strikes_the_same_spot() {
+        let enemies: Vec<Vec3> = (0..20)
+            .map(|i| Vec3::new(i as f32 * 3.0, 0.0, (i % 4) as f32))
+            .collect();
+        for seed in 0..5 {
+            let a = pick_strike_position(&enemies, 4.0, &mut StdRng::seed_from_u64(seed));
+            let b = pick_strike_position(&enemies, 4.0, &mut StdRng::seed_from_u64(seed));
+            assert_eq!(a, b);
+            let a = pick_strike_position(&[], 4.0, &mut StdRng::seed_from_u64(seed));
+            let b = pick_strike_position(&[], 4.0, &mut StdRng::seed_from_u64(seed));
+            assert_eq!(a, b);
+            assert!(a.x.abs() <= FALLBACK_HALF_EXTENT && a.z.abs() <= FALLBACK_HALF_EXTENT);
+        }
+    }
+}
@@ -5,8 +5,10 @@
 //! - `towers`: tower placement logic and spawn effects
 //! - `enemy`: enemy spawning and health bar maintenance
 //! - `projectiles`: tower attacks, projectile movement, and VFX clean-up
+//! - `lightning_strikes`: storm lightning hazards that damage enemies in an area
 
 pub mod assets;
 pub mod enemy;
+pub mod lightning_strikes;
 pub mod projectiles;
 pub mod towers;
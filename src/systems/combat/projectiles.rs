@@ -1,59 +1,204 @@
-use super::assets::CombatVfxAssets;
+use super::assets::{CombatVfxAssets, FrozenMaterialCache};
 use crate::audio::{TowerShotEvent, TowerShotKind};
-use crate::components::{BuiltTower, Enemy, EnemyKind, Player, Tower, TowerKind};
+use crate::components::{
+    BossShield, BuiltTower, BurnEffect, ComboBonus, Enemy, EnemyKind, Player, SlowEffect,
+    StunEffect, TargetingMode, Tower, TowerKind, TowerPriorityKind,
+};
 use crate::constants::Tunables;
+use crate::core::grid::SpatialGrid;
+use crate::core::rng::derive_seed;
 use crate::events::{DamageDealt, EnemyKilled};
 use crate::materials::ImpactMaterial;
+use crate::systems::chunks::WorldSeed;
+use crate::systems::wind::WindState;
 use crate::utils::camera as cam_utils;
 use bevy::pbr::MeshMaterial3d;
 use bevy::prelude::*;
 use bevy::time::TimerMode;
+use rand::{Rng, SeedableRng, rngs::StdRng};
 use std::time::Duration;
 
-/// Makes towers shoot the closest enemy in range at a fixed fire rate.
+/// Salt mixed into the world seed for an arrow's slow roll, keyed by the arrow entity.
+const ARROW_SLOW_ROLL_SALT: u64 = 0x5107;
+/// Tint of a projectile's impact flash.
+const IMPACT_FLASH_COLOR: Color = Color::srgba(1.0, 0.65, 0.3, 0.9);
+/// Tint of the flash when a Boss's shield breaks; the red of an invalid placement ghost.
+const SHIELD_BREAK_FLASH_COLOR: Color = Color::srgba(0.85, 0.2, 0.2, 0.95);
+/// Tint of the flash when a Zombie gets back up.
+const ZOMBIE_RISE_FLASH_COLOR: Color = Color::srgba(0.65, 0.25, 0.95, 0.95);
+/// Tint of a Boss's poison cloud at full strength.
+const POISON_CLOUD_COLOR: Color = Color::srgba(0.35, 0.85, 0.25, 0.35);
+/// Seconds a poisoned enemy keeps burning after leaving every cloud.
+const POISON_LINGER_SECS: f32 = 1.0;
+/// Interval between poison damage ticks.
+const POISON_TICK_SECS: f32 = 0.5;
+/// How long a fallen Zombie lies on the ground before rising again.
+const ZOMBIE_RESURRECTION_SECS: f32 = 3.0;
+/// Height of a fallen Zombie's center, sunk mostly into the ground.
+const ZOMBIE_CORPSE_HEIGHT: f32 = 0.1;
+/// Opacity of a fallen Zombie while it waits to rise.
+const ZOMBIE_CORPSE_ALPHA: f32 = 0.5;
+/// Trail left by Bow arrows.
+const BOW_TRAIL_COLOR: Color = Color::srgb(1.0, 0.7, 0.3);
+/// Trail left by Crossbow bolts.
+const CROSSBOW_TRAIL_COLOR: Color = Color::srgb(0.75, 0.88, 1.0);
+/// How long a trail particle takes to fade away.
+const TRAIL_PARTICLE_SECS: f32 = 0.3;
+
+/// Picks the target for a tower at `origin` according to `mode`, optionally only among enemies
+/// of `only_kind`. Iterates without per-frame allocations.
+fn select_target<'a>(
+    origin: Vec3,
+    range: f32,
+    mode: TargetingMode,
+    enemies: impl Iterator<Item = (&'a Transform, Entity, &'a EnemyKind, &'a Enemy)>,
+    only_kind: Option<EnemyKind>,
+) -> Option<(Vec3, Entity)> {
+    enemies
+        .filter(|(_, _, kind, _)| only_kind.is_none_or(|only| only == **kind))
+        .map(|(transform, entity, kind, enemy)| {
+            let pos = transform.translation;
+            (pos, entity, *kind, enemy.health, origin.distance(pos))
+        })
+        .filter(|(_, _, _, _, d)| *d <= range)
+        .min_by(|a, b| {
+            let nearest = a.4.total_cmp(&b.4);
+            match mode {
+                TargetingMode::Nearest => nearest,
+                TargetingMode::Furthest => nearest.reverse(),
+                TargetingMode::LowestHp => a.3.cmp(&b.3).then(nearest),
+                TargetingMode::HighestHp => b.3.cmp(&a.3).then(nearest),
+                // Any Healer beats any non-Healer; ties go to the nearest
+                TargetingMode::Priority => (b.2 == EnemyKind::Healer)
+                    .cmp(&(a.2 == EnemyKind::Healer))
+                    .then(nearest),
+            }
+        })
+        .map(|(pos, entity, _, _, _)| (pos, entity))
+}
+
+/// Rebuilds the enemy spatial hash that `tower_shooting` looks targets up in. Fading enemies are
+/// left out since they can no longer be shot.
+#[allow(clippy::type_complexity)]
+pub fn update_spatial_grid(
+    tunables: Res<Tunables>,
+    mut grid: ResMut<SpatialGrid<Entity>>,
+    enemies: Query<(Entity, &Transform), (With<Enemy>, Without<EnemyFadeOut>)>,
+) {
+    if grid.cell_size != tunables.spatial_grid_cell_size {
+        *grid = SpatialGrid::new(tunables.spatial_grid_cell_size);
+    } else {
+        grid.clear();
+    }
+    for (entity, transform) in enemies.iter() {
+        grid.insert(transform.translation, entity);
+    }
+}
+
+/// Makes towers shoot an enemy in range at a fixed fire rate, chosen by their `TargetingMode`.
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn tower_shooting(
     time: Res<Time>,
     mut commands: Commands,
-    mut tower_query: Query<(&Transform, &mut Tower, Option<&BuiltTower>)>,
-    enemy_pos: Query<(&Transform, Entity), (With<Enemy>, Without<EnemyFadeOut>)>,
+    mut tower_query: Query<(
+        Entity,
+        &Transform,
+        &mut Tower,
+        Option<&BuiltTower>,
+        Option<&TargetingMode>,
+        Option<&TowerPriorityKind>,
+        Option<&ComboBonus>,
+    )>,
+    enemy_pos: Query<(&Transform, Entity, &EnemyKind, &Enemy), Without<EnemyFadeOut>>,
+    enemy_grid: Res<SpatialGrid<Entity>>,
     tunables: Res<Tunables>,
     vfx_assets: Res<CombatVfxAssets>,
     mut shot_events: MessageWriter<TowerShotEvent>,
 ) {
-    for (tower_transform, mut tower, built_kind_opt) in tower_query.iter_mut() {
+    for (
+        tower_entity,
+        tower_transform,
+        mut tower,
+        built_kind_opt,
+        mode_opt,
+        priority_opt,
+        combo_opt,
+    ) in tower_query.iter_mut()
+    {
         tower.last_shot += time.delta_secs();
 
         if tower.last_shot >= tower.fire_interval_secs {
-            // Find closest enemy within range without per-frame allocations
             let origin = tower_transform.translation;
-            let mut best_entity: Option<(Vec3, Entity)> = None;
-            let mut best_dist: f32 = tower.range;
-            for (enemy_transform, entity) in enemy_pos.iter() {
-                let pos = enemy_transform.translation;
-                let d = origin.distance(pos);
-                if d <= best_dist {
-                    best_dist = d;
-                    best_entity = Some((pos, entity));
-                }
-            }
+            let mode = mode_opt.copied().unwrap_or_default();
+            // A locked enemy kind narrows the candidates first; if none are in range the
+            // tower falls back to its targeting mode across all kinds
+            let locked_kind = priority_opt.and_then(|p| p.kind);
+            let range = tower.range;
+            let in_reach = || {
+                enemy_grid
+                    .query_radius(origin, range)
+                    .filter_map(|entity| enemy_pos.get(*entity).ok())
+            };
+            let best_entity = locked_kind
+                .and_then(|kind| select_target(origin, range, mode, in_reach(), Some(kind)))
+                .or_else(|| select_target(origin, range, mode, in_reach(), None));
 
             if let Some((enemy_pos_vec, enemy_entity)) = best_entity {
-                spawn_projectile(
-                    &mut commands,
-                    &vfx_assets,
-                    tower_transform.translation,
-                    enemy_pos_vec,
-                    enemy_entity,
-                    &tunables,
-                    tower.damage,
-                    tower.height,
-                    tower.projectile_speed,
-                );
+                let tower_kind = built_kind_opt.map(|b| b.kind).unwrap_or(TowerKind::Bow);
+                let damage = combo_opt.map_or(tower.damage, |combo| {
+                    (tower.damage as f32 * combo.damage_multiplier).round() as u32
+                });
+                if tower_kind == TowerKind::Lightning {
+                    spawn_lightning_bolt(
+                        &mut commands,
+                        tower_entity,
+                        tower_transform.translation,
+                        enemy_pos_vec,
+                        enemy_entity,
+                        &tunables,
+                        damage,
+                        tower.height,
+                    );
+                } else {
+                    // Lighter arrows drift more with the wind than heavy bolts
+                    let trail = match tower_kind {
+                        TowerKind::Bow => Some(BOW_TRAIL_COLOR),
+                        TowerKind::Crossbow => Some(CROSSBOW_TRAIL_COLOR),
+                        _ => None,
+                    };
+                    let (projectile_kind, wind_factor) = match tower_kind {
+                        TowerKind::Crossbow => {
+                            (ProjectileKind::Arrow, tunables.crossbow_wind_factor)
+                        }
+                        // Boulders and shells are far too heavy for the wind to move
+                        TowerKind::Catapult | TowerKind::Cannon => {
+                            (ProjectileKind::Splash(tower_kind), 0.0)
+                        }
+                        _ => (ProjectileKind::Arrow, tunables.bow_wind_factor),
+                    };
+                    spawn_projectile(
+                        &mut commands,
+                        &vfx_assets,
+                        tower_entity,
+                        tower_transform.translation,
+                        enemy_pos_vec,
+                        enemy_entity,
+                        &tunables,
+                        damage,
+                        tower.height,
+                        tower.projectile_speed,
+                        wind_factor,
+                        projectile_kind,
+                        trail,
+                    );
+                }
                 // Emit tower shot audio event from tower position
-                let kind = match built_kind_opt.map(|b| b.kind).unwrap_or(TowerKind::Bow) {
+                let kind = match tower_kind {
                     TowerKind::Bow => TowerShotKind::Bow,
                     TowerKind::Crossbow => TowerShotKind::Crossbow,
+                    TowerKind::Lightning => TowerShotKind::Lightning,
+                    TowerKind::Catapult => TowerShotKind::Catapult,
+                    TowerKind::Cannon => TowerShotKind::Cannon,
                 };
                 shot_events.write(TowerShotEvent {
                     kind,
@@ -65,19 +210,54 @@ pub fn tower_shooting(
     }
 }
 
+/// How a projectile reaches its target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProjectileKind {
+    /// Physical arrow/bolt that travels toward its target.
+    Arrow,
+    /// Instant hitscan bolt that chains to nearby enemies on the next update.
+    Lightning,
+    /// Catapult boulder or Cannon shell that damages every enemy within the firing tower kind's
+    /// `TowerKind::splash_radius` of its impact.
+    Splash(TowerKind),
+}
+
+impl ProjectileKind {
+    /// Radius around the impact point that takes full damage, for splashing projectiles.
+    fn splash_radius(self, tunables: &Tunables) -> Option<f32> {
+        match self {
+            ProjectileKind::Splash(tower_kind) => tower_kind.splash_radius(tunables),
+            ProjectileKind::Arrow | ProjectileKind::Lightning => None,
+        }
+    }
+}
+
 #[derive(Component)]
 pub struct Projectile {
+    kind: ProjectileKind,
+    /// Tower that fired it, credited with any kill.
+    source: Entity,
     target: Entity,
     speed: f32,
     damage: u32,
     last_known_target_pos: Vec3,
     lifetime: Timer,
+    wind_factor: f32,
+}
+
+impl Projectile {
+    /// Enemy the projectile was fired at.
+    #[allow(dead_code)]
+    pub fn target(&self) -> Entity {
+        self.target
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
 fn spawn_projectile(
     commands: &mut Commands,
     vfx_assets: &CombatVfxAssets,
+    tower: Entity,
     tower_position: Vec3,
     target_position: Vec3,
     target_entity: Entity,
@@ -85,6 +265,9 @@ fn spawn_projectile(
     damage: u32,
     tower_height: f32,
     projectile_speed: f32,
+    wind_factor: f32,
+    kind: ProjectileKind,
+    trail: Option<Color>,
 ) {
     let spawn_pos = Vec3::new(
         tower_position.x,
@@ -100,7 +283,7 @@ fn spawn_projectile(
         .projectile_mesh_handle()
         .expect("CombatVfxAssets not initialized: projectile_mesh");
 
-    commands.spawn((
+    let mut projectile = commands.spawn((
         Mesh3d(mesh),
         // Use a solid unlit white StandardMaterial for the main projectile visibility
         MeshMaterial3d(
@@ -111,17 +294,147 @@ fn spawn_projectile(
         Transform {
             translation: spawn_pos,
             rotation: Quat::from_rotation_arc(Vec3::Y, direction.normalize_or_zero()),
-            // Further elongated to resemble an arrow/bolt (Y is forward axis)
-            scale: Vec3::new(0.12, 2.4, 0.12),
+            scale: match kind {
+                // Round and chunky
+                ProjectileKind::Splash(TowerKind::Catapult) => Vec3::splat(2.0),
+                ProjectileKind::Splash(_) => Vec3::splat(1.4),
+                // Further elongated to resemble an arrow/bolt (Y is forward axis)
+                _ => Vec3::new(0.12, 2.4, 0.12),
+            },
         },
         GlobalTransform::default(),
         Visibility::default(),
         Projectile {
+            kind,
+            source: tower,
             target: target_entity,
             speed: projectile_speed,
             damage,
             last_known_target_pos: target_position,
             lifetime: Timer::from_seconds(tunables.projectile_lifetime_secs, TimerMode::Once),
+            wind_factor,
+        },
+    ));
+    if let Some(color) = trail {
+        projectile.insert(TrailEmitter {
+            last_emit_pos: spawn_pos,
+            emit_interval: tunables.projectile_trail_interval_secs,
+            color,
+        });
+    }
+}
+
+/// Leaves a particle behind every `emit_interval` seconds of flight.
+#[derive(Component)]
+pub struct TrailEmitter {
+    pub last_emit_pos: Vec3,
+    pub emit_interval: f32,
+    pub color: Color,
+}
+
+/// Fading trail sphere; owns its material so it can fade on its own.
+#[derive(Component)]
+pub struct TrailParticle {
+    pub lifetime: Timer,
+    material: Handle<StandardMaterial>,
+    color: Color,
+}
+
+/// Spawns trail particles behind projectiles that flew `emit_interval * speed` since their last
+/// one, as long as fewer than `max_trail_particles` are alive.
+pub fn projectile_trail_system(
+    mut commands: Commands,
+    mut emitters: Query<(&Transform, &Projectile, &mut TrailEmitter)>,
+    particles: Query<(), With<TrailParticle>>,
+    vfx_assets: Res<CombatVfxAssets>,
+    tunables: Res<Tunables>,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Some(mesh) = vfx_assets.trail_particle_mesh_handle() else {
+        return;
+    };
+    let mut alive = particles.iter().count();
+    for (transform, projectile, mut emitter) in emitters.iter_mut() {
+        let spacing = emitter.emit_interval * projectile.speed;
+        let position = transform.translation;
+        if position.distance_squared(emitter.last_emit_pos) <= spacing * spacing {
+            continue;
+        }
+        emitter.last_emit_pos = position;
+        if alive >= tunables.max_trail_particles as usize {
+            continue;
+        }
+        alive += 1;
+        let material = standard_materials.add(StandardMaterial {
+            base_color: emitter.color,
+            emissive: emitter.color.into(),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        });
+        commands.spawn((
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::from_translation(position),
+            TrailParticle {
+                lifetime: Timer::from_seconds(TRAIL_PARTICLE_SECS, TimerMode::Once),
+                material,
+                color: emitter.color,
+            },
+        ));
+    }
+}
+
+/// Fades and shrinks trail particles, then despawns them with their material.
+pub fn trail_particle_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut particles: Query<(Entity, &mut TrailParticle, &mut Transform)>,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (entity, mut particle, mut transform) in particles.iter_mut() {
+        particle.lifetime.tick(time.delta());
+        let fade = 1.0 - particle.lifetime.fraction();
+        transform.scale = Vec3::splat(0.5 + 0.5 * fade);
+        if let Some(mat) = standard_materials.get_mut(&particle.material) {
+            mat.base_color = particle.color.with_alpha(fade * 0.8);
+        }
+        if particle.lifetime.just_finished() {
+            standard_materials.remove(particle.material.id());
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Spawns an invisible lightning projectile at the tower top; `projectile_system` resolves the
+/// hit and any chained arcs on its next update.
+#[allow(clippy::too_many_arguments)]
+fn spawn_lightning_bolt(
+    commands: &mut Commands,
+    tower: Entity,
+    tower_position: Vec3,
+    target_position: Vec3,
+    target_entity: Entity,
+    tunables: &Tunables,
+    damage: u32,
+    tower_height: f32,
+) {
+    let spawn_pos = Vec3::new(
+        tower_position.x,
+        tower_position.y + tower_height * 0.5,
+        tower_position.z,
+    );
+    commands.spawn((
+        Transform::from_translation(spawn_pos),
+        Projectile {
+            kind: ProjectileKind::Lightning,
+            source: tower,
+            target: target_entity,
+            speed: 0.0,
+            damage,
+            last_known_target_pos: target_position,
+            lifetime: Timer::from_seconds(tunables.projectile_lifetime_secs, TimerMode::Once),
+            wind_factor: 0.0,
         },
     ));
 }
@@ -131,12 +444,16 @@ pub fn projectile_system(
     time: Res<Time>,
     mut commands: Commands,
     mut projectile_query: Query<(Entity, &mut Projectile, &mut Transform), Without<Enemy>>,
-    enemy_pose_query: Query<&GlobalTransform, (With<Enemy>, Without<EnemyFadeOut>)>,
+    enemy_pose_query: Query<(Entity, &GlobalTransform), (With<Enemy>, Without<EnemyFadeOut>)>,
     mut enemy_hit_query: Query<
         (
             &mut Enemy,
             &MeshMaterial3d<StandardMaterial>,
             Option<&mut EnemyHitFlash>,
+            Option<&EnemyFrozenVisual>,
+            Option<&mut BossShield>,
+            Option<&EnemyKind>,
+            Has<ZombieResurrection>,
         ),
         With<Enemy>,
     >,
@@ -144,10 +461,32 @@ pub fn projectile_system(
     mut impact_materials: ResMut<Assets<ImpactMaterial>>,
     vfx_assets: Res<CombatVfxAssets>,
     tunables: Res<Tunables>,
+    wind: Res<WindState>,
+    seed: Res<WorldSeed>,
     mut damage_dealt_events: MessageWriter<DamageDealt>,
     mut enemy_killed_events: MessageWriter<EnemyKilled>,
 ) {
+    let dt = time.delta_secs();
+    let wind_drift = wind.velocity() * dt * tunables.wind_projectile_influence;
+
     for (entity, mut projectile, mut transform) in projectile_query.iter_mut() {
+        if projectile.kind == ProjectileKind::Lightning {
+            resolve_lightning_chain(
+                &mut commands,
+                &projectile,
+                transform.translation,
+                &enemy_pose_query,
+                &mut enemy_hit_query,
+                &mut standard_materials,
+                &vfx_assets,
+                &tunables,
+                &mut damage_dealt_events,
+                &mut enemy_killed_events,
+            );
+            cleanup_projectile(&mut commands, entity);
+            continue;
+        }
+
         projectile.lifetime.tick(time.delta());
         if projectile.lifetime.just_finished() {
             cleanup_projectile(&mut commands, entity);
@@ -155,7 +494,7 @@ pub fn projectile_system(
         }
 
         let (target_position, target_alive) = match enemy_pose_query.get(projectile.target) {
-            Ok(tf) => {
+            Ok((_, tf)) => {
                 let world_pos = tf.translation();
                 projectile.last_known_target_pos = world_pos;
                 (world_pos, true)
@@ -165,7 +504,7 @@ pub fn projectile_system(
 
         let to_target = target_position - transform.translation;
         let distance = to_target.length();
-        let step = projectile.speed * time.delta_secs();
+        let step = projectile.speed * dt;
 
         if distance <= tunables.projectile_hit_radius || distance <= step {
             let impact_point = if target_alive {
@@ -174,12 +513,37 @@ pub fn projectile_system(
                 projectile.last_known_target_pos
             } + Vec3::new(0.0, 0.2, 0.0);
 
-            if target_alive {
+            if let Some(splash_radius) = projectile.kind.splash_radius(&tunables) {
+                // Splash: everything near the impact point takes full damage
+                let radius_sq = splash_radius * splash_radius;
+                for (enemy_entity, enemy_tf) in enemy_pose_query.iter() {
+                    let pos = enemy_tf.translation();
+                    if pos.distance_squared(impact_point) > radius_sq {
+                        continue;
+                    }
+                    handle_projectile_hit(
+                        &mut commands,
+                        enemy_entity,
+                        projectile.damage,
+                        pos,
+                        Some(projectile.source),
+                        &mut enemy_hit_query,
+                        &mut standard_materials,
+                        &tunables,
+                        &mut enemy_killed_events,
+                    );
+                    damage_dealt_events.write(DamageDealt {
+                        amount: projectile.damage,
+                        position: pos + Vec3::new(0.0, tunables.damage_number_spawn_height, 0.0),
+                    });
+                }
+            } else if target_alive {
                 handle_projectile_hit(
                     &mut commands,
                     projectile.target,
                     projectile.damage,
                     impact_point,
+                    Some(projectile.source),
                     &mut enemy_hit_query,
                     &mut standard_materials,
                     &tunables,
@@ -190,6 +554,24 @@ pub fn projectile_system(
                     position: impact_point
                         + Vec3::new(0.0, tunables.damage_number_spawn_height, 0.0),
                 });
+                if projectile.kind == ProjectileKind::Arrow
+                    && StdRng::seed_from_u64(derive_seed(
+                        seed.0,
+                        entity.to_bits(),
+                        ARROW_SLOW_ROLL_SALT,
+                    ))
+                    .random::<f32>()
+                        < tunables.arrow_slow_chance
+                {
+                    // A fresh slow replaces a running one, restarting its timer
+                    commands.entity(projectile.target).try_insert(SlowEffect {
+                        multiplier: tunables.arrow_slow_multiplier,
+                        timer: Some(Timer::from_seconds(
+                            tunables.arrow_slow_secs,
+                            TimerMode::Once,
+                        )),
+                    });
+                }
             }
 
             spawn_impact_flash(
@@ -197,6 +579,7 @@ pub fn projectile_system(
                 &vfx_assets,
                 &mut impact_materials,
                 impact_point,
+                IMPACT_FLASH_COLOR,
                 &tunables,
             );
 
@@ -208,7 +591,7 @@ pub fn projectile_system(
 
         if distance > f32::EPSILON {
             let direction = to_target / distance;
-            transform.translation += direction * step;
+            transform.translation += direction * step + wind_drift * projectile.wind_factor;
             transform.rotation = Quat::from_rotation_arc(Vec3::Y, direction);
         }
 
@@ -220,17 +603,244 @@ pub fn projectile_system(
 
 // trailing removed
 
-#[allow(clippy::type_complexity)]
-fn handle_projectile_hit(
+/// Deals burn damage every time an enemy's `tick_timer` finishes, carrying fractions of a point
+/// over to the next tick, and removes the burn once its `timer` ends.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn burn_tick_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut burning: Query<
+        (Entity, &GlobalTransform, &mut BurnEffect),
+        (With<Enemy>, Without<EnemyFadeOut>),
+    >,
+    mut enemy_hit_query: Query<
+        (
+            &mut Enemy,
+            &MeshMaterial3d<StandardMaterial>,
+            Option<&mut EnemyHitFlash>,
+            Option<&EnemyFrozenVisual>,
+            Option<&mut BossShield>,
+            Option<&EnemyKind>,
+            Has<ZombieResurrection>,
+        ),
+        With<Enemy>,
+    >,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+    tunables: Res<Tunables>,
+    mut damage_dealt_events: MessageWriter<DamageDealt>,
+    mut enemy_killed_events: MessageWriter<EnemyKilled>,
+) {
+    for (entity, tf, mut burn) in burning.iter_mut() {
+        burn.timer.tick(time.delta());
+        burn.tick_timer.tick(time.delta());
+        let ticks = burn.tick_timer.times_finished_this_tick();
+        if ticks > 0 {
+            let total =
+                burn.carry + burn.dps * burn.tick_timer.duration().as_secs_f32() * ticks as f32;
+            let damage = total.floor();
+            burn.carry = total - damage;
+            if damage >= 1.0 {
+                let position = tf.translation();
+                handle_projectile_hit(
+                    &mut commands,
+                    entity,
+                    damage as u32,
+                    position,
+                    None,
+                    &mut enemy_hit_query,
+                    &mut standard_materials,
+                    &tunables,
+                    &mut enemy_killed_events,
+                );
+                damage_dealt_events.write(DamageDealt {
+                    amount: damage as u32,
+                    position: position + Vec3::new(0.0, tunables.damage_number_spawn_height, 0.0),
+                });
+            }
+        }
+        if burn.timer.is_finished() {
+            commands.entity(entity).try_remove::<BurnEffect>();
+        }
+    }
+}
+
+/// Counts down slows and stuns and removes them when they run out.
+pub fn status_effect_timers_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut slowed: Query<(Entity, &mut SlowEffect)>,
+    mut stunned: Query<(Entity, &mut StunEffect)>,
+) {
+    for (entity, mut slow) in slowed.iter_mut() {
+        let Some(timer) = slow.timer.as_mut() else {
+            continue;
+        };
+        if timer.tick(time.delta()).is_finished() {
+            commands.entity(entity).try_remove::<SlowEffect>();
+        }
+    }
+    for (entity, mut stun) in stunned.iter_mut() {
+        if stun.timer.tick(time.delta()).is_finished() {
+            commands.entity(entity).try_remove::<StunEffect>();
+        }
+    }
+}
+
+/// Applies a lightning bolt's damage to its target, then arcs to up to
+/// `lightning_chain_count` further enemies, each hit losing `lightning_chain_damage_falloff`.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn resolve_lightning_chain(
+    commands: &mut Commands,
+    projectile: &Projectile,
+    origin: Vec3,
+    enemy_pose_query: &Query<(Entity, &GlobalTransform), (With<Enemy>, Without<EnemyFadeOut>)>,
+    enemy_hit_query: &mut Query<
+        (
+            &mut Enemy,
+            &MeshMaterial3d<StandardMaterial>,
+            Option<&mut EnemyHitFlash>,
+            Option<&EnemyFrozenVisual>,
+            Option<&mut BossShield>,
+            Option<&EnemyKind>,
+            Has<ZombieResurrection>,
+        ),
+        With<Enemy>,
+    >,
+    standard_materials: &mut Assets<StandardMaterial>,
+    vfx_assets: &CombatVfxAssets,
+    tunables: &Tunables,
+    damage_dealt_events: &mut MessageWriter<DamageDealt>,
+    enemy_killed_events: &mut MessageWriter<EnemyKilled>,
+) {
+    let Ok((_, first_tf)) = enemy_pose_query.get(projectile.target) else {
+        return;
+    };
+
+    let mut already_hit: Vec<Entity> = vec![projectile.target];
+    let mut points: Vec<Vec3> = vec![origin, first_tf.translation()];
+    let mut damage = projectile.damage as f32;
+    let retained = (1.0 - tunables.lightning_chain_damage_falloff).clamp(0.0, 1.0);
+    let max_hits = 1 + tunables.lightning_chain_count as usize;
+    let radius_sq = tunables.lightning_chain_radius * tunables.lightning_chain_radius;
+
+    loop {
+        let target = already_hit[already_hit.len() - 1];
+        let hit_pos = points[points.len() - 1];
+        let hit_damage = (damage.round() as u32).max(1);
+        handle_projectile_hit(
+            commands,
+            target,
+            hit_damage,
+            hit_pos,
+            Some(projectile.source),
+            enemy_hit_query,
+            standard_materials,
+            tunables,
+            enemy_killed_events,
+        );
+        damage_dealt_events.write(DamageDealt {
+            amount: hit_damage,
+            position: hit_pos + Vec3::new(0.0, tunables.damage_number_spawn_height, 0.0),
+        });
+
+        if already_hit.len() >= max_hits {
+            break;
+        }
+
+        // Arc to the nearest living enemy that hasn't been struck by this bolt yet
+        let mut next: Option<(Entity, Vec3, f32)> = None;
+        for (candidate, tf) in enemy_pose_query.iter() {
+            if already_hit.contains(&candidate) {
+                continue;
+            }
+            if enemy_hit_query
+                .get(candidate)
+                .is_ok_and(|(enemy, ..)| enemy.health == 0)
+            {
+                continue;
+            }
+            let pos = tf.translation();
+            let d = hit_pos.distance_squared(pos);
+            if d <= radius_sq && next.is_none_or(|(_, _, best)| d < best) {
+                next = Some((candidate, pos, d));
+            }
+        }
+        let Some((next_entity, next_pos, _)) = next else {
+            break;
+        };
+        already_hit.push(next_entity);
+        points.push(next_pos);
+        damage *= retained;
+    }
+
+    spawn_lightning_arc(commands, vfx_assets, standard_materials, &points, tunables);
+}
+
+/// Spawns thin glowing segments connecting consecutive `points`, grouped under one fading parent.
+fn spawn_lightning_arc(
+    commands: &mut Commands,
+    vfx_assets: &CombatVfxAssets,
+    standard_materials: &mut Assets<StandardMaterial>,
+    points: &[Vec3],
+    tunables: &Tunables,
+) {
+    let mesh = vfx_assets
+        .lightning_arc_mesh_handle()
+        .expect("CombatVfxAssets not initialized: lightning_arc_mesh");
+    let material = standard_materials.add(StandardMaterial {
+        base_color: Color::srgba(0.55, 0.95, 1.0, 1.0),
+        emissive: LinearRgba::rgb(2.0, 7.0, 9.0),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        cull_mode: None,
+        ..default()
+    });
+
+    commands
+        .spawn((
+            Transform::default(),
+            Visibility::default(),
+            LightningArc {
+                timer: Timer::from_seconds(tunables.lightning_arc_fade_secs, TimerMode::Once),
+                material: material.clone(),
+            },
+        ))
+        .with_children(|parent| {
+            for pair in points.windows(2) {
+                let (from, to) = (pair[0], pair[1]);
+                let length = from.distance(to);
+                if length <= f32::EPSILON {
+                    continue;
+                }
+                parent.spawn((
+                    Mesh3d(mesh.clone()),
+                    MeshMaterial3d(material.clone()),
+                    Transform {
+                        translation: (from + to) * 0.5,
+                        rotation: Quat::from_rotation_arc(Vec3::Y, (to - from) / length),
+                        scale: Vec3::new(0.08, length, 0.08),
+                    },
+                ));
+            }
+        });
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(crate) fn handle_projectile_hit(
     commands: &mut Commands,
     enemy_entity: Entity,
     damage: u32,
     impact_point: Vec3,
+    source_tower: Option<Entity>,
     enemy_hit_query: &mut Query<
         (
             &mut Enemy,
             &MeshMaterial3d<StandardMaterial>,
             Option<&mut EnemyHitFlash>,
+            Option<&EnemyFrozenVisual>,
+            Option<&mut BossShield>,
+            Option<&EnemyKind>,
+            Has<ZombieResurrection>,
         ),
         With<Enemy>,
     >,
@@ -238,11 +848,22 @@ fn handle_projectile_hit(
     tunables: &Tunables,
     enemy_killed_events: &mut MessageWriter<EnemyKilled>,
 ) {
-    if let Ok((mut enemy, material_handle, flash_opt)) = enemy_hit_query.get_mut(enemy_entity) {
+    if let Ok((mut enemy, material_handle, flash_opt, frozen_opt, shield_opt, kind, risen)) =
+        enemy_hit_query.get_mut(enemy_entity)
+    {
+        // A Boss's shield soaks up damage first; boss_shield_break_system clears it once empty
+        let damage = match shield_opt {
+            Some(mut shield) => shield.absorb(damage),
+            None => damage,
+        };
         enemy.health = enemy.health.saturating_sub(damage);
         let remaining_health = enemy.health;
 
-        let mat_handle = material_handle.0.clone();
+        // Flash and fade the enemy's own material, never the shared frozen one
+        let mat_handle = frozen_opt.map_or_else(
+            || material_handle.0.clone(),
+            |frozen| frozen.original_material.clone(),
+        );
         let original_color = standard_materials
             .get(&mat_handle)
             .map(|mat| mat.base_color)
@@ -262,10 +883,15 @@ fn handle_projectile_hit(
                 death_position: impact_point,
             });
 
-            // Notify of kill now so other systems can react immediately
-            enemy_killed_events.write(EnemyKilled {
-                position: impact_point,
-            });
+            // Notify of kill now so other systems can react immediately. A Zombie that has not
+            // risen yet only falls here and is killed for good by a later hit.
+            let will_rise = kind == Some(&EnemyKind::Zombie) && !risen;
+            if !will_rise {
+                enemy_killed_events.write(EnemyKilled {
+                    position: impact_point,
+                    tower: source_tower,
+                });
+            }
         } else {
             if let Some(mut flash) = flash_opt {
                 flash
@@ -294,12 +920,13 @@ fn spawn_impact_flash(
     vfx_assets: &CombatVfxAssets,
     impact_materials: &mut Assets<ImpactMaterial>,
     impact_point: Vec3,
+    color: Color,
     tunables: &Tunables,
 ) {
     let mesh = vfx_assets
         .impact_mesh_handle()
         .expect("CombatVfxAssets not initialized: impact_mesh");
-    let material = impact_materials.add(ImpactMaterial::new(Color::srgba(1.0, 0.65, 0.3, 0.9)));
+    let material = impact_materials.add(ImpactMaterial::new(color));
     commands.spawn((
         Mesh3d(mesh),
         MeshMaterial3d(material.clone()),
@@ -402,6 +1029,13 @@ pub struct EnemyHitFlash {
     material: Handle<StandardMaterial>,
 }
 
+/// Slowed enemy wearing the shared frozen material; restored to `original_material` when the
+/// slow ends.
+#[derive(Component)]
+pub struct EnemyFrozenVisual {
+    pub original_material: Handle<StandardMaterial>,
+}
+
 // EnemyPreExplosion removed; replaced by EnemyFadeOut
 
 #[derive(Component)]
@@ -412,6 +1046,78 @@ pub struct EnemyFadeOut {
     death_position: Vec3,
 }
 
+/// A Zombie whose fade-out finished: it lies on the ground until `timer` fires, then rises with a
+/// third of its health. Kept with `risen` set afterwards so the second death is final.
+#[derive(Component)]
+pub struct ZombieResurrection {
+    pub timer: Timer,
+    pub risen: bool,
+}
+
+/// Poison left where a Boss died: every enemy within `radius` burns for `dps` until `duration`
+/// runs out. Each cloud owns its material so it can fade on its own.
+#[derive(Component)]
+pub struct PoisonCloud {
+    pub radius: f32,
+    pub dps: f32,
+    pub duration: Timer,
+    material: Handle<StandardMaterial>,
+}
+
+/// Fading visual left behind by a lightning bolt.
+#[derive(Component)]
+pub struct LightningArc {
+    timer: Timer,
+    material: Handle<StandardMaterial>,
+}
+
+pub fn lightning_arc_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut arcs: Query<(Entity, &mut LightningArc)>,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (entity, mut arc) in arcs.iter_mut() {
+        arc.timer.tick(time.delta());
+        let duration = arc.timer.duration().as_secs_f32().max(f32::EPSILON);
+        let progress = (arc.timer.elapsed().as_secs_f32() / duration).clamp(0.0, 1.0);
+
+        if let Some(mat) = standard_materials.get_mut(&arc.material) {
+            mat.base_color.set_alpha(1.0 - progress);
+        }
+
+        if arc.timer.just_finished() {
+            standard_materials.remove(arc.material.id());
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Removes depleted Boss shields and marks the break with a red flash around the Boss.
+pub fn boss_shield_break_system(
+    mut commands: Commands,
+    shields: Query<(Entity, &BossShield, &GlobalTransform), Changed<BossShield>>,
+    mut impact_materials: ResMut<Assets<ImpactMaterial>>,
+    vfx_assets: Res<CombatVfxAssets>,
+    tunables: Res<Tunables>,
+) {
+    for (entity, shield, tf) in shields.iter() {
+        if shield.absorb_remaining > 0 {
+            continue;
+        }
+        commands.entity(entity).try_remove::<BossShield>();
+        let position = tf.translation();
+        spawn_impact_flash(
+            &mut commands,
+            &vfx_assets,
+            &mut impact_materials,
+            Vec3::new(position.x, 0.0, position.z),
+            SHIELD_BREAK_FLASH_COLOR,
+            &tunables,
+        );
+    }
+}
+
 pub fn impact_effect_system(
     time: Res<Time>,
     mut commands: Commands,
@@ -461,10 +1167,10 @@ pub fn ephemeral_text_despawn_system(
 pub fn enemy_fade_out_system(
     time: Res<Time>,
     mut commands: Commands,
-    mut fading: Query<(Entity, &mut EnemyFadeOut)>,
+    mut fading: Query<(Entity, &mut EnemyFadeOut, Option<&ZombieResurrection>)>,
+    mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     children_query: Query<&Children>,
-    mut enemy_killed_events: MessageWriter<EnemyKilled>,
     enemy_kind_q: Query<&EnemyKind>,
     mut player_q: Query<&mut Player>,
     asset_server: Res<AssetServer>,
@@ -478,7 +1184,11 @@ pub fn enemy_fade_out_system(
     let Ok((camera, cam_tf)) = cam_q.single() else {
         return;
     };
-    for (entity, mut fade) in fading.iter_mut() {
+    for (entity, mut fade, resurrection) in fading.iter_mut() {
+        // Fallen Zombies are handled by zombie_resurrection_system until they rise
+        if resurrection.is_some_and(|r| !r.risen) {
+            continue;
+        }
         fade.timer.tick(time.delta());
         let duration = fade.timer.duration().as_secs_f32().max(f32::EPSILON);
         let progress = (fade.timer.elapsed().as_secs_f32() / duration).clamp(0.0, 1.0);
@@ -491,11 +1201,35 @@ pub fn enemy_fade_out_system(
         }
 
         if fade.timer.just_finished() {
+            // A Zombie's first death only knocks it down; rewards wait for the final one
+            let kind = enemy_kind_q.get(entity).ok().copied();
+            if kind == Some(EnemyKind::Zombie) && resurrection.is_none() {
+                if let Some(mat) = materials.get_mut(&fade.material) {
+                    mat.base_color.set_alpha(ZOMBIE_CORPSE_ALPHA);
+                }
+                commands.entity(entity).insert(ZombieResurrection {
+                    timer: Timer::from_seconds(ZOMBIE_RESURRECTION_SECS, TimerMode::Once),
+                    risen: false,
+                });
+                continue;
+            }
+
+            if kind == Some(EnemyKind::Boss) {
+                spawn_poison_cloud(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    fade.death_position,
+                    &tunables,
+                );
+            }
+
             // Credit currency based on enemy kind
-            let silver_award: u64 = match enemy_kind_q.get(entity).ok().copied() {
+            let silver_award: u64 = match kind {
                 Some(EnemyKind::Minion) => 1u64,
                 Some(EnemyKind::Zombie) => 2u64,
                 Some(EnemyKind::Boss) => 5u64,
+                Some(EnemyKind::Healer) => 3u64,
                 None => 1u64,
             };
 
@@ -596,14 +1330,159 @@ pub fn enemy_fade_out_system(
                 }
             }
 
-            enemy_killed_events.write(EnemyKilled {
-                position: fade.death_position,
-            });
             despawn_entity_recursive(&mut commands, entity, &children_query);
         }
     }
 }
 
+fn spawn_poison_cloud(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    position: Vec3,
+    tunables: &Tunables,
+) {
+    let material = materials.add(StandardMaterial {
+        base_color: POISON_CLOUD_COLOR,
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        cull_mode: None,
+        ..default()
+    });
+    commands.spawn((
+        PoisonCloud {
+            radius: tunables.boss_poison_cloud_radius,
+            dps: tunables.boss_poison_cloud_dps,
+            duration: Timer::from_seconds(tunables.boss_poison_cloud_secs, TimerMode::Once),
+            material: material.clone(),
+        },
+        Mesh3d(meshes.add(Circle::new(tunables.boss_poison_cloud_radius))),
+        MeshMaterial3d(material),
+        Transform {
+            translation: Vec3::new(position.x, 0.04, position.z),
+            rotation: Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2),
+            ..default()
+        },
+        Visibility::Visible,
+    ));
+}
+
+/// Pulses and fades Boss poison clouds and keeps every enemy inside one burning. An enemy in
+/// several clouds burns for their combined `dps`; it stops shortly after leaving them all.
+#[allow(clippy::type_complexity)]
+pub fn poison_cloud_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut clouds: Query<(Entity, &mut PoisonCloud, &mut Transform)>,
+    mut enemies: Query<
+        (Entity, &GlobalTransform, Option<&mut BurnEffect>),
+        (With<Enemy>, Without<EnemyFadeOut>),
+    >,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    if clouds.is_empty() {
+        return;
+    }
+
+    let mut active = Vec::new();
+    for (entity, mut cloud, mut transform) in clouds.iter_mut() {
+        cloud.duration.tick(time.delta());
+        if cloud.duration.is_finished() {
+            materials.remove(cloud.material.id());
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let pulse = 1.0 + 0.06 * (cloud.duration.elapsed_secs() * 4.0).sin();
+        transform.scale = Vec3::splat(pulse);
+        if let Some(mat) = materials.get_mut(&cloud.material) {
+            mat.base_color = POISON_CLOUD_COLOR
+                .with_alpha(POISON_CLOUD_COLOR.alpha() * cloud.duration.fraction_remaining());
+        }
+        active.push((
+            transform.translation,
+            cloud.radius * cloud.radius,
+            cloud.dps,
+        ));
+    }
+
+    for (entity, tf, burn) in enemies.iter_mut() {
+        let position = tf.translation();
+        let dps: f32 = active
+            .iter()
+            .filter(|(center, radius_sq, _)| {
+                (position - *center).with_y(0.0).length_squared() <= *radius_sq
+            })
+            .map(|(_, _, dps)| dps)
+            .sum();
+        if dps <= 0.0 {
+            continue;
+        }
+        match burn {
+            Some(mut burn) => {
+                burn.dps = dps;
+                burn.timer.reset();
+            }
+            None => {
+                commands.entity(entity).insert(BurnEffect {
+                    dps,
+                    timer: Timer::from_seconds(POISON_LINGER_SECS, TimerMode::Once),
+                    tick_timer: Timer::from_seconds(POISON_TICK_SECS, TimerMode::Repeating),
+                    carry: 0.0,
+                });
+            }
+        }
+    }
+}
+
+/// Keeps fallen Zombies on the ground and raises them when their timer fires: a third of their
+/// health back, full opacity, moving and targetable again, with a purple flash.
+pub fn zombie_resurrection_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut zombies: Query<(
+        Entity,
+        &mut ZombieResurrection,
+        &mut Enemy,
+        &mut Transform,
+        &EnemyFadeOut,
+    )>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut impact_materials: ResMut<Assets<ImpactMaterial>>,
+    vfx_assets: Res<CombatVfxAssets>,
+    tunables: Res<Tunables>,
+) {
+    for (entity, mut resurrection, mut enemy, mut transform, fade) in zombies.iter_mut() {
+        if resurrection.risen {
+            continue;
+        }
+        transform.translation.y = ZOMBIE_CORPSE_HEIGHT;
+        resurrection.timer.tick(time.delta());
+        if !resurrection.timer.just_finished() {
+            continue;
+        }
+        resurrection.risen = true;
+        if enemy.health > 0 {
+            continue;
+        }
+
+        enemy.health = (enemy.max_health / 3).max(1);
+        transform.translation.y = EnemyKind::Zombie.stats().size * 0.5;
+        if let Some(mat) = materials.get_mut(&fade.material) {
+            mat.base_color = fade.original_color.with_alpha(1.0);
+            mat.alpha_mode = AlphaMode::Opaque;
+        }
+        commands.entity(entity).try_remove::<EnemyFadeOut>();
+        spawn_impact_flash(
+            &mut commands,
+            &vfx_assets,
+            &mut impact_materials,
+            transform.translation.with_y(0.0),
+            ZOMBIE_RISE_FLASH_COLOR,
+            &tunables,
+        );
+    }
+}
+
 fn despawn_entity_recursive(
     commands: &mut Commands,
     root: Entity,
@@ -653,3 +1532,146 @@ pub fn enemy_flash_system(
         }
     }
 }
+
+/// Swaps slowed enemies onto the shared frozen material and back once the slow ends. Hit flashes
+/// and death fades animate the enemy's own material, so it is shown while those play.
+#[allow(clippy::type_complexity)]
+pub fn enemy_frozen_visual_system(
+    mut commands: Commands,
+    frozen_cache: Res<FrozenMaterialCache>,
+    mut enemies: Query<
+        (
+            Entity,
+            &mut MeshMaterial3d<StandardMaterial>,
+            Option<&EnemyFrozenVisual>,
+            Has<SlowEffect>,
+            Has<EnemyHitFlash>,
+            Has<EnemyFadeOut>,
+        ),
+        With<Enemy>,
+    >,
+) {
+    for (entity, mut material, frozen, slowed, flashing, fading) in enemies.iter_mut() {
+        let show_frozen = slowed && !flashing && !fading;
+        match (show_frozen, frozen) {
+            (true, None) => {
+                commands.entity(entity).insert(EnemyFrozenVisual {
+                    original_material: material.0.clone(),
+                });
+                material.0 = frozen_cache.material.clone();
+            }
+            (false, Some(frozen)) => {
+                material.0 = frozen.original_material.clone();
+                commands.entity(entity).remove::<EnemyFrozenVisual>();
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    const ENEMY_HEALTH: u32 = 100;
+
+    fn poison_world(clouds: &[Vec3]) -> World {
+        let mut world = World::new();
+        world.insert_resource(Tunables::default());
+        world.init_resource::<Time>();
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.init_resource::<Messages<DamageDealt>>();
+        world.init_resource::<Messages<EnemyKilled>>();
+        for position in clouds.iter().copied() {
+            world
+                .run_system_once(
+                    move |mut commands: Commands,
+                          mut meshes: ResMut<Assets<Mesh>>,
+                          mut materials: ResMut<Assets<StandardMaterial>>,
+                          tunables: Res<Tunables>| {
+                        spawn_poison_cloud(
+                            &mut commands,
+                            &mut meshes,
+                            &mut materials,
+                            position,
+                            &tunables,
+                        );
+                    },
+                )
+                .unwrap();
+        }
+        world
+    }
+
+    fn spawn_enemy_at(world: &mut World, position: Vec3) -> Entity {
+        world
+            .spawn((
+                Enemy {
+                    health: ENEMY_HEALTH,
+                    max_health: ENEMY_HEALTH,
+                    speed: 0.0,
+                    damage: 1,
+                },
+                MeshMaterial3d::<StandardMaterial>::default(),
+                GlobalTransform::from_translation(position),
+            ))
+            .id()
+    }
+
+    /// Runs the cloud and burn systems for `steps` frames of `secs` each.
+    fn run_poison(world: &mut World, steps: u32, secs: f32) {
+        for _ in 0..steps {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(secs));
+            world.run_system_once(poison_cloud_system).unwrap();
+            world.run_system_once(burn_tick_system).unwrap();
+        }
+    }
+
+    fn health(world: &World, enemy: Entity) -> u32 {
+        world.get::<Enemy>(enemy).unwrap().health
+    }
+
+    #[test]
+    fn enemies_inside_a_poison_cloud_take_damage_ticks() {
+        let tunables = Tunables::default();
+        let mut world = poison_world(&[Vec3::ZERO]);
+        let inside = spawn_enemy_at(&mut world, Vec3::new(2.0, 1.0, -1.0));
+        let outside = spawn_enemy_at(
+            &mut world,
+            Vec3::new(tunables.boss_poison_cloud_radius + 2.0, 1.0, 0.0),
+        );
+
+        // Two ticks of POISON_TICK_SECS
+        run_poison(&mut world, 4, 0.25);
+        let per_tick = (tunables.boss_poison_cloud_dps * POISON_TICK_SECS).floor() as u32;
+        assert!(per_tick > 0);
+        assert_eq!(health(&world, inside), ENEMY_HEALTH - 2 * per_tick);
+        assert_eq!(health(&world, outside), ENEMY_HEALTH);
+        assert_eq!(world.resource::<Messages<DamageDealt>>().len(), 2);
+    }
+
+    #[test]
+    fn overlapping_clouds_stack() {
+        let tunables = Tunables::default();
+        let mut world = poison_world(&[Vec3::ZERO, Vec3::new(3.0, 0.0, 0.0)]);
+        let both = spawn_enemy_at(&mut world, Vec3::new(1.5, 1.0, 0.0));
+
+        run_poison(&mut world, 4, 0.25);
+        let damage = (tunables.boss_poison_cloud_dps * 2.0 * POISON_TICK_SECS * 2.0) as u32;
+        assert_eq!(health(&world, both), ENEMY_HEALTH - damage);
+    }
+
+    #[test]
+    fn clouds_despawn_when_they_run_out() {
+        let tunables = Tunables::default();
+        let mut world = poison_world(&[Vec3::ZERO]);
+        run_poison(&mut world, 2, tunables.boss_poison_cloud_secs * 0.5 + 0.01);
+        let clouds = world.query::<&PoisonCloud>().iter(&world).count();
+        assert_eq!(clouds, 0);
+    }
+}
@@ -1,17 +1,26 @@
+use super::projectiles::EnemyFadeOut;
 use crate::audio::{BuildingActionEvent, BuildingActionKind};
+use crate::build::placement::{
+    PlacedTowersQuery, PlacementError, find_overlapping_tower, overlaps_static_obstacle,
+    placement_error, tower_limit_reached,
+};
 use crate::components::{
-    BuildingMode, BuiltTower, HasTowerDamageLabel, Player, SellingMode, Tower, TowerBuildSelection,
-    TowerDamageLabel, TowerGhost, TowerKind, TowerUpgradeConfig, TowerUpgrades, UpgradeableStat,
+    BuildingMode, BuiltTower, ComboBonus, Enemy, EnemyKind, FrustumCulledTower,
+    HasTowerDamageLabel, PauseReason, Player, RoadPaths, SellingMode, StaticObstacleAABBs,
+    TargetingMode, Tower, TowerBuildSelection, TowerComboLabel, TowerDamageLabel, TowerGhost,
+    TowerKillCount, TowerKind, TowerPriorityKind, TowerUpgradeConfig, TowerUpgrades,
+    TownSquareCenter, UpgradeableStat, WaveTimerPaused,
 };
 use crate::constants::Tunables;
-use crate::events::TowerBuilt;
+use crate::events::{ConvertTower, EnemyKilled, PlacementRejected, TowerBuilt};
+use crate::utils::camera::{in_view_frustum, is_on_screen_ndc, world_to_viewport_logical};
 use bevy::asset::RenderAssetUsages;
 use bevy::input::mouse::MouseButton;
 use bevy::math::primitives::Cuboid;
 use bevy::pbr::MeshMaterial3d;
 use bevy::prelude::*;
 use bevy::render::render_resource::PrimitiveTopology;
-use std::f32::consts::TAU;
+use std::f32::consts::{FRAC_PI_2, TAU};
 
 /// Places a tower at the cursor when in building mode and within range.
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
@@ -22,7 +31,7 @@ pub fn tower_building(
     camera_query: Query<(&Camera, &GlobalTransform)>,
     mut transforms: ParamSet<(
         Query<&Transform, (With<Player>, Without<Tower>)>,
-        Query<&mut Transform, With<TowerGhost>>,
+        Query<(&mut Transform, &mut Visibility), With<TowerGhost>>,
     )>,
     mut player_res_query: Query<&mut Player, With<Player>>,
     building_mode_query: Query<&BuildingMode>,
@@ -33,13 +42,29 @@ pub fn tower_building(
     mut selection: ResMut<TowerBuildSelection>,
     tunables: Res<Tunables>,
     mut building_sfx: MessageWriter<BuildingActionEvent>,
-    upgrades: Res<TowerUpgrades>,
-    upgrade_config: Res<TowerUpgradeConfig>,
+    (upgrades, upgrade_config, roads, town_square, mut placement_rejected, built_towers): (
+        Res<TowerUpgrades>,
+        Res<TowerUpgradeConfig>,
+        Option<Res<RoadPaths>>,
+        Option<Res<TownSquareCenter>>,
+        MessageWriter<PlacementRejected>,
+        Query<&BuiltTower>,
+    ),
+    (time, asset_server, mut confirm, mut hint_label_q, placed_towers, enemies, obstacles): (
+        Res<Time>,
+        Res<AssetServer>,
+        Local<Option<ConfirmPlacementTimer>>,
+        Query<&mut Node, Or<(With<ConfirmPlacementLabel>, With<OverlapWarningLabel>)>>,
+        PlacedTowersQuery,
+        Query<&GlobalTransform, (With<Enemy>, Without<EnemyFadeOut>)>,
+        Res<StaticObstacleAABBs>,
+    ),
 ) {
     let building_mode_active = building_mode_query.iter().any(|mode| mode.is_active);
 
     if !building_mode_active {
         clear_ghost(&mut commands, &mut meshes, &mut materials, &mut ghost_state);
+        cancel_confirmation(&mut commands, &mut confirm);
         return;
     }
 
@@ -47,6 +72,7 @@ pub fn tower_building(
     let preview_kind = selection.choice;
     if preview_kind.is_none() {
         clear_ghost(&mut commands, &mut meshes, &mut materials, &mut ghost_state);
+        cancel_confirmation(&mut commands, &mut confirm);
         return;
     }
 
@@ -59,16 +85,19 @@ pub fn tower_building(
 
     let Some(cursor_position) = window.cursor_position() else {
         clear_ghost(&mut commands, &mut meshes, &mut materials, &mut ghost_state);
+        cancel_confirmation(&mut commands, &mut confirm);
         return;
     };
     let Some(world_point) = cursor_to_ground(camera, camera_transform, cursor_position, 0.0) else {
         clear_ghost(&mut commands, &mut meshes, &mut materials, &mut ghost_state);
+        cancel_confirmation(&mut commands, &mut confirm);
         return;
     };
 
     let player_query = transforms.p0();
     let Some(player_transform) = player_query.iter().next() else {
         clear_ghost(&mut commands, &mut meshes, &mut materials, &mut ghost_state);
+        cancel_confirmation(&mut commands, &mut confirm);
         return;
     };
 
@@ -85,15 +114,29 @@ pub fn tower_building(
     if distance_sq > max_build_distance_sq && distance_sq > 0.0 {
         offset = offset.normalize() * tunables.max_build_distance;
     }
-    let placement_pos = player_pos + offset;
+    let mut placement_pos = player_pos + offset;
+    if tunables.placement_grid_snap {
+        placement_pos.x = snap_to_grid(placement_pos.x, tunables.grid_snap_size);
+        placement_pos.z = snap_to_grid(placement_pos.z, tunables.grid_snap_size);
+    }
 
     // Determine preview size from selected kind
-    let preview_size: (f32, f32, f32) = match preview_kind.unwrap_or(TowerKind::Bow) {
-        // Bow: smaller (absolute size)
-        TowerKind::Bow => (1.02, 2.72, 1.02),
-        // Crossbow: bigger (absolute size)
-        TowerKind::Crossbow => (1.38, 3.68, 1.38),
-    };
+    let preview_size = preview_kind.unwrap_or(TowerKind::Bow).size();
+
+    // Preview the range the tower will actually have once its kind's upgrades apply
+    let range_kind = preview_kind.unwrap_or(TowerKind::Bow);
+    let effective_range = tunables.tower_range
+        + upgrade_config.calculate_bonus(
+            range_kind,
+            UpgradeableStat::Range,
+            upgrades.get_level(range_kind),
+        );
+    if ghost_state
+        .as_ref()
+        .is_some_and(|ghost| ghost.range != effective_range)
+    {
+        clear_ghost(&mut commands, &mut meshes, &mut materials, &mut ghost_state);
+    }
 
     // Spawn or update ghost preview
     let state = ghost_state.get_or_insert_with(|| {
@@ -103,28 +146,186 @@ pub fn tower_building(
             &mut materials,
             &tunables,
             preview_size,
+            effective_range,
         )
     });
 
     let mut ghost_query = transforms.p1();
-    if let Ok(mut transform) = ghost_query.get_mut(state.root) {
+    if let Ok((mut transform, _)) = ghost_query.get_mut(state.root) {
         transform.translation = placement_pos;
     }
 
+    // Catapult and Cannon: preview the splash around the nearest enemy in range, else the cursor
+    let range_sq = effective_range * effective_range;
+    let xz_distance_sq = |p: Vec3| (p - placement_pos).with_y(0.0).length_squared();
+    let impact_point = enemies
+        .iter()
+        .map(|tf| tf.translation())
+        .filter(|p| xz_distance_sq(*p) <= range_sq)
+        .min_by(|a, b| xz_distance_sq(*a).total_cmp(&xz_distance_sq(*b)))
+        .unwrap_or(world_point);
+    if let Ok((mut transform, mut visibility)) = ghost_query.get_mut(state.splash_ring) {
+        transform.translation = Vec3::new(impact_point.x, 0.06, impact_point.z);
+        // The ring mesh is built at the Catapult's radius; scale it for the Cannon
+        let splash_radius = preview_kind.and_then(|kind| kind.splash_radius(&tunables));
+        if let Some(radius) = splash_radius {
+            transform.scale = Vec3::splat(radius / tunables.catapult_splash_radius);
+        }
+        visibility.set_if_neq(if splash_radius.is_some() {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        });
+    }
+
     // Check affordability per selected tower kind (centralized costs)
     let mut affordable = false;
+    let mut expensive = false;
     let (wood_cost, rock_cost) = preview_kind.unwrap_or(TowerKind::Bow).cost();
     if let Ok(player) = player_res_query.single_mut() {
         affordable = player.wood >= wood_cost && player.rock >= rock_cost;
+        // Spending more than half of the stockpile needs a second click to confirm
+        expensive = (wood_cost + rock_cost) as f32 > 0.5 * (player.wood + player.rock) as f32;
+    }
+
+    let overlapping = find_overlapping_tower(
+        placement_pos,
+        preview_kind.unwrap_or(TowerKind::Bow),
+        &placed_towers,
+    )
+    .is_some();
+    // Walls, the village and the town square are solid
+    let obstructed = overlaps_static_obstacle(
+        placement_pos,
+        preview_kind.unwrap_or(TowerKind::Bow),
+        &obstacles,
+    );
+
+    // Roads and the town square stay clear, and some kinds are capped in number
+    let selected_kind = preview_kind.unwrap_or(TowerKind::Bow);
+    let built_of_kind = built_towers
+        .iter()
+        .filter(|built| built.kind == selected_kind)
+        .count();
+    let blocked_by = if tower_limit_reached(selected_kind, built_of_kind, &tunables) {
+        Some(PlacementError::TowerLimitReached)
+    } else {
+        placement_error(
+            placement_pos,
+            roads.as_deref(),
+            town_square.as_deref().map(|center| center.0),
+            &tunables,
+        )
+    };
+    let is_valid_placement = blocked_by.is_none();
+
+    // Drop a pending confirmation once it expires, the cursor wanders off, or it becomes invalid
+    if let Some(pending) = confirm.as_mut() {
+        pending.timer.tick(time.delta());
+        let moved = pending.anchor.distance(placement_pos) > CONFIRM_CANCEL_DISTANCE;
+        if pending.timer.is_finished()
+            || moved
+            || !in_range
+            || !affordable
+            || overlapping
+            || obstructed
+            || !is_valid_placement
+        {
+            cancel_confirmation(&mut commands, &mut confirm);
+        }
+    }
+
+    // Warn about overlapping an existing tower with a label next to the ghost
+    match (overlapping, state.overlap_label) {
+        (true, None) => {
+            state.overlap_label = Some(
+                commands
+                    .spawn((
+                        OverlapWarningLabel,
+                        Text::new("Overlapping existing tower!"),
+                        TextFont {
+                            font: asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(1.0, 0.35, 0.3)),
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(cursor_position.x - 110.0),
+                            top: Val::Px(cursor_position.y - 40.0),
+                            ..default()
+                        },
+                    ))
+                    .id(),
+            );
+        }
+        (false, Some(label)) => {
+            commands.entity(label).try_despawn();
+            state.overlap_label = None;
+        }
+        _ => {}
+    }
+
+    let hint_label = confirm
+        .as_ref()
+        .map(|pending| pending.label)
+        .or(state.overlap_label);
+    if let Some(label) = hint_label {
+        let label_pos = placement_pos + Vec3::Y * (preview_size.1 + 1.5);
+        if let Ok(viewport) = camera.world_to_viewport(camera_transform, label_pos)
+            && let Ok(mut node) = hint_label_q.get_mut(label)
+        {
+            node.left = Val::Px(viewport.x - 110.0);
+            node.top = Val::Px(viewport.y);
+        }
     }
 
-    update_ghost_visuals(state, in_range && affordable, &mut materials);
+    if confirm.is_some() {
+        let pulse = 0.5 + 0.5 * (time.elapsed_secs() * 8.0).sin();
+        update_ghost_confirm_visuals(state, pulse, &mut materials);
+    } else {
+        update_ghost_visuals(
+            state,
+            in_range && affordable && !overlapping && !obstructed && is_valid_placement,
+            &mut materials,
+        );
+    }
 
-    if in_range
+    let place_clicked = in_range
         && affordable
+        && !overlapping
+        && !obstructed
+        && is_valid_placement
         && mouse_input.just_pressed(MouseButton::Left)
-        && selection.choice.is_some()
-    {
+        && selection.choice.is_some();
+
+    if place_clicked && expensive && confirm.is_none() {
+        // First click on an expensive tower only arms the confirmation
+        let label = commands
+            .spawn((
+                ConfirmPlacementLabel,
+                Text::new("Click again to confirm placement"),
+                TextFont {
+                    font: asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.84, 0.3)),
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(cursor_position.x - 110.0),
+                    top: Val::Px(cursor_position.y - 40.0),
+                    ..default()
+                },
+            ))
+            .id();
+        *confirm = Some(ConfirmPlacementTimer {
+            timer: Timer::from_seconds(CONFIRM_WINDOW_SECS, TimerMode::Once),
+            anchor: placement_pos,
+            label,
+        });
+    } else if place_clicked {
+        cancel_confirmation(&mut commands, &mut confirm);
         let kind = selection.choice.unwrap_or(TowerKind::Bow);
         let (wood_cost, rock_cost) = kind.cost();
         if let Ok(mut player) = player_res_query.single_mut() {
@@ -132,55 +333,16 @@ pub fn tower_building(
             player.wood = player.wood.saturating_sub(wood_cost);
             player.rock = player.rock.saturating_sub(rock_cost);
         }
-        // Determine tower stats from selected kind
-        let (base_damage, base_fire_interval, base_projectile_speed, size, color) = match kind {
-            // Bow: smaller and blue (absolute size); slower projectiles
-            TowerKind::Bow => (
-                12,
-                0.7,
-                60.0,
-                (1.02, 2.72, 1.02),
-                Color::srgb(0.35, 0.45, 0.95),
-            ),
-            // Crossbow: bigger and purple (absolute size); much faster projectiles
-            TowerKind::Crossbow => (
-                35,
-                2.4,
-                140.0,
-                (1.38, 3.68, 1.38),
-                Color::srgb(0.62, 0.36, 0.86),
-            ),
-        };
-
-        // Apply upgrades using declarative config system
-        let level = upgrades.get_level(kind);
-        let damage_bonus =
-            upgrade_config.calculate_bonus(kind, UpgradeableStat::Damage, level) as u32;
-        let range_bonus = upgrade_config.calculate_bonus(kind, UpgradeableStat::Range, level);
-        let fire_speed_bonus =
-            upgrade_config.calculate_bonus(kind, UpgradeableStat::FireSpeed, level);
-        let projectile_speed_bonus =
-            upgrade_config.calculate_bonus(kind, UpgradeableStat::ProjectileSpeed, level);
-
-        let damage = base_damage + damage_bonus;
-        let fire_interval_secs = (base_fire_interval - fire_speed_bonus).max(0.1);
-        let projectile_speed = base_projectile_speed + projectile_speed_bonus;
-        let range = tunables.tower_range + range_bonus;
-
-        place_tower(
+        build_tower(
             &mut commands,
             &mut meshes,
             &mut materials,
-            placement_pos,
             &mut tower_events,
-            damage,
-            fire_interval_secs,
-            projectile_speed,
-            range,
-            size,
-            color,
             &tunables,
+            &upgrades,
+            &upgrade_config,
             kind,
+            placement_pos,
         );
 
         // Emit building place SFX event
@@ -193,11 +355,57 @@ pub fn tower_building(
         selection.choice = None;
         clear_ghost(&mut commands, &mut meshes, &mut materials, &mut ghost_state);
     } else if mouse_input.just_pressed(MouseButton::Left) && selection.choice.is_some() {
-        // Invalid placement attempt: out of range or not affordable
+        // Invalid placement attempt: out of range, not affordable or blocked
         building_sfx.write(BuildingActionEvent {
             kind: BuildingActionKind::Invalid,
             position: placement_pos,
         });
+        // Challenge mode charges part of the tower's cost for the miss; a full tower limit is
+        // no misplacement, since no spot would have worked
+        if blocked_by != Some(PlacementError::TowerLimitReached) {
+            let kind = selection.choice.unwrap_or(TowerKind::Bow);
+            let (wood_penalty, rock_penalty) =
+                kind.misplace_penalty(tunables.challenge_misplace_penalty_pct);
+            if let Ok(mut player) = player_res_query.single_mut() {
+                player.wood = player.wood.saturating_sub(wood_penalty);
+                player.rock = player.rock.saturating_sub(rock_penalty);
+            }
+        }
+        if let Some(reason) = blocked_by {
+            placement_rejected.write(PlacementRejected {
+                reason,
+                position: placement_pos,
+            });
+        }
+    }
+}
+
+/// Seconds the player has to click again to confirm an expensive placement.
+const CONFIRM_WINDOW_SECS: f32 = 2.0;
+/// Moving the placement point further than this (world units) cancels a pending confirmation.
+const CONFIRM_CANCEL_DISTANCE: f32 = 5.0;
+
+/// Pending confirmation for an expensive tower placement.
+pub struct ConfirmPlacementTimer {
+    timer: Timer,
+    anchor: Vec3,
+    label: Entity,
+}
+
+/// Inner/outer radius ratio of the Catapult splash preview ring (close to 1 for a thin ring).
+const SPLASH_RING_INNER_RATIO: f32 = 0.92;
+
+/// Marker for the "Overlapping existing tower!" warning near the ghost.
+#[derive(Component)]
+pub struct OverlapWarningLabel;
+
+/// Marker for the "Click again to confirm placement" hint near the ghost.
+#[derive(Component)]
+pub struct ConfirmPlacementLabel;
+
+fn cancel_confirmation(commands: &mut Commands, confirm: &mut Option<ConfirmPlacementTimer>) {
+    if let Some(pending) = confirm.take() {
+        commands.entity(pending.label).try_despawn();
     }
 }
 
@@ -208,9 +416,23 @@ pub struct TowerGhostData {
     tower_material: Handle<StandardMaterial>,
     ring_material: Handle<StandardMaterial>,
     ring_mesh: Handle<Mesh>,
+    /// Radius the range ring was built with.
+    range: f32,
+    overlap_label: Option<Entity>,
+    splash_ring: Entity,
+    splash_material: Handle<StandardMaterial>,
+    splash_mesh: Handle<Mesh>,
+}
+
+/// Rounds `v` to the nearest multiple of `size`; `v` is returned as is for a non-positive size.
+pub fn snap_to_grid(v: f32, size: f32) -> f32 {
+    if size <= 0.0 {
+        return v;
+    }
+    (v / size).round() * size
 }
 
-fn cursor_to_ground(
+pub fn cursor_to_ground(
     camera: &Camera,
     camera_transform: &GlobalTransform,
     cursor_position: Vec2,
@@ -238,13 +460,10 @@ fn spawn_tower_ghost(
     materials: &mut ResMut<Assets<StandardMaterial>>,
     tunables: &Tunables,
     size: (f32, f32, f32),
+    range: f32,
 ) -> TowerGhostData {
     let tower_mesh = meshes.add(Cuboid::new(size.0, size.1, size.2));
-    let range_mesh = meshes.add(build_ring_mesh(
-        tunables.tower_range,
-        tunables.ring_inner_ratio,
-        96,
-    ));
+    let range_mesh = meshes.add(build_ring_mesh(range, tunables.ring_inner_ratio, 96));
 
     let tower_material = materials.add(StandardMaterial {
         base_color: Color::srgba(0.35, 0.35, 0.35, 0.4),
@@ -270,6 +489,31 @@ fn spawn_tower_ghost(
         ))
         .id();
 
+    // Thin orange ring previewing a Catapult boulder's splash at its predicted impact point
+    let splash_mesh = meshes.add(build_ring_mesh(
+        tunables.catapult_splash_radius,
+        SPLASH_RING_INNER_RATIO,
+        64,
+    ));
+    let splash_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(1.0, 0.55, 0.1, 0.6),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        cull_mode: None,
+        ..default()
+    });
+    let splash_ring = commands
+        .spawn((
+            TowerGhost,
+            Mesh3d(splash_mesh.clone()),
+            MeshMaterial3d(splash_material.clone()),
+            Transform::default(),
+            GlobalTransform::default(),
+            Visibility::Hidden,
+            InheritedVisibility::default(),
+        ))
+        .id();
+
     let mut tower_child = None;
     let mut range_child = None;
 
@@ -307,10 +551,27 @@ fn spawn_tower_ghost(
         tower_material,
         ring_material,
         ring_mesh: range_mesh,
+        range,
+        overlap_label: None,
+        splash_ring,
+        splash_material,
+        splash_mesh,
     }
 }
 
-fn build_ring_mesh(outer_radius: f32, inner_ratio: f32, segments: usize) -> Mesh {
+pub(crate) fn build_ring_mesh(outer_radius: f32, inner_ratio: f32, segments: usize) -> Mesh {
+    build_ring_sector_mesh(outer_radius, inner_ratio, segments, 0.0, TAU)
+}
+
+/// Flat ring sector on the XZ plane from `start_angle` to `end_angle` (radians, measured from +X
+/// toward +Z). A full `0..TAU` sweep gives a closed ring.
+fn build_ring_sector_mesh(
+    outer_radius: f32,
+    inner_ratio: f32,
+    segments: usize,
+    start_angle: f32,
+    end_angle: f32,
+) -> Mesh {
     let inner_radius = outer_radius * inner_ratio.clamp(0.0, 0.999);
 
     let mut positions = Vec::with_capacity(segments * 6);
@@ -318,8 +579,9 @@ fn build_ring_mesh(outer_radius: f32, inner_ratio: f32, segments: usize) -> Mesh
     let mut uvs = Vec::with_capacity(segments * 6);
 
     for i in 0..segments {
-        let angle = (i as f32 / segments as f32) * TAU;
-        let next_angle = ((i + 1) as f32 / segments as f32) * TAU;
+        let sweep = end_angle - start_angle;
+        let angle = start_angle + (i as f32 / segments as f32) * sweep;
+        let next_angle = start_angle + ((i + 1) as f32 / segments as f32) * sweep;
 
         let cos_a = angle.cos();
         let sin_a = angle.sin();
@@ -373,6 +635,21 @@ fn build_ring_mesh(outer_radius: f32, inner_ratio: f32, segments: usize) -> Mesh
     mesh
 }
 
+/// Gold pulsing tint shown while an expensive placement awaits confirmation.
+fn update_ghost_confirm_visuals(
+    data: &TowerGhostData,
+    pulse: f32,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+) {
+    let alpha = 0.35 + 0.3 * pulse;
+    if let Some(material) = materials.get_mut(&data.tower_material) {
+        material.base_color = Color::srgba(1.0, 0.8, 0.2, alpha);
+    }
+    if let Some(material) = materials.get_mut(&data.ring_material) {
+        material.base_color = Color::srgba(1.0, 0.8, 0.2, alpha * 0.8);
+    }
+}
+
 fn update_ghost_visuals(
     data: &TowerGhostData,
     valid: bool,
@@ -398,22 +675,94 @@ fn update_ghost_visuals(
     }
 }
 
+/// Spawns a tower of `kind` at `position` with base stats plus purchased upgrades.
+/// Does not check or deduct costs; callers handle affordability. Returns the tower entity.
+#[allow(clippy::too_many_arguments)]
+pub fn build_tower(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    tower_events: &mut MessageWriter<TowerBuilt>,
+    tunables: &Tunables,
+    upgrades: &TowerUpgrades,
+    upgrade_config: &TowerUpgradeConfig,
+    kind: TowerKind,
+    position: Vec3,
+) -> Entity {
+    // Apply upgrades using declarative config system
+    let (stats, range) =
+        upgrade_config.upgraded_stats(kind, upgrades.get_level(kind), tunables.tower_range);
+
+    let tower_entity = place_tower(
+        commands,
+        meshes,
+        materials,
+        position,
+        stats.damage,
+        stats.fire_interval_secs,
+        stats.projectile_speed,
+        range,
+        tunables,
+        kind,
+    );
+    tower_events.write(TowerBuilt { position, kind });
+    tower_entity
+}
+
+/// Spawns a tower that was built earlier, e.g. one from a save, with the stats it had then.
+/// Unlike `build_tower` it writes no `TowerBuilt`, so the tower is not counted or recorded again.
+#[allow(clippy::too_many_arguments)]
+pub fn restore_tower(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    tunables: &Tunables,
+    kind: TowerKind,
+    position: Vec3,
+    damage: u32,
+    fire_interval_secs: f32,
+    projectile_speed: f32,
+    range: f32,
+) -> Entity {
+    place_tower(
+        commands,
+        meshes,
+        materials,
+        position,
+        damage,
+        fire_interval_secs,
+        projectile_speed,
+        range,
+        tunables,
+        kind,
+    )
+}
+
+fn tower_color(kind: TowerKind) -> Color {
+    match kind {
+        TowerKind::Bow => Color::srgb(0.35, 0.45, 0.95),
+        TowerKind::Crossbow => Color::srgb(0.62, 0.36, 0.86),
+        TowerKind::Lightning => Color::srgb(0.25, 0.85, 0.95),
+        TowerKind::Catapult => Color::srgb(0.55, 0.4, 0.25),
+        TowerKind::Cannon => Color::srgb(0.8, 0.42, 0.14),
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn place_tower(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
     position: Vec3,
-    tower_events: &mut MessageWriter<TowerBuilt>,
     damage: u32,
     fire_interval_secs: f32,
     projectile_speed: f32,
     range: f32,
-    size: (f32, f32, f32),
-    color: Color,
     tunables: &Tunables,
     kind: TowerKind,
-) {
+) -> Entity {
+    let size = kind.size();
+    let color = tower_color(kind);
     let mesh = meshes.add(Cuboid::new(size.0, size.1, size.2));
     let mat = materials.add(StandardMaterial {
         base_color: color,
@@ -422,7 +771,24 @@ fn place_tower(
         ..default()
     });
 
-    let _tower_entity = commands
+    let indicator_material = materials.add(StandardMaterial {
+        base_color: COOLDOWN_ARC_COLOR,
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        cull_mode: None,
+        ..default()
+    });
+    let indicator_radius = size.0.max(size.2) * 0.5 + COOLDOWN_ARC_MARGIN;
+    let range_ring_material = materials.add(StandardMaterial {
+        base_color: RANGE_RING_COLOR,
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        cull_mode: None,
+        ..default()
+    });
+    let range_ring_mesh = meshes.add(build_ring_mesh(range, tunables.ring_inner_ratio, 96));
+
+    let tower_entity = commands
         .spawn((
             Mesh3d(mesh),
             MeshMaterial3d(mat),
@@ -440,14 +806,39 @@ fn place_tower(
                 last_shot: 0.0,
             },
             BuiltTower { kind },
+            TargetingMode::default(),
+            TowerPriorityKind::default(),
+            TowerKillCount::default(),
         ))
+        .with_children(|parent| {
+            // Arc mesh is assigned by tower_cooldown_indicator_system once the tower reloads
+            parent.spawn((
+                Mesh3d(Handle::default()),
+                MeshMaterial3d(indicator_material.clone()),
+                Transform::from_translation(Vec3::new(0.0, 0.06 - size.1 * 0.5, 0.0))
+                    .with_scale(Vec3::splat(indicator_radius)),
+                Visibility::Hidden,
+                TowerCooldownIndicator {
+                    flash: Timer::from_seconds(COOLDOWN_FLASH_SECS, TimerMode::Once),
+                    previous_last_shot: 0.0,
+                    material: indicator_material,
+                },
+            ));
+            // Shown while building; update_tower_range_rings keeps it in step with upgrades
+            parent.spawn((
+                Mesh3d(range_ring_mesh),
+                MeshMaterial3d(range_ring_material),
+                Transform::from_translation(Vec3::new(0.0, 0.05 - size.1 * 0.5, 0.0)),
+                Visibility::Hidden,
+                TowerRangeRing { radius: range },
+            ));
+        })
         .id();
 
     // Label will be spawned by tower_damage_label_spawner system
 
-    tower_events.write(TowerBuilt { position });
-
     spawn_tower_spawn_effect(commands, meshes, materials, position, tunables);
+    tower_entity
 }
 
 fn clear_ghost(
@@ -460,12 +851,146 @@ fn clear_ghost(
         commands.entity(data.tower_child).despawn();
         commands.entity(data.range_child).despawn();
         commands.entity(data.root).despawn();
+        if let Some(label) = data.overlap_label {
+            commands.entity(label).try_despawn();
+        }
+        commands.entity(data.splash_ring).despawn();
+        materials.remove(&data.splash_material);
+        meshes.remove(&data.splash_mesh);
         materials.remove(&data.tower_material);
         materials.remove(&data.ring_material);
         meshes.remove(&data.ring_mesh);
     }
 }
 
+/// Range ring under a built tower; `radius` is the range its mesh was built for.
+#[derive(Component)]
+pub struct TowerRangeRing {
+    pub radius: f32,
+}
+
+const RANGE_RING_COLOR: Color = Color::srgba(0.2, 0.85, 0.2, 0.2);
+
+/// Rebuilds a tower's range ring when its range changes (e.g. after a Range upgrade) and shows
+/// the rings only while building mode is active.
+pub fn update_tower_range_rings(
+    towers: Query<Ref<Tower>>,
+    mut rings: Query<(&ChildOf, &mut TowerRangeRing, &mut Mesh3d, &mut Visibility)>,
+    building_mode_query: Query<&BuildingMode>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    tunables: Res<Tunables>,
+) {
+    let building = building_mode_query.iter().any(|mode| mode.is_active);
+    for (child_of, mut ring, mut mesh, mut visibility) in rings.iter_mut() {
+        visibility.set_if_neq(if building {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+        let Ok(tower) = towers.get(child_of.parent()) else {
+            continue;
+        };
+        if !tower.is_changed() || tower.range == ring.radius {
+            continue;
+        }
+        ring.radius = tower.range;
+        meshes.remove(&mesh.0);
+        mesh.0 = meshes.add(build_ring_mesh(tower.range, tunables.ring_inner_ratio, 96));
+    }
+}
+
+/// Ground arc around a tower's base that sweeps clockwise from north as the tower reloads.
+#[derive(Component)]
+pub struct TowerCooldownIndicator {
+    flash: Timer,
+    previous_last_shot: f32,
+    material: Handle<StandardMaterial>,
+}
+
+/// Number of precomputed arc meshes; the reload ratio is quantized to this many steps.
+const COOLDOWN_ARC_STEPS: usize = 32;
+/// Inner radius of the cooldown arc relative to its outer radius.
+const COOLDOWN_ARC_INNER_RATIO: f32 = 0.85;
+/// Gap between the tower footprint and the outer edge of the cooldown arc.
+const COOLDOWN_ARC_MARGIN: f32 = 0.35;
+/// How long the arc flashes white after the tower fires.
+const COOLDOWN_FLASH_SECS: f32 = 0.15;
+const COOLDOWN_ARC_COLOR: Color = Color::srgba(0.55, 0.8, 1.0, 0.6);
+
+/// Sweeps each tower's cooldown arc with `last_shot / fire_interval_secs` and flashes it white
+/// when the tower fires.
+pub fn tower_cooldown_indicator_system(
+    time: Res<Time>,
+    towers: Query<&Tower>,
+    mut indicators: Query<(
+        &ChildOf,
+        &mut TowerCooldownIndicator,
+        &mut Mesh3d,
+        &mut Visibility,
+    )>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut arc_meshes: Local<Vec<Handle<Mesh>>>,
+) {
+    if arc_meshes.is_empty() {
+        // Unit-radius sectors starting at north (-Z) and sweeping clockwise seen from above;
+        // index i covers i / COOLDOWN_ARC_STEPS of the circle
+        let start = -FRAC_PI_2;
+        *arc_meshes = (0..=COOLDOWN_ARC_STEPS)
+            .map(|i| {
+                let fraction = i as f32 / COOLDOWN_ARC_STEPS as f32;
+                let segments = (fraction * 72.0).ceil().max(1.0) as usize;
+                meshes.add(build_ring_sector_mesh(
+                    1.0,
+                    COOLDOWN_ARC_INNER_RATIO,
+                    segments,
+                    start,
+                    start + fraction * TAU,
+                ))
+            })
+            .collect();
+    }
+
+    for (child_of, mut indicator, mut mesh, mut visibility) in indicators.iter_mut() {
+        let Ok(tower) = towers.get(child_of.parent()) else {
+            continue;
+        };
+        // last_shot only ever drops when the tower fires
+        if tower.last_shot < indicator.previous_last_shot {
+            indicator.flash.reset();
+        }
+        indicator.previous_last_shot = tower.last_shot;
+        indicator.flash.tick(time.delta());
+
+        let flashing = !indicator.flash.is_finished();
+        let ratio = if flashing {
+            1.0
+        } else {
+            (tower.last_shot / tower.fire_interval_secs.max(f32::EPSILON)).clamp(0.0, 1.0)
+        };
+        let step = (ratio * COOLDOWN_ARC_STEPS as f32).round() as usize;
+        if step == 0 {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        }
+        visibility.set_if_neq(Visibility::Inherited);
+        if mesh.0 != arc_meshes[step] {
+            mesh.0 = arc_meshes[step].clone();
+        }
+
+        let color = if flashing {
+            Color::srgba(1.0, 1.0, 1.0, 0.95)
+        } else {
+            COOLDOWN_ARC_COLOR
+        };
+        if let Some(mat) = materials.get_mut(&indicator.material)
+            && mat.base_color != color
+        {
+            mat.base_color = color;
+        }
+    }
+}
+
 #[derive(Component)]
 pub struct TowerSpawnEffect {
     timer: Timer,
@@ -546,12 +1071,45 @@ pub fn tower_damage_label_spawner(
     }
 }
 
-/// Positions tower damage labels in screen space.
+/// Tags towers inside the camera's view frustum with `FrustumCulledTower` and untags the rest,
+/// so the label pass only projects towers that can be on screen. The frustum comes from the
+/// camera's own perspective projection, so it follows any change to its field of view.
+pub fn frustum_cull_towers_system(
+    mut commands: Commands,
+    cam_q: Query<(&GlobalTransform, &Projection), With<Camera3d>>,
+    towers: Query<(Entity, &Transform, &Tower, Has<FrustumCulledTower>), With<BuiltTower>>,
+) {
+    let Ok((camera_transform, projection)) = cam_q.single() else {
+        return;
+    };
+    let Projection::Perspective(perspective) = projection else {
+        return;
+    };
+
+    let aspect = perspective.aspect_ratio;
+    let fov_y = perspective.fov;
+    let world_to_view = camera_transform.affine().inverse();
+    for (entity, transform, tower, tagged) in towers.iter() {
+        let view = world_to_view.transform_point3(transform.translation);
+        // Half the tower's height as margin keeps a tower whose top pokes into view
+        let visible = in_view_frustum(view, fov_y, aspect, tower.height * 0.5);
+        if visible && !tagged {
+            commands.entity(entity).insert(FrustumCulledTower);
+        } else if !visible && tagged {
+            commands.entity(entity).remove::<FrustumCulledTower>();
+        }
+    }
+}
+
+/// Positions tower damage labels in screen space. Labels of towers outside the view frustum are
+/// hidden without projecting them, and labels whose tower is gone are despawned.
 pub fn tower_damage_label_system(
+    mut commands: Commands,
     windows: Query<&Window>,
     cam_q: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    tower_query: Query<(&Transform, &Tower), With<Tower>>,
-    mut labels: Query<(&TowerDamageLabel, &mut Node, &mut Visibility)>,
+    tower_query: Query<(&Transform, &Tower, Has<FrustumCulledTower>), With<Tower>>,
+    mut labels: Query<(Entity, &TowerDamageLabel, &mut Node, &mut Visibility)>,
+    tunables: Res<Tunables>,
 ) {
     let Ok(window) = windows.single() else {
         return;
@@ -561,43 +1119,51 @@ pub fn tower_damage_label_system(
     };
 
     let scale_factor = window.resolution.scale_factor();
+    let camera_pos = camera_transform.translation();
+    let cull_distance_sq = tunables.label_cull_distance * tunables.label_cull_distance;
 
-    for (label, mut node, mut visibility) in labels.iter_mut() {
+    for (label_entity, label, mut node, mut visibility) in labels.iter_mut() {
         // Get tower's transform and dimensions directly
-        if let Ok((tower_transform, tower)) = tower_query.get(label.tower_entity) {
-            let world_pos = tower_transform.translation + label.world_offset;
-
-            // Position label in screen space
-            if let Ok(screen_pos) = camera.world_to_viewport(camera_transform, world_pos) {
-                *visibility = Visibility::Visible;
-
-                let logical_center = screen_pos / scale_factor;
-
-                // Determine tower world half-extents from component
-                let hx = tower.width * 0.5;
-                let hz = tower.depth * 0.5;
-
-                // Estimate half the on-screen width by projecting +X and +Z offsets
-                let mut half_width_px: f32 = 0.0;
-                if let Ok(px) =
-                    camera.world_to_viewport(camera_transform, world_pos + Vec3::new(hx, 0.0, 0.0))
-                {
-                    half_width_px =
-                        half_width_px.max(((px.x / scale_factor) - logical_center.x).abs());
-                }
-                if let Ok(pz) =
-                    camera.world_to_viewport(camera_transform, world_pos + Vec3::new(0.0, 0.0, hz))
-                {
-                    half_width_px =
-                        half_width_px.max(((pz.x / scale_factor) - logical_center.x).abs());
-                }
-
-                // Subtract exactly half the tower's thickness (in pixels)
-                node.left = Val::Px(logical_center.x - half_width_px);
-                node.top = Val::Px(logical_center.y + 10.0);
-            } else {
-                *visibility = Visibility::Hidden;
+        let Ok((tower_transform, tower, in_view)) = tower_query.get(label.tower_entity) else {
+            commands.entity(label_entity).try_despawn();
+            continue;
+        };
+        let world_pos = tower_transform.translation + label.world_offset;
+
+        // Cull labels that are too far away or outside the camera frustum
+        if !in_view || world_pos.distance_squared(camera_pos) > cull_distance_sq {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        }
+
+        // Position label in screen space
+        if let Ok(screen_pos) = camera.world_to_viewport(camera_transform, world_pos) {
+            visibility.set_if_neq(Visibility::Visible);
+
+            let logical_center = screen_pos / scale_factor;
+
+            // Determine tower world half-extents from component
+            let hx = tower.width * 0.5;
+            let hz = tower.depth * 0.5;
+
+            // Estimate half the on-screen width by projecting +X and +Z offsets
+            let mut half_width_px: f32 = 0.0;
+            if let Ok(px) =
+                camera.world_to_viewport(camera_transform, world_pos + Vec3::new(hx, 0.0, 0.0))
+            {
+                half_width_px = half_width_px.max(((px.x / scale_factor) - logical_center.x).abs());
+            }
+            if let Ok(pz) =
+                camera.world_to_viewport(camera_transform, world_pos + Vec3::new(0.0, 0.0, hz))
+            {
+                half_width_px = half_width_px.max(((pz.x / scale_factor) - logical_center.x).abs());
             }
+
+            // Subtract exactly half the tower's thickness (in pixels)
+            node.left = Val::Px(logical_center.x - half_width_px);
+            node.top = Val::Px(logical_center.y + 10.0);
+        } else {
+            visibility.set_if_neq(Visibility::Hidden);
         }
     }
 }
@@ -616,19 +1182,6 @@ pub fn update_tower_damage_labels(
     }
 }
 
-/// Cleanup labels for towers that no longer exist
-pub fn cleanup_tower_damage_labels(
-    mut commands: Commands,
-    towers: Query<(), With<Tower>>,
-    labels: Query<(Entity, &TowerDamageLabel)>,
-) {
-    for (entity, label) in labels.iter() {
-        if towers.get(label.tower_entity).is_err() {
-            commands.entity(entity).despawn();
-        }
-    }
-}
-
 pub fn tower_spawn_effect_system(
     mut commands: Commands,
     time: Res<Time>,
@@ -663,9 +1216,156 @@ pub fn tower_spawn_effect_system(
     }
 }
 
+/// Name of the Bow + Crossbow combo.
+const SYNERGY_COMBO: &str = "Synergy";
+const COMBO_LABEL_COLOR: Color = Color::srgba(1.0, 0.85, 0.3, 0.95);
+
+/// The tower kind that completes a combo with `kind`, if any.
+fn combo_partner(kind: TowerKind) -> Option<TowerKind> {
+    match kind {
+        TowerKind::Bow => Some(TowerKind::Crossbow),
+        TowerKind::Crossbow => Some(TowerKind::Bow),
+        _ => None,
+    }
+}
+
+/// Re-evaluates tower combos whenever a tower is built, sold or converted: every Bow within
+/// `combo_detection_radius` of a Crossbow (and vice versa) counts as a partner, and each partner
+/// adds `combo_damage_bonus`, up to `combo_max_stacks` partners.
+pub fn tower_combo_detector(
+    mut commands: Commands,
+    tunables: Res<Tunables>,
+    mut built: MessageReader<TowerBuilt>,
+    mut removed: RemovedComponents<BuiltTower>,
+    added: Query<(), Added<BuiltTower>>,
+    towers: Query<(Entity, &Transform, &BuiltTower, Option<&ComboBonus>)>,
+) {
+    let built = built.read().count() > 0;
+    let removed = removed.read().count() > 0;
+    if !built && !removed && added.is_empty() {
+        return;
+    }
+
+    let radius_sq = tunables.combo_detection_radius * tunables.combo_detection_radius;
+    for (entity, transform, tower, current) in towers.iter() {
+        let partners = combo_partner(tower.kind).map_or(0, |partner| {
+            towers
+                .iter()
+                .filter(|(other, other_tf, other_tower, _)| {
+                    *other != entity
+                        && other_tower.kind == partner
+                        && (other_tf.translation - transform.translation)
+                            .with_y(0.0)
+                            .length_squared()
+                            <= radius_sq
+                })
+                .count() as u32
+        });
+        let stacks = partners.min(tunables.combo_max_stacks);
+        let bonus = (stacks > 0).then(|| ComboBonus {
+            damage_multiplier: 1.0 + tunables.combo_damage_bonus * stacks as f32,
+            label: SYNERGY_COMBO,
+        });
+        if bonus.as_ref() == current {
+            continue;
+        }
+        match bonus {
+            Some(bonus) => commands.entity(entity).insert(bonus),
+            None => commands.entity(entity).remove::<ComboBonus>(),
+        };
+    }
+}
+
+/// Keeps one combo label per tower with a `ComboBonus`, above the tower, e.g. "Synergy: +20% dmg".
+#[allow(clippy::type_complexity)]
+pub fn tower_combo_label_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    tunables: Res<Tunables>,
+    windows: Query<&Window>,
+    cam_q: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    towers: Query<(Entity, &Transform, &Tower, Option<&ComboBonus>)>,
+    mut labels: Query<(
+        Entity,
+        &TowerComboLabel,
+        &mut Text,
+        &mut Node,
+        &mut Visibility,
+    )>,
+) {
+    let mut labelled = Vec::new();
+    for (label_entity, label, mut text, mut node, mut visibility) in labels.iter_mut() {
+        let Ok((_, transform, tower, Some(bonus))) = towers.get(label.tower_entity) else {
+            commands.entity(label_entity).despawn();
+            continue;
+        };
+        labelled.push(label.tower_entity);
+
+        let caption = combo_caption(bonus);
+        if text.0 != caption {
+            text.0 = caption;
+        }
+        let world_pos = transform.translation + Vec3::Y * (tower.height * 0.5 + 1.0);
+        let logical = windows
+            .single()
+            .ok()
+            .zip(cam_q.single().ok())
+            .filter(|(_, (_, cam_tf))| {
+                world_pos.distance_squared(cam_tf.translation())
+                    <= tunables.label_cull_distance * tunables.label_cull_distance
+            })
+            .and_then(|(window, (camera, cam_tf))| {
+                is_on_screen_ndc(camera, cam_tf, world_pos, 0.0)
+                    .then(|| world_to_viewport_logical(camera, cam_tf, window, world_pos))
+                    .flatten()
+            });
+        let Some(logical) = logical else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        visibility.set_if_neq(Visibility::Visible);
+        node.left = Val::Px(logical.x - 40.0);
+        node.top = Val::Px(logical.y - 16.0);
+    }
+
+    // New labels are placed by the next run, once they exist
+    for (tower_entity, _, _, bonus) in towers.iter() {
+        let Some(bonus) = bonus else {
+            continue;
+        };
+        if labelled.contains(&tower_entity) {
+            continue;
+        }
+        commands.spawn((
+            TowerComboLabel { tower_entity },
+            Text::new(combo_caption(bonus)),
+            TextFont {
+                font: asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(COMBO_LABEL_COLOR),
+            Node {
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            Visibility::Hidden,
+            Pickable::IGNORE,
+        ));
+    }
+}
+
+fn combo_caption(bonus: &ComboBonus) -> String {
+    let percent = ((bonus.damage_multiplier - 1.0) * 100.0).round();
+    format!("{}: +{}% dmg", bonus.label, percent)
+}
+
 /// Click-to-sell system. When in selling mode and left-click, sell the nearest tower
-/// under the cursor within a small radius and refund 50% of its cost.
+/// under the cursor within a small radius and refund 50% of its cost. Challenge mode allows no
+/// selling.
+#[allow(clippy::too_many_arguments)]
 pub fn tower_selling_click(
+    tunables: Res<Tunables>,
     mouse_input: Res<ButtonInput<MouseButton>>,
     windows: Query<&Window>,
     camera_q: Query<(&Camera, &GlobalTransform)>,
@@ -676,7 +1376,7 @@ pub fn tower_selling_click(
     mut building_sfx: MessageWriter<BuildingActionEvent>,
 ) {
     let selling_active = selling_q.iter().any(|s| s.is_active);
-    if !selling_active {
+    if tunables.challenge_mode || !selling_active {
         return;
     }
     if !mouse_input.just_pressed(MouseButton::Left) {
@@ -715,9 +1415,7 @@ pub fn tower_selling_click(
         // Labels are children and will be automatically despawned with the tower
 
         if let Ok(mut player) = player_q.single_mut() {
-            let (wood_cost, rock_cost) = kind.cost();
-            let wood_refund = wood_cost / 2;
-            let rock_refund = rock_cost / 2;
+            let (wood_refund, rock_refund) = kind.sell_refund();
             player.wood = player.wood.saturating_add(wood_refund);
             player.rock = player.rock.saturating_add(rock_refund);
         }
@@ -729,3 +1427,760 @@ pub fn tower_selling_click(
         });
     }
 }
+
+/// Credits each kill to the tower that landed the killing blow.
+pub fn count_tower_kills(
+    mut killed: MessageReader<EnemyKilled>,
+    mut towers: Query<&mut TowerKillCount>,
+) {
+    for e in killed.read() {
+        if let Some(mut count) = e.tower.and_then(|tower| towers.get_mut(tower).ok()) {
+            count.0 += 1;
+        }
+    }
+}
+
+/// Middle-click a tower, or press T while hovering it, to cycle its targeting mode.
+pub fn tower_targeting_toggle_click(
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window>,
+    camera_q: Query<(&Camera, &GlobalTransform)>,
+    mut towers_q: Query<(&Transform, &mut TargetingMode), With<Tower>>,
+) {
+    if !mouse_input.just_pressed(MouseButton::Middle) && !keyboard_input.just_pressed(KeyCode::KeyT)
+    {
+        return;
+    }
+
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Ok((camera, cam_tf)) = camera_q.single() else {
+        return;
+    };
+    let Some(cursor_pos) = window.cursor_position() else {
+        return;
+    };
+    let Some(world_point) = cursor_to_ground(camera, cam_tf, cursor_pos, 0.0) else {
+        return;
+    };
+
+    // Same ~2.0 pick radius as selling
+    let nearest = towers_q
+        .iter_mut()
+        .map(|(transform, mode)| {
+            let dx = transform.translation.x - world_point.x;
+            let dz = transform.translation.z - world_point.z;
+            (dx * dx + dz * dz, mode)
+        })
+        .filter(|(d2, _)| *d2 <= 4.0)
+        .min_by(|a, b| a.0.total_cmp(&b.0));
+
+    if let Some((_, mut mode)) = nearest {
+        *mode = mode.next();
+        if cfg!(debug_assertions) {
+            info!("Tower targeting mode: {:?}", *mode);
+        }
+    }
+}
+
+/// Tooltip next to the cursor naming the hovered tower and its targeting mode.
+#[derive(Component)]
+pub struct TowerHoverTooltip;
+
+/// Shows the tooltip while the cursor is within the usual ~2.0 pick radius of a tower.
+#[allow(clippy::type_complexity)]
+pub fn tower_hover_tooltip_system(
+    mut commands: Commands,
+    windows: Query<&Window>,
+    camera_q: Query<(&Camera, &GlobalTransform)>,
+    towers_q: Query<(&Transform, &BuiltTower, &TargetingMode), With<Tower>>,
+    mut tooltip_q: Query<(&mut Node, &mut Text, &mut Visibility), With<TowerHoverTooltip>>,
+    asset_server: Res<AssetServer>,
+) {
+    let hovered = windows
+        .single()
+        .ok()
+        .and_then(|window| window.cursor_position())
+        .and_then(|cursor_pos| {
+            let (camera, cam_tf) = camera_q.single().ok()?;
+            let world_point = cursor_to_ground(camera, cam_tf, cursor_pos, 0.0)?;
+            towers_q
+                .iter()
+                .map(|(transform, built, mode)| {
+                    let d2 = (transform.translation - world_point)
+                        .with_y(0.0)
+                        .length_squared();
+                    (d2, built.kind, *mode)
+                })
+                .filter(|(d2, _, _)| *d2 <= 4.0)
+                .min_by(|a, b| a.0.total_cmp(&b.0))
+                .map(|(_, kind, mode)| (cursor_pos, kind, mode))
+        });
+
+    let Ok((mut node, mut text, mut visibility)) = tooltip_q.single_mut() else {
+        commands.spawn((
+            TowerHoverTooltip,
+            Text::new(""),
+            TextFont {
+                font: asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(Color::srgba(0.95, 0.95, 0.95, 1.0)),
+            BackgroundColor(Color::srgba(0.05, 0.06, 0.08, 0.85)),
+            Node {
+                position_type: PositionType::Absolute,
+                padding: UiRect::axes(Val::Px(6.0), Val::Px(3.0)),
+                ..default()
+            },
+            Visibility::Hidden,
+            Pickable::IGNORE,
+        ));
+        return;
+    };
+    let Some((cursor_pos, kind, mode)) = hovered else {
+        visibility.set_if_neq(Visibility::Hidden);
+        return;
+    };
+    let label = format!("{} Tower\nTarget: {}", kind.name(), mode.label());
+    if text.0 != label {
+        text.0 = label;
+    }
+    node.left = Val::Px(cursor_pos.x + 16.0);
+    node.top = Val::Px(cursor_pos.y + 16.0);
+    visibility.set_if_neq(Visibility::Visible);
+}
+
+/// The open "Target Priority" picker and the tower it edits.
+#[derive(Resource, Default)]
+pub struct TowerPriorityPicker {
+    tower: Option<Entity>,
+    panel: Option<Entity>,
+}
+
+/// Picker button locking the edited tower onto `kind` (`None` clears the lock).
+#[derive(Component)]
+pub struct PriorityOptionButton {
+    kind: Option<EnemyKind>,
+}
+
+/// Picker button converting the edited tower into `kind`.
+#[derive(Component)]
+pub struct ConvertOptionButton {
+    kind: TowerKind,
+}
+
+const PRIORITY_OPTIONS: [(Option<EnemyKind>, &str); 5] = [
+    (None, "Any"),
+    (Some(EnemyKind::Minion), "Minion"),
+    (Some(EnemyKind::Zombie), "Zombie"),
+    (Some(EnemyKind::Boss), "Boss"),
+    (Some(EnemyKind::Healer), "Healer"),
+];
+
+/// Alt + right-click a tower to open a picker that locks it onto a specific enemy kind or
+/// converts it into another tower kind.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn tower_priority_picker_open(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    camera_q: Query<(&Camera, &GlobalTransform)>,
+    towers_q: Query<(Entity, &Transform, &BuiltTower, Option<&TowerPriorityKind>), With<Tower>>,
+    player_q: Query<&Player>,
+    tunables: Res<Tunables>,
+    asset_server: Res<AssetServer>,
+    mut picker: ResMut<TowerPriorityPicker>,
+    mut timer_paused: ResMut<WaveTimerPaused>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        close_priority_picker(&mut commands, &mut picker, &mut timer_paused);
+        return;
+    }
+    let alt_held =
+        keyboard_input.pressed(KeyCode::AltLeft) || keyboard_input.pressed(KeyCode::AltRight);
+    if !alt_held || !mouse_input.just_pressed(MouseButton::Right) {
+        return;
+    }
+
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Ok((camera, cam_tf)) = camera_q.single() else {
+        return;
+    };
+    let Some(cursor_pos) = window.cursor_position() else {
+        return;
+    };
+    let Some(world_point) = cursor_to_ground(camera, cam_tf, cursor_pos, 0.0) else {
+        return;
+    };
+
+    // Same ~2.0 pick radius as selling
+    let nearest = towers_q
+        .iter()
+        .map(|(entity, transform, built, priority)| {
+            let dx = transform.translation.x - world_point.x;
+            let dz = transform.translation.z - world_point.z;
+            (
+                dx * dx + dz * dz,
+                entity,
+                built.kind,
+                priority.and_then(|p| p.kind),
+            )
+        })
+        .filter(|(d2, _, _, _)| *d2 <= 4.0)
+        .min_by(|a, b| a.0.total_cmp(&b.0));
+
+    close_priority_picker(&mut commands, &mut picker, &mut timer_paused);
+    let Some((_, tower, tower_kind, current)) = nearest else {
+        return;
+    };
+    let (wood, rock) = player_q
+        .single()
+        .map(|player| (player.wood, player.rock))
+        .unwrap_or((0, 0));
+
+    let font = asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf");
+    let panel = commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(cursor_pos.x + 12.0),
+                top: Val::Px(cursor_pos.y),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.08, 0.12, 0.2, 0.9)),
+            BorderColor::all(Color::srgba(0.45, 0.75, 1.0, 0.7)),
+        ))
+        .with_children(|p| {
+            p.spawn((
+                Text::new("Target Priority"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(0.85, 0.93, 1.0, 1.0)),
+            ));
+            for (kind, label) in PRIORITY_OPTIONS {
+                let selected = kind == current;
+                p.spawn((
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                        ..default()
+                    },
+                    BackgroundColor(if selected {
+                        Color::srgba(0.25, 0.45, 0.8, 0.9)
+                    } else {
+                        Color::srgba(0.15, 0.2, 0.3, 0.9)
+                    }),
+                    PriorityOptionButton { kind },
+                ))
+                .with_children(|b| {
+                    b.spawn((
+                        Text::new(label),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgba(0.85, 0.93, 1.0, 1.0)),
+                    ));
+                });
+            }
+
+            p.spawn((
+                Text::new("Convert Tower"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(0.85, 0.93, 1.0, 1.0)),
+                Node {
+                    margin: UiRect::top(Val::Px(6.0)),
+                    ..default()
+                },
+            ));
+            for kind in TowerKind::ALL.into_iter().filter(|k| *k != tower_kind) {
+                let (wood_cost, rock_cost) =
+                    tower_kind.conversion_cost(kind, tunables.conversion_premium);
+                let affordable = wood >= wood_cost && rock >= rock_cost;
+                p.spawn((
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.15, 0.2, 0.3, 0.9)),
+                    ConvertOptionButton { kind },
+                ))
+                .with_children(|b| {
+                    b.spawn((
+                        Text::new(format!("{}  {}W {}R", kind.name(), wood_cost, rock_cost)),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(if affordable {
+                            Color::srgba(0.85, 0.93, 1.0, 1.0)
+                        } else {
+                            Color::srgba(1.0, 0.45, 0.4, 1.0)
+                        }),
+                    ));
+                });
+            }
+        })
+        .id();
+    picker.tower = Some(tower);
+    picker.panel = Some(panel);
+    // Waves hold still while the player is choosing
+    timer_paused.set(PauseReason::PriorityPicker, true);
+}
+
+/// Applies the option chosen in the priority picker to its tower and closes the picker.
+pub fn tower_priority_picker_interaction(
+    mut commands: Commands,
+    interactions: Query<(&Interaction, &PriorityOptionButton), Changed<Interaction>>,
+    mut picker: ResMut<TowerPriorityPicker>,
+    mut timer_paused: ResMut<WaveTimerPaused>,
+) {
+    let Some(kind) = interactions
+        .iter()
+        .find(|(interaction, _)| matches!(**interaction, Interaction::Pressed))
+        .map(|(_, option)| option.kind)
+    else {
+        return;
+    };
+    if let Some(tower) = picker.tower {
+        commands
+            .entity(tower)
+            .try_insert(TowerPriorityKind { kind });
+        if cfg!(debug_assertions) {
+            info!("Tower priority kind: {:?}", kind);
+        }
+    }
+    close_priority_picker(&mut commands, &mut picker, &mut timer_paused);
+}
+
+/// Requests converting the picker's tower into the chosen kind and closes the picker.
+pub fn tower_convert_option_interaction(
+    mut commands: Commands,
+    interactions: Query<(&Interaction, &ConvertOptionButton), Changed<Interaction>>,
+    mut picker: ResMut<TowerPriorityPicker>,
+    mut timer_paused: ResMut<WaveTimerPaused>,
+    mut convert_events: MessageWriter<ConvertTower>,
+) {
+    let Some(target_kind) = interactions
+        .iter()
+        .find(|(interaction, _)| matches!(**interaction, Interaction::Pressed))
+        .map(|(_, option)| option.kind)
+    else {
+        return;
+    };
+    if let Some(tower) = picker.tower {
+        convert_events.write(ConvertTower { tower, target_kind });
+    }
+    close_priority_picker(&mut commands, &mut picker, &mut timer_paused);
+}
+
+/// Replaces a tower with another kind at the same spot, charging the conversion cost.
+/// The new tower keeps the old one's targeting settings. Conversions into a kind that is at its
+/// tower limit are refused like a placement would be.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn convert_tower_system(
+    mut commands: Commands,
+    mut convert_events: MessageReader<ConvertTower>,
+    towers_q: Query<(&Transform, &BuiltTower, &TargetingMode, &TowerPriorityKind), With<Tower>>,
+    mut player_q: Query<&mut Player>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut tower_events: MessageWriter<TowerBuilt>,
+    mut building_sfx: MessageWriter<BuildingActionEvent>,
+    mut placement_rejected: MessageWriter<PlacementRejected>,
+    tunables: Res<Tunables>,
+    (upgrades, upgrade_config): (Res<TowerUpgrades>, Res<TowerUpgradeConfig>),
+) {
+    for event in convert_events.read() {
+        let Ok((transform, built, targeting, priority)) = towers_q.get(event.tower) else {
+            continue;
+        };
+        if built.kind == event.target_kind {
+            continue;
+        }
+        let Ok(mut player) = player_q.single_mut() else {
+            continue;
+        };
+        let position = transform.translation.with_y(0.0);
+        let built_of_kind = towers_q
+            .iter()
+            .filter(|(_, other, ..)| other.kind == event.target_kind)
+            .count();
+        if tower_limit_reached(event.target_kind, built_of_kind, &tunables) {
+            building_sfx.write(BuildingActionEvent {
+                kind: BuildingActionKind::Invalid,
+                position,
+            });
+            placement_rejected.write(PlacementRejected {
+                reason: PlacementError::TowerLimitReached,
+                position,
+            });
+            continue;
+        }
+        let (wood_cost, rock_cost) = built
+            .kind
+            .conversion_cost(event.target_kind, tunables.conversion_premium);
+        if player.wood < wood_cost || player.rock < rock_cost {
+            building_sfx.write(BuildingActionEvent {
+                kind: BuildingActionKind::Invalid,
+                position,
+            });
+            continue;
+        }
+        player.wood -= wood_cost;
+        player.rock -= rock_cost;
+
+        commands.entity(event.tower).despawn();
+        let tower = build_tower(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            &mut tower_events,
+            &tunables,
+            &upgrades,
+            &upgrade_config,
+            event.target_kind,
+            position,
+        );
+        // build_tower spawns with default targeting; carry the old settings over
+        commands.entity(tower).insert((*targeting, *priority));
+        building_sfx.write(BuildingActionEvent {
+            kind: BuildingActionKind::Upgrade,
+            position,
+        });
+        if cfg!(debug_assertions) {
+            info!(
+                "Converted {:?} tower into {:?} for {}W {}R",
+                built.kind, event.target_kind, wood_cost, rock_cost
+            );
+        }
+    }
+}
+
+fn close_priority_picker(
+    commands: &mut Commands,
+    picker: &mut TowerPriorityPicker,
+    timer_paused: &mut ResMut<WaveTimerPaused>,
+) {
+    if let Some(panel) = picker.panel.take() {
+        commands.entity(panel).try_despawn();
+        timer_paused.set(PauseReason::PriorityPicker, false);
+    }
+    picker.tower = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::TowerUpgradeBonuses;
+    use bevy::camera::{CameraProjection, RenderTargetInfo};
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::mesh::VertexAttributeValues;
+
+    #[test]
+    fn snap_to_grid_rounds_to_the_nearest_multiple() {
+        assert_eq!(snap_to_grid(0.0, 2.0), 0.0);
+        assert_eq!(snap_to_grid(0.9, 2.0), 0.0);
+        assert_eq!(snap_to_grid(1.1, 2.0), 2.0);
+        assert_eq!(snap_to_grid(7.4, 2.5), 7.5);
+        assert_eq!(snap_to_grid(10.0, 2.0), 10.0);
+    }
+
+    #[test]
+    fn snap_to_grid_handles_negative_positions() {
+        assert_eq!(snap_to_grid(-0.9, 2.0), 0.0);
+        assert_eq!(snap_to_grid(-1.1, 2.0), -2.0);
+        assert_eq!(snap_to_grid(-3.2, 2.0), -4.0);
+        assert_eq!(snap_to_grid(-10.0, 2.0), -10.0);
+    }
+
+    #[test]
+    fn snap_to_grid_halfway_rounds_away_from_zero() {
+        assert_eq!(snap_to_grid(1.0, 2.0), 2.0);
+        assert_eq!(snap_to_grid(-1.0, 2.0), -2.0);
+    }
+
+    #[test]
+    fn snap_to_grid_ignores_a_non_positive_size() {
+        assert_eq!(snap_to_grid(3.7, 0.0), 3.7);
+        assert_eq!(snap_to_grid(-3.7, -2.0), -3.7);
+    }
+
+    fn combo_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(Tunables::default());
+        world.init_resource::<Messages<TowerBuilt>>();
+        world
+    }
+
+    fn spawn_built(world: &mut World, kind: TowerKind, x: f32) -> Entity {
+        world
+            .spawn((Transform::from_xyz(x, 0.0, 0.0), BuiltTower { kind }))
+            .id()
+    }
+
+    fn combo_multiplier(world: &World, tower: Entity) -> Option<f32> {
+        world
+            .get::<ComboBonus>(tower)
+            .map(|bonus| bonus.damage_multiplier)
+    }
+
+    #[test]
+    fn bow_and_crossbow_in_range_form_a_combo() {
+        let mut world = combo_world();
+        let bonus = world.resource::<Tunables>().combo_damage_bonus;
+        let radius = world.resource::<Tunables>().combo_detection_radius;
+        let bow = spawn_built(&mut world, TowerKind::Bow, 0.0);
+        let crossbow = spawn_built(&mut world, TowerKind::Crossbow, radius - 1.0);
+        let cannon = spawn_built(&mut world, TowerKind::Cannon, 1.0);
+        let far_bow = spawn_built(&mut world, TowerKind::Bow, radius * 3.0);
+
+        world.run_system_once(tower_combo_detector).unwrap();
+
+        assert_eq!(combo_multiplier(&world, bow), Some(1.0 + bonus));
+        assert_eq!(combo_multiplier(&world, crossbow), Some(1.0 + bonus));
+        assert_eq!(combo_multiplier(&world, cannon), None);
+        assert_eq!(combo_multiplier(&world, far_bow), None);
+        let caption = combo_caption(world.get::<ComboBonus>(bow).unwrap());
+        assert_eq!(caption, "Synergy: +20% dmg");
+    }
+
+    #[test]
+    fn combo_stacks_up_to_the_cap() {
+        let mut world = combo_world();
+        let tunables = world.resource::<Tunables>().clone();
+        let bow = spawn_built(&mut world, TowerKind::Bow, 0.0);
+        let crossbows: Vec<Entity> = (1..=tunables.combo_max_stacks + 1)
+            .map(|i| spawn_built(&mut world, TowerKind::Crossbow, i as f32))
+            .collect();
+
+        world.run_system_once(tower_combo_detector).unwrap();
+
+        let capped = 1.0 + tunables.combo_damage_bonus * tunables.combo_max_stacks as f32;
+        assert_eq!(combo_multiplier(&world, bow), Some(capped));
+        for crossbow in crossbows {
+            assert_eq!(
+                combo_multiplier(&world, crossbow),
+                Some(1.0 + tunables.combo_damage_bonus)
+            );
+        }
+    }
+
+    #[test]
+    fn selling_a_partner_removes_the_combo() {
+        let mut world = combo_world();
+        let bow = spawn_built(&mut world, TowerKind::Bow, 0.0);
+        let crossbow = spawn_built(&mut world, TowerKind::Crossbow, 2.0);
+        world.run_system_once(tower_combo_detector).unwrap();
+        assert!(combo_multiplier(&world, bow).is_some());
+
+        world.despawn(crossbow);
+        world.run_system_once(tower_combo_detector).unwrap();
+        assert_eq!(combo_multiplier(&world, bow), None);
+    }
+
+    /// Farthest distance from the ring's center to any of its vertices on the XZ plane.
+    fn ring_outer_radius(mesh: &Mesh) -> f32 {
+        let Some(VertexAttributeValues::Float32x3(positions)) =
+            mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        else {
+            panic!("ring mesh has no positions");
+        };
+        positions
+            .iter()
+            .map(|p| Vec2::new(p[0], p[2]).length())
+            .fold(0.0, f32::max)
+    }
+
+    #[test]
+    fn range_ring_matches_the_effective_range_after_an_upgrade() {
+        let mut world = World::new();
+        let tunables = Tunables::default();
+        let base_range = tunables.tower_range;
+        world.insert_resource(tunables);
+        world.init_resource::<Assets<Mesh>>();
+        let mut upgrade_config = TowerUpgradeConfig::default();
+        upgrade_config.set_bonuses(TowerKind::Bow, TowerUpgradeBonuses::new().with_range(3.0));
+        let base = TowerKind::Bow.stats();
+        let tower = world
+            .spawn((
+                Tower {
+                    range: base_range,
+                    damage: base.damage,
+                    fire_interval_secs: base.fire_interval_secs,
+                    height: 2.0,
+                    width: 1.0,
+                    depth: 1.0,
+                    projectile_speed: base.projectile_speed,
+                    last_shot: 0.0,
+                },
+                BuiltTower {
+                    kind: TowerKind::Bow,
+                },
+            ))
+            .id();
+        let mesh = world
+            .resource_mut::<Assets<Mesh>>()
+            .add(build_ring_mesh(base_range, 0.9, 96));
+        let ring = world
+            .spawn((
+                TowerRangeRing { radius: base_range },
+                Mesh3d(mesh),
+                Visibility::Hidden,
+                ChildOf(tower),
+            ))
+            .id();
+
+        let mut upgrades = TowerUpgrades::default();
+        let level = upgrades.increment_level(TowerKind::Bow);
+        let (_, effective_range) = upgrade_config.upgraded_stats(TowerKind::Bow, level, base_range);
+        assert_eq!(effective_range, base_range + 3.0);
+        world.get_mut::<Tower>(tower).unwrap().range = effective_range;
+        world.run_system_once(update_tower_range_rings).unwrap();
+
+        assert_eq!(
+            world.get::<TowerRangeRing>(ring).unwrap().radius,
+            effective_range
+        );
+        let handle = world.get::<Mesh3d>(ring).unwrap().0.clone();
+        let meshes = world.resource::<Assets<Mesh>>();
+        let radius = ring_outer_radius(meshes.get(&handle).unwrap());
+        assert!(
+            (radius - effective_range).abs() < 1e-3,
+            "ring radius {radius}"
+        );
+    }
+
+    /// World with a camera at the origin looking down -Z through a square 90 degree frustum onto
+    /// an 800 pixel square viewport, a window, and a tower at `position` carrying a visible damage
+    /// label.
+    fn label_world(position: Vec3) -> (World, Entity, Entity) {
+        let mut world = World::new();
+        world.insert_resource(Tunables::default());
+        world.spawn(Window::default());
+        let projection = PerspectiveProjection {
+            fov: std::f32::consts::FRAC_PI_2,
+            aspect_ratio: 1.0,
+            ..default()
+        };
+        // What camera_system would compute for a rendered camera
+        let mut camera = Camera::default();
+        camera.computed.clip_from_view = projection.get_clip_from_view();
+        camera.computed.target_info = Some(RenderTargetInfo {
+            physical_size: UVec2::splat(800),
+            scale_factor: 1.0,
+        });
+        world.spawn((
+            Camera3d::default(),
+            camera,
+            Projection::Perspective(projection),
+            GlobalTransform::IDENTITY,
+        ));
+        let tower = world
+            .spawn((
+                Transform::from_translation(position),
+                Tower {
+                    range: 10.0,
+                    damage: 5,
+                    fire_interval_secs: 1.0,
+                    height: 2.0,
+                    width: 1.0,
+                    depth: 1.0,
+                    projectile_speed: 10.0,
+                    last_shot: 0.0,
+                },
+                BuiltTower {
+                    kind: TowerKind::Bow,
+                },
+            ))
+            .id();
+        let label = world
+            .spawn((
+                TowerDamageLabel {
+                    tower_entity: tower,
+                    world_offset: Vec3::ZERO,
+                },
+                Node::default(),
+                Visibility::Visible,
+            ))
+            .id();
+        (world, tower, label)
+    }
+
+    fn move_tower(world: &mut World, tower: Entity, position: Vec3) -> bool {
+        world.get_mut::<Transform>(tower).unwrap().translation = position;
+        world.run_system_once(frustum_cull_towers_system).unwrap();
+        world.entity(tower).contains::<FrustumCulledTower>()
+    }
+
+    #[test]
+    fn towers_are_tagged_while_inside_the_frustum() {
+        let ahead = Vec3::new(0.0, 0.0, -20.0);
+        let (mut world, tower, _) = label_world(ahead);
+        assert!(move_tower(&mut world, tower, ahead));
+        // Behind the camera, then off to the side
+        assert!(!move_tower(&mut world, tower, Vec3::new(0.0, 0.0, 20.0)));
+        assert!(!move_tower(&mut world, tower, Vec3::new(40.0, 0.0, -20.0)));
+        // Just past the edge, but close enough for the tower's top to poke into view
+        assert!(move_tower(&mut world, tower, Vec3::new(20.5, 0.0, -20.0)));
+        assert!(move_tower(&mut world, tower, ahead));
+    }
+
+    #[test]
+    fn label_of_a_tower_leaving_the_frustum_is_hidden() {
+        let ahead = Vec3::new(0.0, 0.0, -20.0);
+        let (mut world, tower, label) = label_world(ahead);
+        assert!(!move_tower(&mut world, tower, Vec3::new(0.0, 0.0, 20.0)));
+        world.run_system_once(tower_damage_label_system).unwrap();
+        assert_eq!(*world.get::<Visibility>(label).unwrap(), Visibility::Hidden);
+
+        // Shown again once the tower is back in view
+        assert!(move_tower(&mut world, tower, ahead));
+        world.run_system_once(tower_damage_label_system).unwrap();
+        assert_eq!(
+            *world.get::<Visibility>(label).unwrap(),
+            Visibility::Visible
+        );
+
+        // In view but beyond label_cull_distance, then back within it
+        let far = Vec3::new(0.0, 0.0, -(Tunables::default().label_cull_distance + 50.0));
+        assert!(move_tower(&mut world, tower, far));
+        world.run_system_once(tower_damage_label_system).unwrap();
+        assert_eq!(*world.get::<Visibility>(label).unwrap(), Visibility::Hidden);
+        assert!(move_tower(&mut world, tower, ahead));
+        world.run_system_once(tower_damage_label_system).unwrap();
+        assert_eq!(
+            *world.get::<Visibility>(label).unwrap(),
+            Visibility::Visible
+        );
+    }
+
+    #[test]
+    fn label_of_a_removed_tower_is_despawned() {
+        let (mut world, tower, label) = label_world(Vec3::new(0.0, 0.0, -20.0));
+        world.despawn(tower);
+        world.run_system_once(tower_damage_label_system).unwrap();
+        assert!(world.get_entity(label).is_err());
+    }
+}
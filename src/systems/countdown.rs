@@ -0,0 +1,130 @@
+//! Pre-game countdown: a big "5, 4, ... 1" in the middle of the screen once loading finishes.
+//! While it runs the player, tower building and the waves hold still (their systems check that
+//! `PreGameCountdown` is absent).
+
+use crate::audio::CountdownTickEvent;
+use crate::components::GameState;
+use crate::constants::Tunables;
+use bevy::prelude::*;
+
+const FONT_PATH: &str = "fonts/Luckiest_Guy/LuckiestGuy-Regular.ttf";
+const COUNTDOWN_FONT_SIZE: f32 = 160.0;
+const COUNTDOWN_INK: Color = Color::srgb(0.95, 0.95, 0.98);
+/// Scale each new number pops in at before shrinking back to 1.
+const PULSE_SCALE: f32 = 1.6;
+/// Seconds the pop of a new number takes to settle.
+const PULSE_SECS: f32 = 0.35;
+
+pub struct PreGameCountdownPlugin;
+
+impl Plugin for PreGameCountdownPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnTransition {
+                exited: GameState::Loading,
+                entered: GameState::Playing,
+            },
+            start_pre_game_countdown,
+        )
+        .add_systems(
+            Update,
+            pre_game_countdown_system
+                .run_if(in_state(GameState::Playing).and(resource_exists::<PreGameCountdown>)),
+        );
+    }
+}
+
+/// Seconds left before play begins. Present only while the countdown runs.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct PreGameCountdown {
+    pub remaining: f32,
+}
+
+#[derive(Component)]
+pub struct CountdownRoot;
+
+#[derive(Component)]
+pub struct CountdownText;
+
+/// Shows the first number and holds play until `pre_game_countdown_system` runs it down; also
+/// run by `restart_run` so a fresh run gets the same countdown.
+pub fn start_pre_game_countdown(
+    mut commands: Commands,
+    tunables: Res<Tunables>,
+    asset_server: Res<AssetServer>,
+    mut ticks: MessageWriter<CountdownTickEvent>,
+) {
+    let secs = tunables.pre_game_countdown_secs;
+    if secs <= 0.0 {
+        return;
+    }
+    commands.insert_resource(PreGameCountdown { remaining: secs });
+    let shown = secs.ceil() as u32;
+    commands
+        .spawn((
+            CountdownRoot,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            GlobalZIndex(20),
+            Pickable::IGNORE,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                CountdownText,
+                Text::new(shown.to_string()),
+                TextFont {
+                    font: asset_server.load(FONT_PATH),
+                    font_size: COUNTDOWN_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(COUNTDOWN_INK),
+                UiTransform {
+                    scale: Vec2::splat(PULSE_SCALE),
+                    ..default()
+                },
+            ));
+        });
+    ticks.write(CountdownTickEvent { remaining: shown });
+}
+
+/// Counts down, popping in each new number with a beep; removes the countdown when it runs out.
+fn pre_game_countdown_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut countdown: ResMut<PreGameCountdown>,
+    mut ticks: MessageWriter<CountdownTickEvent>,
+    mut texts: Query<(&mut Text, &mut UiTransform), With<CountdownText>>,
+    roots: Query<Entity, With<CountdownRoot>>,
+) {
+    let before = countdown.remaining.ceil() as u32;
+    countdown.remaining -= time.delta_secs();
+    if countdown.remaining <= 0.0 {
+        commands.remove_resource::<PreGameCountdown>();
+        for root in roots.iter() {
+            commands.entity(root).try_despawn();
+        }
+        return;
+    }
+
+    let shown = countdown.remaining.ceil() as u32;
+    if shown != before {
+        ticks.write(CountdownTickEvent { remaining: shown });
+    }
+    // Time into the current second drives the pop
+    let since_tick = shown as f32 - countdown.remaining;
+    let settle = (since_tick / PULSE_SECS).min(1.0);
+    let scale = PULSE_SCALE + (1.0 - PULSE_SCALE) * settle;
+    for (mut text, mut ui_tf) in texts.iter_mut() {
+        let label = shown.to_string();
+        if text.0 != label {
+            text.0 = label;
+        }
+        ui_tf.scale = Vec2::splat(scale);
+    }
+}
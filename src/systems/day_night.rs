@@ -0,0 +1,200 @@
+//! Day/night cycle: each completed wave moves the clock toward night, which dims the sun, ground
+//! and ambient light and speeds up enemy spawns. The clock returns to day every
+//! `Tunables::day_night_reset_wave` waves.
+
+use crate::audio::{DayNightChangedEvent, WaveCompletedEvent};
+use crate::components::{GameState, Ground, SunLight, WaveState};
+use crate::constants::Tunables;
+use crate::systems::ui::tooltip::Tooltip;
+use bevy::prelude::*;
+
+const FONT_PATH: &str = "fonts/Nova_Mono/NovaMono-Regular.ttf";
+/// Phase from which it counts as night (for the indicator, spawn rate and nightfall sound).
+const NIGHT_THRESHOLD: f32 = 0.5;
+/// How fast the lighting catches up with the clock, in phase per second.
+const TRANSITION_SPEED: f32 = 0.25;
+/// Ground color at full night; the day color is `Tunables::ground_color`.
+const NIGHT_GROUND_COLOR: Color = Color::srgb(0.03, 0.05, 0.09);
+/// Share of the day ambient brightness left at full night.
+const NIGHT_AMBIENT_FACTOR: f32 = 0.25;
+const SUN_COLOR: Color = Color::srgb(1.0, 0.85, 0.3);
+const MOON_COLOR: Color = Color::srgb(0.7, 0.8, 1.0);
+
+pub struct DayNightPlugin;
+
+impl Plugin for DayNightPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DayNightCycle>()
+            .add_systems(OnEnter(GameState::Loading), spawn_day_night_indicator)
+            .add_systems(
+                Update,
+                (
+                    advance_day_night,
+                    day_night_system,
+                    update_day_night_indicator,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Time of day: `current_phase` is 0.0 at day and 1.0 at full night. `displayed_phase` eases
+/// toward it and drives the lighting, so changes fade in instead of popping.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct DayNightCycle {
+    pub current_phase: f32,
+    pub displayed_phase: f32,
+    /// Day ambient brightness, captured the first time the lighting is updated.
+    day_ambient_brightness: Option<f32>,
+}
+
+impl DayNightCycle {
+    pub fn is_night(&self) -> bool {
+        self.current_phase >= NIGHT_THRESHOLD
+    }
+
+    /// Factor enemy spawn rate is multiplied by: 1.0 at day, `night_spawn_multiplier` at night.
+    pub fn spawn_rate_multiplier(&self, tunables: &Tunables) -> f32 {
+        if self.is_night() {
+            tunables.night_spawn_multiplier
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Advances the clock on every completed wave and resets it to day on each multiple of
+/// `day_night_reset_wave`, announcing nightfall and dawn.
+pub fn advance_day_night(
+    mut completed: MessageReader<WaveCompletedEvent>,
+    wave_state: Res<WaveState>,
+    tunables: Res<Tunables>,
+    mut cycle: ResMut<DayNightCycle>,
+    mut changed: MessageWriter<DayNightChangedEvent>,
+) {
+    if completed.read().count() == 0 {
+        return;
+    }
+    let was_night = cycle.is_night();
+    let reset_wave = tunables.day_night_reset_wave;
+    cycle.current_phase = if reset_wave > 0 && wave_state.current_wave.is_multiple_of(reset_wave) {
+        0.0
+    } else {
+        (cycle.current_phase + tunables.day_night_phase_per_wave).min(1.0)
+    };
+    if cycle.is_night() != was_night {
+        changed.write(DayNightChangedEvent {
+            night: cycle.is_night(),
+        });
+    }
+}
+
+/// Blends sun illuminance, ambient light and ground color between day and night.
+pub fn day_night_system(
+    time: Res<Time>,
+    tunables: Res<Tunables>,
+    mut cycle: ResMut<DayNightCycle>,
+    mut suns: Query<&mut DirectionalLight, With<SunLight>>,
+    grounds: Query<&MeshMaterial3d<StandardMaterial>, With<Ground>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut ambient: Option<ResMut<AmbientLight>>,
+) {
+    let step = TRANSITION_SPEED * time.delta_secs();
+    let target = cycle.current_phase;
+    if cycle.displayed_phase == target && cycle.day_ambient_brightness.is_some() {
+        return;
+    }
+    cycle.displayed_phase += (target - cycle.displayed_phase).clamp(-step, step);
+    let phase = cycle.displayed_phase;
+
+    for mut sun in suns.iter_mut() {
+        sun.illuminance = tunables
+            .light_illuminance
+            .lerp(tunables.night_illuminance, phase);
+    }
+    for material in grounds.iter() {
+        if let Some(mat) = materials.get_mut(&material.0) {
+            mat.base_color = tunables.ground_color.mix(&NIGHT_GROUND_COLOR, phase);
+        }
+    }
+    if let Some(ambient) = ambient.as_mut() {
+        let day = *cycle
+            .day_ambient_brightness
+            .get_or_insert(ambient.brightness);
+        ambient.brightness = day * (1.0 - (1.0 - NIGHT_AMBIENT_FACTOR) * phase);
+    }
+}
+
+#[derive(Component)]
+pub struct DayNightIndicator;
+
+#[derive(Component)]
+pub struct DayNightIndicatorText;
+
+/// Sun/moon disk with a "Day"/"Dusk"/"Night" label, right of the game speed indicator.
+fn spawn_day_night_indicator(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                // Clear of the widest speed readout ("0.25x") and above the resource counters
+                left: Val::Px(110.0),
+                top: Val::Px(28.0),
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(6.0),
+                ..default()
+            },
+            Tooltip::new(
+                "Time of day: night falls as waves pass, and enemies spawn faster at night",
+            ),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                DayNightIndicator,
+                Node {
+                    width: Val::Px(14.0),
+                    height: Val::Px(14.0),
+                    ..default()
+                },
+                BorderRadius::MAX,
+                BackgroundColor(SUN_COLOR),
+            ));
+            parent.spawn((
+                DayNightIndicatorText,
+                Text::new("Day"),
+                TextFont {
+                    font: asset_server.load(FONT_PATH),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(0.95, 0.95, 0.95, 0.9)),
+            ));
+        });
+}
+
+fn update_day_night_indicator(
+    cycle: Res<DayNightCycle>,
+    mut icons: Query<&mut BackgroundColor, With<DayNightIndicator>>,
+    mut texts: Query<&mut Text, With<DayNightIndicatorText>>,
+) {
+    if !cycle.is_changed() {
+        return;
+    }
+    let phase = cycle.displayed_phase;
+    for mut icon in icons.iter_mut() {
+        icon.0 = SUN_COLOR.mix(&MOON_COLOR, phase);
+    }
+    let label = if cycle.is_night() {
+        "Night"
+    } else if cycle.current_phase > 0.0 {
+        "Dusk"
+    } else {
+        "Day"
+    };
+    for mut text in texts.iter_mut() {
+        if text.0 != label {
+            text.0 = label.to_string();
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::components::*;
+use crate::save::{load_game, save_game};
 use bevy::input::keyboard::KeyCode;
 use bevy::input::mouse::MouseButton;
 use bevy::prelude::*;
@@ -42,6 +43,15 @@ pub fn handle_game_input(
     }
 }
 
+/// Stops the virtual clock, and with it gameplay time, while paused or in the menu.
+pub fn pause_virtual_time(mut time: ResMut<Time<Virtual>>) {
+    time.pause();
+}
+
+pub fn resume_virtual_time(mut time: ResMut<Time<Virtual>>) {
+    time.unpause();
+}
+
 pub fn pause_toggle_input(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     state: Res<State<GameState>>,
@@ -55,3 +65,51 @@ pub fn pause_toggle_input(
         }
     }
 }
+
+/// `+` / `-` speed the game up or slow it down in quarter steps, `0` returns to normal speed.
+pub fn game_speed_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut game_speed: ResMut<GameSpeed>,
+) {
+    if keyboard_input.any_just_pressed([KeyCode::Equal, KeyCode::NumpadAdd]) {
+        game_speed.step(1);
+    }
+    if keyboard_input.any_just_pressed([KeyCode::Minus, KeyCode::NumpadSubtract]) {
+        game_speed.step(-1);
+    }
+    if keyboard_input.any_just_pressed([KeyCode::Digit0, KeyCode::Numpad0]) {
+        *game_speed = GameSpeed::default();
+    }
+}
+
+/// Runs the virtual clock at the current `GameSpeed`; it takes effect from the next frame.
+pub fn apply_game_speed(game_speed: Res<GameSpeed>, mut time: ResMut<Time<Virtual>>) {
+    if game_speed.is_changed() {
+        time.set_relative_speed(game_speed.multiplier);
+    }
+}
+
+/// F5 saves the game to disk, F9 replaces it with the last save. Shift+F5/F9 belong to the
+/// chunk debug controls and are left alone.
+pub fn save_load_input(world: &mut World) {
+    let keyboard = world.resource::<ButtonInput<KeyCode>>();
+    if keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        return;
+    }
+    let (save, load) = (
+        keyboard.just_pressed(KeyCode::F5),
+        keyboard.just_pressed(KeyCode::F9),
+    );
+    if save {
+        match save_game(world) {
+            Ok(path) => info!("Game saved to {:?}", path),
+            Err(e) => warn!("Saving failed: {}", e),
+        }
+    }
+    if load {
+        match load_game(world) {
+            Ok(()) => info!("Game loaded"),
+            Err(e) => warn!("Loading failed: {}", e),
+        }
+    }
+}
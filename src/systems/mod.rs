@@ -1,9 +1,29 @@
+pub mod accessibility;
 pub mod camera;
+pub mod celebration;
+pub mod choke_advisor;
 pub mod combat;
+pub mod countdown;
+pub mod day_night;
 pub mod input;
 pub mod movement;
+#[cfg(feature = "online")]
+pub mod online_leaderboard;
+#[cfg(feature = "devtools")]
+pub mod path_debug;
+pub mod pathfinding;
+pub mod replay;
+pub mod restart;
+pub mod road_surface;
+pub mod screenshots;
+pub mod spawn_indicators;
 pub mod tree_collection;
+#[cfg(debug_assertions)]
+pub mod tunables_reload;
 pub mod ui;
+pub mod village_shield;
+pub mod wall_breach;
+pub mod wind;
 pub mod window;
 // world module removed
 pub mod chunks;
@@ -1,12 +1,20 @@
-use crate::audio::PlayerFootstepEvent;
+use crate::audio::{PlayerFootstepEvent, SurfaceKind};
 use crate::components::*;
 use crate::constants::Tunables;
-use crate::core::geometry::direction_xz;
+use crate::core::geometry::{direction_xz, distance_to_polyline_xz};
+use crate::core::replay::{ReplayPlayback, ReplayRecorder};
+use crate::events::VillageDamaged;
 use crate::systems::combat::projectiles::EnemyFadeOut;
+use crate::systems::resource_passes::TrafficHeatmap;
+use crate::systems::wall_breach::outside_town;
 use bevy::input::keyboard::KeyCode;
 use bevy::prelude::*;
+use std::collections::HashMap;
 
-/// Moves the player using WASD/arrow keys at a fixed speed.
+/// Moves the player using WASD/arrow keys at a fixed speed; nothing moves while Ctrl is held.
+/// During replay playback the recorded direction and frame time are used instead; while
+/// recording, both are logged.
+#[allow(clippy::too_many_arguments)]
 pub fn player_movement(
     time: Res<Time>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
@@ -15,39 +23,67 @@ pub fn player_movement(
     mut log_accumulator: Local<f32>,
     mut step_accumulator: Local<f32>,
     mut footstep_events: MessageWriter<PlayerFootstepEvent>,
+    roads: Option<Res<RoadPaths>>,
+    mut recorder: Option<ResMut<ReplayRecorder>>,
+    playback: Option<Res<ReplayPlayback>>,
 ) {
     if let Ok(mut transform) = player_query.single_mut() {
         let mut direction = Vec3::ZERO;
+        let mut delta_secs = time.delta_secs();
 
-        if keyboard_input.pressed(KeyCode::KeyW) || keyboard_input.pressed(KeyCode::ArrowUp) {
-            direction.z -= 1.0;
-        }
-        if keyboard_input.pressed(KeyCode::KeyS) || keyboard_input.pressed(KeyCode::ArrowDown) {
-            direction.z += 1.0;
-        }
-        if keyboard_input.pressed(KeyCode::KeyA) || keyboard_input.pressed(KeyCode::ArrowLeft) {
-            direction.x -= 1.0;
+        if let Some(frame) = playback.as_ref().and_then(|playback| playback.current()) {
+            direction = Vec3::new(frame.movement.x, 0.0, frame.movement.y);
+            delta_secs = frame.delta_secs;
+        } else if playback.is_none()
+            // Ctrl+S toggles spectator mode and must not walk the player as well
+            && !keyboard_input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+        {
+            if keyboard_input.pressed(KeyCode::KeyW) || keyboard_input.pressed(KeyCode::ArrowUp) {
+                direction.z -= 1.0;
+            }
+            if keyboard_input.pressed(KeyCode::KeyS) || keyboard_input.pressed(KeyCode::ArrowDown) {
+                direction.z += 1.0;
+            }
+            if keyboard_input.pressed(KeyCode::KeyA) || keyboard_input.pressed(KeyCode::ArrowLeft) {
+                direction.x -= 1.0;
+            }
+            if keyboard_input.pressed(KeyCode::KeyD) || keyboard_input.pressed(KeyCode::ArrowRight)
+            {
+                direction.x += 1.0;
+            }
         }
-        if keyboard_input.pressed(KeyCode::KeyD) || keyboard_input.pressed(KeyCode::ArrowRight) {
-            direction.x += 1.0;
+
+        direction = direction.normalize_or_zero();
+        if let Some(recorder) = recorder.as_mut() {
+            recorder.record_movement(delta_secs, direction.xz());
         }
 
         if direction.length() > 0.0 {
-            direction = direction.normalize();
-            transform.translation += direction * tunables.player_speed * time.delta_secs();
+            transform.translation += direction * tunables.player_speed * delta_secs;
 
             // Footstep: emit at a regular cadence while moving
-            *step_accumulator += time.delta_secs();
+            *step_accumulator += delta_secs;
             let step_interval = 0.4_f32; // seconds per step (generic surface)
             if *step_accumulator >= step_interval {
+                let on_road = roads.as_ref().is_some_and(|roads| {
+                    roads.roads.iter().any(|road| {
+                        distance_to_polyline_xz(transform.translation, road)
+                            <= tunables.road_width * 0.5
+                    })
+                });
                 footstep_events.write(PlayerFootstepEvent {
                     position: transform.translation,
+                    surface: if on_road {
+                        SurfaceKind::Road
+                    } else {
+                        SurfaceKind::Grass
+                    },
                 });
                 *step_accumulator = 0.0;
             }
 
             // Debug: Log player position every few seconds without unsafe statics
-            *log_accumulator += time.delta_secs();
+            *log_accumulator += delta_secs;
             if *log_accumulator > 2.0 {
                 if cfg!(debug_assertions) {
                     info!("Player position: {:?}", transform.translation);
@@ -58,23 +94,46 @@ pub fn player_movement(
     }
 }
 
-/// Moves enemies along roads (if assigned) or toward the village center.
-#[allow(dead_code)]
+/// Members close to their slot move a bit faster than their own speed to keep up with the leader.
+const FORMATION_CATCH_UP: f32 = 1.25;
+
+/// Moves enemies along roads (if assigned) or toward the village center. Enemies outside the
+/// town head for the nearest wall breach instead when it is closer than their road's gate, and
+/// enemies attacking a wall stand still. Formation members are moved after everyone else so they
+/// can follow their leader's new position.
+#[allow(dead_code, clippy::too_many_arguments, clippy::type_complexity)]
 pub fn enemy_movement(
     time: Res<Time>,
     mut commands: Commands,
     mut enemy_query: Query<
-        (Entity, &mut Transform, &Enemy, Option<&mut PathFollower>),
-        Without<EnemyFadeOut>,
+        (
+            Entity,
+            &mut Transform,
+            &Enemy,
+            Option<&mut PathFollower>,
+            Option<&mut DynamicPath>,
+            Option<&mut BouncedBack>,
+            Option<&FormationMember>,
+            Option<&SlowEffect>,
+            Option<&SwarmBoost>,
+            Has<StunEffect>,
+        ),
+        (Without<EnemyFadeOut>, Without<AttackingWall>),
     >,
+    breaches: Query<&WallBreachMarker, With<WallBreached>>,
     // Split queries to avoid Transform access conflicts; ensure disjoint via Without<Enemy>
     village_tf_query: Query<&Transform, (With<TownCenter>, Without<Enemy>)>,
     mut village_query: Query<&mut Village, With<TownCenter>>,
     roads: Option<Res<RoadPaths>>,
     tunables: Res<Tunables>,
+    shield: Res<VillageShield>,
+    mut heatmap: ResMut<TrafficHeatmap>,
+    mut village_damaged: MessageWriter<VillageDamaged>,
 ) {
     // Collision radius for village impact
     let village_collision_radius = tunables.village_collision_radius;
+    let delta = time.delta();
+    let dt = delta.as_secs_f32();
 
     // Resolve current village/base position once (assumes single TownCenter)
     let village_pos = village_tf_query
@@ -82,61 +141,436 @@ pub fn enemy_movement(
         .map(|tf| tf.translation)
         .unwrap_or(Vec3::ZERO);
 
-    for (entity, mut transform, enemy, follower_opt) in enemy_query.iter_mut() {
-        if let (Some(roads), Some(mut follower)) = (&roads, follower_opt) {
-            if let Some(road) = roads.roads.get(follower.road_index) {
-                if follower.next_index < road.len() {
-                    let target = road[follower.next_index];
-                    let dir = direction_xz(transform.translation, target);
-                    transform.translation += dir * enemy.speed * time.delta_secs();
-                    // Advance waypoint when close
-                    if Vec2::new(
+    // Position, heading and road progress of every enemy moved in the first pass
+    let mut leaders: HashMap<Entity, (Vec3, Vec3, Option<usize>)> = HashMap::new();
+    let break_distance_sq = tunables.formation_break_distance * tunables.formation_break_distance;
+    let breach_entries: Vec<Vec3> = breaches.iter().map(|breach| breach.entry).collect();
+
+    for members_pass in [false, true] {
+        for (
+            entity,
+            mut transform,
+            enemy,
+            follower_opt,
+            dynamic_opt,
+            bounce_opt,
+            member_opt,
+            slow_opt,
+            swarm_opt,
+            stunned,
+        ) in enemy_query.iter_mut()
+        {
+            if member_opt.is_some() != members_pass {
+                continue;
+            }
+            let speed = if stunned {
+                0.0
+            } else {
+                enemy.speed
+                    * slow_opt.map_or(1.0, |slow| slow.multiplier)
+                    * swarm_opt.map_or(1.0, |swarm| swarm.multiplier)
+            };
+
+            // Bounced off the village shield: walk away from the village until the timer ends
+            if let Some(mut bounce) = bounce_opt {
+                bounce.timer.tick(delta);
+                let dir = direction_xz(village_pos, transform.translation);
+                transform.translation += dir * speed * dt;
+                if bounce.timer.is_finished() {
+                    commands.entity(entity).remove::<BouncedBack>();
+                }
+                continue;
+            }
+
+            let start = transform.translation;
+            let mut follower_opt = follower_opt;
+            let road = roads
+                .as_ref()
+                .zip(follower_opt.as_ref())
+                .and_then(|(roads, follower)| roads.roads.get(follower.road_index));
+            // Nearest breach, for enemies still outside the walls; road walkers only take it when
+            // it is closer than the gate their road ends at
+            let gate = road
+                .zip(follower_opt.as_ref())
+                .filter(|(road, follower)| follower.next_index < road.len())
+                .and_then(|(road, _)| road.last());
+            let breach = breach_entries
+                .iter()
+                .copied()
+                .filter(|_| outside_town(start, &tunables))
+                .min_by(|a, b| {
+                    a.xz()
+                        .distance_squared(start.xz())
+                        .total_cmp(&b.xz().distance_squared(start.xz()))
+                })
+                .filter(|entry| {
+                    gate.is_none_or(|gate| {
+                        entry.xz().distance_squared(start.xz())
+                            < gate.xz().distance_squared(start.xz())
+                    })
+                });
+            let leader = member_opt.and_then(|member| {
+                leaders
+                    .get(&member.leader)
+                    .map(|&(pos, heading, road_next)| (member, pos, heading, road_next))
+            });
+
+            if let Some((member, leader_pos, heading, road_next)) = leader {
+                let right = heading.cross(Vec3::Y);
+                let slot = leader_pos + right * member.offset.x + heading * member.offset.y;
+                let to_slot = Vec3::new(slot.x - start.x, 0.0, slot.z - start.z);
+                let step = speed * FORMATION_CATCH_UP * dt;
+                transform.translation += to_slot.clamp_length_max(step);
+                transform.rotation = Quat::from_rotation_arc(Vec3::NEG_Z, heading);
+
+                // Too far behind: leave the formation and continue along the leader's road progress
+                let to_leader = Vec2::new(leader_pos.x - start.x, leader_pos.z - start.z);
+                if to_leader.length_squared() > break_distance_sq {
+                    commands.entity(entity).remove::<FormationMember>();
+                    if let (Some(follower), Some(next)) = (follower_opt.as_mut(), road_next) {
+                        follower.next_index = next;
+                    }
+                }
+            } else if let Some(mut dynamic) = dynamic_opt {
+                // Rerouted path takes precedence over the road
+                let target = dynamic
+                    .waypoints
+                    .get(dynamic.next_index)
+                    .copied()
+                    .unwrap_or(village_pos);
+                let dir = direction_xz(transform.translation, target);
+                transform.translation += dir * speed * dt;
+                if dynamic.next_index < dynamic.waypoints.len()
+                    && Vec2::new(
                         target.x - transform.translation.x,
                         target.z - transform.translation.z,
                     )
                     .length()
                         < 1.0
-                    {
-                        follower.next_index += 1;
+                {
+                    dynamic.next_index += 1;
+                }
+            } else if let Some(entry) = breach {
+                // Off the road for good: once through the breach it's straight on to the village
+                if let (Some(road), Some(follower)) = (road, follower_opt.as_mut()) {
+                    follower.next_index = road.len();
+                    follower.path_t = 0.0;
+                }
+                let dir = direction_xz(transform.translation, entry);
+                transform.translation += dir * speed * dt;
+            } else if let (Some(road), Some(follower)) = (road, follower_opt.as_mut())
+                && follower.next_index < road.len()
+            {
+                let target = road[follower.next_index];
+                // Past the last waypoint the road continues to the village itself
+                let following = road
+                    .get(follower.next_index + 1)
+                    .copied()
+                    .unwrap_or(village_pos);
+                let to_target = Vec2::new(
+                    target.x - transform.translation.x,
+                    target.z - transform.translation.z,
+                )
+                .length();
+                // Ease into the next leg near the waypoint instead of turning on the spot
+                let blend = (1.0 - to_target / tunables.waypoint_arrival_radius).clamp(0.0, 1.0);
+                let dir = direction_xz(transform.translation, target)
+                    .lerp(direction_xz(target, following), blend)
+                    .normalize_or_zero();
+                transform.translation += dir * speed * dt;
+
+                follower.path_t = match follower.next_index.checked_sub(1).map(|i| road[i]) {
+                    Some(previous) => {
+                        let segment = (target - previous).with_y(0.0);
+                        let progress = (transform.translation - previous).with_y(0.0);
+                        (progress.dot(segment) / segment.length_squared().max(f32::EPSILON))
+                            .clamp(0.0, 1.0)
                     }
-                } else {
-                    // Finished following the road, now move to the actual village position
-                    let dir = direction_xz(transform.translation, village_pos);
-                    transform.translation += dir * enemy.speed * time.delta_secs();
+                    None => 1.0 - blend,
+                };
+                // Corner cutting may never come within 1.0 of the waypoint, so passing it counts
+                if follower.path_t >= 1.0 || to_target < 1.0 {
+                    follower.next_index += 1;
+                    follower.path_t = 0.0;
                 }
+            } else {
+                // Past the road's end, on a missing road or with no road: head for the village
+                let dir = direction_xz(transform.translation, village_pos);
+                transform.translation += dir * speed * dt;
             }
-        } else {
-            // Fallback: Move towards the actual village position
-            let dir = direction_xz(transform.translation, village_pos);
-            transform.translation += dir * enemy.speed * time.delta_secs();
-        }
 
-        // Check if enemy actually hit the village block (much more precise collision)
-        let dx = transform.translation.x - village_pos.x;
-        let dz = transform.translation.z - village_pos.z;
-        if Vec2::new(dx, dz).length() < village_collision_radius {
-            if let Ok(mut village) = village_query.single_mut() {
-                village.health = village.health.saturating_sub(enemy.damage);
-                if cfg!(debug_assertions) {
-                    info!(
-                        "Village hit! Health remaining: {}/{}",
-                        village.health, village.max_health
-                    );
-                }
+            if !members_pass {
+                // Face the direction of travel; members share their leader's heading instead
+                let moved = Vec3::new(
+                    transform.translation.x - start.x,
+                    0.0,
+                    transform.translation.z - start.z,
+                );
+                let heading = moved.try_normalize().unwrap_or_else(|| {
+                    leaders
+                        .get(&entity)
+                        .map(|&(_, heading, _)| heading)
+                        .unwrap_or(Vec3::NEG_Z)
+                });
+                let facing = Quat::from_rotation_arc(Vec3::NEG_Z, heading);
+                let turn = (tunables.enemy_turn_speed * dt).min(1.0);
+                transform.rotation = transform.rotation.slerp(facing, turn);
+                let road_next = follower_opt.as_ref().map(|follower| follower.next_index);
+                leaders.insert(entity, (transform.translation, heading, road_next));
+            }
+
+            // Only road cells wear; enemies cutting across the grass leave no trace
+            let on_road = road.is_some_and(|road| {
+                distance_to_polyline_xz(transform.translation, road) <= tunables.road_width * 0.5
+            });
+            if on_road {
+                heatmap.record(transform.translation, tunables.road_width, dt);
+            }
 
-                // Reset village health when destroyed (for easier testing)
-                if village.health == 0 {
-                    village.health = village.max_health;
+            // Check if enemy actually hit the village block (much more precise collision)
+            let dx = transform.translation.x - village_pos.x;
+            let dz = transform.translation.z - village_pos.z;
+            if Vec2::new(dx, dz).length() < village_collision_radius {
+                if shield.active {
+                    commands.entity(entity).insert(BouncedBack {
+                        timer: Timer::from_seconds(tunables.shield_bounce_secs, TimerMode::Once),
+                    });
+                    continue;
+                }
+                if let Ok(mut village) = village_query.single_mut() {
+                    village.health = village.health.saturating_sub(enemy.damage);
+                    village_damaged.write(VillageDamaged {
+                        amount: enemy.damage,
+                        remaining: village.health,
+                    });
                     if cfg!(debug_assertions) {
                         info!(
-                            "Village destroyed! Resetting health to {}",
-                            village.max_health
+                            "Village hit! Health remaining: {}/{}",
+                            village.health, village.max_health
                         );
                     }
                 }
+                // Despawn enemy when it actually hits the village
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
+
+/// Promotes the member closest to a fallen (dying or despawned) leader so the group keeps moving
+/// together; the remaining members re-attach to the new leader. The new leader carries on from
+/// the old one's road progress, or from the nearest stretch of its road if the old one is gone.
+#[allow(clippy::type_complexity)]
+pub fn formation_leader_promotion_system(
+    mut commands: Commands,
+    living: Query<(), (With<Enemy>, Without<EnemyFadeOut>)>,
+    transforms: Query<&Transform, With<Enemy>>,
+    mut members: Query<(Entity, &mut FormationMember)>,
+    mut followers: Query<&mut PathFollower>,
+    roads: Option<Res<RoadPaths>>,
+) {
+    let mut orphans: HashMap<Entity, Vec<(Entity, Vec3)>> = HashMap::new();
+    for (entity, member) in members.iter() {
+        if living.contains(member.leader) {
+            continue;
+        }
+        let Ok(transform) = transforms.get(entity) else {
+            continue;
+        };
+        orphans
+            .entry(member.leader)
+            .or_default()
+            .push((entity, transform.translation));
+    }
+
+    for (old_leader, group) in orphans {
+        // Use the fallen leader's last position if it is still fading out; else the group centroid
+        let anchor = transforms
+            .get(old_leader)
+            .map(|tf| tf.translation)
+            .unwrap_or_else(|_| {
+                group.iter().map(|(_, pos)| *pos).sum::<Vec3>() / group.len() as f32
+            });
+        let Some(&(new_leader, new_leader_pos)) = group.iter().min_by(|a, b| {
+            a.1.distance_squared(anchor)
+                .total_cmp(&b.1.distance_squared(anchor))
+        }) else {
+            continue;
+        };
+
+        // Members never advance their own road progress while in formation
+        let leader_progress = followers.get(old_leader).ok().copied();
+        if let Ok(mut follower) = followers.get_mut(new_leader) {
+            match leader_progress {
+                Some(progress) => {
+                    follower.next_index = progress.next_index;
+                    follower.path_t = progress.path_t;
+                }
+                None => {
+                    let road = roads
+                        .as_ref()
+                        .and_then(|roads| roads.roads.get(follower.road_index));
+                    if let Some(next_index) = road.and_then(|road| {
+                        (1..road.len()).min_by(|&a, &b| {
+                            let distance = |i: usize| {
+                                distance_to_polyline_xz(new_leader_pos, &road[i - 1..=i])
+                            };
+                            distance(a).total_cmp(&distance(b))
+                        })
+                    }) {
+                        follower.next_index = next_index;
+                        follower.path_t = 0.0;
+                    }
+                }
             }
-            // Despawn enemy when it actually hits the village
-            commands.entity(entity).despawn();
         }
+        commands.entity(new_leader).remove::<FormationMember>();
+        for (entity, _) in group {
+            if entity == new_leader {
+                continue;
+            }
+            if let Ok((_, mut member)) = members.get_mut(entity) {
+                member.leader = new_leader;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    /// Everything `enemy_movement` reads apart from the clock; the village sits at the origin.
+    fn insert_movement_resources(world: &mut World) {
+        let tunables = Tunables::default();
+        world.insert_resource(VillageShield::new(tunables.shield_duration_secs));
+        world.insert_resource(tunables);
+        world.init_resource::<TrafficHeatmap>();
+        world.init_resource::<Messages<VillageDamaged>>();
+    }
+
+    fn movement_world() -> World {
+        let mut world = World::new();
+        insert_movement_resources(&mut world);
+        world.insert_resource(Time::<()>::default());
+        world
+    }
+
+    fn spawn_enemy(world: &mut World, position: Vec3) -> Entity {
+        world
+            .spawn((
+                Transform::from_translation(position),
+                Enemy {
+                    health: 10,
+                    max_health: 10,
+                    speed: 4.0,
+                    damage: 1,
+                },
+            ))
+            .id()
+    }
+
+    /// Distance `entity` covered towards the village in one `secs` long frame.
+    fn distance_moved(world: &mut World, entity: Entity, secs: f32) -> f32 {
+        let before = world.get::<Transform>(entity).unwrap().translation;
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(secs));
+        world.run_system_once(enemy_movement).unwrap();
+        before.distance(world.get::<Transform>(entity).unwrap().translation)
+    }
+
+    #[test]
+    fn slow_effect_scales_enemy_speed() {
+        let mut world = movement_world();
+        let normal = spawn_enemy(&mut world, Vec3::new(40.0, 0.0, 0.0));
+        let slowed = spawn_enemy(&mut world, Vec3::new(-40.0, 0.0, 0.0));
+        world.entity_mut(slowed).insert(SlowEffect {
+            multiplier: 0.5,
+            timer: Some(Timer::from_seconds(1.0, TimerMode::Once)),
+        });
+
+        let before_normal = world.get::<Transform>(normal).unwrap().translation;
+        let moved_slowed = distance_moved(&mut world, slowed, 0.5);
+        let moved_normal =
+            before_normal.distance(world.get::<Transform>(normal).unwrap().translation);
+        assert!((moved_normal - 2.0).abs() < 1e-4);
+        assert!((moved_slowed - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn untimed_slow_applies_like_a_timed_one() {
+        let mut world = movement_world();
+        let enemy = spawn_enemy(&mut world, Vec3::new(0.0, 0.0, 40.0));
+        world.entity_mut(enemy).insert(SlowEffect {
+            multiplier: 0.25,
+            timer: None,
+        });
+        assert!((distance_moved(&mut world, enemy, 1.0) - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn stunned_enemy_stays_put_even_when_slowed() {
+        let mut world = movement_world();
+        let enemy = spawn_enemy(&mut world, Vec3::new(0.0, 0.0, -40.0));
+        world.entity_mut(enemy).insert((
+            SlowEffect {
+                multiplier: 0.5,
+                timer: None,
+            },
+            StunEffect {
+                timer: Timer::from_seconds(1.0, TimerMode::Once),
+            },
+        ));
+        assert_eq!(distance_moved(&mut world, enemy, 0.5), 0.0);
+    }
+
+    #[test]
+    fn game_speed_scales_enemy_movement() {
+        let mut app = App::new();
+        app.add_plugins(bevy::time::TimePlugin)
+            .insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+                Duration::from_secs_f32(0.25),
+            ))
+            .insert_resource(GameSpeed { multiplier: 2.0 })
+            .add_systems(
+                Update,
+                (crate::systems::input::apply_game_speed, enemy_movement).chain(),
+            );
+        insert_movement_resources(app.world_mut());
+        let enemy = spawn_enemy(app.world_mut(), Vec3::new(40.0, 0.0, 0.0));
+
+        // The first frame has no delta; the speed set during it applies from the next one
+        app.update();
+        app.update();
+        let moved = 40.0 - app.world().get::<Transform>(enemy).unwrap().translation.x;
+        // A quarter second of real time is half a second of game time at 4 units per second
+        assert!((moved - 2.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn only_enemies_on_a_road_wear_it_by_the_second() {
+        let mut world = movement_world();
+        world.insert_resource(RoadPaths {
+            roads: vec![vec![Vec3::new(100.0, 0.0, 0.0), Vec3::new(50.0, 0.0, 0.0)]],
+        });
+        let walker = spawn_enemy(&mut world, Vec3::new(90.0, 0.0, 0.0));
+        world.entity_mut(walker).insert(PathFollower {
+            road_index: 0,
+            next_index: 1,
+            path_t: 0.0,
+        });
+        spawn_enemy(&mut world, Vec3::new(0.0, 0.0, 60.0));
+
+        for _ in 0..4 {
+            distance_moved(&mut world, walker, 0.25);
+        }
+        let heatmap = world.resource::<TrafficHeatmap>();
+        let total: f32 = heatmap.counts.values().sum();
+        assert!((total - 1.0).abs() < 1e-4);
+        assert!(heatmap.counts.keys().all(|&(_, z)| z == 0 || z == -1));
     }
 }
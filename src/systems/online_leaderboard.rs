@@ -0,0 +1,259 @@
+use crate::components::{GameState, WaveState};
+use crate::constants::Tunables;
+use crate::events::VillageDamaged;
+use bevy::prelude::*;
+use bevy::tasks::{IoTaskPool, Task, block_on, futures_lite::future};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+/// Results arriving later than this after game over are discarded.
+const RESULT_DEADLINE_SECS: f32 = 5.0;
+/// Connect/read/write timeout for each HTTP request.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Submits the run's score to `Tunables.leaderboard_url` on game over and fetches the global
+/// top 10. Nothing is sent until the player has chosen a `Tunables.leaderboard_name`. Only plain
+/// `http://` endpoints are supported; every failure is logged and ignored.
+///
+/// Expected API: `POST <url>` with `{"name", "seed", "score"}` answers `{"rank": N}`, and
+/// `GET <url>` answers a JSON array of `{"name", "score"}` sorted best first.
+pub struct OnlineLeaderboardPlugin;
+
+impl Plugin for OnlineLeaderboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<OnlineLeaderboardData>()
+            .init_resource::<OnlineLeaderboardRequest>()
+            .add_systems(
+                Update,
+                (submit_score_on_game_over, poll_online_leaderboard).chain(),
+            )
+            .add_systems(OnExit(GameState::GameOver), hide_global_ranking);
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LeaderboardEntry {
+    pub name: String,
+    pub score: u32,
+}
+
+/// Latest global leaderboard fetched from the server.
+#[derive(Resource, Default, Debug)]
+pub struct OnlineLeaderboardData {
+    pub top_scores: Vec<LeaderboardEntry>,
+    pub rank: Option<u32>,
+}
+
+#[derive(Serialize)]
+struct ScoreSubmission<'a> {
+    name: &'a str,
+    seed: u64,
+    score: u32,
+}
+
+#[derive(Deserialize)]
+struct SubmissionResponse {
+    rank: Option<u32>,
+}
+
+/// Outcome of the submission (the player's rank) and of the top 10 fetch, each on its own.
+type LeaderboardResult = (
+    Result<Option<u32>, String>,
+    Result<Vec<LeaderboardEntry>, String>,
+);
+
+/// In-flight leaderboard requests; at most one submission per run.
+#[derive(Resource, Default)]
+pub struct OnlineLeaderboardRequest {
+    task: Option<Task<LeaderboardResult>>,
+    started_at: f32,
+    submitted: bool,
+}
+
+#[derive(Component)]
+pub struct GlobalRankingText;
+
+/// Starts the submit + fetch task the first time the village falls, if the player opted in by
+/// setting a leaderboard name.
+fn submit_score_on_game_over(
+    time: Res<Time<Real>>,
+    tunables: Res<Tunables>,
+    wave_state: Res<WaveState>,
+    mut village_damaged: MessageReader<VillageDamaged>,
+    mut request: ResMut<OnlineLeaderboardRequest>,
+) {
+    let game_over = village_damaged.read().any(|e| e.remaining == 0);
+    let name = tunables.leaderboard_name.trim().to_string();
+    if !game_over || request.submitted || tunables.leaderboard_url.is_empty() || name.is_empty() {
+        return;
+    }
+    request.submitted = true;
+    request.started_at = time.elapsed_secs();
+
+    let url = tunables.leaderboard_url.clone();
+    let seed = tunables.world_seed;
+    let score = wave_state.current_wave;
+    request.task = Some(IoTaskPool::get().spawn(async move {
+        // A failed submission still shows the global top 10, and the other way round
+        let rank = submit_score(&url, &name, seed, score);
+        let top_scores = fetch_top_scores(&url);
+        (rank, top_scores)
+    }));
+}
+
+/// Posts the score and returns the rank the server gave it.
+fn submit_score(url: &str, name: &str, seed: u64, score: u32) -> Result<Option<u32>, String> {
+    let submission =
+        serde_json::to_string(&ScoreSubmission { name, seed, score }).map_err(|e| e.to_string())?;
+    let response = http_request("POST", url, Some(&submission))?;
+    serde_json::from_str::<SubmissionResponse>(&response)
+        .map(|response| response.rank)
+        .map_err(|e| e.to_string())
+}
+
+/// Fetches the best 10 scores.
+fn fetch_top_scores(url: &str) -> Result<Vec<LeaderboardEntry>, String> {
+    let top = http_request("GET", url, None)?;
+    let mut top_scores: Vec<LeaderboardEntry> =
+        serde_json::from_str(&top).map_err(|e| e.to_string())?;
+    top_scores.truncate(10);
+    Ok(top_scores)
+}
+
+/// Stores finished results and shows the player's global ranking if it arrived in time.
+fn poll_online_leaderboard(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    asset_server: Res<AssetServer>,
+    mut request: ResMut<OnlineLeaderboardRequest>,
+    mut data: ResMut<OnlineLeaderboardData>,
+) {
+    let Some(task) = request.task.as_mut() else {
+        return;
+    };
+    let Some(result) = block_on(future::poll_once(task)) else {
+        return;
+    };
+    request.task = None;
+
+    let (rank, top_scores) = result;
+    match top_scores {
+        Ok(top_scores) => {
+            if cfg!(debug_assertions) {
+                for (i, entry) in top_scores.iter().enumerate() {
+                    info!("Global #{}: {} (wave {})", i + 1, entry.name, entry.score);
+                }
+            }
+            data.top_scores = top_scores;
+        }
+        Err(e) => warn!("Fetching the online leaderboard failed: {}", e),
+    }
+    let rank = match rank {
+        Ok(rank) => rank,
+        Err(e) => {
+            warn!(
+                "Submitting the score to the online leaderboard failed: {}",
+                e
+            );
+            return;
+        }
+    };
+    data.rank = rank;
+
+    if time.elapsed_secs() - request.started_at > RESULT_DEADLINE_SECS {
+        return;
+    }
+    let Some(rank) = rank else {
+        return;
+    };
+    commands.spawn((
+        GlobalRankingText,
+        Text::new(format!("Global Ranking: #{}", rank)),
+        TextFont {
+            font: asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+            font_size: 28.0,
+            ..default()
+        },
+        TextColor(Color::srgba(0.97, 0.975, 0.965, 1.0)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(40.0),
+            left: Val::Percent(40.0),
+            ..default()
+        },
+    ));
+}
+
+impl OnlineLeaderboardRequest {
+    /// Lets the next run submit its own score; called by `restart_run`, so keeping on playing
+    /// after game over doesn't submit the same run twice. A request still in flight is dropped,
+    /// which cancels it, so the last run's rank never shows up in the new one.
+    pub fn reset_for_next_run(&mut self) {
+        self.task = None;
+        self.submitted = false;
+    }
+}
+
+/// The ranking belongs to the game over screen and leaves with it.
+pub fn hide_global_ranking(mut commands: Commands, texts: Query<Entity, With<GlobalRankingText>>) {
+    for text in texts.iter() {
+        commands.entity(text).try_despawn();
+    }
+}
+
+/// Minimal blocking HTTP/1.0 client (HTTP/1.0 so responses are never chunked).
+/// Returns the response body for 2xx statuses.
+fn http_request(method: &str, url: &str, body: Option<&str>) -> Result<String, String> {
+    let rest = url
+        .strip_prefix("http://")
+        .ok_or_else(|| format!("unsupported leaderboard URL (only http:// works): {}", url))?;
+    let (host_port, path) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/"),
+    };
+    let (host, port) = match host_port.rsplit_once(':') {
+        Some((host, port)) => (host, port.parse::<u16>().map_err(|e| e.to_string())?),
+        None => (host_port, 80),
+    };
+
+    let addr = (host, port)
+        .to_socket_addrs()
+        .map_err(|e| e.to_string())?
+        .next()
+        .ok_or_else(|| format!("could not resolve {}", host))?;
+    let mut stream =
+        TcpStream::connect_timeout(&addr, REQUEST_TIMEOUT).map_err(|e| e.to_string())?;
+    stream
+        .set_read_timeout(Some(REQUEST_TIMEOUT))
+        .and_then(|_| stream.set_write_timeout(Some(REQUEST_TIMEOUT)))
+        .map_err(|e| e.to_string())?;
+
+    let body = body.unwrap_or("");
+    let request = format!(
+        "{method} {path} HTTP/1.0\r\nHost: {host_port}\r\nAccept: application/json\r\n\
+         Content-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+        body.len()
+    );
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|e| e.to_string())?;
+    let mut response = String::new();
+    stream
+        .read_to_string(&mut response)
+        .map_err(|e| e.to_string())?;
+
+    let (head, payload) = response
+        .split_once("\r\n\r\n")
+        .ok_or_else(|| "malformed HTTP response".to_string())?;
+    let status = head
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| "missing HTTP status".to_string())?;
+    if !(200..300).contains(&status) {
+        return Err(format!("{} {} returned HTTP {}", method, url, status));
+    }
+    Ok(payload.to_string())
+}
@@ -0,0 +1,178 @@
+//! Road path debug overlay (devtools builds, Ctrl+P): every waypoint of `RoadPaths` as a small
+//! white sphere, consecutive waypoints joined by lines, and the waypoint each enemy is walking
+//! toward (`PathFollower::next_index`) in yellow.
+
+use crate::components::{DynamicPath, Enemy, GameState, PathFollower, RoadPaths};
+use bevy::asset::RenderAssetUsages;
+use bevy::pbr::MeshMaterial3d;
+use bevy::prelude::*;
+use bevy::render::render_resource::PrimitiveTopology;
+use std::collections::HashSet;
+
+const WAYPOINT_RADIUS: f32 = 0.15;
+/// Height above the road the overlay floats at, so the road surface doesn't hide it.
+const OVERLAY_HEIGHT: f32 = 0.3;
+const WAYPOINT_COLOR: Color = Color::WHITE;
+const TARGET_COLOR: Color = Color::srgb(1.0, 0.9, 0.1);
+const LINE_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.6);
+
+pub struct PathDebugPlugin;
+
+impl Plugin for PathDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PathDebugOverlay>().add_systems(
+            Update,
+            (
+                path_debug_toggle_input,
+                manage_path_debug_markers,
+                highlight_enemy_targets,
+            )
+                .chain()
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// Whether the overlay is shown, and the materials its markers switch between.
+#[derive(Resource, Default)]
+pub struct PathDebugOverlay {
+    pub visible: bool,
+    waypoint_material: Option<Handle<StandardMaterial>>,
+    target_material: Option<Handle<StandardMaterial>>,
+}
+
+/// Sphere on waypoint `index` of road `road`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PathDebugMarker {
+    pub road: usize,
+    pub index: usize,
+}
+
+/// Line mesh joining the waypoints of one road.
+#[derive(Component)]
+pub struct PathDebugLine;
+
+/// Ctrl+P shows or hides the overlay.
+pub fn path_debug_toggle_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut overlay: ResMut<PathDebugOverlay>,
+) {
+    let ctrl = keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    if ctrl && keyboard.just_pressed(KeyCode::KeyP) {
+        overlay.visible = !overlay.visible;
+    }
+}
+
+/// Spawns the markers and lines when the overlay is switched on (or the roads change) and
+/// removes them when it is switched off.
+pub fn manage_path_debug_markers(
+    mut commands: Commands,
+    mut overlay: ResMut<PathDebugOverlay>,
+    roads: Option<Res<RoadPaths>>,
+    markers: Query<Entity, Or<(With<PathDebugMarker>, With<PathDebugLine>)>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let roads = roads.filter(|_| overlay.visible);
+    let stale = roads.as_ref().is_none_or(|roads| roads.is_changed());
+    if stale {
+        for entity in markers.iter() {
+            commands.entity(entity).try_despawn();
+        }
+    }
+    let Some(roads) = roads else {
+        return;
+    };
+    if !stale && !markers.is_empty() {
+        return;
+    }
+
+    let unlit = |color: Color| StandardMaterial {
+        base_color: color,
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    };
+    let waypoint_material = overlay
+        .waypoint_material
+        .get_or_insert_with(|| materials.add(unlit(WAYPOINT_COLOR)))
+        .clone();
+    overlay
+        .target_material
+        .get_or_insert_with(|| materials.add(unlit(TARGET_COLOR)));
+    let line_material = materials.add(unlit(LINE_COLOR));
+    let sphere = meshes.add(Sphere::new(WAYPOINT_RADIUS));
+
+    for (road_index, road) in roads.roads.iter().enumerate() {
+        for (index, waypoint) in road.iter().enumerate() {
+            commands.spawn((
+                PathDebugMarker {
+                    road: road_index,
+                    index,
+                },
+                Mesh3d(sphere.clone()),
+                MeshMaterial3d(waypoint_material.clone()),
+                Transform::from_translation(waypoint.with_y(OVERLAY_HEIGHT)),
+            ));
+        }
+        if road.len() > 1 {
+            commands.spawn((
+                PathDebugLine,
+                Mesh3d(meshes.add(build_line_mesh(road))),
+                MeshMaterial3d(line_material.clone()),
+                Transform::default(),
+            ));
+        }
+    }
+}
+
+/// Colors the waypoints enemies are heading for yellow and the rest white. Enemies on a rerouted
+/// `DynamicPath` aren't heading for a road waypoint and are left out.
+#[allow(clippy::type_complexity)]
+pub fn highlight_enemy_targets(
+    overlay: Res<PathDebugOverlay>,
+    enemies: Query<&PathFollower, (With<Enemy>, Without<DynamicPath>)>,
+    mut markers: Query<(&PathDebugMarker, &mut MeshMaterial3d<StandardMaterial>)>,
+) {
+    let (Some(waypoint_material), Some(target_material)) =
+        (&overlay.waypoint_material, &overlay.target_material)
+    else {
+        return;
+    };
+    if !overlay.visible || markers.is_empty() {
+        return;
+    }
+    let targets: HashSet<(usize, usize)> = enemies
+        .iter()
+        .map(|follower| (follower.road_index, follower.next_index))
+        .collect();
+    for (marker, mut material) in markers.iter_mut() {
+        let wanted = if targets.contains(&(marker.road, marker.index)) {
+            target_material
+        } else {
+            waypoint_material
+        };
+        if material.0 != *wanted {
+            material.0 = wanted.clone();
+        }
+    }
+}
+
+/// Line segments between consecutive waypoints of `road`, at overlay height.
+fn build_line_mesh(road: &[Vec3]) -> Mesh {
+    let positions: Vec<[f32; 3]> = road
+        .windows(2)
+        .flat_map(|pair| {
+            [
+                pair[0].with_y(OVERLAY_HEIGHT).to_array(),
+                pair[1].with_y(OVERLAY_HEIGHT).to_array(),
+            ]
+        })
+        .collect();
+    let normals = vec![[0.0, 1.0, 0.0]; positions.len()];
+
+    let mut mesh = Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::default());
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh
+}
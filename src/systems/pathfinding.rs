@@ -0,0 +1,102 @@
+use crate::components::{BuiltTower, DynamicPath, Enemy, PathFollower, RoadPaths, TownCenter};
+use crate::constants::Tunables;
+use crate::core::geometry::distance_to_polyline_xz;
+use crate::core::pathfinding::{OccupancyGrid, reroute_path_xz};
+use crate::events::TowerBuilt;
+use bevy::prelude::*;
+use bevy::tasks::{AsyncComputeTaskPool, Task, block_on, futures_lite::future};
+use std::sync::Arc;
+
+/// Path recomputation running on the async compute pool.
+/// The enemy keeps following its last known path until the task completes.
+#[derive(Component)]
+pub struct PathfindTask {
+    pub task: Task<Vec<Vec3>>,
+}
+
+/// Starts re-pathing tasks for enemies whose remaining path passes near a newly built tower.
+/// The new path is found with A* on an occupancy grid of every tower on the map, so a detour
+/// never runs into an older tower either.
+#[allow(clippy::type_complexity)]
+pub fn spawn_pathfind_tasks_system(
+    mut commands: Commands,
+    mut tower_events: MessageReader<TowerBuilt>,
+    enemies: Query<
+        (
+            Entity,
+            &Transform,
+            Option<&PathFollower>,
+            Option<&DynamicPath>,
+        ),
+        (With<Enemy>, Without<PathfindTask>),
+    >,
+    village_q: Query<&Transform, (With<TownCenter>, Without<Enemy>)>,
+    towers: Query<&Transform, (With<BuiltTower>, Without<Enemy>)>,
+    roads: Option<Res<RoadPaths>>,
+    tunables: Res<Tunables>,
+) {
+    let built: Vec<Vec3> = tower_events.read().map(|e| e.position).collect();
+    if built.is_empty() {
+        return;
+    }
+
+    let clearance = tunables.pathfind_tower_clearance;
+    let village_pos = village_q.single().map(|tf| tf.translation).ok();
+    let pool = AsyncComputeTaskPool::get();
+    // Towers spawned this frame may not be queryable yet, so include the built positions too
+    let tower_positions: Vec<Vec3> = towers
+        .iter()
+        .map(|tf| tf.translation)
+        .chain(built.iter().copied())
+        .collect();
+    let grid = Arc::new(OccupancyGrid::from_obstacles(
+        &tower_positions,
+        clearance,
+        tunables.pathfinding_cell_size,
+    ));
+
+    for (entity, transform, follower, dynamic) in enemies.iter() {
+        // Remaining path: current position, the waypoints still ahead, then the village
+        let mut remaining = vec![transform.translation];
+        if let Some(dynamic) = dynamic {
+            remaining.extend(dynamic.waypoints.iter().skip(dynamic.next_index));
+        } else if let (Some(follower), Some(roads)) = (follower, &roads)
+            && let Some(road) = roads.roads.get(follower.road_index)
+        {
+            remaining.extend(road.iter().skip(follower.next_index));
+        }
+        remaining.extend(village_pos);
+
+        let blocked = built
+            .iter()
+            .any(|pos| distance_to_polyline_xz(*pos, &remaining) < clearance);
+        if !blocked {
+            continue;
+        }
+
+        let grid = Arc::clone(&grid);
+        let task = pool.spawn(async move { reroute_path_xz(&remaining, &grid) });
+        commands.entity(entity).insert(PathfindTask { task });
+    }
+}
+
+/// Applies finished re-pathing tasks by switching the enemy onto the new path.
+pub fn poll_pathfind_tasks_system(
+    mut commands: Commands,
+    mut tasks: Query<(Entity, &mut PathfindTask)>,
+) {
+    for (entity, mut pending) in tasks.iter_mut() {
+        let Some(waypoints) = block_on(future::poll_once(&mut pending.task)) else {
+            continue;
+        };
+        // The first waypoint is where the enemy stood when the task started; skip it.
+        // The enemy may have been despawned meanwhile, hence the `try_` variants.
+        commands
+            .entity(entity)
+            .try_remove::<PathfindTask>()
+            .try_insert(DynamicPath {
+                waypoints,
+                next_index: 1,
+            });
+    }
+}
@@ -0,0 +1,239 @@
+use crate::components::{Player, TowerKind, TowerUpgradeConfig, TowerUpgrades, WaveState};
+use crate::constants::Tunables;
+use crate::core::replay::{ReplayPlayback, ReplayRecorder};
+use crate::events::TowerBuilt;
+use crate::systems::combat::towers::build_tower;
+use crate::utils::paths::app_data_dir;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs;
+
+/// Grid cell size (world units) used to quantize recorded tower placements.
+pub const RUN_CODE_CELL_SIZE: f32 = 1.0;
+
+/// A tower placement as recorded for run sharing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedPlacement {
+    /// Wave number that was current when the tower was placed (0 = before the first wave).
+    pub wave: u32,
+    pub cell: IVec2,
+    pub kind: TowerKind,
+}
+
+impl RecordedPlacement {
+    pub fn world_position(&self) -> Vec3 {
+        Vec3::new(
+            self.cell.x as f32 * RUN_CODE_CELL_SIZE,
+            0.0,
+            self.cell.y as f32 * RUN_CODE_CELL_SIZE,
+        )
+    }
+}
+
+/// Key player actions recorded during this run, used to build a shareable run code.
+#[derive(Resource, Default, Debug)]
+pub struct RunActionLog {
+    pub placements: Vec<RecordedPlacement>,
+}
+
+/// This session's run code as it stands, for the game over screen's Copy Run Code button.
+#[derive(Resource, Default, Debug)]
+pub struct RunCodeText(pub String);
+
+/// Present only when launched with `--run-code`: placements still waiting to be replicated.
+#[derive(Resource, Default, Debug)]
+pub struct GhostReplay {
+    pub pending: VecDeque<RecordedPlacement>,
+}
+
+/// Records every newly built tower with its grid cell and the current wave. Towers restored from
+/// a save write no `TowerBuilt`, so they are not recorded twice.
+pub fn record_tower_placements(
+    mut built: MessageReader<TowerBuilt>,
+    wave_state: Res<WaveState>,
+    mut log: ResMut<RunActionLog>,
+) {
+    for event in built.read() {
+        let cell = IVec2::new(
+            (event.position.x / RUN_CODE_CELL_SIZE).round() as i32,
+            (event.position.z / RUN_CODE_CELL_SIZE).round() as i32,
+        );
+        log.placements.push(RecordedPlacement {
+            wave: wave_state.current_wave,
+            cell,
+            kind: event.kind,
+        });
+    }
+}
+
+/// Best-effort ghost replay: places recorded towers once their wave has been reached.
+/// Costs are not charged since the original player already paid for them.
+#[allow(clippy::too_many_arguments)]
+pub fn ghost_replay_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut tower_events: MessageWriter<TowerBuilt>,
+    tunables: Res<Tunables>,
+    upgrades: Res<TowerUpgrades>,
+    upgrade_config: Res<TowerUpgradeConfig>,
+    wave_state: Res<WaveState>,
+    mut replay: ResMut<GhostReplay>,
+) {
+    while replay
+        .pending
+        .front()
+        .is_some_and(|p| p.wave <= wave_state.current_wave)
+    {
+        let Some(placement) = replay.pending.pop_front() else {
+            break;
+        };
+        build_tower(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            &mut tower_events,
+            &tunables,
+            &upgrades,
+            &upgrade_config,
+            placement.kind,
+            placement.world_position(),
+        );
+        if cfg!(debug_assertions) {
+            info!(
+                "Ghost replay: placed {:?} at {:?} (wave {})",
+                placement.kind, placement.cell, placement.wave
+            );
+        }
+    }
+}
+
+/// Closes the recorder's frame once per `Playing` frame, adding the towers built during it.
+pub fn finish_replay_frame(
+    mut recorder: ResMut<ReplayRecorder>,
+    mut tower_events: MessageReader<TowerBuilt>,
+) {
+    for event in tower_events.read() {
+        recorder.record_placement(event.position, event.kind);
+    }
+    recorder.end_frame();
+}
+
+/// Writes the recorded replay to `td/replay_{seed}.bin` when the window is closed.
+pub fn save_replay_on_close(
+    mut close_events: MessageReader<bevy::window::WindowCloseRequested>,
+    tunables: Res<Tunables>,
+    recorder: Res<ReplayRecorder>,
+) {
+    if close_events.read().next().is_none() || !recorder.enabled {
+        return;
+    }
+    let Some(dir) = app_data_dir() else {
+        return;
+    };
+    let file_path = dir.join(format!("replay_{}.bin", tunables.world_seed));
+    let bytes = match recorder.to_file(tunables.world_seed).encode() {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            warn!("Failed to encode replay: {}", e);
+            return;
+        }
+    };
+    match fs::create_dir_all(&dir).and_then(|_| fs::write(&file_path, bytes)) {
+        Ok(()) => println!(
+            "[td] Replay of {} frames saved to {:?}",
+            recorder.frames.len(),
+            file_path
+        ),
+        Err(e) => warn!("Failed to write replay to {:?}: {}", file_path, e),
+    }
+}
+
+/// Builds the towers placed in the current playback frame, paying for them like the player did.
+#[allow(clippy::too_many_arguments)]
+pub fn replay_playback_placements(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut tower_events: MessageWriter<TowerBuilt>,
+    tunables: Res<Tunables>,
+    upgrades: Res<TowerUpgrades>,
+    upgrade_config: Res<TowerUpgradeConfig>,
+    playback: Res<ReplayPlayback>,
+    mut player_query: Query<&mut Player>,
+) {
+    let Some(frame) = playback.current() else {
+        return;
+    };
+    for placement in &frame.placements {
+        let (wood_cost, rock_cost) = placement.kind.cost();
+        if let Ok(mut player) = player_query.single_mut() {
+            player.wood = player.wood.saturating_sub(wood_cost);
+            player.rock = player.rock.saturating_sub(rock_cost);
+        }
+        build_tower(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            &mut tower_events,
+            &tunables,
+            &upgrades,
+            &upgrade_config,
+            placement.kind,
+            placement.position,
+        );
+    }
+}
+
+/// Moves playback on to the next recorded frame at the end of every `Playing` frame, and ends
+/// playback once the recording runs out so that input and building are live again.
+pub fn advance_replay_playback(mut commands: Commands, mut playback: ResMut<ReplayPlayback>) {
+    playback.advance();
+    if playback.is_finished() {
+        commands.remove_resource::<ReplayPlayback>();
+        println!(
+            "[td] Replay finished after {} frames; input is live again",
+            playback.frames.len()
+        );
+    }
+}
+
+/// Debug readout of the replay frame being played back.
+#[derive(Component)]
+pub struct ReplayFrameIndexText;
+
+pub fn spawn_replay_frame_index_text(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        ReplayFrameIndexText,
+        Text::new("Replay frame: 0"),
+        TextFont {
+            font: asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::srgba(0.95, 0.95, 0.6, 1.0)),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(20.0),
+            bottom: Val::Px(20.0),
+            ..default()
+        },
+    ));
+}
+
+pub fn update_replay_frame_index_text(
+    playback: Res<ReplayPlayback>,
+    mut texts: Query<&mut Text, With<ReplayFrameIndexText>>,
+) {
+    if !playback.is_changed() {
+        return;
+    }
+    for mut text in texts.iter_mut() {
+        *text = Text::new(format!(
+            "Replay frame: {}/{}",
+            playback.frame_index,
+            playback.frames.len()
+        ));
+    }
+}
@@ -8,6 +8,10 @@ pub mod rocks_along_road;
 pub use rocks_along_road::*;
 pub mod town_square_exclusion;
 pub use town_square_exclusion::*;
+pub mod traffic_decals;
+pub use traffic_decals::*;
+pub mod tree_density;
+pub use tree_density::*;
 
 /// System set for resource post-processing passes (run after random chunk spawns).
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
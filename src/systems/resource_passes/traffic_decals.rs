@@ -0,0 +1,166 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::components::GameState;
+use crate::constants::Tunables;
+use crate::systems::resource_passes::ResourcePassSet;
+
+/// Accumulated enemy presence per road cell (cell size = road width), in seconds.
+/// Every frame, each enemy walking on a road adds the frame time to the cell under it.
+#[derive(Resource, Default, Debug)]
+pub struct TrafficHeatmap {
+    pub counts: HashMap<(i32, i32), f32>,
+}
+
+impl TrafficHeatmap {
+    /// Records `secs` of enemy presence at `pos`.
+    pub fn record(&mut self, pos: Vec3, cell_size: f32, secs: f32) {
+        *self
+            .counts
+            .entry(traffic_cell(pos, cell_size))
+            .or_insert(0.0) += secs;
+    }
+}
+
+/// Maps a world position to its traffic cell coordinates.
+pub fn traffic_cell(pos: Vec3, cell_size: f32) -> (i32, i32) {
+    let size = cell_size.max(0.1);
+    ((pos.x / size).floor() as i32, (pos.z / size).floor() as i32)
+}
+
+/// Wear level of a cell's decal; later levels layer on top of earlier ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum TrafficWear {
+    Dirt,
+    Rut,
+}
+
+#[derive(Resource, Default)]
+struct TrafficDecalState {
+    spawned: HashMap<(i32, i32), TrafficWear>,
+    mesh: Option<Handle<Mesh>>,
+    dirt_mat: Option<Handle<StandardMaterial>>,
+    rut_mat: Option<Handle<StandardMaterial>>,
+}
+
+/// Marker for worn-road decals. They persist for the whole session.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TrafficDecal;
+
+/// Spawns worn-dirt decals on road cells with heavy enemy traffic.
+pub struct TrafficDecalSystem;
+
+impl Plugin for TrafficDecalSystem {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TrafficHeatmap>()
+            .init_resource::<TrafficDecalState>()
+            .add_systems(
+                PostUpdate,
+                spawn_traffic_decals
+                    .in_set(ResourcePassSet::Apply)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn spawn_traffic_decals(
+    mut commands: Commands,
+    heatmap: Res<TrafficHeatmap>,
+    tunables: Res<Tunables>,
+    mut state: ResMut<TrafficDecalState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    if !heatmap.is_changed() {
+        return;
+    }
+
+    let threshold = tunables.traffic_threshold_secs;
+    let cell_size = tunables.road_width.max(0.1);
+
+    let mesh = state
+        .mesh
+        .get_or_insert_with(|| {
+            meshes.add(
+                Plane3d::default()
+                    .mesh()
+                    .size(cell_size * 0.9, cell_size * 0.9)
+                    .build(),
+            )
+        })
+        .clone();
+    let dirt_mat = state
+        .dirt_mat
+        .get_or_insert_with(|| {
+            materials.add(StandardMaterial {
+                base_color: Color::srgba(0.32, 0.24, 0.15, 0.55),
+                alpha_mode: AlphaMode::Blend,
+                perceptual_roughness: 1.0,
+                metallic: 0.0,
+                ..default()
+            })
+        })
+        .clone();
+    let rut_mat = state
+        .rut_mat
+        .get_or_insert_with(|| {
+            materials.add(StandardMaterial {
+                base_color: Color::srgba(0.16, 0.11, 0.06, 0.75),
+                alpha_mode: AlphaMode::Blend,
+                perceptual_roughness: 1.0,
+                metallic: 0.0,
+                ..default()
+            })
+        })
+        .clone();
+
+    for (&cell, &count) in heatmap.counts.iter() {
+        let wear = if count >= threshold * 3.0 {
+            TrafficWear::Rut
+        } else if count >= threshold {
+            TrafficWear::Dirt
+        } else {
+            continue;
+        };
+        if state.spawned.get(&cell).is_some_and(|w| *w >= wear) {
+            continue;
+        }
+
+        // Slightly above the road patches (y = 0.011); ruts layer over dirt
+        let (material, y) = match wear {
+            TrafficWear::Dirt => (dirt_mat.clone(), 0.013),
+            TrafficWear::Rut => (rut_mat.clone(), 0.015),
+        };
+        let center = Vec3::new(
+            (cell.0 as f32 + 0.5) * cell_size,
+            y,
+            (cell.1 as f32 + 0.5) * cell_size,
+        );
+        commands.spawn((
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material),
+            Transform::from_translation(center),
+            TrafficDecal,
+        ));
+        state.spawned.insert(cell, wear);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heatmap_adds_up_seconds_per_cell() {
+        let mut heatmap = TrafficHeatmap::default();
+        for _ in 0..60 {
+            heatmap.record(Vec3::new(2.0, 0.0, 3.0), 5.0, 1.0 / 60.0);
+        }
+        heatmap.record(Vec3::new(4.9, 0.0, 0.1), 5.0, 0.5);
+        heatmap.record(Vec3::new(-0.1, 0.0, 0.1), 5.0, 0.25);
+
+        assert!((heatmap.counts[&(0, 0)] - 1.5).abs() < 1e-4);
+        assert_eq!(heatmap.counts[&(-1, 0)], 0.25);
+        assert_eq!(heatmap.counts.len(), 2);
+    }
+}
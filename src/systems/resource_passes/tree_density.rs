@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+
+use crate::components::harvesting::{Harvestable, HarvestableKind, Tree};
+use crate::components::roads::RoadPaths;
+use crate::constants::Tunables;
+use crate::core::rng::hash_combine;
+use crate::random_policy::RandomizationPolicy;
+use crate::systems::resource_passes::{
+    PlacedByRule, ResourcePassSet, ResourceRuleConfig, distance_to_polyline_xz,
+};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/// Clears trees off the roads and thins the forest toward the middle of the map, so it is
+/// densest at the edges. Trees are checked once, as their chunk streams in.
+pub struct TreeDensityPassPlugin;
+
+impl Plugin for TreeDensityPassPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ResourceRuleConfig>().add_systems(
+            PostUpdate,
+            enforce_tree_density
+                .in_set(ResourcePassSet::Apply)
+                .run_if(resource_exists::<RoadPaths>),
+        );
+    }
+}
+
+const RULE_ID_TREE_DENSITY: u64 = 0x7EE5_DE45_1717;
+
+/// Tree that survived the density pass, so it is not rolled again.
+#[derive(Component)]
+struct TreeDensityChecked;
+
+/// Share of trees that survive at `distance` from the map center: none of the thinning at the
+/// edge of the ground, the full `thinning` share removed at the center.
+fn tree_keep_chance(distance: f32, half_ground_size: f32, thinning: f32) -> f32 {
+    let density = (1.0 - distance / half_ground_size.max(f32::EPSILON)).clamp(0.0, 1.0);
+    1.0 - density * thinning
+}
+
+#[allow(clippy::type_complexity)]
+fn enforce_tree_density(
+    mut commands: Commands,
+    roads: Res<RoadPaths>,
+    rule_cfg: Res<ResourceRuleConfig>,
+    tunables: Res<Tunables>,
+    policy: Res<RandomizationPolicy>,
+    trees_q: Query<
+        (Entity, &Transform, &Harvestable),
+        (
+            With<Tree>,
+            Without<TreeDensityChecked>,
+            Without<PlacedByRule>,
+        ),
+    >,
+) {
+    if !rule_cfg.enabled {
+        return;
+    }
+
+    let half_ground_size = tunables.ground_size * 0.5;
+    for (entity, tf, harvestable) in trees_q.iter() {
+        if harvestable.kind != HarvestableKind::Wood {
+            continue;
+        }
+        let pos = tf.translation;
+        let on_road = roads
+            .roads
+            .iter()
+            .any(|road| distance_to_polyline_xz(pos, road) < tunables.road_tree_clearance);
+
+        // Roll per tree from its position so a reloaded chunk thins out the same way
+        let roll: f32 = if policy.resource_rules_seeded {
+            let seed = hash_combine(
+                tunables.world_seed ^ RULE_ID_TREE_DENSITY,
+                pos.x.to_bits() as i32,
+                pos.z.to_bits() as i32,
+            );
+            StdRng::seed_from_u64(seed).random()
+        } else {
+            rand::random()
+        };
+        let distance = Vec2::new(pos.x, pos.z).length();
+        let thinned =
+            roll >= tree_keep_chance(distance, half_ground_size, tunables.tree_center_thinning);
+
+        if on_road || thinned {
+            // Meshes and materials are the shared ChunkAssets handles, so only the entity goes
+            commands.entity(entity).despawn();
+        } else {
+            commands.entity(entity).insert(TreeDensityChecked);
+        }
+    }
+}
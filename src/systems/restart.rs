@@ -0,0 +1,178 @@
+//! Starting a fresh run from the game over screen, in place: the town, roads and HUD stay, while
+//! everything the last run added is removed and its state put back to how a new game begins.
+
+use crate::build::market::MarketState;
+use crate::components::{
+    BuiltTower, Enemy, GameSpeed, Player, StaticObstacleAABBs, TowerUpgrades, TownSquareCenter,
+    Village, VillageShield, Wall, WallBreachMarker, WallHealth, WaveState,
+};
+use crate::constants::Tunables;
+use crate::core::replay::{ReplayPlayback, ReplayRecorder};
+use crate::core::stats::RunStats;
+use crate::systems::celebration::CelebrationParticle;
+use crate::systems::combat::lightning_strikes::{LightningFlash, LightningTelegraph};
+use crate::systems::combat::projectiles::{
+    EphemeralText, ImpactEffect, LightningArc, PoisonCloud, Projectile, TrailParticle,
+};
+use crate::systems::countdown::start_pre_game_countdown;
+use crate::systems::day_night::DayNightCycle;
+#[cfg(feature = "online")]
+use crate::systems::online_leaderboard::{OnlineLeaderboardRequest, hide_global_ranking};
+use crate::systems::replay::RunActionLog;
+use crate::systems::resource_passes::traffic_decals::TrafficHeatmap;
+use crate::systems::ui::run_summary::RunSummary;
+use crate::systems::wind::WindState;
+use bevy::pbr::MeshMaterial3d;
+use bevy::prelude::*;
+
+/// Removes enemies, towers, projectiles and combat effects, rebuilds breached walls, and resets
+/// the player's inventory and position, the village, the waves and the run's stats, along with
+/// the market prices, the wind and the road traffic. The replay recording, the run code's action
+/// log and the leaderboard submission start over too, and the pre-game countdown runs again.
+#[allow(clippy::type_complexity)]
+pub fn restart_run(world: &mut World) {
+    let doomed: Vec<Entity> = world
+        .query_filtered::<Entity, Or<(
+            With<Enemy>,
+            With<BuiltTower>,
+            With<Projectile>,
+            With<TrailParticle>,
+            With<ImpactEffect>,
+            With<LightningArc>,
+            With<PoisonCloud>,
+            With<EphemeralText>,
+            With<LightningTelegraph>,
+            With<LightningFlash>,
+            With<CelebrationParticle>,
+        )>>()
+        .iter(world)
+        .collect();
+    for entity in doomed {
+        // Children (health bars, range rings) may already be gone with their parent
+        if let Ok(entity) = world.get_entity_mut(entity) {
+            entity.despawn();
+        }
+    }
+
+    let tunables = world.resource::<Tunables>().clone();
+    let breaches: Vec<(Entity, WallBreachMarker)> = world
+        .query::<(Entity, &WallBreachMarker)>()
+        .iter(world)
+        .map(|(entity, marker)| (entity, marker.clone()))
+        .collect();
+    for (entity, marker) in breaches {
+        world.spawn((
+            Mesh3d(marker.mesh),
+            MeshMaterial3d(marker.material),
+            marker.wall_transform,
+            Wall,
+            WallHealth {
+                hp: tunables.wall_health,
+            },
+        ));
+        world
+            .resource_mut::<StaticObstacleAABBs>()
+            .boxes
+            .push(marker.bounds);
+        world.despawn(entity);
+    }
+    for mut health in world
+        .query_filtered::<&mut WallHealth, With<Wall>>()
+        .iter_mut(world)
+    {
+        health.hp = tunables.wall_health;
+    }
+
+    let plaza = world
+        .get_resource::<TownSquareCenter>()
+        .map(|center| center.0);
+    if let Ok((mut player, mut transform)) = world
+        .query::<(&mut Player, &mut Transform)>()
+        .single_mut(world)
+    {
+        *player = Player::starting();
+        if let Some(plaza) = plaza {
+            transform.translation = Vec3::new(plaza.x, transform.translation.y, plaza.z);
+        }
+    }
+    if let Ok(mut village) = world.query::<&mut Village>().single_mut(world) {
+        village.health = village.max_health;
+    }
+
+    let run_start_time = world.resource::<Time<Virtual>>().elapsed_secs_f64();
+    world.insert_resource(WaveState::new(&tunables));
+    world.insert_resource(VillageShield::new(tunables.shield_duration_secs));
+    world.insert_resource(GameSpeed::default());
+    world.insert_resource(TowerUpgrades::default());
+    world.insert_resource(MarketState::default());
+    world.insert_resource(WindState::new(&tunables));
+    world.insert_resource(TrafficHeatmap::default());
+    world.insert_resource(RunActionLog::default());
+    world.insert_resource(RunStats {
+        run_start_time,
+        challenge_mode: tunables.challenge_mode,
+        ..default()
+    });
+    world.remove_resource::<RunSummary>();
+    if let Some(mut cycle) = world.get_resource_mut::<DayNightCycle>() {
+        cycle.current_phase = 0.0;
+    }
+    // A replay drives only the run it was recorded from
+    world.remove_resource::<ReplayPlayback>();
+    if let Some(mut recorder) = world.get_resource_mut::<ReplayRecorder>() {
+        recorder.clear();
+    }
+    #[cfg(feature = "online")]
+    if let Some(mut request) = world.get_resource_mut::<OnlineLeaderboardRequest>() {
+        request.reset_for_next_run();
+    }
+    #[cfg(feature = "online")]
+    if let Err(e) = world.run_system_cached(hide_global_ranking) {
+        warn!("Failed to hide the global ranking: {}", e);
+    }
+    if let Err(e) = world.run_system_cached(start_pre_game_countdown) {
+        warn!("Failed to start the pre-game countdown: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::TowerKind;
+    use crate::systems::replay::RecordedPlacement;
+
+    #[test]
+    fn restart_clears_the_last_runs_logs() {
+        let mut world = World::new();
+        let tunables = Tunables::default();
+        world.init_resource::<StaticObstacleAABBs>();
+        world.init_resource::<Time<Virtual>>();
+        world.insert_resource(RunActionLog {
+            placements: vec![RecordedPlacement {
+                wave: 2,
+                cell: IVec2::new(3, -1),
+                kind: TowerKind::Bow,
+            }],
+        });
+        let mut heatmap = TrafficHeatmap::default();
+        heatmap.record(Vec3::new(4.0, 0.0, 2.0), tunables.road_width, 1.5);
+        world.insert_resource(heatmap);
+        world.insert_resource(MarketState {
+            transactions_this_wave: 3,
+        });
+        let mut wind = WindState::new(&tunables);
+        wind.direction = Vec2::Y;
+        wind.speed = tunables.wind_max_speed;
+        world.insert_resource(wind);
+        world.insert_resource(tunables.clone());
+
+        restart_run(&mut world);
+
+        assert!(world.resource::<RunActionLog>().placements.is_empty());
+        assert!(world.resource::<TrafficHeatmap>().counts.is_empty());
+        assert_eq!(world.resource::<MarketState>().transactions_this_wave, 0);
+        let wind = world.resource::<WindState>();
+        assert_eq!(wind.speed, 0.0);
+        assert_eq!(wind.direction, Vec2::X);
+    }
+}
@@ -0,0 +1,49 @@
+//! Road surface animation: the tiles of every road segment scroll toward the village, faster the
+//! more enemies are walking on that segment.
+
+use crate::components::{Enemy, RoadSegment};
+use crate::constants::Tunables;
+use crate::core::geometry::distance_to_polyline_xz;
+use crate::materials::RoadMaterial;
+use bevy::prelude::*;
+
+/// Sets each segment's scroll speed from the enemies on it. The shader scrolls by
+/// `scroll + scroll_speed * globals.time`, so a material is only written when its speed changes,
+/// with `scroll` shifted to keep the pattern where it was.
+pub fn road_material_params_system(
+    time: Res<Time>,
+    tunables: Res<Tunables>,
+    enemies: Query<&Transform, With<Enemy>>,
+    segments: Query<&RoadSegment>,
+    mut materials: ResMut<Assets<RoadMaterial>>,
+) {
+    // Same clock as the shader's `globals.time`
+    let now = time.elapsed_secs_wrapped();
+    let on_road_distance = tunables.road_width * 0.5;
+    for segment in segments.iter() {
+        let walkers = enemies
+            .iter()
+            .filter(|transform| {
+                distance_to_polyline_xz(transform.translation, &[segment.start, segment.end])
+                    <= on_road_distance
+            })
+            .count();
+        let scroll_speed =
+            tunables.road_scroll_speed + tunables.road_scroll_speed_per_enemy * walkers as f32;
+        let unchanged = materials.get(&segment.material).is_none_or(|material| {
+            material.data.scroll_speed == scroll_speed
+                && material.data.tile_scale == tunables.road_tile_scale
+        });
+        if unchanged {
+            continue;
+        }
+        let Some(material) = materials.get_mut(&segment.material) else {
+            continue;
+        };
+        let data = &mut material.data;
+        // Wrapped to keep precision; the pattern repeats every tile
+        data.scroll = (data.scroll + (data.scroll_speed - scroll_speed) * now).rem_euclid(1.0);
+        data.scroll_speed = scroll_speed;
+        data.tile_scale = tunables.road_tile_scale;
+    }
+}
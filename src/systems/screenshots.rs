@@ -0,0 +1,77 @@
+use crate::components::{WavePhase, WaveState};
+use crate::constants::Tunables;
+use crate::utils::paths::app_data_dir;
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{Screenshot, save_to_disk};
+use std::fs;
+use std::path::Path;
+
+/// Wave whose end should be captured on the next frame, giving the UI one frame to settle.
+#[derive(Resource, Default)]
+pub struct WaveEndScreenshot {
+    pending: Option<u32>,
+    last_phase: Option<WavePhase>,
+}
+
+/// Saves `td/screenshots/<seed>_wave<N>.png` one frame after each wave ends, when enabled.
+pub fn wave_end_screenshot_system(
+    mut commands: Commands,
+    tunables: Res<Tunables>,
+    wave_state: Res<WaveState>,
+    mut screenshot: ResMut<WaveEndScreenshot>,
+) {
+    if let Some(wave) = screenshot.pending.take() {
+        capture_wave_screenshot(&mut commands, &tunables, wave);
+    }
+
+    let phase = wave_state.phase;
+    let wave_ended =
+        screenshot.last_phase == Some(WavePhase::Spawning) && phase == WavePhase::Intermission;
+    screenshot.last_phase = Some(phase);
+    if wave_ended && tunables.auto_screenshot {
+        screenshot.pending = Some(wave_state.current_wave);
+    }
+}
+
+fn capture_wave_screenshot(commands: &mut Commands, tunables: &Tunables, wave: u32) {
+    let Some(dir) = app_data_dir().map(|dir| dir.join("screenshots")) else {
+        return;
+    };
+    if let Err(e) = fs::create_dir_all(&dir) {
+        warn!("Failed to create screenshot directory {:?}: {}", dir, e);
+        return;
+    }
+    // Make room for the new file so at most `max_screenshots` remain afterwards
+    prune_oldest_screenshots(&dir, tunables.max_screenshots.saturating_sub(1));
+
+    let path = dir.join(format!("{}_wave{}.png", tunables.world_seed, wave));
+    commands
+        .spawn(Screenshot::primary_window())
+        .observe(save_to_disk(path));
+}
+
+/// Deletes the oldest PNGs in `dir` until at most `keep` remain.
+fn prune_oldest_screenshots(dir: &Path, keep: usize) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut shots: Vec<_> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "png"))
+        .filter_map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((modified, path))
+        })
+        .collect();
+    if shots.len() <= keep {
+        return;
+    }
+    shots.sort_by_key(|(modified, _)| *modified);
+    let excess = shots.len() - keep;
+    for (_, path) in shots.into_iter().take(excess) {
+        if let Err(e) = fs::remove_file(&path) {
+            warn!("Failed to delete old screenshot {:?}: {}", path, e);
+        }
+    }
+}
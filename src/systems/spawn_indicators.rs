@@ -0,0 +1,136 @@
+use crate::components::{RoadPaths, WavePhase, WaveState};
+use crate::utils::camera as cam_utils;
+use bevy::pbr::MeshMaterial3d;
+use bevy::prelude::*;
+
+/// Height above the ground at which spawn markers hover.
+const INDICATOR_HEIGHT: f32 = 4.0;
+/// Pulse frequency (rad/s) while waiting for the wave and while it is spawning.
+const CALM_PULSE_SPEED: f32 = 3.0;
+const FLASH_PULSE_SPEED: f32 = 14.0;
+
+/// Hovering marker above the first waypoint of a road, shown around wave starts.
+#[derive(Component)]
+pub struct SpawnIndicator {
+    pub base_position: Vec3,
+}
+
+/// Screen-space "SPAWN" label that follows a `SpawnIndicator`.
+#[derive(Component)]
+pub struct SpawnIndicatorLabel {
+    pub indicator: Entity,
+}
+
+/// Shows markers at road entry points during intermission, flashes them while the wave
+/// spawns, and removes them once the wave has finished spawning.
+#[allow(clippy::too_many_arguments)]
+pub fn manage_spawn_indicators_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    wave_state: Res<WaveState>,
+    roads: Option<Res<RoadPaths>>,
+    asset_server: Res<AssetServer>,
+    mut indicators: Query<(Entity, &SpawnIndicator, &mut Transform)>,
+    labels: Query<(Entity, &SpawnIndicatorLabel)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let spawning_done = wave_state.phase == WavePhase::Spawning
+        && wave_state.enemies_spawned >= wave_state.enemies_to_spawn;
+    let Some(roads) = roads.filter(|_| !spawning_done) else {
+        for (entity, _, _) in indicators.iter() {
+            commands.entity(entity).despawn();
+        }
+        for (entity, _) in labels.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+
+    if indicators.is_empty() && wave_state.phase == WavePhase::Intermission {
+        let mesh = meshes.add(Tetrahedron::default());
+        let material = materials.add(StandardMaterial {
+            base_color: Color::srgb(0.95, 0.1, 0.1),
+            emissive: LinearRgba::rgb(2.0, 0.1, 0.1),
+            unlit: true,
+            ..default()
+        });
+        for road in roads.roads.iter() {
+            let Some(first) = road.first() else {
+                continue;
+            };
+            let base_position = Vec3::new(first.x, INDICATOR_HEIGHT, first.z);
+            let indicator = commands
+                .spawn((
+                    Mesh3d(mesh.clone()),
+                    MeshMaterial3d(material.clone()),
+                    Transform::from_translation(base_position).with_scale(Vec3::splat(2.0)),
+                    SpawnIndicator { base_position },
+                ))
+                .id();
+            commands.spawn((
+                Text::new("SPAWN"),
+                TextFont {
+                    font: asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(1.0, 0.3, 0.3, 1.0)),
+                Node {
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                Visibility::Hidden,
+                SpawnIndicatorLabel { indicator },
+            ));
+        }
+        return;
+    }
+
+    let pulse_speed = match wave_state.phase {
+        WavePhase::Intermission => CALM_PULSE_SPEED,
+        WavePhase::Spawning => FLASH_PULSE_SPEED,
+    };
+    let t = time.elapsed_secs();
+    let pulse = 0.5 + 0.5 * (t * pulse_speed).sin();
+    for (_, indicator, mut transform) in indicators.iter_mut() {
+        transform.translation = indicator.base_position + Vec3::Y * (0.4 * pulse);
+        transform.scale = Vec3::splat(1.6 + 0.8 * pulse);
+        transform.rotation = Quat::from_rotation_y(t * 1.5);
+    }
+}
+
+/// Positions "SPAWN" labels under their markers in screen space.
+pub fn spawn_indicator_label_system(
+    windows: Query<&Window>,
+    cam_q: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    indicators: Query<&GlobalTransform, With<SpawnIndicator>>,
+    mut labels: Query<(&SpawnIndicatorLabel, &mut Node, &mut Visibility)>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Ok((camera, cam_tf)) = cam_q.single() else {
+        return;
+    };
+
+    for (label, mut node, mut visibility) in labels.iter_mut() {
+        let Ok(indicator_tf) = indicators.get(label.indicator) else {
+            continue;
+        };
+        let world_pos = indicator_tf.translation() - Vec3::Y * 2.0;
+        if !cam_utils::is_on_screen_ndc(camera, cam_tf, world_pos, 0.0) {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        }
+        let Some(logical) = cam_utils::world_to_viewport_logical(camera, cam_tf, window, world_pos)
+        else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        visibility.set_if_neq(Visibility::Visible);
+        // Roughly center the 5-character label under the marker
+        node.left = Val::Px(logical.x - 25.0);
+        node.top = Val::Px(logical.y + 8.0);
+    }
+}
@@ -1,9 +1,16 @@
+use crate::audio::ResourceHarvestedEvent;
 use crate::components::*;
 use crate::constants::Tunables;
 use crate::events::*;
 use bevy::input::keyboard::Key;
 use bevy::prelude::*;
 
+const COLLECT_RADIUS: f32 = 8.0;
+const SMALL_TREE_HOLD_DURATION: f32 = 1.0;
+const BIG_TREE_HOLD_DURATION: f32 = 2.5;
+/// Pulses per second of the auto-collect ring's opacity.
+const AUTO_COLLECT_PULSE_HZ: f32 = 0.8;
+
 /// Local state for hold-to-collect interaction.
 /// Tracks which target is being collected and how long E has been held.
 #[derive(Default)]
@@ -34,6 +41,7 @@ pub fn hold_to_collect(
         Option<&RockSize>,
     )>,
     mut resource_events: MessageWriter<ResourceCollected>,
+    mut harvest_sfx: MessageWriter<ResourceHarvestedEvent>,
     mut current: ResMut<CurrentCollectProgress>,
     mut commands: Commands,
     mut hold: Local<HoldCollectState>,
@@ -46,10 +54,6 @@ pub fn hold_to_collect(
         return;
     };
 
-    const COLLECT_RADIUS: f32 = 8.0;
-    const SMALL_TREE_HOLD_DURATION: f32 = 1.0;
-    const BIG_TREE_HOLD_DURATION: f32 = 2.5;
-
     // Only do the O(N) nearest scan when the key is held
     let is_holding = keyboard_input.pressed(Key::Character("e".into()));
     if !is_holding {
@@ -98,51 +102,21 @@ pub fn hold_to_collect(
                 hold.elapsed_seconds = 0.0;
             }
 
-            // Determine hold duration based on size: big trees or big rocks take longer
-            let is_big_target = matches!(tree_size, Some(TreeSize::Big))
-                || matches!(rock_size, Some(RockSize::Big));
-            let hold_duration = if is_big_target {
-                BIG_TREE_HOLD_DURATION
-            } else {
-                SMALL_TREE_HOLD_DURATION
-            };
+            let hold_duration = hold_duration(tree_size, rock_size);
 
             current.target = Some(entity);
             current.progress = (hold.elapsed_seconds / hold_duration).clamp(0.0, 1.0);
 
             if hold.elapsed_seconds >= hold_duration {
-                let collected = harvestable.amount;
-                if collected > 0 {
-                    match harvestable.kind {
-                        HarvestableKind::Wood => {
-                            // Small trees give 2 wood, big trees give 8 wood (4x value)
-                            let actual_wood = match tree_size {
-                                Some(TreeSize::Big) => 8,
-                                _ => 2,
-                            };
-                            player.wood += actual_wood;
-                            resource_events.write(ResourceCollected {
-                                kind: harvestable.kind,
-                                amount: actual_wood,
-                                position: target_pos,
-                            });
-                        }
-                        HarvestableKind::Rock => {
-                            // Small rocks give 1, big rocks give 4
-                            let actual_rock = match rock_size {
-                                Some(RockSize::Big) => 4,
-                                _ => 1,
-                            };
-                            player.rock += actual_rock;
-                            resource_events.write(ResourceCollected {
-                                kind: harvestable.kind,
-                                amount: actual_rock,
-                                position: target_pos,
-                            });
-                        }
-                    }
-                }
-
+                harvest(
+                    &mut player,
+                    harvestable,
+                    tree_size,
+                    rock_size,
+                    target_pos,
+                    &mut resource_events,
+                    &mut harvest_sfx,
+                );
                 commands.entity(entity).despawn();
                 hold.current_target = None;
                 hold.elapsed_seconds = 0.0;
@@ -159,6 +133,189 @@ pub fn hold_to_collect(
     }
 }
 
+/// Seconds of collecting a target takes: big trees and big rocks take longer.
+fn hold_duration(tree_size: Option<TreeSize>, rock_size: Option<RockSize>) -> f32 {
+    let is_big_target =
+        matches!(tree_size, Some(TreeSize::Big)) || matches!(rock_size, Some(RockSize::Big));
+    if is_big_target {
+        BIG_TREE_HOLD_DURATION
+    } else {
+        SMALL_TREE_HOLD_DURATION
+    }
+}
+
+/// Grants the yield of a fully collected target and emits the collection events.
+/// The caller despawns the target.
+fn harvest(
+    player: &mut Player,
+    harvestable: Harvestable,
+    tree_size: Option<TreeSize>,
+    rock_size: Option<RockSize>,
+    target_pos: Vec3,
+    resource_events: &mut MessageWriter<ResourceCollected>,
+    harvest_sfx: &mut MessageWriter<ResourceHarvestedEvent>,
+) {
+    if harvestable.amount == 0 {
+        return;
+    }
+    let amount = match harvestable.kind {
+        HarvestableKind::Wood => {
+            // Small trees give 2 wood, big trees give 8 wood (4x value)
+            let actual_wood = match tree_size {
+                Some(TreeSize::Big) => 8,
+                _ => 2,
+            };
+            player.wood += actual_wood;
+            actual_wood
+        }
+        HarvestableKind::Rock => {
+            // Small rocks give 1, big rocks give 4
+            let actual_rock = match rock_size {
+                Some(RockSize::Big) => 4,
+                _ => 1,
+            };
+            player.rock += actual_rock;
+            actual_rock
+        }
+    };
+    resource_events.write(ResourceCollected {
+        kind: harvestable.kind,
+        amount,
+        position: target_pos,
+    });
+    harvest_sfx.write(ResourceHarvestedEvent {
+        kind: harvestable.kind,
+        position: target_pos,
+    });
+}
+
+/// C asks to switch auto-collect on or off.
+pub fn auto_collect_toggle_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut toggles: MessageWriter<ToggleAutoCollect>,
+) {
+    if keyboard_input.just_pressed(KeyCode::KeyC) {
+        toggles.write(ToggleAutoCollect);
+    }
+}
+
+pub fn apply_auto_collect_toggle(
+    mut toggles: MessageReader<ToggleAutoCollect>,
+    mut auto_collect_q: Query<&mut AutoCollect, With<Player>>,
+) {
+    for _ in toggles.read() {
+        for mut auto_collect in auto_collect_q.iter_mut() {
+            auto_collect.active = !auto_collect.active;
+            info!(
+                "Auto-collect {}",
+                if auto_collect.active { "on" } else { "off" }
+            );
+        }
+    }
+}
+
+/// While auto-collect is active, gathers the nearest eligible target within its radius at the
+/// hold-to-collect pace, one target at a time. Stands aside while E is held so the two never
+/// work on the same target.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn auto_collect(
+    time: Res<Time>,
+    tunables: Res<Tunables>,
+    keyboard_input: Res<ButtonInput<Key>>,
+    mut player_query: Query<(&Transform, &mut Player, &AutoCollect)>,
+    harvestables: Query<(
+        Entity,
+        &Transform,
+        &Harvestable,
+        Option<&TreeSize>,
+        Option<&RockSize>,
+    )>,
+    mut resource_events: MessageWriter<ResourceCollected>,
+    mut harvest_sfx: MessageWriter<ResourceHarvestedEvent>,
+    mut commands: Commands,
+    mut state: Local<HoldCollectState>,
+) {
+    let Ok((player_transform, mut player, auto_collect)) = player_query.single_mut() else {
+        return;
+    };
+    if !auto_collect.active || keyboard_input.pressed(Key::Character("e".into())) {
+        state.current_target = None;
+        state.elapsed_seconds = 0.0;
+        return;
+    }
+
+    let player_pos = player_transform.translation;
+    let radius_sq = auto_collect.radius * auto_collect.radius;
+    let nearest = harvestables
+        .iter()
+        .filter(|(_, _, harvestable, _, _)| {
+            harvestable.amount > 0
+                && (harvestable.kind == HarvestableKind::Wood || tunables.auto_collect_rocks)
+        })
+        .map(|(entity, transform, harvestable, tree_size, rock_size)| {
+            (
+                player_pos.distance_squared(transform.translation),
+                entity,
+                transform.translation,
+                *harvestable,
+                tree_size.copied(),
+                rock_size.copied(),
+            )
+        })
+        .filter(|(d2, ..)| *d2 <= radius_sq)
+        .min_by(|a, b| a.0.total_cmp(&b.0));
+    let Some((_, entity, target_pos, harvestable, tree_size, rock_size)) = nearest else {
+        state.current_target = None;
+        state.elapsed_seconds = 0.0;
+        return;
+    };
+
+    if state.current_target == Some(entity) {
+        state.elapsed_seconds += time.delta_secs();
+    } else {
+        state.current_target = Some(entity);
+        state.elapsed_seconds = 0.0;
+    }
+    if state.elapsed_seconds >= hold_duration(tree_size, rock_size) {
+        harvest(
+            &mut player,
+            harvestable,
+            tree_size,
+            rock_size,
+            target_pos,
+            &mut resource_events,
+            &mut harvest_sfx,
+        );
+        commands.entity(entity).despawn();
+        state.current_target = None;
+        state.elapsed_seconds = 0.0;
+    }
+}
+
+/// Shows the auto-collect ring while auto-collect is active and pulses its opacity.
+pub fn auto_collect_ring_system(
+    time: Res<Time>,
+    auto_collect_q: Query<&AutoCollect, With<Player>>,
+    mut rings: Query<(&AutoCollectRing, &mut Visibility)>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let active = auto_collect_q
+        .iter()
+        .any(|auto_collect| auto_collect.active);
+    let pulse =
+        0.5 + 0.5 * (time.elapsed_secs() * AUTO_COLLECT_PULSE_HZ * std::f32::consts::TAU).sin();
+    for (ring, mut visibility) in rings.iter_mut() {
+        if !active {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        }
+        visibility.set_if_neq(Visibility::Inherited);
+        if let Some(material) = materials.get_mut(&ring.material) {
+            material.base_color.set_alpha(0.08 + 0.14 * pulse);
+        }
+    }
+}
+
 /// System to spawn floating resource collection numbers when resources are collected.
 pub fn resource_collected_spawn_text_system(
     mut commands: Commands,
@@ -0,0 +1,310 @@
+use crate::components::{
+    BuiltTower, Tower, TowerSpecificUpgrade, TowerUpgradeConfig, TowerUpgrades, UpgradeableStat,
+    WaveState,
+};
+use crate::constants::{Tunables, validate_tunables};
+use crate::systems::ui::hud::spawn_toast;
+use crate::utils::paths::app_data_dir;
+use bevy::prelude::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// How often the overrides file is checked for changes.
+const POLL_INTERVAL_SECS: f32 = 2.0;
+
+/// Development-only watcher for `td/tunables.toml`. Whenever the file's modification time
+/// changes, every valid field whose value differs from the last version of the file is applied
+/// to the `Tunables` resource in place. The file is created with the default values on the first
+/// check if it does not exist; at launch it is compared against those defaults, so only fields
+/// edited in it override the values the game started with (e.g. from `--challenge`).
+#[derive(Resource)]
+pub struct TunablesHotReload {
+    path: Option<PathBuf>,
+    last_modified: Option<SystemTime>,
+    /// Contents of the file as last read; the defaults before the first read.
+    last_seen: toml::Table,
+    poll_timer: Timer,
+}
+
+impl Default for TunablesHotReload {
+    fn default() -> Self {
+        TunablesHotReload {
+            path: app_data_dir().map(|dir| dir.join("tunables.toml")),
+            last_modified: None,
+            last_seen: defaults_as_written(),
+            poll_timer: Timer::from_seconds(POLL_INTERVAL_SECS, TimerMode::Repeating),
+        }
+    }
+}
+
+/// Polls the overrides file and re-applies it when it has been modified, then pushes the new
+/// values into state that copied them at spawn time (spawn timer, tower ranges).
+#[allow(clippy::too_many_arguments)]
+pub fn tunables_hot_reload_system(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    asset_server: Res<AssetServer>,
+    mut reload: ResMut<TunablesHotReload>,
+    mut tunables: ResMut<Tunables>,
+    wave_state: Option<ResMut<WaveState>>,
+    (upgrades, upgrade_config): (Res<TowerUpgrades>, Res<TowerUpgradeConfig>),
+    mut towers: Query<(Entity, &mut Tower, &BuiltTower)>,
+    specific_upgrades: Query<&TowerSpecificUpgrade>,
+) {
+    reload.poll_timer.tick(time.delta());
+    if !reload.poll_timer.just_finished() {
+        return;
+    }
+    let Some(path) = reload.path.clone() else {
+        return;
+    };
+    if !path.exists() {
+        reload.last_modified = write_defaults(&path);
+        return;
+    }
+    let Ok(modified) = fs::metadata(&path).and_then(|meta| meta.modified()) else {
+        return;
+    };
+    if reload.last_modified == Some(modified) {
+        return;
+    }
+    reload.last_modified = Some(modified);
+
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) => {
+            warn!("Failed to read {:?}: {}", path, e);
+            return;
+        }
+    };
+    let file = match text.parse::<toml::Table>() {
+        Ok(table) => table,
+        Err(e) => {
+            warn!("Failed to parse {:?}: {}", path, e);
+            return;
+        }
+    };
+    let overrides = changed_fields(&reload.last_seen, &file);
+    if overrides.is_empty() {
+        return;
+    }
+
+    let previous = tunables.clone();
+    let applied = match apply_overrides(&mut tunables, overrides) {
+        Ok(applied) => applied,
+        Err(errors) => {
+            // Left out of `last_seen`, so the whole edit is applied once the file is fixed
+            warn!(
+                "Ignoring {:?}, keeping the current tunables:\n  - {}",
+                path,
+                errors.join("\n  - ")
+            );
+            return;
+        }
+    };
+    reload.last_seen = file;
+    info!("Reloaded {} tunable(s) from {:?}", applied, path);
+
+    if let Some(mut wave_state) = wave_state
+        && tunables.enemy_spawn_interval_secs != previous.enemy_spawn_interval_secs
+    {
+        wave_state
+            .spawn_timer
+            .set_duration(Duration::from_secs_f32(tunables.enemy_spawn_interval_secs));
+        wave_state.spawn_timer.reset();
+    }
+    if tunables.tower_range != previous.tower_range {
+        // Same recomputation as buying a level: base range, the kind's bonus, then the range
+        // bought for the single tower
+        for (entity, mut tower, built) in towers.iter_mut() {
+            tower.range = tunables.tower_range
+                + upgrade_config.calculate_bonus(
+                    built.kind,
+                    UpgradeableStat::Range,
+                    upgrades.get_level(built.kind),
+                );
+            for upgrade in specific_upgrades.iter().filter(|upgrade| {
+                upgrade.tower_entity == entity && upgrade.stat == UpgradeableStat::Range
+            }) {
+                upgrade.apply(&mut tower);
+            }
+        }
+    }
+    spawn_toast(
+        &mut commands,
+        &asset_server,
+        "Tunables reloaded".to_string(),
+    );
+}
+
+/// Writes the default values to `path` so there is a complete file to edit. Returns the new
+/// file's modification time, so writing it does not count as a change.
+fn write_defaults(path: &Path) -> Option<SystemTime> {
+    let text = match toml::to_string_pretty(&Tunables::default()) {
+        Ok(text) => text,
+        Err(e) => {
+            warn!("Failed to serialize tunables: {}", e);
+            return None;
+        }
+    };
+    let written = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(path, text));
+    if let Err(e) = written {
+        warn!("Failed to write {:?}: {}", path, e);
+        return None;
+    }
+    info!("Wrote default tunables to {:?}", path);
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+/// The defaults as they read back from a written file. Serializing straight into a table keeps
+/// `f32` fields widened to their `f64` values (0.2 becomes 0.20000000298023224), which would not
+/// match the same numbers parsed from the file.
+fn defaults_as_written() -> toml::Table {
+    toml::to_string(&Tunables::default())
+        .ok()
+        .and_then(|text| text.parse().ok())
+        .unwrap_or_default()
+}
+
+/// Keys of `file` that are new or hold a different value than in `last_seen`.
+fn changed_fields(last_seen: &toml::Table, file: &toml::Table) -> toml::Table {
+    file.iter()
+        .filter(|(key, value)| last_seen.get(*key) != Some(*value))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
+
+/// Applies each key of `overrides` that deserializes cleanly on top of the current values;
+/// unknown keys and values of the wrong type are skipped with a warning.
+/// Returns the number of fields applied, or the `validate_tunables` errors (leaving `tunables`
+/// untouched) when the result is out of range.
+fn apply_overrides(tunables: &mut Tunables, overrides: toml::Table) -> Result<usize, Vec<String>> {
+    let mut merged = match toml::Table::try_from(&*tunables) {
+        Ok(table) => table,
+        Err(e) => {
+            warn!("Failed to serialize current tunables: {}", e);
+            return Ok(0);
+        }
+    };
+
+    let mut applied = 0;
+    for (key, value) in overrides {
+        if !merged.contains_key(&key) {
+            warn!("Unknown tunable `{}` ignored", key);
+            continue;
+        }
+        let mut candidate = merged.clone();
+        candidate.insert(key.clone(), value);
+        match candidate.clone().try_into::<Tunables>() {
+            Ok(_) => {
+                merged = candidate;
+                applied += 1;
+            }
+            Err(e) => warn!("Invalid value for tunable `{}`: {}", key, e),
+        }
+    }
+
+    if let Ok(mut updated) = merged.try_into::<Tunables>() {
+        // Restart-only fields are not serialized; keep the values the game launched with.
+        updated.window_title = tunables.window_title;
+        updated.world_seed = tunables.world_seed;
+        validate_tunables(&updated)?;
+        *tunables = updated;
+    }
+    Ok(applied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn defaults_table() -> toml::Table {
+        toml::Table::try_from(Tunables::default()).unwrap()
+    }
+
+    #[test]
+    fn tunables_round_trip_through_toml() {
+        let defaults = Tunables::default();
+        let text = toml::to_string_pretty(&defaults).unwrap();
+        let back: Tunables = toml::from_str(&text).unwrap();
+        assert_eq!(toml::Table::try_from(&back).unwrap(), defaults_table());
+    }
+
+    #[test]
+    fn written_defaults_change_nothing_on_the_next_launch() {
+        let text = toml::to_string_pretty(&Tunables::default()).unwrap();
+        let file = text.parse::<toml::Table>().unwrap();
+        let reload = TunablesHotReload::default();
+        assert!(changed_fields(&reload.last_seen, &file).is_empty());
+    }
+
+    #[test]
+    fn only_edited_fields_are_applied() {
+        let mut file = defaults_table();
+        file.insert(
+            "wave_intermission_secs".to_string(),
+            toml::Value::Float(3.5),
+        );
+        let changed = changed_fields(&defaults_table(), &file);
+        assert_eq!(changed.len(), 1);
+
+        // A value the game launched with that the file doesn't touch survives the reload
+        let mut tunables = Tunables {
+            challenge_mode: true,
+            world_seed: 99,
+            ..Tunables::default()
+        };
+        assert_eq!(apply_overrides(&mut tunables, changed), Ok(1));
+        assert_eq!(tunables.wave_intermission_secs, 3.5);
+        assert!(tunables.challenge_mode);
+        assert_eq!(tunables.world_seed, 99);
+    }
+
+    #[test]
+    fn unknown_and_mistyped_fields_are_skipped() {
+        let mut overrides = toml::Table::new();
+        overrides.insert("no_such_tunable".to_string(), toml::Value::Integer(1));
+        overrides.insert(
+            "wave_intermission_secs".to_string(),
+            toml::Value::String("soon".to_string()),
+        );
+        let mut tunables = Tunables::default();
+        assert_eq!(apply_overrides(&mut tunables, overrides), Ok(0));
+        assert_eq!(
+            tunables.wave_intermission_secs,
+            Tunables::default().wave_intermission_secs
+        );
+    }
+
+    #[test]
+    fn out_of_range_values_are_rejected() {
+        let mut overrides = toml::Table::new();
+        overrides.insert(
+            "enemy_spawn_interval_secs".to_string(),
+            toml::Value::Float(-1.0),
+        );
+        overrides.insert(
+            "wave_intermission_secs".to_string(),
+            toml::Value::Float(3.5),
+        );
+        let mut tunables = Tunables {
+            world_seed: 99,
+            ..Tunables::default()
+        };
+        let before = toml::Table::try_from(&tunables).unwrap();
+
+        let errors = apply_overrides(&mut tunables, overrides).unwrap_err();
+        assert!(
+            errors
+                .iter()
+                .any(|error| error.contains("enemy_spawn_interval_secs"))
+        );
+        // Nothing from the rejected file is applied, not even its valid fields
+        assert_eq!(toml::Table::try_from(&tunables).unwrap(), before);
+        assert_eq!(tunables.world_seed, 99);
+    }
+}
@@ -0,0 +1,121 @@
+//! Placement grid: G toggles `Tunables::placement_grid_snap`, and while building with snapping on
+//! the grid towers snap to is drawn around the player.
+
+use crate::components::{BuildingMode, GameState, Player};
+use crate::constants::Tunables;
+use crate::systems::combat::towers::snap_to_grid;
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+use bevy::render::render_resource::PrimitiveTopology;
+
+/// Just above the ground and roads so the lines are not z-fighting them.
+const GRID_HEIGHT: f32 = 0.05;
+const GRID_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.25);
+
+pub struct GridOverlayPlugin;
+
+impl Plugin for GridOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (grid_snap_input, manage_grid_overlay)
+                .chain()
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// Grid lines covering the build range, centered on the grid point nearest the player.
+#[derive(Component)]
+pub struct GridOverlay {
+    /// Cell size the mesh was built with.
+    cell_size: f32,
+    /// Half width of the mesh, in world units.
+    extent: f32,
+}
+
+/// G switches placement grid snapping on or off.
+pub fn grid_snap_input(keyboard: Res<ButtonInput<KeyCode>>, mut tunables: ResMut<Tunables>) {
+    if keyboard.just_pressed(KeyCode::KeyG) {
+        tunables.placement_grid_snap = !tunables.placement_grid_snap;
+        info!(
+            "Placement grid snapping {}",
+            if tunables.placement_grid_snap {
+                "on"
+            } else {
+                "off"
+            }
+        );
+    }
+}
+
+/// Shows the grid while building mode is active with snapping on, rebuilding it when the cell
+/// size or build range changes, and removes it otherwise.
+pub fn manage_grid_overlay(
+    mut commands: Commands,
+    tunables: Res<Tunables>,
+    building_mode: Query<&BuildingMode>,
+    player_q: Query<&Transform, (With<Player>, Without<GridOverlay>)>,
+    mut overlays: Query<(Entity, &GridOverlay, &mut Transform)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let building = building_mode.iter().any(|mode| mode.is_active);
+    let shown = building && tunables.placement_grid_snap;
+    let Some(player) = player_q.single().ok().filter(|_| shown) else {
+        for (entity, ..) in overlays.iter() {
+            commands.entity(entity).try_despawn();
+        }
+        return;
+    };
+
+    let cell_size = tunables.grid_snap_size;
+    let extent = (tunables.max_build_distance / cell_size).ceil() * cell_size + cell_size;
+    // Moving in whole cells keeps the lines on the world grid
+    let center = Vec3::new(
+        snap_to_grid(player.translation.x, cell_size),
+        GRID_HEIGHT,
+        snap_to_grid(player.translation.z, cell_size),
+    );
+
+    let mut current = false;
+    for (entity, overlay, mut transform) in overlays.iter_mut() {
+        if overlay.cell_size == cell_size && overlay.extent == extent {
+            transform.translation = center;
+            current = true;
+        } else {
+            commands.entity(entity).try_despawn();
+        }
+    }
+    if current {
+        return;
+    }
+
+    commands.spawn((
+        GridOverlay { cell_size, extent },
+        Mesh3d(meshes.add(grid_mesh(cell_size, extent))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: GRID_COLOR,
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        })),
+        Transform::from_translation(center),
+        Visibility::Visible,
+    ));
+}
+
+/// Square grid of line segments `cell_size` apart, spanning `-extent..=extent` on X and Z.
+fn grid_mesh(cell_size: f32, extent: f32) -> Mesh {
+    let lines_per_axis = (2.0 * extent / cell_size).round() as usize + 1;
+    let mut positions = Vec::with_capacity(lines_per_axis * 4);
+    for i in 0..lines_per_axis {
+        let offset = -extent + i as f32 * cell_size;
+        positions.push([offset, 0.0, -extent]);
+        positions.push([offset, 0.0, extent]);
+        positions.push([-extent, 0.0, offset]);
+        positions.push([extent, 0.0, offset]);
+    }
+    Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::default())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+}
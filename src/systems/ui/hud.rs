@@ -1,10 +1,20 @@
 use crate::components::*;
+use crate::constants::Tunables;
+use crate::core::stats::RunStats;
+use crate::events::WaveCompletionBonusEvent;
+use crate::systems::camera::SpectatorCameraState;
+use crate::systems::ui::tooltip::Tooltip;
+use crate::systems::wind::WindState;
 use bevy::prelude::*;
 
 // Health bar HUD
 #[derive(Component)]
 pub struct HealthBar;
 
+/// Frame around the village health bar, carrying its "Village HP" tooltip.
+#[derive(Component)]
+pub struct HealthBarFrame;
+
 pub fn spawn_village_health_bar(mut commands: Commands) {
     commands
         .spawn((
@@ -19,6 +29,8 @@ pub fn spawn_village_health_bar(mut commands: Commands) {
             },
             BackgroundColor(Color::srgba(0.05, 0.05, 0.05, 0.85)),
             BorderColor::all(Color::srgba(0.95, 0.95, 0.98, 0.55)),
+            HealthBarFrame,
+            Tooltip::new("Village HP"),
         ))
         .with_children(|parent| {
             parent.spawn((
@@ -38,6 +50,7 @@ pub fn village_health_hud(
     windows: Query<&Window>,
     village_query: Query<&Village, Changed<Village>>,
     mut fill_query: Query<&mut Node, With<HealthBar>>,
+    mut tooltips: Query<&mut Tooltip, With<HealthBarFrame>>,
 ) {
     let Ok(window) = windows.single() else {
         return;
@@ -50,7 +63,165 @@ pub fn village_health_hud(
         for mut node in fill_query.iter_mut() {
             node.width = Val::Px(fill_width_px);
         }
+        for mut tooltip in tooltips.iter_mut() {
+            tooltip.text = format!("Village HP: {} / {}", village.health, village.max_health);
+        }
+    }
+}
+
+/// Full-screen red tint that pulses while the village is in danger.
+#[derive(Component)]
+pub struct DangerOverlay;
+
+/// Peak alpha of the danger overlay's pulse.
+const DANGER_OVERLAY_MAX_ALPHA: f32 = 0.15;
+/// Pulses per second, doubled once the village drops below `DANGER_CRITICAL_RATIO`.
+const DANGER_PULSE_HZ: f32 = 1.0;
+const DANGER_CRITICAL_RATIO: f32 = 0.1;
+
+pub fn spawn_danger_overlay(mut commands: Commands) {
+    commands.spawn((
+        DangerOverlay,
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            top: Val::Px(0.0),
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.8, 0.05, 0.05, 0.0)),
+        Pickable::IGNORE,
+    ));
+}
+
+/// Pulses the danger overlay while village health is below `Tunables.danger_threshold`.
+pub fn danger_overlay_system(
+    time: Res<Time>,
+    tunables: Res<Tunables>,
+    village_query: Query<&Village>,
+    mut overlay_query: Query<&mut BackgroundColor, With<DangerOverlay>>,
+) {
+    let ratio = village_query.single().map_or(1.0, Village::health_ratio);
+    let alpha = if ratio < tunables.danger_threshold {
+        let hz = if ratio < DANGER_CRITICAL_RATIO {
+            DANGER_PULSE_HZ * 2.0
+        } else {
+            DANGER_PULSE_HZ
+        };
+        let wave = (time.elapsed_secs() * hz * std::f32::consts::TAU).sin();
+        DANGER_OVERLAY_MAX_ALPHA * (wave * 0.5 + 0.5)
+    } else {
+        0.0
+    };
+    for mut color in overlay_query.iter_mut() {
+        color.set_if_neq(BackgroundColor(Color::srgba(0.8, 0.05, 0.05, alpha)));
+    }
+}
+
+// Placement error message
+#[derive(Component)]
+pub struct PlacementErrorText;
+
+/// Seconds a refused placement's reason stays on screen.
+const PLACEMENT_ERROR_SECS: f32 = 1.5;
+
+pub fn spawn_placement_error_text(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(120.0),
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            Pickable::IGNORE,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                PlacementErrorText,
+                Text::new(""),
+                TextFont {
+                    font: asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.35, 0.3)),
+                Visibility::Hidden,
+            ));
+        });
+}
+
+#[derive(Component)]
+pub struct SpectatorWatermark;
+
+pub fn spawn_spectator_watermark(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn((
+            SpectatorWatermark,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(20.0),
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            Pickable::IGNORE,
+            Visibility::Hidden,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("SPECTATOR"),
+                TextFont {
+                    font: asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                    font_size: 30.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(0.95, 0.95, 0.95, 0.45)),
+            ));
+        });
+}
+
+pub fn update_spectator_watermark(
+    spectator: Res<SpectatorCameraState>,
+    mut watermarks: Query<&mut Visibility, With<SpectatorWatermark>>,
+) {
+    if !spectator.is_changed() {
+        return;
+    }
+    let shown = if spectator.active {
+        Visibility::Visible
+    } else {
+        Visibility::Hidden
+    };
+    for mut visibility in watermarks.iter_mut() {
+        visibility.set_if_neq(shown);
+    }
+}
+
+/// Shows the reason of the latest refused tower placement for `PLACEMENT_ERROR_SECS`.
+pub fn show_placement_errors(
+    time: Res<Time>,
+    mut rejected: MessageReader<crate::events::PlacementRejected>,
+    mut text_query: Query<(&mut Text, &mut Visibility), With<PlacementErrorText>>,
+    mut remaining: Local<f32>,
+) {
+    let latest = rejected.read().last().map(|e| e.reason.message());
+    let Ok((mut text, mut visibility)) = text_query.single_mut() else {
+        return;
+    };
+    if let Some(message) = latest {
+        text.0 = message.to_string();
+        *remaining = PLACEMENT_ERROR_SECS;
+    } else if *remaining > 0.0 {
+        *remaining -= time.delta_secs();
     }
+    visibility.set_if_neq(if *remaining > 0.0 {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    });
 }
 
 // Resource counters and wave HUD
@@ -69,9 +240,33 @@ pub struct GoldCounterText;
 #[derive(Component)]
 pub struct WaveCounterText;
 
+#[derive(Component)]
+pub struct KillCounterText;
+
 #[derive(Component)]
 pub struct WaveTimerText;
 
+#[derive(Component)]
+pub struct EnemyScalingWarningText;
+
+/// "Skip wave" button at the bottom of the wave HUD, only in debug builds.
+#[cfg(debug_assertions)]
+#[derive(Component)]
+pub struct SkipWaveButton;
+
+/// Track of the wave progress bar under the wave counter.
+#[derive(Component)]
+pub struct WaveProgressBar;
+
+/// Fill of the wave progress bar; `shown` is the fraction currently drawn.
+#[derive(Component)]
+pub struct WaveProgressFill {
+    pub(crate) shown: f32,
+    pub(crate) phase: WavePhase,
+    /// Runs while the bar slides back to empty after a new wave starts.
+    pub(crate) reset: Option<(f32, Timer)>,
+}
+
 #[derive(Component)]
 pub struct ResourceCounter {
     pub(crate) kind: HarvestableKind,
@@ -89,12 +284,13 @@ pub struct WaveTimerDisplay {
 }
 
 #[derive(Component)]
-pub struct SilverCounterDisplay {
-    pub(crate) last_value: u64,
+pub struct EnemyScalingWarningDisplay {
+    pub(crate) last_percent: Option<u32>,
 }
 
 #[derive(Component)]
-pub struct GoldCounterDisplay {
+pub struct CurrencyCounter {
+    pub(crate) kind: CurrencyKind,
     pub(crate) last_value: u64,
 }
 
@@ -125,6 +321,7 @@ pub fn spawn_resource_counters(mut commands: Commands, asset_server: Res<AssetSe
                 },
                 TextColor(Color::srgba(0.93, 0.86, 0.68, 1.0)),
                 WoodCounterText,
+                Tooltip::new("Wood: used for tower construction"),
                 ResourceCounter {
                     kind: HarvestableKind::Wood,
                     last_value: 0,
@@ -140,6 +337,7 @@ pub fn spawn_resource_counters(mut commands: Commands, asset_server: Res<AssetSe
                 },
                 TextColor(Color::srgba(0.86, 0.88, 0.95, 1.0)),
                 RockCounterText,
+                Tooltip::new("Rock: used for tower construction"),
                 ResourceCounter {
                     kind: HarvestableKind::Rock,
                     last_value: 0,
@@ -153,9 +351,14 @@ pub fn spawn_resource_counters(mut commands: Commands, asset_server: Res<AssetSe
                     font_size: 26.0,
                     ..default()
                 },
-                TextColor(Color::srgba(0.88, 0.9, 0.96, 1.0)),
+                // Same tint as the "+NS" silver pickup text
+                TextColor(Color::srgba(0.8, 0.82, 0.90, 1.0)),
                 SilverCounterText,
-                SilverCounterDisplay { last_value: 0 },
+                Tooltip::new("Silver: dropped by enemies, spent on tower upgrades"),
+                CurrencyCounter {
+                    kind: CurrencyKind::Silver,
+                    last_value: 0,
+                },
             ));
 
             parent.spawn((
@@ -165,16 +368,28 @@ pub fn spawn_resource_counters(mut commands: Commands, asset_server: Res<AssetSe
                     font_size: 26.0,
                     ..default()
                 },
-                TextColor(Color::srgba(1.0, 0.9, 0.35, 1.0)),
+                TextColor(Color::srgba(1.0, 0.92, 0.35, 1.0)),
                 GoldCounterText,
-                GoldCounterDisplay { last_value: 0 },
+                Tooltip::new("Gold: rare enemy drop, spent on upgrades and the village shield"),
+                CurrencyCounter {
+                    kind: CurrencyKind::Gold,
+                    last_value: 0,
+                },
             ));
         });
 }
 
+const WAVE_PROGRESS_BAR_WIDTH: f32 = 200.0;
+const WAVE_PROGRESS_BAR_HEIGHT: f32 = 8.0;
+const WAVE_PROGRESS_RESET_SECS: f32 = 0.3;
+const WAVE_PROGRESS_SPAWNING_COLOR: Color = Color::srgba(0.62, 0.38, 0.92, 0.95);
+const WAVE_PROGRESS_INTERMISSION_COLOR: Color = Color::srgba(0.2, 0.75, 0.72, 0.95);
+
 pub fn spawn_wave_hud(
     mut commands: Commands,
     wave_state: Res<WaveState>,
+    tunables: Res<Tunables>,
+    challenge: Option<Res<ChallengeMode>>,
     asset_server: Res<AssetServer>,
 ) {
     let wave_number = wave_state.upcoming_wave_number();
@@ -213,6 +428,18 @@ pub fn spawn_wave_hud(
             BorderColor::all(Color::srgba(0.75, 0.6, 0.9, 0.45)),
         ))
         .with_children(|parent| {
+            if let Some(challenge) = &challenge {
+                parent.spawn((
+                    Text::new(format!("CHALLENGE: {}", challenge.name.to_uppercase())),
+                    TextFont {
+                        font: asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                        font_size: 36.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgba(1.0, 0.35, 0.25, 1.0)),
+                ));
+            }
+
             parent.spawn((
                 Text::new(format!("Wave: {}", wave_number)),
                 TextFont {
@@ -222,11 +449,54 @@ pub fn spawn_wave_hud(
                 },
                 TextColor(Color::srgba(0.92, 0.88, 1.0, 1.0)),
                 WaveCounterText,
+                Tooltip::new("Wave: enemies grow in number and strength every wave"),
                 WaveCounterDisplay {
                     last_value: wave_number,
                 },
             ));
 
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(WAVE_PROGRESS_BAR_WIDTH),
+                        height: Val::Px(WAVE_PROGRESS_BAR_HEIGHT),
+                        display: if tunables.show_wave_progress_bar {
+                            Display::Flex
+                        } else {
+                            Display::None
+                        },
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.15, 0.15, 0.2, 0.9)),
+                    WaveProgressBar,
+                ))
+                .with_children(|bar| {
+                    bar.spawn((
+                        Node {
+                            width: Val::Percent(0.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(WAVE_PROGRESS_INTERMISSION_COLOR),
+                        WaveProgressFill {
+                            shown: 0.0,
+                            phase: wave_state.phase,
+                            reset: None,
+                        },
+                    ));
+                });
+
+            parent.spawn((
+                Text::new(format!("Kills: 0 / {}", wave_state.enemies_to_spawn)),
+                TextFont {
+                    font: asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(0.95, 0.75, 0.7, 1.0)),
+                KillCounterText,
+            ));
+
             parent.spawn((
                 Text::new(timer_label),
                 TextFont {
@@ -238,67 +508,232 @@ pub fn spawn_wave_hud(
                 WaveTimerText,
                 timer_state,
             ));
+
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font: asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(1.0, 0.45, 0.35, 1.0)),
+                Node {
+                    display: Display::None,
+                    ..default()
+                },
+                EnemyScalingWarningText,
+                EnemyScalingWarningDisplay { last_percent: None },
+            ));
+
+            #[cfg(debug_assertions)]
+            {
+                parent
+                    .spawn((
+                        Button,
+                        SkipWaveButton,
+                        Node {
+                            padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                            border: UiRect::all(Val::Px(1.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgba(0.2, 0.12, 0.16, 0.9)),
+                        BorderColor::all(Color::srgba(0.75, 0.6, 0.9, 0.45)),
+                    ))
+                    .with_children(|button| {
+                        button.spawn((
+                            Text::new("Skip wave [Shift+N]"),
+                            TextFont {
+                                font: asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgba(0.95, 0.75, 0.7, 1.0)),
+                        ));
+                    });
+            }
         });
 }
 
-pub fn update_resource_counters(
-    player_q: Query<&Player>,
-    mut counters: Query<(&mut Text, &mut ResourceCounter)>,
+/// Shows "Kills: X / N" for the current wave, N being the wave's enemy count.
+pub fn update_kill_counter(
+    wave_state: Res<WaveState>,
+    stats: Option<Res<RunStats>>,
+    mut text_q: Query<&mut Text, With<KillCounterText>>,
 ) {
-    if let Ok(player) = player_q.single() {
-        for (mut text, mut counter) in counters.iter_mut() {
-            let value = match counter.kind {
-                HarvestableKind::Wood => player.wood,
-                HarvestableKind::Rock => player.rock,
-            };
-            if counter.last_value != value {
-                counter.last_value = value;
-                let label = match counter.kind {
-                    HarvestableKind::Wood => "Wood",
-                    HarvestableKind::Rock => "Rock",
-                };
-                *text = Text::new(format!("{}: {}", label, value));
+    let kills = stats.map_or(0, |s| s.kills_in_wave(wave_state.current_wave));
+    let label = format!("Kills: {} / {}", kills, wave_state.enemies_to_spawn);
+    for mut text in text_q.iter_mut() {
+        if text.0 != label {
+            text.0 = label.clone();
+        }
+    }
+}
+
+/// Seconds a toast notification takes to fade out.
+const TOAST_FADE_SECS: f32 = 2.0;
+
+/// Short-lived message at the top of the screen that fades out and despawns.
+#[derive(Component)]
+pub struct ToastNotification {
+    pub timer: Timer,
+}
+
+pub fn spawn_toast(commands: &mut Commands, asset_server: &AssetServer, message: String) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(90.0),
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            Pickable::IGNORE,
+            ToastNotification {
+                timer: Timer::from_seconds(TOAST_FADE_SECS, TimerMode::Once),
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(message),
+                TextFont {
+                    font: asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                    font_size: 26.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(0.95, 0.92, 0.85, 1.0)),
+            ));
+        });
+}
+
+/// Fades toast text out over `TOAST_FADE_SECS`, then despawns the toast.
+pub fn toast_fade_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut toasts: Query<(Entity, &mut ToastNotification, &Children)>,
+    mut text_colors: Query<&mut TextColor>,
+) {
+    for (entity, mut toast, children) in toasts.iter_mut() {
+        toast.timer.tick(time.delta());
+        if toast.timer.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let alpha = 1.0 - toast.timer.fraction();
+        for child in children.iter() {
+            if let Ok(mut color) = text_colors.get_mut(child) {
+                color.0.set_alpha(alpha);
             }
         }
     }
 }
 
-pub fn update_currency_counters(
+/// Toasts "Perfect Defense!" with the bonus when a wave ends without village damage.
+pub fn wave_completion_bonus_toast(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut bonuses: MessageReader<WaveCompletionBonusEvent>,
+) {
+    for bonus in bonuses.read().filter(|bonus| bonus.perfect) {
+        spawn_toast(
+            &mut commands,
+            &asset_server,
+            format!(
+                "Perfect Defense! +{} wood, +{} rock, +{} silver",
+                bonus.wood, bonus.rock, bonus.silver
+            ),
+        );
+    }
+}
+
+/// When a new wave starts, toasts how many enemies of the previous wave were killed.
+pub fn wave_kill_summary_toast(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    wave_state: Res<WaveState>,
+    stats: Option<Res<RunStats>>,
+    mut wave_started: MessageReader<crate::audio::WaveStartedEvent>,
+    mut boss_wave_started: MessageReader<crate::audio::BossWaveStartedEvent>,
+    mut last_wave: Local<(u32, u32)>,
+) {
+    let started = wave_started.read().count() + boss_wave_started.read().count() > 0;
+    let (wave, total) = *last_wave;
+    if started && wave > 0 && wave != wave_state.current_wave {
+        let kills = stats.as_ref().map_or(0, |s| s.kills_in_wave(wave));
+        spawn_toast(
+            &mut commands,
+            &asset_server,
+            format!("Wave {}: {}/{} killed", wave, kills, total),
+        );
+    }
+    *last_wave = (wave_state.current_wave, wave_state.enemies_to_spawn);
+}
+
+/// Refreshes the wood/rock and silver/gold counters whenever the player's amounts change.
+pub fn update_resource_counters(
     player_q: Query<&Player>,
-    mut silver_text_q: Query<
-        (&mut Text, &mut SilverCounterDisplay),
-        (With<SilverCounterText>, Without<GoldCounterText>),
-    >,
-    mut gold_text_q: Query<
-        (&mut Text, &mut GoldCounterDisplay),
-        (With<GoldCounterText>, Without<SilverCounterText>),
-    >,
+    mut counters: Query<(&mut Text, &mut ResourceCounter), Without<CurrencyCounter>>,
+    mut currency_counters: Query<(&mut Text, &mut CurrencyCounter), Without<ResourceCounter>>,
 ) {
-    if let Ok(player) = player_q.single() {
-        for (mut text, mut display) in silver_text_q.iter_mut() {
-            if display.last_value != player.silver {
-                display.last_value = player.silver;
-                *text = Text::new(format!("Silver: {}", player.silver));
-            }
+    let Ok(player) = player_q.single() else {
+        return;
+    };
+    for (mut text, mut counter) in counters.iter_mut() {
+        let value = match counter.kind {
+            HarvestableKind::Wood => player.wood,
+            HarvestableKind::Rock => player.rock,
+        };
+        if counter.last_value != value {
+            counter.last_value = value;
+            let label = match counter.kind {
+                HarvestableKind::Wood => "Wood",
+                HarvestableKind::Rock => "Rock",
+            };
+            *text = Text::new(format!("{}: {}", label, value));
         }
-        for (mut text, mut display) in gold_text_q.iter_mut() {
-            if display.last_value != player.gold {
-                display.last_value = player.gold;
-                *text = Text::new(format!("Gold: {}", player.gold));
-            }
+    }
+    for (mut text, mut counter) in currency_counters.iter_mut() {
+        let value = counter.kind.amount(player);
+        if counter.last_value != value {
+            counter.last_value = value;
+            *text = Text::new(format!("{}: {}", counter.kind.label(), value));
         }
     }
 }
 
 #[allow(clippy::type_complexity)]
 pub fn update_wave_hud(
+    time: Res<Time>,
+    tunables: Res<Tunables>,
     wave_state: Res<WaveState>,
     mut wave_text_q: Query<(&mut Text, &mut WaveCounterDisplay), With<WaveCounterText>>,
     mut timer_text_q: Query<
         (&mut Text, &mut WaveTimerDisplay),
         (With<WaveTimerText>, Without<WaveCounterText>),
     >,
+    mut bar_q: Query<&mut Node, (With<WaveProgressBar>, Without<WaveProgressFill>)>,
+    mut fill_q: Query<(&mut Node, &mut BackgroundColor, &mut WaveProgressFill)>,
 ) {
+    let display = if tunables.show_wave_progress_bar {
+        Display::Flex
+    } else {
+        Display::None
+    };
+    for mut node in bar_q.iter_mut() {
+        if node.display != display {
+            node.display = display;
+        }
+    }
+    for (mut node, mut color, mut fill) in fill_q.iter_mut() {
+        update_wave_progress_fill(&wave_state, &time, &mut fill);
+        node.width = Val::Percent(fill.shown * 100.0);
+        let target = match fill.phase {
+            WavePhase::Spawning => WAVE_PROGRESS_SPAWNING_COLOR,
+            WavePhase::Intermission => WAVE_PROGRESS_INTERMISSION_COLOR,
+        };
+        color.set_if_neq(BackgroundColor(target));
+    }
+
     if !wave_state.is_changed() {
         return;
     }
@@ -329,6 +764,153 @@ pub fn update_wave_hud(
     }
 }
 
+/// Spawning fills the bar with the share of the wave already spawned; intermission drains it
+/// with the time left. A new wave first slides the bar back to empty.
+fn update_wave_progress_fill(wave_state: &WaveState, time: &Time, fill: &mut WaveProgressFill) {
+    if fill.phase != wave_state.phase {
+        fill.phase = wave_state.phase;
+        if fill.phase == WavePhase::Spawning {
+            fill.reset = Some((
+                fill.shown,
+                Timer::from_seconds(WAVE_PROGRESS_RESET_SECS, TimerMode::Once),
+            ));
+        }
+    }
+
+    if let Some((from, timer)) = fill.reset.as_mut() {
+        timer.tick(time.delta());
+        fill.shown = from.lerp(0.0, timer.fraction());
+        if timer.is_finished() {
+            fill.reset = None;
+        }
+        return;
+    }
+
+    fill.shown = match wave_state.phase {
+        WavePhase::Spawning => {
+            wave_state.enemies_spawned as f32 / wave_state.enemies_to_spawn.max(1) as f32
+        }
+        WavePhase::Intermission => {
+            let total = wave_state.intermission_timer.duration().as_secs_f32();
+            if total > 0.0 {
+                wave_state.remaining_intermission_secs() / total
+            } else {
+                0.0
+            }
+        }
+    }
+    .clamp(0.0, 1.0);
+}
+
+/// Shows "Enemy power scaling: +N% HP" during waves whose tower-count HP modifier exceeds 1.2.
+pub fn update_enemy_scaling_warning(
+    wave_state: Res<WaveState>,
+    mut warning_q: Query<
+        (&mut Text, &mut Node, &mut EnemyScalingWarningDisplay),
+        With<EnemyScalingWarningText>,
+    >,
+) {
+    if !wave_state.is_changed() {
+        return;
+    }
+    let show = wave_state.phase == WavePhase::Spawning && wave_state.enemy_hp_modifier > 1.2;
+    let percent = show.then(|| ((wave_state.enemy_hp_modifier - 1.0) * 100.0).round() as u32);
+
+    for (mut text, mut node, mut display) in warning_q.iter_mut() {
+        if display.last_percent == percent {
+            continue;
+        }
+        display.last_percent = percent;
+        match percent {
+            Some(p) => {
+                *text = Text::new(format!("Enemy power scaling: +{}% HP", p));
+                node.display = Display::Flex;
+            }
+            None => node.display = Display::None,
+        }
+    }
+}
+
+// Wind indicator
+#[derive(Component)]
+pub struct WindIndicatorArrow;
+
+#[derive(Component)]
+pub struct WindIndicatorText;
+
+pub fn spawn_wind_indicator(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(20.0),
+                bottom: Val::Px(20.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                row_gap: Val::Px(4.0),
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.04, 0.04, 0.06, 0.85)),
+            BorderColor::all(Color::srgba(0.6, 0.8, 0.9, 0.45)),
+            Tooltip::new("Wind: speed and direction; strong wind brings storms"),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    width: Val::Px(64.0),
+                    height: Val::Px(64.0),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                })
+                .with_children(|dial| {
+                    dial.spawn((
+                        Node {
+                            width: Val::Px(8.0),
+                            height: Val::Px(4.0),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgba(0.75, 0.92, 1.0, 0.95)),
+                        UiTransform::default(),
+                        WindIndicatorArrow,
+                    ));
+                });
+
+            parent.spawn((
+                Text::new("Wind: 0.0"),
+                TextFont {
+                    font: asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(0.8, 0.9, 0.95, 1.0)),
+                WindIndicatorText,
+            ));
+        });
+}
+
+pub fn update_wind_indicator(
+    wind: Res<WindState>,
+    tunables: Res<Tunables>,
+    mut arrow_q: Query<(&mut Node, &mut UiTransform), With<WindIndicatorArrow>>,
+    mut text_q: Query<&mut Text, With<WindIndicatorText>>,
+) {
+    if !wind.is_changed() {
+        return;
+    }
+    let strength = (wind.speed / tunables.wind_max_speed.max(0.01)).clamp(0.0, 1.0);
+    for (mut node, mut ui_tf) in arrow_q.iter_mut() {
+        node.width = Val::Px(8.0 + 52.0 * strength);
+        // UI rotation is clockwise with Y down, matching world +Z pointing toward the camera
+        ui_tf.rotation = Rot2::radians(wind.direction.y.atan2(wind.direction.x));
+    }
+    for mut text in text_q.iter_mut() {
+        *text = Text::new(format!("Wind: {:.1}", wind.speed));
+    }
+}
+
 // Game speed / pause indicator
 #[derive(Component)]
 pub struct GameSpeedIndicatorText;
@@ -353,23 +935,122 @@ pub fn spawn_game_speed_indicator(mut commands: Commands, asset_server: Res<Asse
                 },
                 TextColor(Color::srgba(0.95, 0.95, 0.95, 1.0)),
                 GameSpeedIndicatorText,
+                Tooltip::new("Game speed: + / - to change, 0 to reset, Space to pause"),
             ));
         });
 }
 
+/// Shows the speed multiplier while playing ("2x" in yellow when fast-forwarding, "0.5x" in blue
+/// in slow motion) and "||" while paused.
 pub fn update_game_speed_indicator(
     state: Res<State<GameState>>,
-    mut query: Query<&mut Text, With<GameSpeedIndicatorText>>,
+    game_speed: Res<GameSpeed>,
+    mut query: Query<(&mut Text, &mut TextColor), With<GameSpeedIndicatorText>>,
 ) {
-    if !state.is_changed() {
+    if !state.is_changed() && !game_speed.is_changed() {
         return;
     }
-    let desired = match state.get() {
-        GameState::Playing => "1x",
-        GameState::Paused => "||",
-        _ => "",
+    let normal = Color::srgba(0.95, 0.95, 0.95, 1.0);
+    let (desired, color) = match state.get() {
+        GameState::Playing => {
+            let multiplier = game_speed.multiplier;
+            let color = if multiplier > 1.0 {
+                Color::srgba(1.0, 0.85, 0.2, 1.0)
+            } else if multiplier < 1.0 {
+                Color::srgba(0.45, 0.75, 1.0, 1.0)
+            } else {
+                normal
+            };
+            (format!("{}x", multiplier), color)
+        }
+        GameState::Paused => ("||".to_string(), normal),
+        _ => (String::new(), normal),
     };
-    for mut text in query.iter_mut() {
-        *text = Text::new(desired.to_string());
+    for (mut text, mut text_color) in query.iter_mut() {
+        *text = Text::new(desired.clone());
+        text_color.0 = color;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn text(world: &World, entity: Entity) -> &str {
+        &world.get::<Text>(entity).unwrap().0
+    }
+
+    #[test]
+    fn counters_show_every_resource_and_currency() {
+        let mut world = World::new();
+        let player = world
+            .spawn(Player {
+                wood: 3,
+                rock: 4,
+                silver: 7,
+                gold: 2,
+            })
+            .id();
+        let wood = world
+            .spawn((
+                Text::default(),
+                ResourceCounter {
+                    kind: HarvestableKind::Wood,
+                    last_value: 0,
+                },
+            ))
+            .id();
+        let silver = world
+            .spawn((
+                Text::default(),
+                CurrencyCounter {
+                    kind: CurrencyKind::Silver,
+                    last_value: 0,
+                },
+            ))
+            .id();
+        let gold = world
+            .spawn((
+                Text::new("Gold: 0"),
+                CurrencyCounter {
+                    kind: CurrencyKind::Gold,
+                    last_value: 0,
+                },
+            ))
+            .id();
+
+        world.run_system_once(update_resource_counters).unwrap();
+        assert_eq!(text(&world, wood), "Wood: 3");
+        assert_eq!(text(&world, silver), "Silver: 7");
+        assert_eq!(text(&world, gold), "Gold: 2");
+
+        world.get_mut::<Player>(player).unwrap().gold = 40;
+        world.run_system_once(update_resource_counters).unwrap();
+        assert_eq!(text(&world, gold), "Gold: 40");
+        assert_eq!(world.get::<CurrencyCounter>(gold).unwrap().last_value, 40);
+    }
+
+    #[test]
+    fn counters_are_left_alone_while_amounts_hold() {
+        let mut world = World::new();
+        world.spawn(Player {
+            wood: 0,
+            rock: 0,
+            silver: 5,
+            gold: 0,
+        });
+        let silver = world
+            .spawn((
+                Text::new("stale"),
+                CurrencyCounter {
+                    kind: CurrencyKind::Silver,
+                    last_value: 5,
+                },
+            ))
+            .id();
+
+        world.run_system_once(update_resource_counters).unwrap();
+        assert_eq!(text(&world, silver), "stale");
     }
 }
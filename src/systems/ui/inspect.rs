@@ -0,0 +1,506 @@
+//! Tower inspection: right-click a tower to open a card with its live stats. The card's Upgrade
+//! button lists the catalog upgrades for the tower's kind, bought with silver for that one tower.
+
+use crate::audio::{BuildingActionEvent, BuildingActionKind};
+use crate::build::definitions::{BuildCatalog, BuildDefinitionId};
+use crate::components::{
+    BuildingMode, BuiltTower, GameState, Player, SellingMode, Tower, TowerBuildSelection,
+    TowerKillCount, TowerSpecificUpgrade, TowerUpgradeConfig, TowerUpgrades,
+};
+use crate::systems::combat::towers::cursor_to_ground;
+use crate::systems::input::handle_game_input;
+use bevy::asset::RenderAssetUsages;
+use bevy::pbr::MeshMaterial3d;
+use bevy::prelude::*;
+use bevy::render::render_resource::PrimitiveTopology;
+use std::f32::consts::{PI, TAU};
+
+const FONT_PATH: &str = "fonts/Nova_Mono/NovaMono-Regular.ttf";
+/// Same ~2.0 pick radius as selling and the targeting toggle.
+const PICK_RADIUS_SQ: f32 = 4.0;
+/// Screen offset of the card from the tower top, in logical pixels.
+const POPUP_OFFSET: Vec2 = Vec2::new(24.0, -24.0);
+const POPUP_TEXT: Color = Color::srgba(0.85, 0.93, 1.0, 1.0);
+const POPUP_BUTTON_BG: Color = Color::srgba(0.16, 0.28, 0.45, 0.9);
+const STAR_COLOR: Color = Color::srgb(1.0, 0.82, 0.2);
+/// Outer radius of a tower's upgrade star icons, in world units.
+const STAR_ICON_RADIUS: f32 = 0.22;
+/// Gap between neighbouring star icons above a tower.
+const STAR_ICON_SPACING: f32 = 0.5;
+/// Height of the star icons above the tower top.
+const STAR_ICON_LIFT: f32 = 0.5;
+/// Seconds the star burst of a purchase takes to grow, rise and fade.
+const STAR_BURST_SECS: f32 = 0.6;
+
+pub struct TowerInspectionPlugin;
+
+impl Plugin for TowerInspectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                // Before the right-click that cancels building mode, so that click never inspects
+                open_tower_inspection.before(handle_game_input),
+                toggle_inspection_upgrade_panel,
+                buy_tower_specific_upgrade,
+                update_inspection_popup,
+                upgrade_star_burst_system,
+            )
+                .chain()
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// Stats card for `tower`, following it on screen.
+#[derive(Component)]
+pub struct InspectionPopup {
+    pub tower: Entity,
+}
+
+#[derive(Component)]
+pub struct InspectionPopupText;
+
+/// Button on the card that shows or hides the upgrade list.
+#[derive(Component)]
+pub struct InspectionUpgradeButton;
+
+/// List of upgrades under the card's Upgrade button, hidden until the button is pressed.
+#[derive(Component)]
+pub struct InspectionUpgradePanel;
+
+/// Entry of the upgrade list buying `upgrade` for `tower` alone.
+#[derive(Component, Clone, Copy)]
+pub struct InspectionUpgradeOption {
+    pub tower: Entity,
+    pub upgrade: BuildDefinitionId,
+}
+
+/// Star flaring up over a tower when an upgrade is bought for it.
+#[derive(Component)]
+pub struct UpgradeStarBurst {
+    timer: Timer,
+    material: Handle<StandardMaterial>,
+}
+
+/// Right-click opens the card for the tower under the cursor (outside building and selling
+/// mode); right-clicking elsewhere or Escape closes it. Alt + right-click is left to the
+/// target priority picker.
+#[allow(clippy::too_many_arguments)]
+pub fn open_tower_inspection(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    camera_q: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    towers: Query<(Entity, &Transform, &BuiltTower)>,
+    popups: Query<Entity, With<InspectionPopup>>,
+    building_mode: Query<&BuildingMode>,
+    selling_mode: Query<&SellingMode>,
+    selection: Res<TowerBuildSelection>,
+    asset_server: Res<AssetServer>,
+    catalog: Res<BuildCatalog>,
+    upgrade_config: Res<TowerUpgradeConfig>,
+) {
+    let right_click = mouse.just_pressed(MouseButton::Right);
+    if !right_click && !keyboard.just_pressed(KeyCode::Escape) {
+        return;
+    }
+    for popup in popups.iter() {
+        commands.entity(popup).try_despawn();
+    }
+    let alt_held = keyboard.pressed(KeyCode::AltLeft) || keyboard.pressed(KeyCode::AltRight);
+    let busy = building_mode.iter().any(|mode| mode.is_active)
+        || selling_mode.iter().any(|mode| mode.is_active)
+        || selection.choice.is_some();
+    if !right_click || alt_held || busy {
+        return;
+    }
+
+    let Some(world_point) = windows
+        .single()
+        .ok()
+        .and_then(|window| window.cursor_position())
+        .and_then(|cursor| {
+            let (camera, cam_tf) = camera_q.single().ok()?;
+            cursor_to_ground(camera, cam_tf, cursor, 0.0)
+        })
+    else {
+        return;
+    };
+    let nearest = towers
+        .iter()
+        .map(|(entity, transform, built)| {
+            let d2 = (transform.translation - world_point)
+                .with_y(0.0)
+                .length_squared();
+            (d2, entity, built.kind)
+        })
+        .filter(|(d2, _, _)| *d2 <= PICK_RADIUS_SQ)
+        .min_by(|a, b| a.0.total_cmp(&b.0));
+    let Some((_, tower, kind)) = nearest else {
+        return;
+    };
+    let font = asset_server.load(FONT_PATH);
+
+    commands
+        .spawn((
+            InspectionPopup { tower },
+            Node {
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(8.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.08, 0.12, 0.2, 0.9)),
+            BorderColor::all(Color::srgba(0.45, 0.75, 1.0, 0.7)),
+            // Placed by update_inspection_popup once the tower's screen position is known
+            Visibility::Hidden,
+            Pickable::IGNORE,
+        ))
+        .with_children(|card| {
+            card.spawn((
+                InspectionPopupText,
+                Text::new(""),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(POPUP_TEXT),
+            ));
+            card.spawn((
+                InspectionUpgradeButton,
+                Button,
+                Node {
+                    margin: UiRect::top(Val::Px(6.0)),
+                    padding: UiRect::axes(Val::Px(8.0), Val::Px(3.0)),
+                    align_self: AlignSelf::FlexStart,
+                    ..default()
+                },
+                BackgroundColor(POPUP_BUTTON_BG),
+            ))
+            .with_children(|button| {
+                button.spawn((
+                    Text::new("Upgrade"),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 15.0,
+                        ..default()
+                    },
+                    TextColor(POPUP_TEXT),
+                ));
+            });
+            card.spawn((
+                InspectionUpgradePanel,
+                Node {
+                    display: Display::None,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.0),
+                    margin: UiRect::top(Val::Px(6.0)),
+                    ..default()
+                },
+            ))
+            .with_children(|panel| {
+                for upgrade in catalog.upgrades.iter().filter(|u| u.tower_kind == kind) {
+                    let bonus = upgrade_config.calculate_bonus(kind, upgrade.stat, 1);
+                    panel
+                        .spawn((
+                            InspectionUpgradeOption {
+                                tower,
+                                upgrade: upgrade.id,
+                            },
+                            Button,
+                            Node {
+                                padding: UiRect::axes(Val::Px(8.0), Val::Px(3.0)),
+                                ..default()
+                            },
+                            BackgroundColor(POPUP_BUTTON_BG),
+                        ))
+                        .with_children(|option| {
+                            option.spawn((
+                                Text::new(format!(
+                                    "{}  {} silver  +{} {}",
+                                    upgrade.display_name,
+                                    upgrade.silver_cost,
+                                    bonus,
+                                    upgrade.stat.label()
+                                )),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(POPUP_TEXT),
+                            ));
+                        });
+                }
+            });
+        });
+}
+
+/// The card's Upgrade button shows or hides its upgrade list.
+pub fn toggle_inspection_upgrade_panel(
+    buttons: Query<&Interaction, (Changed<Interaction>, With<InspectionUpgradeButton>)>,
+    mut panels: Query<&mut Node, With<InspectionUpgradePanel>>,
+) {
+    if !buttons.iter().any(|i| *i == Interaction::Pressed) {
+        return;
+    }
+    for mut node in panels.iter_mut() {
+        node.display = match node.display {
+            Display::None => Display::Flex,
+            _ => Display::None,
+        };
+    }
+}
+
+/// Mesh and material shared by the star icons of tower specific upgrades.
+pub fn upgrade_star_assets(
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+) -> (Handle<Mesh>, Handle<StandardMaterial>) {
+    (
+        meshes.add(build_star_mesh(STAR_ICON_RADIUS)),
+        materials.add(StandardMaterial {
+            base_color: STAR_COLOR,
+            emissive: LinearRgba::from(STAR_COLOR) * 0.6,
+            unlit: true,
+            cull_mode: None,
+            ..default()
+        }),
+    )
+}
+
+/// Spawns the star icon that carries `upgrade` as a child of its tower, in the given slot of the
+/// tower's row of stars.
+pub fn spawn_upgrade_star(
+    commands: &mut Commands,
+    upgrade: TowerSpecificUpgrade,
+    (star_mesh, star_material): (Handle<Mesh>, Handle<StandardMaterial>),
+    slot: u32,
+    max_level: u32,
+    tower_height: f32,
+) -> Entity {
+    // Stars sit in a row above the tower, centered on a full row of max_level stars
+    let offset = slot as f32 - (max_level.saturating_sub(1)) as f32 * 0.5;
+    let icon = commands
+        .spawn((
+            upgrade,
+            Mesh3d(star_mesh),
+            MeshMaterial3d(star_material),
+            Transform::from_xyz(
+                offset * STAR_ICON_SPACING,
+                tower_height * 0.5 + STAR_ICON_LIFT,
+                0.0,
+            ),
+        ))
+        .id();
+    commands.entity(upgrade.tower_entity).add_child(icon);
+    icon
+}
+
+/// Buys the clicked upgrade for the inspected tower only: charges its silver cost, raises that
+/// tower's stat and marks it with another star. A tower takes up to `TowerUpgradeConfig::max_level`
+/// of these.
+#[allow(clippy::too_many_arguments)]
+pub fn buy_tower_specific_upgrade(
+    mut commands: Commands,
+    options: Query<(&Interaction, &InspectionUpgradeOption), Changed<Interaction>>,
+    catalog: Res<BuildCatalog>,
+    upgrade_config: Res<TowerUpgradeConfig>,
+    mut player_q: Query<&mut Player>,
+    mut towers: Query<(&mut Tower, &BuiltTower, &Transform)>,
+    bought: Query<&TowerSpecificUpgrade>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut building_sfx: MessageWriter<BuildingActionEvent>,
+    mut star_assets: Local<Option<(Handle<Mesh>, Handle<StandardMaterial>)>>,
+) {
+    for (interaction, option) in options.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(upgrade) = catalog.upgrades.iter().find(|u| u.id == option.upgrade) else {
+            continue;
+        };
+        let Ok((mut tower, built, transform)) = towers.get_mut(option.tower) else {
+            continue;
+        };
+        let Ok(mut player) = player_q.single_mut() else {
+            continue;
+        };
+        let stars = bought
+            .iter()
+            .filter(|u| u.tower_entity == option.tower)
+            .count() as u32;
+        if player.silver < upgrade.silver_cost || stars >= upgrade_config.max_level {
+            building_sfx.write(BuildingActionEvent {
+                kind: BuildingActionKind::Invalid,
+                position: transform.translation,
+            });
+            continue;
+        }
+
+        player.silver -= upgrade.silver_cost;
+        let specific = TowerSpecificUpgrade {
+            tower_entity: option.tower,
+            bonus: upgrade_config.calculate_bonus(built.kind, upgrade.stat, 1),
+            stat: upgrade.stat,
+        };
+        specific.apply(&mut tower);
+
+        let (star_mesh, star_material) = star_assets
+            .get_or_insert_with(|| upgrade_star_assets(&mut meshes, &mut materials))
+            .clone();
+        spawn_upgrade_star(
+            &mut commands,
+            specific,
+            (star_mesh.clone(), star_material),
+            stars,
+            upgrade_config.max_level,
+            tower.height,
+        );
+
+        let burst_material = materials.add(StandardMaterial {
+            base_color: STAR_COLOR,
+            emissive: LinearRgba::from(STAR_COLOR),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            cull_mode: None,
+            ..default()
+        });
+        commands.spawn((
+            Mesh3d(star_mesh),
+            MeshMaterial3d(burst_material.clone()),
+            Transform::from_translation(
+                transform.translation + Vec3::Y * (tower.height * 0.5 + STAR_ICON_LIFT),
+            ),
+            UpgradeStarBurst {
+                timer: Timer::from_seconds(STAR_BURST_SECS, TimerMode::Once),
+                material: burst_material,
+            },
+        ));
+
+        building_sfx.write(BuildingActionEvent {
+            kind: BuildingActionKind::Upgrade,
+            position: transform.translation,
+        });
+    }
+}
+
+/// Grows, spins and fades out the star of a fresh purchase.
+pub fn upgrade_star_burst_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut bursts: Query<(Entity, &mut UpgradeStarBurst, &mut Transform)>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (entity, mut burst, mut transform) in bursts.iter_mut() {
+        burst.timer.tick(time.delta());
+        let t = burst.timer.fraction();
+        transform.scale = Vec3::splat(1.0 + t * 3.0);
+        transform.translation.y += time.delta_secs() * 1.5;
+        transform.rotate_y(time.delta_secs() * PI);
+        if let Some(mat) = materials.get_mut(&burst.material) {
+            mat.base_color.set_alpha(burst.timer.fraction_remaining());
+        }
+        if burst.timer.just_finished() {
+            materials.remove(burst.material.id());
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Flat five-pointed star on the XZ plane, facing up.
+fn build_star_mesh(outer_radius: f32) -> Mesh {
+    let inner_radius = outer_radius * 0.45;
+    let rim: Vec<Vec3> = (0..10)
+        .map(|i| {
+            let radius = if i % 2 == 0 {
+                outer_radius
+            } else {
+                inner_radius
+            };
+            let angle = i as f32 / 10.0 * TAU;
+            Vec3::new(radius * angle.sin(), 0.0, -radius * angle.cos())
+        })
+        .collect();
+    let mut positions = Vec::with_capacity(rim.len() * 3);
+    for i in 0..rim.len() {
+        let next = rim[(i + 1) % rim.len()];
+        positions.extend([[0.0, 0.0, 0.0], rim[i].to_array(), next.to_array()]);
+    }
+    let normals = vec![[0.0, 1.0, 0.0]; positions.len()];
+
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    );
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh
+}
+
+/// Keeps the card next to its tower and its stats current; closes it once the tower is gone.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn update_inspection_popup(
+    mut commands: Commands,
+    camera_q: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    towers: Query<(&Transform, &Tower, &BuiltTower, Option<&TowerKillCount>)>,
+    upgrades: Res<TowerUpgrades>,
+    upgrade_config: Res<TowerUpgradeConfig>,
+    bought: Query<&TowerSpecificUpgrade>,
+    mut popups: Query<(
+        Entity,
+        &InspectionPopup,
+        &mut Node,
+        &mut Visibility,
+        &Children,
+    )>,
+    mut texts: Query<&mut Text, With<InspectionPopupText>>,
+) {
+    let Ok((camera, cam_tf)) = camera_q.single() else {
+        return;
+    };
+    for (entity, popup, mut node, mut visibility, children) in popups.iter_mut() {
+        let Ok((transform, tower, built, kills)) = towers.get(popup.tower) else {
+            commands.entity(entity).try_despawn();
+            continue;
+        };
+
+        let top = transform.translation + Vec3::Y * tower.height * 0.5;
+        let Ok(screen) = camera.world_to_viewport(cam_tf, top) else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        node.left = Val::Px(screen.x + POPUP_OFFSET.x);
+        node.top = Val::Px(screen.y + POPUP_OFFSET.y);
+        visibility.set_if_neq(Visibility::Visible);
+
+        let stars = bought
+            .iter()
+            .filter(|u| u.tower_entity == popup.tower)
+            .count();
+        let stats = format!(
+            "{} Tower\nDamage: {}\nFire interval: {:.2}s\nRange: {:.1}\nProjectile speed: {:.1}\nUpgrade level: {}\nTower upgrades: {}/{}\nKills: {}",
+            built.kind.name(),
+            tower.damage,
+            tower.fire_interval_secs,
+            tower.range,
+            tower.projectile_speed,
+            upgrades.get_level(built.kind),
+            stars,
+            upgrade_config.max_level,
+            kills.map_or(0, |k| k.0),
+        );
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child)
+                && text.0 != stats
+            {
+                text.0 = stats.clone();
+            }
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use crate::components::{Enemy, GameState, Player, Tower, TowerGhost, Village};
+use crate::constants::Tunables;
+use bevy::input::mouse::AccumulatedMouseScroll;
+use bevy::prelude::*;
+
+/// Side of the square map area in pixels.
+const MINIMAP_SIZE: f32 = 200.0;
+/// Sits below the wave HUD, which owns the top-right corner.
+const MINIMAP_TOP: f32 = 200.0;
+const MINIMAP_MAX_SCALE: f32 = 8.0;
+/// Zoom factor applied per mouse wheel step over the minimap.
+const ZOOM_STEP: f32 = 1.25;
+const TOWER_DOT: (f32, Color) = (5.0, Color::srgb(0.3, 0.9, 0.35));
+const ENEMY_DOT: (f32, Color) = (4.0, Color::srgb(0.95, 0.2, 0.2));
+const VILLAGE_DOT: (f32, Color) = (10.0, Color::srgb(0.7, 0.35, 0.95));
+const PLAYER_MARKER_SIZE: f32 = 8.0;
+
+/// Top-right overview of the ground with towers, enemies, the village and the player.
+/// `M` hides it; the mouse wheel over it zooms in around the player.
+pub struct MinimapPlugin;
+
+impl Plugin for MinimapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MinimapScale>()
+            .add_systems(OnEnter(GameState::Loading), spawn_minimap)
+            .add_systems(
+                Update,
+                (
+                    minimap_toggle_input,
+                    minimap_zoom_system,
+                    update_minimap_system,
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Zoom of the minimap: 1 shows the whole ground, 2 shows half its width, and so on.
+#[derive(Resource, Clone, Copy)]
+pub struct MinimapScale(pub f32);
+
+impl Default for MinimapScale {
+    fn default() -> Self {
+        MinimapScale(1.0)
+    }
+}
+
+#[derive(Component)]
+pub struct MinimapRoot;
+
+/// Clipped map area the dots are positioned in.
+#[derive(Component)]
+pub struct MinimapFrame;
+
+#[derive(Component)]
+pub struct MinimapDot;
+
+#[derive(Component)]
+pub struct MinimapPlayerMarker;
+
+pub fn spawn_minimap(mut commands: Commands) {
+    commands.spawn((
+        MinimapRoot,
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(20.0),
+            top: Val::Px(MINIMAP_TOP),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.05, 0.06, 0.08, 0.9)),
+        BorderColor::all(Color::srgba(0.75, 0.6, 0.9, 0.45)),
+        children![(
+            MinimapFrame,
+            Interaction::default(),
+            Node {
+                width: Val::Px(MINIMAP_SIZE),
+                height: Val::Px(MINIMAP_SIZE),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            children![(
+                MinimapPlayerMarker,
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Px(PLAYER_MARKER_SIZE),
+                    height: Val::Px(PLAYER_MARKER_SIZE),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BorderColor::all(Color::WHITE),
+                ZIndex(1),
+            )],
+        )],
+    ));
+}
+
+pub fn minimap_toggle_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut roots: Query<&mut Visibility, With<MinimapRoot>>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyM) {
+        return;
+    }
+    for mut visibility in roots.iter_mut() {
+        *visibility = match *visibility {
+            Visibility::Hidden => Visibility::Inherited,
+            _ => Visibility::Hidden,
+        };
+    }
+}
+
+/// Mouse wheel over the minimap zooms it.
+pub fn minimap_zoom_system(
+    scroll: Res<AccumulatedMouseScroll>,
+    frames: Query<&Interaction, With<MinimapFrame>>,
+    mut scale: ResMut<MinimapScale>,
+) {
+    if scroll.delta.y == 0.0 || !frames.iter().any(|i| *i != Interaction::None) {
+        return;
+    }
+    let zoomed = scale.0 * ZOOM_STEP.powf(scroll.delta.y.signum());
+    scale.0 = zoomed.clamp(1.0, MINIMAP_MAX_SCALE);
+}
+
+/// Projects tower, enemy and village positions onto the minimap, reusing a pool of dot nodes.
+/// The view is centered on the player and kept inside the ground, so it only pans when zoomed.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn update_minimap_system(
+    mut commands: Commands,
+    tunables: Res<Tunables>,
+    scale: Res<MinimapScale>,
+    frames: Query<Entity, With<MinimapFrame>>,
+    towers: Query<&Transform, (With<Tower>, Without<TowerGhost>)>,
+    enemies: Query<&Transform, With<Enemy>>,
+    villages: Query<&Transform, With<Village>>,
+    players: Query<&Transform, With<Player>>,
+    mut dots: Query<
+        (&mut Node, &mut BackgroundColor, &mut Visibility),
+        (With<MinimapDot>, Without<MinimapPlayerMarker>),
+    >,
+    mut markers: Query<(&mut Node, &mut Visibility), With<MinimapPlayerMarker>>,
+    mut pool: Local<Vec<Entity>>,
+) {
+    let Ok(frame) = frames.single() else {
+        return;
+    };
+    let player = players.single().map(|tf| tf.translation.xz()).ok();
+
+    let extent = tunables.ground_size / scale.0.max(1.0);
+    let half_room = (tunables.ground_size - extent) * 0.5;
+    let center = player
+        .unwrap_or(Vec2::ZERO)
+        .clamp(Vec2::splat(-half_room), Vec2::splat(half_room));
+    // North (-Z) is up, matching the camera
+    let project = |p: Vec2| ((p - center) / extent + Vec2::splat(0.5)) * MINIMAP_SIZE;
+    let on_map =
+        |px: Vec2| px.x >= 0.0 && px.y >= 0.0 && px.x <= MINIMAP_SIZE && px.y <= MINIMAP_SIZE;
+
+    let points = villages
+        .iter()
+        .map(|tf| (tf.translation.xz(), VILLAGE_DOT))
+        .chain(towers.iter().map(|tf| (tf.translation.xz(), TOWER_DOT)))
+        .chain(enemies.iter().map(|tf| (tf.translation.xz(), ENEMY_DOT)))
+        .map(|(p, style)| (project(p), style))
+        .filter(|(px, _)| on_map(*px));
+
+    let mut used = 0;
+    for (px, (size, color)) in points {
+        let (left, top) = (Val::Px(px.x - size * 0.5), Val::Px(px.y - size * 0.5));
+        if let Some(dot) = pool.get(used)
+            && let Ok((mut node, mut background, mut visibility)) = dots.get_mut(*dot)
+        {
+            node.left = left;
+            node.top = top;
+            node.width = Val::Px(size);
+            node.height = Val::Px(size);
+            background.0 = color;
+            visibility.set_if_neq(Visibility::Inherited);
+        } else if used >= pool.len() {
+            let dot = commands
+                .spawn((
+                    MinimapDot,
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left,
+                        top,
+                        width: Val::Px(size),
+                        height: Val::Px(size),
+                        ..default()
+                    },
+                    BackgroundColor(color),
+                    Visibility::Inherited,
+                    ChildOf(frame),
+                ))
+                .id();
+            pool.push(dot);
+        }
+        used += 1;
+    }
+    for dot in pool.iter().skip(used) {
+        if let Ok((_, _, mut visibility)) = dots.get_mut(*dot) {
+            visibility.set_if_neq(Visibility::Hidden);
+        }
+    }
+
+    if let Ok((mut node, mut visibility)) = markers.single_mut() {
+        let px = player.map(project).filter(|px| on_map(*px));
+        visibility.set_if_neq(if px.is_some() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+        if let Some(px) = px {
+            node.left = Val::Px(px.x - PLAYER_MARKER_SIZE * 0.5);
+            node.top = Val::Px(px.y - PLAYER_MARKER_SIZE * 0.5);
+        }
+    }
+}
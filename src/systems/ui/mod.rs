@@ -1,4 +1,14 @@
 pub mod collect_bar;
+pub mod grid_overlay;
 pub mod hud;
+pub mod inspect;
+pub mod minimap;
 pub mod observers;
+#[cfg(feature = "devtools")]
+pub mod policy_editor;
+pub mod run_summary;
+pub mod settings;
+pub mod tooltip;
 pub mod warmup;
+pub mod wave_preview;
+pub mod wiki;
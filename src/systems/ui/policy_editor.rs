@@ -0,0 +1,232 @@
+//! Devtools editor for `RandomizationPolicy` (F2), plus a corner readout of the running policy.
+//!
+//! The policy is read once at launch, so toggles are saved to `td/policy.toml` and take effect on
+//! the next start. `--no-seed-*` flags only affect the current run and are never saved.
+
+use crate::components::GameState;
+use crate::random_policy::{RandomizationPolicy, load_policy, save_policy};
+use bevy::prelude::*;
+
+const FONT_PATH: &str = "fonts/Nova_Mono/NovaMono-Regular.ttf";
+const TEXT: Color = Color::srgba(0.85, 0.93, 1.0, 1.0);
+const TEXT_FAINT: Color = Color::srgba(0.85, 0.93, 1.0, 0.6);
+const BUTTON_BG: Color = Color::srgba(0.12, 0.18, 0.3, 0.9);
+const BUTTON_BG_HOVER: Color = Color::srgba(0.18, 0.26, 0.42, 0.95);
+
+pub struct PolicyEditorPlugin;
+
+impl Plugin for PolicyEditorPlugin {
+    fn build(&self, app: &mut App) {
+        let saved = load_policy();
+        app.insert_resource(EditedPolicy {
+            policy: saved,
+            at_launch: saved,
+        })
+        .add_systems(
+            OnEnter(GameState::Loading),
+            (spawn_policy_editor, spawn_policy_indicator),
+        )
+        .add_systems(
+            Update,
+            (
+                toggle_policy_editor,
+                policy_toggle_interaction,
+                update_policy_toggles,
+                update_policy_indicator,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// The policy as edited in the panel (and saved to `td/policy.toml`), used from the next launch.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct EditedPolicy {
+    pub policy: RandomizationPolicy,
+    /// What `td/policy.toml` held when the game started.
+    pub at_launch: RandomizationPolicy,
+}
+
+#[derive(Component)]
+pub struct PolicyEditorPanel;
+
+/// Toggle for the field at this index of `RandomizationPolicy::fields()`.
+#[derive(Component, Clone, Copy)]
+pub struct PolicyToggle(pub usize);
+
+#[derive(Component)]
+pub struct PolicyIndicatorText;
+
+fn toggle_label(name: &str, seeded: bool) -> String {
+    format!("[{}] {}", if seeded { "x" } else { " " }, name)
+}
+
+fn spawn_policy_editor(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    edited: Res<EditedPolicy>,
+) {
+    let font = asset_server.load(FONT_PATH);
+    let text = |value: String, size: f32, color: Color| {
+        (
+            Text::new(value),
+            TextFont {
+                font: font.clone(),
+                font_size: size,
+                ..default()
+            },
+            TextColor(color),
+        )
+    };
+
+    commands
+        .spawn((
+            PolicyEditorPanel,
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(20.0),
+                top: Val::Px(120.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.08, 0.12, 0.2, 0.9)),
+            BorderColor::all(Color::srgba(0.45, 0.75, 1.0, 0.7)),
+            GlobalZIndex(15),
+            Visibility::Hidden,
+        ))
+        .with_children(|panel| {
+            panel.spawn(text("Randomization policy (F2)".to_string(), 18.0, TEXT));
+            for (index, (name, seeded)) in edited.policy.fields().into_iter().enumerate() {
+                panel
+                    .spawn((
+                        Button,
+                        PolicyToggle(index),
+                        Node {
+                            padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                            ..default()
+                        },
+                        BackgroundColor(BUTTON_BG),
+                    ))
+                    .with_children(|button| {
+                        button.spawn(text(toggle_label(name, seeded), 15.0, TEXT));
+                    });
+            }
+            panel.spawn(text(
+                "Saved to td/policy.toml, applies on restart".to_string(),
+                13.0,
+                TEXT_FAINT,
+            ));
+        });
+}
+
+fn spawn_policy_indicator(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        PolicyIndicatorText,
+        Text::new(""),
+        TextFont {
+            font: asset_server.load(FONT_PATH),
+            font_size: 13.0,
+            ..default()
+        },
+        TextColor(TEXT_FAINT),
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(8.0),
+            bottom: Val::Px(4.0),
+            ..default()
+        },
+        Pickable::IGNORE,
+    ));
+}
+
+fn toggle_policy_editor(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut panels: Query<&mut Visibility, With<PolicyEditorPanel>>,
+) {
+    if !keyboard.just_pressed(KeyCode::F2) {
+        return;
+    }
+    for mut visibility in panels.iter_mut() {
+        *visibility = match *visibility {
+            Visibility::Hidden => Visibility::Visible,
+            _ => Visibility::Hidden,
+        };
+    }
+}
+
+/// Flips the clicked field and saves the edited policy right away.
+fn policy_toggle_interaction(
+    mut interactions: Query<
+        (&Interaction, &PolicyToggle, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    mut edited: ResMut<EditedPolicy>,
+) {
+    for (interaction, toggle, mut bg) in interactions.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                if let Some(seeded) = edited.policy.field_mut(toggle.0) {
+                    *seeded = !*seeded;
+                }
+                match save_policy(&edited.policy) {
+                    Ok(path) => info!("Randomization policy saved to {:?}", path),
+                    Err(e) => warn!("Failed to save randomization policy: {}", e),
+                }
+            }
+            Interaction::Hovered => *bg = BackgroundColor(BUTTON_BG_HOVER),
+            Interaction::None => *bg = BackgroundColor(BUTTON_BG),
+        }
+    }
+}
+
+fn update_policy_toggles(
+    edited: Res<EditedPolicy>,
+    toggles: Query<(&PolicyToggle, &Children)>,
+    mut texts: Query<&mut Text>,
+) {
+    if !edited.is_changed() {
+        return;
+    }
+    let fields = edited.policy.fields();
+    for (toggle, children) in toggles.iter() {
+        let Some(&(name, seeded)) = fields.get(toggle.0) else {
+            continue;
+        };
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                text.0 = toggle_label(name, seeded);
+            }
+        }
+    }
+}
+
+/// Lists the unseeded fields of the running policy, and flags pending edits.
+fn update_policy_indicator(
+    active: Res<RandomizationPolicy>,
+    edited: Res<EditedPolicy>,
+    mut texts: Query<&mut Text, With<PolicyIndicatorText>>,
+) {
+    if !active.is_changed() && !edited.is_changed() {
+        return;
+    }
+    let unseeded: Vec<&str> = active
+        .fields()
+        .into_iter()
+        .filter(|(_, seeded)| !seeded)
+        .map(|(name, _)| name)
+        .collect();
+    let mut line = if unseeded.is_empty() {
+        "Policy: all seeded".to_string()
+    } else {
+        format!("Policy unseeded: {}", unseeded.join(", "))
+    };
+    if edited.policy != edited.at_launch {
+        line.push_str(" (edited, restart to apply)");
+    }
+    for mut text in texts.iter_mut() {
+        text.0 = line.clone();
+    }
+}
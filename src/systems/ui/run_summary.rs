@@ -0,0 +1,383 @@
+//! Run statistics and the game over screen shown when a run ends.
+//!
+//! A run ends when the village falls or, with `Tunables.max_waves` set, once that wave has been
+//! survived; either way the game enters `GameState::GameOver`. The best value of every stat is
+//! kept in `td/highscore.json`, with challenge runs ranked separately from normal ones.
+
+use crate::audio::{GameOverEvent, WaveCompletedEvent};
+use crate::build::theme::{paper_panel, shadow_node};
+use crate::components::{GameState, PauseReason, Village, WaveState, WaveTimerPaused};
+use crate::constants::Tunables;
+use crate::core::stats::{HighScores, RunStats};
+use crate::events::{DamageDealt, EnemyKilled, ResourceCollected, TowerBuilt};
+use crate::systems::replay::RunCodeText;
+use crate::systems::restart::restart_run;
+use crate::utils::paths::app_data_dir;
+use bevy::prelude::*;
+use std::fs;
+use std::path::PathBuf;
+
+const HIGHSCORE_FILE_NAME: &str = "highscore.json";
+const FONT_PATH: &str = "fonts/Nova_Mono/NovaMono-Regular.ttf";
+const INK: Color = Color::srgba(0.08, 0.09, 0.11, 1.0);
+const INK_FAINT: Color = Color::srgba(0.18, 0.17, 0.19, 0.85);
+const HIGHLIGHT: Color = Color::srgba(0.85, 0.55, 0.05, 1.0);
+const DEFEAT_INK: Color = Color::srgba(0.62, 0.08, 0.06, 1.0);
+const VICTORY_INK: Color = Color::srgba(0.78, 0.52, 0.02, 1.0);
+const DEFEAT_BACKDROP: Color = Color::srgba(0.18, 0.0, 0.0, 0.6);
+const VICTORY_BACKDROP: Color = Color::srgba(0.2, 0.15, 0.0, 0.45);
+
+/// Tracks `RunStats` during play and shows the game over screen when the run ends.
+pub struct RunSummaryPlugin;
+
+impl Plugin for RunSummaryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunStats>()
+            .add_systems(
+                OnTransition {
+                    exited: GameState::Loading,
+                    entered: GameState::Playing,
+                },
+                start_run_clock,
+            )
+            .add_systems(
+                Update,
+                (accumulate_run_stats, check_game_over_system)
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(OnEnter(GameState::GameOver), spawn_game_over_screen)
+            .add_systems(OnExit(GameState::GameOver), despawn_game_over_screen)
+            .add_systems(
+                Update,
+                game_over_buttons.run_if(in_state(GameState::GameOver)),
+            );
+    }
+}
+
+/// Set once the run has ended; holds what the summary panel displays.
+#[derive(Resource, Debug, Clone)]
+pub struct RunSummary {
+    pub victory: bool,
+    pub run_time_secs: f64,
+    /// Stats that beat the previous personal best, by display name.
+    pub personal_bests: Vec<&'static str>,
+}
+
+/// Root of the game over overlay.
+#[derive(Component)]
+pub struct RunSummaryPanel;
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameOverButton {
+    /// Back to the game after a victory.
+    KeepPlaying,
+    /// Fresh run on the same map.
+    Restart,
+    /// Fresh run, waiting in the menu.
+    MainMenu,
+    /// Puts this run's shareable code on the clipboard.
+    CopyRunCode,
+}
+
+/// Starts the run clock when the first run begins and notes whether it is played under challenge
+/// rules. Later runs start from `restart_run`.
+fn start_run_clock(time: Res<Time<Virtual>>, tunables: Res<Tunables>, mut stats: ResMut<RunStats>) {
+    stats.run_start_time = time.elapsed_secs_f64();
+    stats.challenge_mode = tunables.challenge_mode;
+}
+
+fn accumulate_run_stats(
+    mut stats: ResMut<RunStats>,
+    wave_state: Res<WaveState>,
+    mut killed: MessageReader<EnemyKilled>,
+    mut damage: MessageReader<DamageDealt>,
+    mut built: MessageReader<TowerBuilt>,
+    mut collected: MessageReader<ResourceCollected>,
+    mut waves_completed: MessageReader<WaveCompletedEvent>,
+) {
+    for _ in killed.read() {
+        stats.record_kill(wave_state.current_wave);
+    }
+    for e in damage.read() {
+        stats.record_damage(e.amount);
+    }
+    for _ in built.read() {
+        stats.record_tower_built();
+    }
+    for e in collected.read() {
+        stats.record_resources(e.amount);
+    }
+    for _ in waves_completed.read() {
+        stats.record_wave_survived();
+    }
+}
+
+/// Ends the run when the village's health reaches zero or `max_waves` have been survived:
+/// records personal bests and switches to `GameState::GameOver`. A victory is only reported once,
+/// so the player can keep playing past it; a later defeat still ends the run.
+#[allow(clippy::too_many_arguments)]
+fn check_game_over_system(
+    mut commands: Commands,
+    time: Res<Time<Virtual>>,
+    tunables: Res<Tunables>,
+    stats: Res<RunStats>,
+    summary: Option<Res<RunSummary>>,
+    village: Query<&Village>,
+    mut game_over: MessageWriter<GameOverEvent>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let defeat = village.iter().any(|village| village.health == 0);
+    let victory =
+        summary.is_none() && tunables.max_waves > 0 && stats.waves_survived >= tunables.max_waves;
+    if !(defeat || victory) {
+        return;
+    }
+
+    let run_time_secs = stats.run_time_secs(time.elapsed_secs_f64());
+    let mut scores = load_high_scores();
+    let personal_bests = scores
+        .for_mode(stats.challenge_mode)
+        .merge(&stats, run_time_secs);
+    if !personal_bests.is_empty() {
+        save_high_scores(&scores);
+    }
+
+    commands.insert_resource(RunSummary {
+        victory: !defeat,
+        run_time_secs,
+        personal_bests,
+    });
+    game_over.write(GameOverEvent { victory: !defeat });
+    next_state.set(GameState::GameOver);
+}
+
+fn highscore_path() -> Option<PathBuf> {
+    app_data_dir().map(|dir| dir.join(HIGHSCORE_FILE_NAME))
+}
+
+/// Previous bests, or all zeros when there is no (readable) highscore file yet.
+fn load_high_scores() -> HighScores {
+    let Some(path) = highscore_path() else {
+        return HighScores::default();
+    };
+    let Ok(json) = fs::read_to_string(&path) else {
+        return HighScores::default();
+    };
+    serde_json::from_str(&json).unwrap_or_else(|e| {
+        warn!("Ignoring unreadable highscores in {:?}: {}", path, e);
+        HighScores::default()
+    })
+}
+
+fn save_high_scores(scores: &HighScores) {
+    let Some(path) = highscore_path() else {
+        return;
+    };
+    let json = match serde_json::to_string_pretty(scores) {
+        Ok(json) => json,
+        Err(e) => {
+            warn!("Failed to serialize highscores: {}", e);
+            return;
+        }
+    };
+    let written = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&path, json));
+    if let Err(e) = written {
+        warn!("Failed to write highscores to {:?}: {}", path, e);
+    }
+}
+
+fn spawn_game_over_screen(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    stats: Res<RunStats>,
+    summary: Option<Res<RunSummary>>,
+    wave_state: Res<WaveState>,
+    mut timer_paused: ResMut<WaveTimerPaused>,
+) {
+    let Some(summary) = summary else {
+        return;
+    };
+    timer_paused.set(PauseReason::GameOver, true);
+    let wave = wave_state.current_wave;
+    let font = asset_server.load(FONT_PATH);
+    let text = |value: String, size: f32, color: Color| {
+        (
+            Text::new(value),
+            TextFont {
+                font: font.clone(),
+                font_size: size,
+                ..default()
+            },
+            TextColor(color),
+        )
+    };
+    let total_secs = summary.run_time_secs as u64;
+    let rows = [
+        ("Final wave", wave.to_string()),
+        ("Waves survived", stats.waves_survived.to_string()),
+        ("Enemies killed", stats.enemies_killed.to_string()),
+        ("Damage dealt", stats.total_damage_dealt.to_string()),
+        ("Towers built", stats.towers_built.to_string()),
+        ("Resources collected", stats.resources_collected.to_string()),
+        (
+            "Run time",
+            format!("{}:{:02}", total_secs / 60, total_secs % 60),
+        ),
+    ];
+    let (heading, subtitle, heading_color, backdrop_color) = if summary.victory {
+        (
+            "VICTORY",
+            format!(
+                "The village held through all {} waves",
+                stats.waves_survived
+            ),
+            VICTORY_INK,
+            VICTORY_BACKDROP,
+        )
+    } else {
+        (
+            "GAME OVER",
+            format!("The village fell on wave {}", wave),
+            DEFEAT_INK,
+            DEFEAT_BACKDROP,
+        )
+    };
+    let mut buttons = vec![
+        (GameOverButton::Restart, "Restart"),
+        (GameOverButton::MainMenu, "Main Menu"),
+        (GameOverButton::CopyRunCode, "Copy Run Code"),
+    ];
+    if summary.victory {
+        buttons.insert(0, (GameOverButton::KeepPlaying, "Keep playing"));
+    }
+
+    commands
+        .spawn((
+            RunSummaryPanel,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                top: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(backdrop_color),
+            GlobalZIndex(20),
+        ))
+        .with_children(|backdrop| {
+            backdrop.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                shadow_node(),
+            ));
+            let (marker, mut node, background, border) = paper_panel();
+            node.flex_direction = FlexDirection::Column;
+            backdrop
+                .spawn((marker, node, background, border, Name::new("RunSummary")))
+                .with_children(|panel| {
+                    panel.spawn(text(heading.to_string(), 44.0, heading_color));
+                    panel.spawn(text(subtitle, 20.0, INK_FAINT));
+
+                    for (label, value) in rows {
+                        let is_best = summary.personal_bests.contains(&label);
+                        let line = if is_best {
+                            format!("{}: {}  (new personal best!)", label, value)
+                        } else {
+                            format!("{}: {}", label, value)
+                        };
+                        panel.spawn(text(line, 18.0, if is_best { HIGHLIGHT } else { INK }));
+                    }
+
+                    if summary.personal_bests.is_empty() {
+                        panel.spawn(text(
+                            "No personal bests beaten this time".to_string(),
+                            14.0,
+                            INK_FAINT,
+                        ));
+                    }
+
+                    panel
+                        .spawn(Node {
+                            column_gap: Val::Px(10.0),
+                            margin: UiRect::top(Val::Px(8.0)),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            for (button, label) in buttons {
+                                row.spawn((
+                                    Button,
+                                    Node {
+                                        padding: UiRect::all(Val::Px(10.0)),
+                                        border: UiRect::all(Val::Px(2.0)),
+                                        ..default()
+                                    },
+                                    BackgroundColor(Color::srgba(0.98, 0.98, 0.975, 0.9)),
+                                    BorderColor::all(Color::srgba(0.18, 0.17, 0.19, 0.9)),
+                                    button,
+                                ))
+                                .with_children(|b| {
+                                    b.spawn(text(label.to_string(), 18.0, INK));
+                                });
+                            }
+                        });
+                });
+        });
+}
+
+fn despawn_game_over_screen(
+    mut commands: Commands,
+    panels: Query<Entity, With<RunSummaryPanel>>,
+    mut timer_paused: ResMut<WaveTimerPaused>,
+) {
+    timer_paused.set(PauseReason::GameOver, false);
+    for panel in panels.iter() {
+        commands.entity(panel).try_despawn();
+    }
+}
+
+/// Keep playing resumes after a victory; Restart and Main Menu both start a fresh run, the
+/// latter waiting in the menu until the player starts it. Copy Run Code leaves the screen up.
+fn game_over_buttons(
+    mut commands: Commands,
+    interactions: Query<(&Interaction, &GameOverButton), Changed<Interaction>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    run_code: Option<Res<RunCodeText>>,
+) {
+    for (interaction, button) in interactions.iter() {
+        if !matches!(*interaction, Interaction::Pressed) {
+            continue;
+        }
+        match button {
+            GameOverButton::KeepPlaying => next_state.set(GameState::Playing),
+            GameOverButton::Restart => {
+                commands.queue(restart_run);
+                next_state.set(GameState::Playing);
+            }
+            GameOverButton::MainMenu => {
+                commands.queue(restart_run);
+                next_state.set(GameState::Menu);
+            }
+            GameOverButton::CopyRunCode => {
+                if let Some(code) = run_code.as_ref().filter(|code| !code.0.is_empty()) {
+                    copy_to_clipboard(&code.0);
+                }
+            }
+        }
+    }
+}
+
+fn copy_to_clipboard(text: &str) {
+    match arboard::Clipboard::new().and_then(|mut clipboard| clipboard.set_text(text)) {
+        Ok(()) => info!("Run code copied to the clipboard"),
+        Err(e) => warn!("Failed to copy the run code to the clipboard: {}", e),
+    }
+}
@@ -0,0 +1,451 @@
+//! Settings panel opened from the build menu while the game is paused.
+//!
+//! Holds one slider per `AudioVolumes` field. Values are loaded from `td/settings.toml` at
+//! startup and written back when the window is closed.
+
+use crate::audio::AudioVolumes;
+use crate::build::theme::{paper_panel, shadow_node};
+use crate::components::{GameState, PauseReason, WaveTimerPaused};
+use crate::utils::paths::app_data_dir;
+use bevy::input::InputSystems;
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use std::fs;
+use std::path::PathBuf;
+
+const SETTINGS_FILE_NAME: &str = "settings.toml";
+const FONT_PATH: &str = "fonts/Nova_Mono/NovaMono-Regular.ttf";
+const INK: Color = Color::srgba(0.08, 0.09, 0.11, 1.0);
+const INK_FAINT: Color = Color::srgba(0.18, 0.17, 0.19, 0.85);
+const BUTTON_BG: Color = Color::srgba(0.98, 0.98, 0.975, 0.9);
+const BUTTON_BG_HOVER: Color = Color::srgba(0.99, 0.99, 0.985, 0.95);
+const BORDER: Color = Color::srgba(0.18, 0.17, 0.19, 0.9);
+const TRACK_WIDTH_PX: f32 = 360.0;
+
+/// Fullscreen audio settings reachable through the build menu's "Settings" button.
+pub struct SettingsMenuPlugin;
+
+impl Plugin for SettingsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SettingsMenuState>()
+            .add_systems(Startup, load_settings)
+            .add_systems(PreUpdate, close_settings_on_escape.after(InputSystems))
+            .add_systems(
+                Update,
+                (
+                    settings_button_interaction,
+                    settings_reset_interaction,
+                    settings_close_interaction,
+                    drag_volume_sliders,
+                    manage_settings_panel_ui,
+                    update_volume_sliders,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    close_settings_when_unpaused,
+                    save_settings_on_close.before(crate::systems::window::force_exit_on_close),
+                ),
+            );
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct SettingsMenuState {
+    pub open: bool,
+}
+
+/// One of the four volume categories a slider edits.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VolumeSetting {
+    Master,
+    Sfx,
+    Music,
+    Ambience,
+}
+
+impl VolumeSetting {
+    const ALL: [VolumeSetting; 4] = [
+        VolumeSetting::Master,
+        VolumeSetting::Sfx,
+        VolumeSetting::Music,
+        VolumeSetting::Ambience,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            VolumeSetting::Master => "Master",
+            VolumeSetting::Sfx => "SFX",
+            VolumeSetting::Music => "Music",
+            VolumeSetting::Ambience => "Ambience",
+        }
+    }
+
+    fn value_mut(self, volumes: &mut AudioVolumes) -> &mut f32 {
+        match self {
+            VolumeSetting::Master => &mut volumes.master,
+            VolumeSetting::Sfx => &mut volumes.sfx,
+            VolumeSetting::Music => &mut volumes.music,
+            VolumeSetting::Ambience => &mut volumes.ambience,
+        }
+    }
+
+    fn value(self, volumes: &AudioVolumes) -> f32 {
+        match self {
+            VolumeSetting::Master => volumes.master,
+            VolumeSetting::Sfx => volumes.sfx,
+            VolumeSetting::Music => volumes.music,
+            VolumeSetting::Ambience => volumes.ambience,
+        }
+    }
+}
+
+/// "Settings" button in the build menu's category column.
+#[derive(Component)]
+pub struct SettingsButton;
+
+/// Root of the settings overlay.
+#[derive(Component)]
+pub struct SettingsPanel;
+
+#[derive(Component)]
+pub struct SettingsResetButton;
+
+#[derive(Component)]
+pub struct SettingsCloseButton;
+
+/// Drag-able track of a volume slider.
+#[derive(Component, Clone, Copy)]
+pub struct VolumeSliderTrack(pub VolumeSetting);
+
+/// Filled part of a volume slider's track.
+#[derive(Component, Clone, Copy)]
+pub struct VolumeSliderFill(pub VolumeSetting);
+
+/// "Master: 80%" label above a volume slider.
+#[derive(Component, Clone, Copy)]
+pub struct VolumeSliderLabel(pub VolumeSetting);
+
+/// Button bundle matching the build menu's category buttons.
+pub fn settings_button() -> impl Bundle {
+    (
+        Button,
+        Node {
+            padding: UiRect::all(Val::Px(10.0)),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(BUTTON_BG),
+        BorderColor::all(BORDER),
+        SettingsButton,
+    )
+}
+
+fn settings_path() -> Option<PathBuf> {
+    app_data_dir().map(|dir| dir.join(SETTINGS_FILE_NAME))
+}
+
+/// Replaces the default volumes with the ones saved by a previous session, if any.
+fn load_settings(mut volumes: ResMut<AudioVolumes>) {
+    let Some(path) = settings_path() else {
+        return;
+    };
+    let Ok(text) = fs::read_to_string(&path) else {
+        return;
+    };
+    match toml::from_str::<AudioVolumes>(&text) {
+        Ok(loaded) => {
+            *volumes = AudioVolumes {
+                master: loaded.master.clamp(0.0, 1.0),
+                sfx: loaded.sfx.clamp(0.0, 1.0),
+                music: loaded.music.clamp(0.0, 1.0),
+                ambience: loaded.ambience.clamp(0.0, 1.0),
+            };
+        }
+        Err(e) => warn!("Failed to parse {:?}: {}", path, e),
+    }
+}
+
+/// Writes the current volumes to td/settings.toml when the window is closed.
+fn save_settings_on_close(
+    mut close_events: MessageReader<bevy::window::WindowCloseRequested>,
+    volumes: Res<AudioVolumes>,
+) {
+    if close_events.read().next().is_none() {
+        return;
+    }
+    let Some(path) = settings_path() else {
+        return;
+    };
+    let text = match toml::to_string(&*volumes) {
+        Ok(text) => text,
+        Err(e) => {
+            warn!("Failed to serialize settings: {}", e);
+            return;
+        }
+    };
+    let written = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&path, text));
+    if let Err(e) = written {
+        warn!("Failed to write settings to {:?}: {}", path, e);
+    }
+}
+
+/// Escape closes the settings panel without also closing the build menu underneath.
+fn close_settings_on_escape(
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    mut settings: ResMut<SettingsMenuState>,
+) {
+    if settings.open && keyboard.just_pressed(KeyCode::Escape) {
+        settings.open = false;
+        keyboard.clear_just_pressed(KeyCode::Escape);
+    }
+}
+
+/// The panel only makes sense while paused; close it if play resumes underneath.
+fn close_settings_when_unpaused(
+    game_state: Res<State<GameState>>,
+    mut settings: ResMut<SettingsMenuState>,
+) {
+    if settings.open && *game_state.get() != GameState::Paused {
+        settings.open = false;
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn settings_button_interaction(
+    mut interactions: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<SettingsButton>),
+    >,
+    mut settings: ResMut<SettingsMenuState>,
+) {
+    for (interaction, mut bg) in interactions.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => settings.open = true,
+            Interaction::Hovered => *bg = BackgroundColor(BUTTON_BG_HOVER),
+            Interaction::None => *bg = BackgroundColor(BUTTON_BG),
+        }
+    }
+}
+
+fn settings_reset_interaction(
+    interactions: Query<&Interaction, (Changed<Interaction>, With<SettingsResetButton>)>,
+    mut volumes: ResMut<AudioVolumes>,
+) {
+    if interactions
+        .iter()
+        .any(|i| matches!(*i, Interaction::Pressed))
+    {
+        *volumes = AudioVolumes::default();
+    }
+}
+
+fn settings_close_interaction(
+    interactions: Query<&Interaction, (Changed<Interaction>, With<SettingsCloseButton>)>,
+    mut settings: ResMut<SettingsMenuState>,
+) {
+    if interactions
+        .iter()
+        .any(|i| matches!(*i, Interaction::Pressed))
+    {
+        settings.open = false;
+    }
+}
+
+/// While a track is held, its value follows the cursor's horizontal position on it.
+fn drag_volume_sliders(
+    tracks: Query<(&Interaction, &RelativeCursorPosition, &VolumeSliderTrack)>,
+    mut volumes: ResMut<AudioVolumes>,
+) {
+    for (interaction, cursor, track) in tracks.iter() {
+        if !matches!(*interaction, Interaction::Pressed) {
+            continue;
+        }
+        // Normalized coordinates are centered on the node, spanning -0.5..0.5
+        let Some(normalized) = cursor.normalized else {
+            continue;
+        };
+        let value = (normalized.x + 0.5).clamp(0.0, 1.0);
+        if track.0.value(&volumes) != value {
+            *track.0.value_mut(&mut volumes) = value;
+        }
+    }
+}
+
+/// Keeps slider fills and percentage labels in sync with `AudioVolumes`.
+fn update_volume_sliders(
+    volumes: Res<AudioVolumes>,
+    mut fills: Query<(Ref<VolumeSliderFill>, &mut Node)>,
+    mut labels: Query<(Ref<VolumeSliderLabel>, &mut Text)>,
+) {
+    for (fill, mut node) in fills.iter_mut() {
+        if fill.is_added() || volumes.is_changed() {
+            node.width = Val::Percent(fill.0.value(&volumes) * 100.0);
+        }
+    }
+    for (label, mut text) in labels.iter_mut() {
+        if label.is_added() || volumes.is_changed() {
+            text.0 = format!(
+                "{}: {:.0}%",
+                label.0.label(),
+                label.0.value(&volumes) * 100.0
+            );
+        }
+    }
+}
+
+/// Spawns or despawns the panel when it is opened or closed; the wave timers hold while it is
+/// open.
+fn manage_settings_panel_ui(
+    mut commands: Commands,
+    settings: Res<SettingsMenuState>,
+    asset_server: Res<AssetServer>,
+    roots_q: Query<Entity, With<SettingsPanel>>,
+    mut timer_paused: ResMut<WaveTimerPaused>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    timer_paused.set(PauseReason::Settings, settings.open);
+    for root in roots_q.iter() {
+        commands.entity(root).try_despawn();
+    }
+    if !settings.open {
+        return;
+    }
+
+    let font = asset_server.load(FONT_PATH);
+    let text = |value: &str, size: f32, color: Color| {
+        (
+            Text::new(value),
+            TextFont {
+                font: font.clone(),
+                font_size: size,
+                ..default()
+            },
+            TextColor(color),
+        )
+    };
+
+    commands
+        .spawn((
+            SettingsPanel,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                top: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.25)),
+            // Draw above the build menu it was opened from
+            GlobalZIndex(10),
+        ))
+        .with_children(|backdrop| {
+            backdrop.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                shadow_node(),
+            ));
+            let (marker, mut node, background, border) = paper_panel();
+            node.flex_direction = FlexDirection::Column;
+            node.row_gap = Val::Px(20.0);
+            backdrop
+                .spawn((marker, node, background, border, Name::new("SettingsPanel")))
+                .with_children(|panel| {
+                    panel.spawn(text("Settings", 28.0, INK));
+
+                    for setting in VolumeSetting::ALL {
+                        panel
+                            .spawn(Node {
+                                flex_direction: FlexDirection::Column,
+                                row_gap: Val::Px(6.0),
+                                ..default()
+                            })
+                            .with_children(|row| {
+                                row.spawn((
+                                    text(setting.label(), 18.0, INK),
+                                    VolumeSliderLabel(setting),
+                                ));
+                                row.spawn((
+                                    Button,
+                                    Node {
+                                        width: Val::Px(TRACK_WIDTH_PX),
+                                        height: Val::Px(18.0),
+                                        border: UiRect::all(Val::Px(2.0)),
+                                        ..default()
+                                    },
+                                    BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.98)),
+                                    BorderColor::all(BORDER),
+                                    RelativeCursorPosition::default(),
+                                    VolumeSliderTrack(setting),
+                                ))
+                                .with_children(|track| {
+                                    track.spawn((
+                                        Node {
+                                            height: Val::Percent(100.0),
+                                            ..default()
+                                        },
+                                        BackgroundColor(Color::srgba(0.12, 0.47, 0.95, 0.95)),
+                                        VolumeSliderFill(setting),
+                                    ));
+                                });
+                            });
+                    }
+
+                    panel
+                        .spawn(Node {
+                            column_gap: Val::Px(8.0),
+                            ..default()
+                        })
+                        .with_children(|buttons| {
+                            buttons
+                                .spawn((
+                                    Button,
+                                    Node {
+                                        padding: UiRect::all(Val::Px(10.0)),
+                                        border: UiRect::all(Val::Px(2.0)),
+                                        ..default()
+                                    },
+                                    BackgroundColor(BUTTON_BG),
+                                    BorderColor::all(BORDER),
+                                    SettingsResetButton,
+                                ))
+                                .with_children(|b| {
+                                    b.spawn(text("Reset to Defaults", 18.0, INK));
+                                });
+                            buttons
+                                .spawn((
+                                    Button,
+                                    Node {
+                                        padding: UiRect::all(Val::Px(10.0)),
+                                        border: UiRect::all(Val::Px(2.0)),
+                                        ..default()
+                                    },
+                                    BackgroundColor(BUTTON_BG),
+                                    BorderColor::all(BORDER),
+                                    SettingsCloseButton,
+                                ))
+                                .with_children(|b| {
+                                    b.spawn(text("Back", 18.0, INK));
+                                });
+                        });
+
+                    panel.spawn(text(
+                        "Drag a bar to change its volume, Escape to go back",
+                        14.0,
+                        INK_FAINT,
+                    ));
+                });
+        });
+}
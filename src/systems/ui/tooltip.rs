@@ -0,0 +1,186 @@
+//! Hover tooltips: a node with `Tooltip` shows its text in a small floating panel next to the
+//! cursor once it has been hovered for `Tooltip::delay` seconds.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+const FONT_PATH: &str = "fonts/Nova_Mono/NovaMono-Regular.ttf";
+/// Hover time before a tooltip shows, in seconds.
+pub const TOOLTIP_DELAY_SECS: f32 = 0.5;
+/// Seconds a tooltip takes to fade out once hover ends.
+const TOOLTIP_FADE_SECS: f32 = 0.1;
+/// Offset of the tooltip from the cursor, in logical pixels.
+const CURSOR_OFFSET: Vec2 = Vec2::new(16.0, 16.0);
+const PANEL_BG: Color = Color::srgba(0.08, 0.12, 0.2, 0.9);
+const TEXT: Color = Color::srgba(0.85, 0.93, 1.0, 1.0);
+
+pub struct TooltipPlugin;
+
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TooltipTimer>()
+            .add_systems(Startup, spawn_tooltip_node)
+            .add_systems(Update, (track_tooltip_hover, update_tooltip_node).chain());
+    }
+}
+
+/// Text shown when the node is hovered for `delay` seconds. Hovering needs `Interaction`, which
+/// is added along with the tooltip.
+#[derive(Component, Clone, Debug)]
+#[require(Interaction)]
+pub struct Tooltip {
+    pub text: String,
+    pub delay: f32,
+}
+
+impl Tooltip {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            delay: TOOLTIP_DELAY_SECS,
+        }
+    }
+}
+
+/// The hovered tooltip source and how long it has been hovered; `fade` counts down once hover
+/// ends while the tooltip is still showing.
+#[derive(Resource, Debug, Default)]
+pub struct TooltipTimer {
+    pub source: Option<Entity>,
+    pub hovered_secs: f32,
+    pub fade: Option<f32>,
+}
+
+/// The one floating tooltip panel, shared by every `Tooltip`.
+#[derive(Component)]
+pub struct TooltipNode;
+
+#[derive(Component)]
+pub struct TooltipNodeText;
+
+fn spawn_tooltip_node(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn((
+            TooltipNode,
+            Node {
+                position_type: PositionType::Absolute,
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                ..default()
+            },
+            BackgroundColor(PANEL_BG),
+            GlobalZIndex(30),
+            Visibility::Hidden,
+            Pickable::IGNORE,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                TooltipNodeText,
+                Text::new(""),
+                TextFont {
+                    font: asset_server.load(FONT_PATH),
+                    font_size: 15.0,
+                    ..default()
+                },
+                TextColor(TEXT),
+            ));
+        });
+}
+
+/// Picks the hovered tooltip source and times the hover; starts the fade when hover ends.
+fn track_tooltip_hover(
+    time: Res<Time<Real>>,
+    sources: Query<(Entity, &Interaction, &Tooltip)>,
+    mut timer: ResMut<TooltipTimer>,
+) {
+    let hovered = sources
+        .iter()
+        .find(|(_, interaction, _)| **interaction != Interaction::None)
+        .map(|(entity, _, _)| entity);
+    if hovered != timer.source {
+        let was_shown = timer
+            .source
+            .and_then(|source| sources.get(source).ok())
+            .is_some_and(|(_, _, tooltip)| timer.hovered_secs >= tooltip.delay);
+        if was_shown && timer.fade.is_none() {
+            timer.fade = Some(TOOLTIP_FADE_SECS);
+        }
+        timer.source = hovered;
+        timer.hovered_secs = 0.0;
+    }
+    if timer.source.is_some() {
+        timer.hovered_secs += time.delta_secs();
+    }
+    if let Some(fade) = timer.fade.as_mut() {
+        *fade -= time.delta_secs();
+    }
+}
+
+/// Shows the hovered source's text next to the cursor, flipped to the other side of the cursor
+/// where it would leave the window, and fades it out after hover ends.
+#[allow(clippy::type_complexity)]
+fn update_tooltip_node(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    sources: Query<&Tooltip>,
+    mut timer: ResMut<TooltipTimer>,
+    mut panels: Query<
+        (
+            &mut Node,
+            &mut Visibility,
+            &mut BackgroundColor,
+            &ComputedNode,
+        ),
+        With<TooltipNode>,
+    >,
+    mut texts: Query<(&mut Text, &mut TextColor), With<TooltipNodeText>>,
+) {
+    let Ok((mut node, mut visibility, mut background, computed)) = panels.single_mut() else {
+        return;
+    };
+    let shown = timer
+        .source
+        .and_then(|source| sources.get(source).ok())
+        .filter(|tooltip| timer.hovered_secs >= tooltip.delay);
+
+    let alpha = match (shown, timer.fade) {
+        (Some(_), _) => {
+            timer.fade = None;
+            1.0
+        }
+        (None, Some(fade)) if fade > 0.0 => fade / TOOLTIP_FADE_SECS,
+        (None, _) => {
+            timer.fade = None;
+            visibility.set_if_neq(Visibility::Hidden);
+            return;
+        }
+    };
+    background.0 = PANEL_BG.with_alpha(PANEL_BG.alpha() * alpha);
+    for (mut text, mut color) in texts.iter_mut() {
+        color.0 = TEXT.with_alpha(alpha);
+        if let Some(tooltip) = shown
+            && text.0 != tooltip.text
+        {
+            text.0 = tooltip.text.clone();
+        }
+    }
+
+    // Only follow the cursor while hovered; a fading tooltip stays where it was
+    if shown.is_some()
+        && let Some((window, cursor)) = windows
+            .single()
+            .ok()
+            .and_then(|window| window.cursor_position().map(|cursor| (window, cursor)))
+    {
+        let size = computed.size() * computed.inverse_scale_factor();
+        let mut pos = cursor + CURSOR_OFFSET;
+        if pos.x + size.x > window.width() {
+            pos.x = cursor.x - CURSOR_OFFSET.x - size.x;
+        }
+        if pos.y + size.y > window.height() {
+            pos.y = cursor.y - CURSOR_OFFSET.y - size.y;
+        }
+        let pos = pos.max(Vec2::ZERO);
+        node.left = Val::Px(pos.x);
+        node.top = Val::Px(pos.y);
+    }
+    visibility.set_if_neq(Visibility::Visible);
+}
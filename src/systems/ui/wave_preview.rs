@@ -0,0 +1,200 @@
+use crate::components::{EnemyKind, WavePhase, WaveState};
+use crate::constants::Tunables;
+use crate::random_policy::RandomizationPolicy;
+use crate::systems::chunks::WorldSeed;
+use crate::waves::rules::{WaveRules, WaveSchedule};
+use bevy::prelude::*;
+
+/// The preview starts fading in this many seconds before the next wave begins.
+const PREVIEW_LEAD_SECS: f32 = 3.0;
+/// Seconds for the preview to fully fade in or out.
+const FADE_SECS: f32 = 0.5;
+/// Boss icon pulses per second on boss waves.
+const BOSS_PULSE_HZ: f32 = 2.0;
+const FONT_PATH: &str = "fonts/Nova_Mono/NovaMono-Regular.ttf";
+
+/// "Next Wave" panel listing the upcoming wave's enemies, shown at the end of intermissions.
+#[derive(Component)]
+pub struct WavePreviewPanel {
+    /// Wave whose composition is currently listed.
+    wave: Option<u32>,
+    alpha: f32,
+}
+
+/// Panel element whose color fades with the panel; keeps its fully-opaque color.
+#[derive(Component)]
+pub struct WavePreviewTint(Color);
+
+/// Icon of the boss entry, pulsing red on boss waves.
+#[derive(Component)]
+pub struct WavePreviewBossIcon;
+
+pub fn spawn_wave_preview_panel(mut commands: Commands) {
+    let background = Color::srgba(0.05, 0.06, 0.08, 0.9);
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(20.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        Visibility::Hidden,
+        Pickable::IGNORE,
+        children![(
+            Node {
+                padding: UiRect::axes(Val::Px(14.0), Val::Px(8.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                column_gap: Val::Px(8.0),
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(background.with_alpha(0.0)),
+            BorderColor::all(Color::srgba(0.75, 0.6, 0.9, 0.0)),
+            WavePreviewTint(background),
+            WavePreviewPanel {
+                wave: None,
+                alpha: 0.0,
+            },
+        )],
+    ));
+}
+
+/// Lists the upcoming wave's composition and fades the panel in during the last seconds of the
+/// intermission, then out once the wave begins.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn update_wave_preview(
+    mut commands: Commands,
+    time: Res<Time>,
+    wave_state: Res<WaveState>,
+    plan_sources: (
+        Res<Tunables>,
+        Res<WaveRules>,
+        Option<Res<WaveSchedule>>,
+        Res<WorldSeed>,
+        Res<RandomizationPolicy>,
+    ),
+    asset_server: Res<AssetServer>,
+    mut panels: Query<(Entity, &mut WavePreviewPanel, &ChildOf)>,
+    mut roots: Query<&mut Visibility>,
+    children_q: Query<&Children>,
+    mut tints: Query<(
+        &WavePreviewTint,
+        Option<&mut BackgroundColor>,
+        Option<&mut TextColor>,
+        Option<&mut BorderColor>,
+        Has<WavePreviewBossIcon>,
+    )>,
+) {
+    let Ok((panel_entity, mut panel, child_of)) = panels.single_mut() else {
+        return;
+    };
+
+    let showing = wave_state.phase == WavePhase::Intermission
+        && wave_state.remaining_intermission_secs() <= PREVIEW_LEAD_SECS;
+    let step = time.delta_secs() / FADE_SECS;
+    panel.alpha = (panel.alpha + if showing { step } else { -step }).clamp(0.0, 1.0);
+
+    let next_wave = wave_state.current_wave + 1;
+    if showing && panel.wave != Some(next_wave) {
+        panel.wave = Some(next_wave);
+        let (tunables, rules, schedule, seed, policy) = &plan_sources;
+        let plan = wave_state.upcoming_plan(
+            tunables,
+            rules,
+            schedule.as_deref(),
+            policy.wave_composition_seeded.then_some(seed.0),
+        );
+        let composition = wave_state.upcoming_composition(tunables, &plan);
+        let tier = wave_state.upcoming_difficulty_tier();
+        if let Ok(children) = children_q.get(panel_entity) {
+            for child in children.iter() {
+                commands.entity(child).try_despawn();
+            }
+        }
+        let font = asset_server.load(FONT_PATH);
+        commands.entity(panel_entity).with_children(|p| {
+            spawn_preview_entries(p, &font, next_wave, tier, &composition, plan.is_boss)
+        });
+    }
+
+    if let Ok(mut visibility) = roots.get_mut(child_of.parent()) {
+        visibility.set_if_neq(if panel.alpha > 0.0 {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+    }
+    if panel.alpha <= 0.0 {
+        return;
+    }
+
+    let pulse = 0.5 + 0.5 * (time.elapsed_secs() * BOSS_PULSE_HZ * std::f32::consts::TAU).sin();
+    let alpha = panel.alpha;
+    let mut stack = vec![panel_entity];
+    while let Some(entity) = stack.pop() {
+        if let Ok(children) = children_q.get(entity) {
+            stack.extend(children.iter());
+        }
+        let Ok((tint, background, text, border, boss_icon)) = tints.get_mut(entity) else {
+            continue;
+        };
+        let mut color = tint.0;
+        if boss_icon {
+            color = color.mix(&Color::srgb(1.0, 0.1, 0.1), pulse);
+        }
+        let color = color.with_alpha(color.alpha() * alpha);
+        if let Some(mut background) = background {
+            background.0 = color;
+        }
+        if let Some(mut text) = text {
+            text.0 = color;
+        }
+        if let Some(mut border) = border {
+            *border = BorderColor::all(Color::srgba(0.75, 0.6, 0.9, 0.45 * alpha));
+        }
+    }
+}
+
+fn spawn_preview_entries(
+    parent: &mut ChildSpawnerCommands,
+    font: &Handle<Font>,
+    wave: u32,
+    tier: u32,
+    composition: &[(EnemyKind, u32)],
+    is_boss_wave: bool,
+) {
+    let text_color = Color::srgba(0.92, 0.88, 1.0, 1.0);
+    let text = |value: String| {
+        (
+            Text::new(value),
+            TextFont {
+                font: font.clone(),
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(text_color.with_alpha(0.0)),
+            WavePreviewTint(text_color),
+        )
+    };
+
+    parent.spawn(text(format!("Wave {} (tier {}):", wave, tier)));
+    for (i, (kind, count)) in composition.iter().enumerate() {
+        let icon = (
+            Node {
+                width: Val::Px(14.0),
+                height: Val::Px(14.0),
+                ..default()
+            },
+            BackgroundColor(kind.color().with_alpha(0.0)),
+            WavePreviewTint(kind.color()),
+        );
+        if *kind == EnemyKind::Boss && is_boss_wave {
+            parent.spawn((icon, WavePreviewBossIcon));
+        } else {
+            parent.spawn(icon);
+        }
+        let separator = if i + 1 < composition.len() { "," } else { "" };
+        parent.spawn(text(format!("{}× {}{}", count, kind.name(), separator)));
+    }
+}
@@ -0,0 +1,354 @@
+use crate::build::theme::{paper_panel, shadow_node};
+use crate::components::{EnemyKind, GameState, TowerKind};
+use crate::constants::Tunables;
+use bevy::input::ButtonState;
+use bevy::input::InputSystems;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+/// Every key binding shown on the Controls tab as (key, action).
+/// There is no rebinding yet, so this mirrors the hardcoded input systems.
+const CONTROLS: [(&str, &str); 26] = [
+    ("WASD / Arrows", "Move"),
+    ("P", "Start game from the menu"),
+    ("Space", "Pause / unpause"),
+    ("+ / - / 0", "Speed up / slow down / reset game speed"),
+    ("Tab", "Open the build menu"),
+    ("B (hold)", "Radial tower menu (when enabled)"),
+    ("Left click", "Place the selected tower"),
+    ("G", "Toggle placement grid snapping"),
+    ("Escape / Right click", "Cancel building or selection"),
+    ("Right click a tower", "Inspect its stats and kills"),
+    (
+        "Middle click / T",
+        "Cycle the hovered tower's targeting mode",
+    ),
+    (
+        "Alt + Right click",
+        "Lock a tower onto an enemy kind or convert it",
+    ),
+    ("E (hold)", "Collect wood and rock"),
+    ("C", "Toggle auto-collect"),
+    ("M", "Toggle the minimap"),
+    ("Mouse wheel", "Zoom the camera"),
+    ("Cursor at screen edge", "Pan the camera"),
+    ("Ctrl + S", "Spectator camera (moving ends it)"),
+    ("Mouse wheel over minimap", "Zoom the minimap"),
+    ("F1", "Toggle this reference"),
+    ("F3", "Toggle the chunk debug HUD"),
+    ("Ctrl + H", "Toggle the choke point advisor"),
+    ("F5", "Quick save"),
+    ("F9", "Load the quick save"),
+    ("Shift + F4 - F11", "Tune chunk streaming limits (debug)"),
+    ("Shift + N", "Skip the current wave (debug)"),
+];
+
+const FONT_PATH: &str = "fonts/Nova_Mono/NovaMono-Regular.ttf";
+const INK: Color = Color::srgba(0.08, 0.09, 0.11, 1.0);
+const INK_FAINT: Color = Color::srgba(0.18, 0.17, 0.19, 0.85);
+
+/// F1 reference panel listing tower stats, enemy stats and controls, filtered by a search box.
+pub struct WikiPanelPlugin;
+
+impl Plugin for WikiPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WikiPanelState>()
+            .add_systems(PreUpdate, wiki_keyboard_input.after(InputSystems))
+            .add_systems(
+                Update,
+                (handle_wiki_tab_buttons, manage_wiki_panel_ui).chain(),
+            );
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum WikiTab {
+    #[default]
+    Towers,
+    Enemies,
+    Controls,
+}
+
+impl WikiTab {
+    const ALL: [WikiTab; 3] = [WikiTab::Towers, WikiTab::Enemies, WikiTab::Controls];
+
+    fn label(self) -> &'static str {
+        match self {
+            WikiTab::Towers => "Towers",
+            WikiTab::Enemies => "Enemies",
+            WikiTab::Controls => "Controls",
+        }
+    }
+}
+
+/// Whether the panel is open, the selected tab and the current search text.
+#[derive(Resource, Default)]
+pub struct WikiPanelState {
+    pub open: bool,
+    pub tab: WikiTab,
+    pub query: String,
+    /// Set when opening the panel paused the game, so closing it resumes play.
+    paused_game: bool,
+}
+
+/// Root of the wiki overlay.
+#[derive(Component)]
+pub struct WikiPanel;
+
+#[derive(Component, Clone, Copy)]
+pub struct WikiTabButton(pub WikiTab);
+
+/// F1 toggles the panel. While it is open the keyboard belongs to the search box: typed text
+/// edits the query and the key state is cleared so no other hotkey fires.
+pub fn wiki_keyboard_input(
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    mut key_events: MessageReader<KeyboardInput>,
+    game_state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut wiki: ResMut<WikiPanelState>,
+) {
+    let allow = matches!(game_state.get(), GameState::Playing | GameState::Paused);
+    let toggled = allow && keyboard.just_pressed(KeyCode::F1);
+    if !wiki.open {
+        if toggled {
+            wiki.open = true;
+            wiki.query.clear();
+            wiki.paused_game = *game_state.get() == GameState::Playing;
+            if wiki.paused_game {
+                next_state.set(GameState::Paused);
+            }
+            keyboard.reset_all();
+        }
+        key_events.clear();
+        return;
+    }
+
+    if toggled || keyboard.just_pressed(KeyCode::Escape) {
+        wiki.open = false;
+        if wiki.paused_game {
+            next_state.set(GameState::Playing);
+        }
+        key_events.clear();
+        keyboard.reset_all();
+        return;
+    }
+
+    for event in key_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Backspace => {
+                wiki.query.pop();
+            }
+            Key::Character(text) => {
+                wiki.query.extend(text.chars().filter(|c| !c.is_control()));
+            }
+            Key::Space => wiki.query.push(' '),
+            _ => {}
+        }
+    }
+    keyboard.reset_all();
+}
+
+pub fn handle_wiki_tab_buttons(
+    interactions: Query<(&Interaction, &WikiTabButton), Changed<Interaction>>,
+    mut wiki: ResMut<WikiPanelState>,
+) {
+    for (interaction, button) in interactions.iter() {
+        if matches!(*interaction, Interaction::Pressed) && wiki.tab != button.0 {
+            wiki.tab = button.0;
+        }
+    }
+}
+
+/// Rebuilds the panel whenever its state changes (opened, tab switched, search edited).
+pub fn manage_wiki_panel_ui(
+    mut commands: Commands,
+    wiki: Res<WikiPanelState>,
+    tunables: Res<Tunables>,
+    asset_server: Res<AssetServer>,
+    roots_q: Query<Entity, With<WikiPanel>>,
+) {
+    if !wiki.is_changed() {
+        return;
+    }
+    for root in roots_q.iter() {
+        commands.entity(root).try_despawn();
+    }
+    if !wiki.open {
+        return;
+    }
+
+    let font = asset_server.load(FONT_PATH);
+    let text = |value: String, size: f32, color: Color| {
+        (
+            Text::new(value),
+            TextFont {
+                font: font.clone(),
+                font_size: size,
+                ..default()
+            },
+            TextColor(color),
+        )
+    };
+    let needle = wiki.query.to_lowercase();
+    let entries: Vec<(String, String)> = wiki_entries(wiki.tab, &tunables)
+        .into_iter()
+        .filter(|(name, _)| name.to_lowercase().contains(&needle))
+        .collect();
+
+    commands
+        .spawn((
+            WikiPanel,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                top: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.25)),
+        ))
+        .with_children(|backdrop| {
+            backdrop.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                shadow_node(),
+            ));
+            let (marker, mut node, background, border) = paper_panel();
+            node.flex_direction = FlexDirection::Column;
+            backdrop
+                .spawn((marker, node, background, border, Name::new("WikiPanel")))
+                .with_children(|panel| {
+                    // Search box
+                    panel
+                        .spawn((
+                            Node {
+                                width: Val::Percent(100.0),
+                                padding: UiRect::all(Val::Px(8.0)),
+                                border: UiRect::all(Val::Px(2.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.98)),
+                            BorderColor::all(Color::srgba(0.18, 0.17, 0.19, 0.9)),
+                        ))
+                        .with_children(|search| {
+                            search.spawn(text(format!("Search: {}_", wiki.query), 18.0, INK));
+                        });
+
+                    // Tabs
+                    panel
+                        .spawn(Node {
+                            column_gap: Val::Px(8.0),
+                            ..default()
+                        })
+                        .with_children(|tabs| {
+                            for tab in WikiTab::ALL {
+                                let selected = tab == wiki.tab;
+                                tabs.spawn((
+                                    Button,
+                                    Node {
+                                        padding: UiRect::all(Val::Px(10.0)),
+                                        border: UiRect::all(Val::Px(2.0)),
+                                        ..default()
+                                    },
+                                    BackgroundColor(if selected {
+                                        Color::srgba(0.12, 0.47, 0.95, 0.95)
+                                    } else {
+                                        Color::srgba(0.98, 0.98, 0.975, 0.9)
+                                    }),
+                                    BorderColor::all(Color::srgba(0.18, 0.17, 0.19, 0.9)),
+                                    WikiTabButton(tab),
+                                ))
+                                .with_children(|b| {
+                                    let color = if selected { Color::WHITE } else { INK };
+                                    b.spawn(text(tab.label().to_string(), 20.0, color));
+                                });
+                            }
+                        });
+
+                    // Entries
+                    panel
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(6.0),
+                            overflow: Overflow::clip(),
+                            ..default()
+                        })
+                        .with_children(|list| {
+                            if entries.is_empty() {
+                                list.spawn(text("No matches".to_string(), 16.0, INK_FAINT));
+                            }
+                            for (name, details) in entries {
+                                list.spawn(Node {
+                                    flex_direction: FlexDirection::Column,
+                                    ..default()
+                                })
+                                .with_children(|entry| {
+                                    entry.spawn(text(name, 18.0, INK));
+                                    entry.spawn(text(details, 14.0, INK_FAINT));
+                                });
+                            }
+                        });
+
+                    panel.spawn(text(
+                        "Type to search, F1 or Escape to close".to_string(),
+                        14.0,
+                        INK_FAINT,
+                    ));
+                });
+        });
+}
+
+/// (name, details) rows for `tab`, derived from the game's own stat tables.
+fn wiki_entries(tab: WikiTab, tunables: &Tunables) -> Vec<(String, String)> {
+    match tab {
+        WikiTab::Towers => TowerKind::ALL
+            .iter()
+            .map(|kind| {
+                let stats = kind.stats();
+                let (wood, rock) = kind.cost();
+                let projectile = if stats.projectile_speed > 0.0 {
+                    format!("projectile speed {}", stats.projectile_speed)
+                } else {
+                    "instant hit".to_string()
+                };
+                (
+                    format!("{} Tower", kind.name()),
+                    format!(
+                        "Cost {}W {}R | {} damage every {}s | range {} | {}",
+                        wood,
+                        rock,
+                        stats.damage,
+                        stats.fire_interval_secs,
+                        tunables.tower_range,
+                        projectile
+                    ),
+                )
+            })
+            .collect(),
+        WikiTab::Enemies => EnemyKind::ALL
+            .iter()
+            .map(|kind| {
+                let stats = kind.stats();
+                (
+                    kind.name().to_string(),
+                    format!(
+                        "{} HP | {} village damage | speed {} (before wave scaling)",
+                        stats.health, stats.damage, stats.speed
+                    ),
+                )
+            })
+            .collect(),
+        WikiTab::Controls => CONTROLS
+            .iter()
+            .map(|(key, action)| (key.to_string(), action.to_string()))
+            .collect(),
+    }
+}
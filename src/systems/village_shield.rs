@@ -0,0 +1,194 @@
+use crate::components::{
+    Enemy, Player, SlowEffect, TownCenter, VillageShield, WavePhase, WaveState,
+};
+use crate::constants::Tunables;
+use crate::materials::ShieldMaterial;
+use bevy::pbr::MeshMaterial3d;
+use bevy::prelude::*;
+
+/// Button that purchases the village shield during a wave.
+#[derive(Component)]
+pub struct ShieldButton;
+
+/// Translucent dome rendered around the village while the shield is active.
+#[derive(Component)]
+pub struct ShieldDome;
+
+pub fn spawn_shield_button(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    tunables: Res<Tunables>,
+) {
+    commands
+        .spawn((
+            Button,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(20.0),
+                top: Val::Px(68.0),
+                padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.08, 0.12, 0.2, 0.9)),
+            BorderColor::all(Color::srgba(0.45, 0.75, 1.0, 0.7)),
+            Visibility::Hidden,
+            ShieldButton,
+        ))
+        .with_children(|b| {
+            b.spawn((
+                Text::new(format!("Activate Shield ({}G)", tunables.shield_gold_cost)),
+                TextFont {
+                    font: asset_server.load("fonts/Nova_Mono/NovaMono-Regular.ttf"),
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(0.85, 0.93, 1.0, 1.0)),
+            ));
+        });
+}
+
+/// Shows the shield button only while a wave is running and the shield is still available.
+pub fn update_shield_button_visibility(
+    wave_state: Res<WaveState>,
+    shield: Res<VillageShield>,
+    mut buttons: Query<&mut Visibility, With<ShieldButton>>,
+) {
+    let visible =
+        wave_state.phase == WavePhase::Spawning && shield.is_available(wave_state.current_wave);
+    let desired = if visible {
+        Visibility::Visible
+    } else {
+        Visibility::Hidden
+    };
+    for mut visibility in buttons.iter_mut() {
+        visibility.set_if_neq(desired);
+    }
+}
+
+/// Activates the shield when the button is pressed and the player can afford it.
+pub fn shield_button_interaction(
+    interactions: Query<&Interaction, (Changed<Interaction>, With<ShieldButton>)>,
+    wave_state: Res<WaveState>,
+    tunables: Res<Tunables>,
+    mut shield: ResMut<VillageShield>,
+    mut player_q: Query<&mut Player>,
+) {
+    if !interactions
+        .iter()
+        .any(|interaction| matches!(*interaction, Interaction::Pressed))
+    {
+        return;
+    }
+    if wave_state.phase != WavePhase::Spawning || !shield.is_available(wave_state.current_wave) {
+        return;
+    }
+    let Ok(mut player) = player_q.single_mut() else {
+        return;
+    };
+    if player.gold < tunables.shield_gold_cost {
+        if cfg!(debug_assertions) {
+            info!("Not enough gold for the village shield");
+        }
+        return;
+    }
+
+    player.gold -= tunables.shield_gold_cost;
+    shield.activate(wave_state.current_wave, tunables.shield_duration_secs);
+    if cfg!(debug_assertions) {
+        info!(
+            "Village shield activated for wave {}",
+            wave_state.current_wave
+        );
+    }
+}
+
+/// Counts down the active shield.
+pub fn tick_village_shield(time: Res<Time>, mut shield: ResMut<VillageShield>) {
+    if !shield.active {
+        return;
+    }
+    shield.duration.tick(time.delta());
+    if shield.duration.is_finished() {
+        shield.active = false;
+    }
+}
+
+/// While the shield is up, slows enemies within `shield_slow_radius` of the village.
+#[allow(clippy::type_complexity)]
+pub fn shield_slow_field_system(
+    mut commands: Commands,
+    shield: Res<VillageShield>,
+    tunables: Res<Tunables>,
+    village_q: Query<&Transform, With<TownCenter>>,
+    enemies: Query<(Entity, &Transform, Option<&SlowEffect>), (With<Enemy>, Without<TownCenter>)>,
+) {
+    let village_pos = village_q
+        .single()
+        .map(|tf| tf.translation)
+        .unwrap_or(Vec3::ZERO);
+    let radius_sq = tunables.shield_slow_radius * tunables.shield_slow_radius;
+
+    for (entity, transform, slow) in enemies.iter() {
+        let dx = transform.translation.x - village_pos.x;
+        let dz = transform.translation.z - village_pos.z;
+        let in_field = shield.active && dx * dx + dz * dz <= radius_sq;
+        // A timed slow from a hit runs its course; the field takes over again once it ends
+        match slow {
+            None if in_field => {
+                commands.entity(entity).try_insert(SlowEffect {
+                    multiplier: tunables.shield_slow_factor,
+                    timer: None,
+                });
+            }
+            Some(slow) if !in_field && slow.timer.is_none() => {
+                commands.entity(entity).try_remove::<SlowEffect>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Spawns, pulses, and removes the shield dome to match the shield state.
+#[allow(clippy::too_many_arguments)]
+pub fn update_shield_dome(
+    mut commands: Commands,
+    time: Res<Time>,
+    shield: Res<VillageShield>,
+    tunables: Res<Tunables>,
+    village_q: Query<&Transform, With<TownCenter>>,
+    domes: Query<(Entity, &MeshMaterial3d<ShieldMaterial>), With<ShieldDome>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ShieldMaterial>>,
+) {
+    if !shield.active {
+        for (entity, _) in domes.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    if domes.is_empty() {
+        let Ok(village_tf) = village_q.single() else {
+            return;
+        };
+        let radius = tunables.village_collision_radius + 2.0;
+        commands.spawn((
+            Mesh3d(meshes.add(Sphere::new(radius).mesh().uv(48, 24))),
+            MeshMaterial3d(materials.add(ShieldMaterial::new(Color::srgba(0.35, 0.7, 1.0, 0.55)))),
+            Transform::from_translation(Vec3::new(
+                village_tf.translation.x,
+                0.0,
+                village_tf.translation.z,
+            )),
+            ShieldDome,
+        ));
+        return;
+    }
+
+    for (_, material) in domes.iter() {
+        if let Some(mat) = materials.get_mut(&material.0) {
+            mat.data.time = time.elapsed_secs();
+        }
+    }
+}
@@ -0,0 +1,221 @@
+//! Wall breaches: enemies that reach a wall from outside the town stop and hit it until it
+//! falls. A fallen wall leaves rubble (`WallBreachMarker` + `WallBreached`) that enemies walk
+//! through instead of going round to the gate, until the player rebuilds it with wood (R while
+//! standing next to the rubble).
+
+use crate::components::{
+    AttackingWall, Enemy, GameState, Player, StaticObstacleAABBs, StunEffect, Wall,
+    WallBreachMarker, WallBreached, WallHealth,
+};
+use crate::constants::Tunables;
+use crate::events::WallRepaired;
+use crate::setup::mesh_world_bounds;
+use crate::systems::combat::projectiles::EnemyFadeOut;
+use crate::systems::movement::enemy_movement;
+use bevy::pbr::MeshMaterial3d;
+use bevy::prelude::*;
+
+/// Height of the rubble pile left where a wall fell.
+const RUBBLE_HEIGHT: f32 = 1.0;
+
+pub struct WallBreachPlugin;
+
+impl Plugin for WallBreachPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                enemy_attack_wall_system.before(enemy_movement),
+                wall_repair_input,
+            )
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// Whether `position` lies outside the town walls.
+pub fn outside_town(position: Vec3, tunables: &Tunables) -> bool {
+    let half = tunables.town_size * 0.5;
+    position.x.abs() > half || position.z.abs() > half
+}
+
+/// Horizontal distance from `position` to the box `(min, max)`; zero inside it.
+fn distance_to_bounds_xz(position: Vec3, (min, max): (Vec3, Vec3)) -> f32 {
+    let dx = (min.x - position.x).max(position.x - max.x).max(0.0);
+    let dz = (min.z - position.z).max(position.z - max.z).max(0.0);
+    Vec2::new(dx, dz).length()
+}
+
+/// Enemies outside the town that come within `wall_attack_range` of a wall stop and hit the
+/// nearest one for `Enemy::damage` per second. A wall at zero hit points is replaced by rubble
+/// marking the breach, and its attackers move on.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn enemy_attack_wall_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    tunables: Res<Tunables>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut obstacles: ResMut<StaticObstacleAABBs>,
+    mut enemies: Query<
+        (
+            Entity,
+            &Transform,
+            &Enemy,
+            Option<&mut AttackingWall>,
+            Has<StunEffect>,
+        ),
+        Without<EnemyFadeOut>,
+    >,
+    mut walls: Query<
+        (
+            Entity,
+            &Mesh3d,
+            &MeshMaterial3d<StandardMaterial>,
+            &Transform,
+            &mut WallHealth,
+        ),
+        (With<Wall>, Without<Enemy>),
+    >,
+) {
+    if enemies.is_empty() {
+        return;
+    }
+    let dt = time.delta_secs();
+    let bounds: Vec<(Entity, (Vec3, Vec3))> = walls
+        .iter()
+        .filter_map(|(entity, mesh, _, transform, _)| {
+            Some((entity, mesh_world_bounds(meshes.get(&mesh.0)?, transform)?))
+        })
+        .collect();
+
+    for (entity, transform, enemy, attacking, stunned) in enemies.iter_mut() {
+        let position = transform.translation;
+        let Some(mut attacking) = attacking else {
+            if !outside_town(position, &tunables) {
+                continue;
+            }
+            let nearest = bounds
+                .iter()
+                .map(|(wall, wall_bounds)| (distance_to_bounds_xz(position, *wall_bounds), *wall))
+                .filter(|(distance, _)| *distance <= tunables.wall_attack_range)
+                .min_by(|a, b| a.0.total_cmp(&b.0));
+            if let Some((_, wall)) = nearest {
+                commands.entity(entity).insert(AttackingWall {
+                    wall,
+                    pending_damage: 0.0,
+                });
+            }
+            continue;
+        };
+
+        let Some(&(_, wall_bounds)) = bounds.iter().find(|(wall, _)| *wall == attacking.wall)
+        else {
+            // The wall fell (or was never there): walk on
+            commands.entity(entity).remove::<AttackingWall>();
+            continue;
+        };
+        let Ok((wall, mesh, material, wall_transform, mut health)) = walls.get_mut(attacking.wall)
+        else {
+            continue;
+        };
+        if health.hp == 0 || stunned {
+            continue;
+        }
+        attacking.pending_damage += enemy.damage as f32 * dt;
+        let dealt = attacking.pending_damage.floor();
+        attacking.pending_damage -= dealt;
+        health.hp = health.hp.saturating_sub(dealt as u32);
+        if health.hp > 0 {
+            continue;
+        }
+
+        // The rubble sits where the wall was hit; enemies aim a little past it, inside the town
+        let (min, max) = wall_bounds;
+        let breach = Vec3::new(
+            position.x.clamp(min.x, max.x),
+            0.0,
+            position.z.clamp(min.z, max.z),
+        );
+        let center = (min + max) * 0.5;
+        let inward = if max.x - min.x < max.z - min.z {
+            Vec3::new(-center.x.signum(), 0.0, 0.0)
+        } else {
+            Vec3::new(0.0, 0.0, -center.z.signum())
+        };
+        let rubble = meshes.add(Cuboid::new(
+            tunables.wall_thickness * 2.0,
+            RUBBLE_HEIGHT,
+            tunables.wall_thickness * 2.0,
+        ));
+        commands.spawn((
+            Mesh3d(rubble),
+            MeshMaterial3d(material.0.clone()),
+            Transform::from_translation(breach.with_y(RUBBLE_HEIGHT * 0.5)),
+            WallBreachMarker {
+                mesh: mesh.0.clone(),
+                material: material.0.clone(),
+                wall_transform: *wall_transform,
+                bounds: wall_bounds,
+                entry: breach + inward * tunables.wall_thickness * 2.0,
+            },
+            WallBreached,
+        ));
+        commands.entity(wall).despawn();
+        obstacles.boxes.retain(|bounds| *bounds != wall_bounds);
+        if cfg!(debug_assertions) {
+            info!("Wall breached at {:?}", breach);
+        }
+    }
+}
+
+/// R next to a breach rebuilds its wall at full health for `wall_repair_wood_cost` wood.
+#[allow(clippy::too_many_arguments)]
+pub fn wall_repair_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    tunables: Res<Tunables>,
+    mut player_q: Query<(&Transform, &mut Player)>,
+    breaches: Query<(Entity, &Transform, &WallBreachMarker), With<WallBreached>>,
+    mut obstacles: ResMut<StaticObstacleAABBs>,
+    mut repaired: MessageWriter<WallRepaired>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyR) {
+        return;
+    }
+    let Ok((player_transform, mut player)) = player_q.single_mut() else {
+        return;
+    };
+    let player_pos = player_transform.translation;
+    let nearest = breaches
+        .iter()
+        .map(|(entity, transform, marker)| {
+            let offset = (transform.translation - player_pos).with_y(0.0);
+            (offset.length(), entity, transform.translation, marker)
+        })
+        .filter(|(distance, ..)| *distance <= tunables.wall_repair_radius)
+        .min_by(|a, b| a.0.total_cmp(&b.0));
+    let Some((_, entity, position, marker)) = nearest else {
+        return;
+    };
+    if player.wood < tunables.wall_repair_wood_cost {
+        info!(
+            "Not enough wood to repair the wall ({} needed)",
+            tunables.wall_repair_wood_cost
+        );
+        return;
+    }
+
+    player.wood -= tunables.wall_repair_wood_cost;
+    commands.spawn((
+        Mesh3d(marker.mesh.clone()),
+        MeshMaterial3d(marker.material.clone()),
+        marker.wall_transform,
+        Wall,
+        WallHealth {
+            hp: tunables.wall_health,
+        },
+    ));
+    commands.entity(entity).despawn();
+    obstacles.boxes.push(marker.bounds);
+    repaired.write(WallRepaired { position });
+}
@@ -1,25 +1,44 @@
-use crate::audio::{BossWaveStartedEvent, WaveStartedEvent};
-use crate::components::{Enemy, WavePhase, WaveState};
+use crate::audio::{BossWaveStartedEvent, WaveCompletedEvent, WaveStartedEvent};
+use crate::components::{
+    BuiltTower, Enemy, Player, Village, WavePhase, WaveState, WaveTimerPaused,
+};
 use crate::constants::Tunables;
+use crate::core::replay::{ReplayPlayback, ReplayRecorder};
+#[cfg(debug_assertions)]
+use crate::events::EnemyKilled;
+use crate::events::WaveCompletionBonusEvent;
 use crate::random_policy::RandomizationPolicy;
 use crate::systems::chunks::WorldSeed;
+#[cfg(debug_assertions)]
+use crate::systems::combat::projectiles::EnemyFadeOut;
+#[cfg(debug_assertions)]
+use crate::systems::ui::hud::SkipWaveButton;
 use crate::waves::rules::{WaveRules, WaveSchedule};
 use bevy::prelude::*;
 use std::time::Duration;
 
-/// Handles transitioning between wave intermissions and active waves.
+/// Handles transitioning between wave intermissions and active waves, and pays out the wave
+/// completion bonuses.
+#[allow(clippy::too_many_arguments)]
 pub fn wave_progression(
     time: Res<Time>,
     mut wave_state: ResMut<WaveState>,
     tunables: Res<Tunables>,
     enemy_query: Query<Entity, With<Enemy>>,
+    tower_query: Query<(), With<BuiltTower>>,
     seed: Res<WorldSeed>,
     policy: Res<RandomizationPolicy>,
     schedule: Option<Res<WaveSchedule>>,
     rules: Res<WaveRules>,
     mut wave_started_writer: MessageWriter<WaveStartedEvent>,
     mut boss_wave_started_writer: MessageWriter<BossWaveStartedEvent>,
+    timer_paused: Res<WaveTimerPaused>,
+    mut wave_completed_writer: MessageWriter<WaveCompletedEvent>,
+    (mut recorder, playback): (Option<ResMut<ReplayRecorder>>, Option<Res<ReplayPlayback>>),
+    (village_query, mut player_query): (Query<&Village>, Query<&mut Player>),
+    mut bonus_writer: MessageWriter<WaveCompletionBonusEvent>,
 ) {
+    let village_health = village_query.single().map_or(0, |village| village.health);
     match wave_state.phase {
         WavePhase::Intermission => {
             let target_duration = if wave_state.current_wave == 0 {
@@ -35,35 +54,320 @@ pub fn wave_progression(
                     .set_duration(Duration::from_secs_f32(target_duration));
             }
 
+            if timer_paused.is_paused() {
+                return;
+            }
             wave_state.intermission_timer.tick(time.delta());
             if wave_state.intermission_timer.just_finished() {
                 let next_wave = wave_state.current_wave + 1;
-                if next_wave % 10 == 0 {
-                    boss_wave_started_writer.write(BossWaveStartedEvent);
+                if next_wave.is_multiple_of(tunables.wave_boss_every) {
+                    boss_wave_started_writer.write(BossWaveStartedEvent { village_health });
                 } else {
-                    wave_started_writer.write(WaveStartedEvent);
+                    wave_started_writer.write(WaveStartedEvent { village_health });
                 }
-                let seed_mode = if policy.wave_composition_seeded {
+                // A replay reuses the seed recorded for the wave when its frame carries one
+                let recorded_seed = playback
+                    .as_ref()
+                    .and_then(|playback| playback.current())
+                    .and_then(|frame| frame.wave_seed);
+                let seed_mode = if recorded_seed.is_some() {
+                    recorded_seed
+                } else if policy.wave_composition_seeded {
                     Some(seed.0)
                 } else {
                     None
                 };
-                if let Some(schedule) = schedule {
-                    if let Some(plan) = schedule.plans.get((next_wave - 1) as usize).cloned() {
-                        wave_state.start_next_wave_from_plan(&tunables, plan);
-                    } else {
-                        wave_state.start_next_wave(&tunables, seed_mode, &rules);
-                    }
-                } else {
-                    wave_state.start_next_wave(&tunables, seed_mode, &rules);
+                if let Some(recorder) = recorder.as_mut() {
+                    recorder.record_wave_seed(seed_mode);
                 }
+                start_scheduled_wave(
+                    &mut wave_state,
+                    &tunables,
+                    seed_mode,
+                    schedule.as_deref(),
+                    &rules,
+                );
+                let tower_count = tower_query.iter().count() as u32;
+                wave_state.apply_tower_scaling(tower_count, &tunables);
+                wave_state.health_at_wave_start = village_health;
             }
         }
         WavePhase::Spawning => {
             let no_enemies_alive = enemy_query.iter().next().is_none();
             if wave_state.enemies_spawned >= wave_state.enemies_to_spawn && no_enemies_alive {
+                wave_completed_writer.write(WaveCompletedEvent);
+                let bonus = wave_completion_bonus(
+                    &tunables,
+                    wave_state.current_wave,
+                    village_health >= wave_state.health_at_wave_start,
+                );
+                if let Ok(mut player) = player_query.single_mut() {
+                    player.wood = player.wood.saturating_add(bonus.wood);
+                    player.rock = player.rock.saturating_add(bonus.rock);
+                    player.silver = player.silver.saturating_add(bonus.silver);
+                }
+                bonus_writer.write(bonus);
                 wave_state.start_intermission(tunables.wave_intermission_secs);
             }
         }
     }
 }
+
+/// Silver for every finished wave, plus wood and rock when the village took no damage.
+fn wave_completion_bonus(
+    tunables: &Tunables,
+    wave: u32,
+    perfect: bool,
+) -> WaveCompletionBonusEvent {
+    let (wood, rock) = if perfect {
+        (
+            tunables.perfect_wave_wood_bonus,
+            tunables.perfect_wave_rock_bonus,
+        )
+    } else {
+        (0, 0)
+    };
+    WaveCompletionBonusEvent {
+        wave,
+        perfect,
+        wood,
+        rock,
+        silver: tunables.wave_completion_silver_bonus,
+    }
+}
+
+/// Starts the next wave from the precomputed schedule when it covers it, else from `rules`.
+fn start_scheduled_wave(
+    wave_state: &mut WaveState,
+    tunables: &Tunables,
+    seed_mode: Option<u64>,
+    schedule: Option<&WaveSchedule>,
+    rules: &WaveRules,
+) {
+    let next_wave = wave_state.current_wave + 1;
+    match schedule.and_then(|schedule| schedule.plans.get((next_wave - 1) as usize).cloned()) {
+        Some(plan) => wave_state.start_next_wave_from_plan(tunables, plan),
+        None => wave_state.start_next_wave(tunables, seed_mode, rules),
+    }
+}
+
+/// Waves to get through instantly at the start of a run, from `--skip-to-wave=N` (debug builds).
+#[cfg(debug_assertions)]
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct SkipToWave(pub u32);
+
+/// Starts and immediately wins every wave up to `SkipToWave` with no enemies, leaving the game in
+/// the intermission before the next one. No wave messages are sent for the skipped waves.
+#[cfg(debug_assertions)]
+#[allow(clippy::too_many_arguments)]
+pub fn skip_to_wave_system(
+    mut commands: Commands,
+    skip: Res<SkipToWave>,
+    mut wave_state: ResMut<WaveState>,
+    tunables: Res<Tunables>,
+    seed: Res<WorldSeed>,
+    policy: Res<RandomizationPolicy>,
+    schedule: Option<Res<WaveSchedule>>,
+    rules: Res<WaveRules>,
+) {
+    commands.remove_resource::<SkipToWave>();
+    let seed_mode = policy.wave_composition_seeded.then_some(seed.0);
+    while wave_state.current_wave < skip.0 {
+        start_scheduled_wave(
+            &mut wave_state,
+            &tunables,
+            seed_mode,
+            schedule.as_deref(),
+            &rules,
+        );
+        wave_state.spawn_queue.clear();
+        wave_state.enemies_spawned = wave_state.enemies_to_spawn;
+        wave_state.start_intermission(tunables.wave_intermission_secs);
+    }
+    info!("Skipped to wave {}", wave_state.current_wave);
+}
+
+/// Testing shortcut: the wave HUD's "Skip wave" button or Shift+N ends a wave in progress. Nothing
+/// is left to spawn, every living enemy is removed (and counted as killed) and the intermission
+/// starts as if the wave had been won.
+#[cfg(debug_assertions)]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn skip_wave_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<SkipWaveButton>)>,
+    enemies: Query<(Entity, &Transform, Has<EnemyFadeOut>), With<Enemy>>,
+    tunables: Res<Tunables>,
+    mut wave_state: ResMut<WaveState>,
+    mut enemy_killed: MessageWriter<EnemyKilled>,
+    mut wave_completed: MessageWriter<WaveCompletedEvent>,
+) {
+    let shift = keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let requested = (shift && keyboard.just_pressed(KeyCode::KeyN))
+        || buttons.iter().any(|i| *i == Interaction::Pressed);
+    if !requested || wave_state.phase != WavePhase::Spawning {
+        return;
+    }
+
+    wave_state.spawn_queue.clear();
+    wave_state.enemies_spawned = wave_state.enemies_to_spawn;
+    for (entity, transform, fading) in enemies.iter() {
+        // Fading enemies were already reported when they died
+        if !fading {
+            enemy_killed.write(EnemyKilled {
+                position: transform.translation,
+                tower: None,
+            });
+        }
+        commands.entity(entity).try_despawn();
+    }
+    wave_completed.write(WaveCompletedEvent);
+    wave_state.start_intermission(tunables.wave_intermission_secs);
+    info!("Skipped the rest of wave {}", wave_state.current_wave);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::PauseReason;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    /// World with everything `wave_progression` reads, before the first wave.
+    fn wave_world(paused: bool) -> World {
+        let tunables = Tunables::default();
+        let mut world = World::new();
+        world.insert_resource(WaveState::new(&tunables));
+        world.insert_resource(tunables);
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(WorldSeed(1));
+        world.insert_resource(RandomizationPolicy::default());
+        world.insert_resource(WaveRules::default());
+        let mut timer_paused = WaveTimerPaused::default();
+        timer_paused.set(PauseReason::Settings, paused);
+        world.insert_resource(timer_paused);
+        world.init_resource::<Messages<WaveStartedEvent>>();
+        world.init_resource::<Messages<BossWaveStartedEvent>>();
+        world.init_resource::<Messages<WaveCompletedEvent>>();
+        world.init_resource::<Messages<WaveCompletionBonusEvent>>();
+        world
+    }
+
+    fn step(world: &mut World, secs: f32) {
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(secs));
+        world.run_system_once(wave_progression).unwrap();
+    }
+
+    #[test]
+    fn paused_wave_timer_makes_no_progress() {
+        let mut world = wave_world(true);
+        for _ in 0..600 {
+            step(&mut world, 1.0 / 60.0);
+        }
+        let wave_state = world.resource::<WaveState>();
+        assert_eq!(wave_state.intermission_timer.elapsed(), Duration::ZERO);
+        assert_eq!(wave_state.current_wave, 0);
+    }
+
+    #[test]
+    fn unpaused_wave_timer_advances() {
+        let mut world = wave_world(false);
+        for _ in 0..30 {
+            step(&mut world, 1.0 / 60.0);
+        }
+        assert!(world.resource::<WaveState>().intermission_timer.elapsed() > Duration::ZERO);
+    }
+
+    #[test]
+    fn closing_one_of_two_overlays_keeps_the_wave_timer_paused() {
+        let mut world = wave_world(false);
+        let mut timer_paused = world.resource_mut::<WaveTimerPaused>();
+        timer_paused.set(PauseReason::Settings, true);
+        timer_paused.set(PauseReason::PriorityPicker, true);
+        timer_paused.set(PauseReason::Settings, false);
+        for _ in 0..30 {
+            step(&mut world, 1.0 / 60.0);
+        }
+        assert_eq!(
+            world.resource::<WaveState>().intermission_timer.elapsed(),
+            Duration::ZERO
+        );
+
+        world
+            .resource_mut::<WaveTimerPaused>()
+            .set(PauseReason::PriorityPicker, false);
+        for _ in 0..30 {
+            step(&mut world, 1.0 / 60.0);
+        }
+        assert!(world.resource::<WaveState>().intermission_timer.elapsed() > Duration::ZERO);
+    }
+
+    #[test]
+    fn bonus_pays_wood_and_rock_only_for_perfect_waves() {
+        let tunables = Tunables::default();
+        let perfect = wave_completion_bonus(&tunables, 3, true);
+        assert_eq!(perfect.wave, 3);
+        assert_eq!(perfect.wood, tunables.perfect_wave_wood_bonus);
+        assert_eq!(perfect.rock, tunables.perfect_wave_rock_bonus);
+        assert_eq!(perfect.silver, tunables.wave_completion_silver_bonus);
+
+        let damaged = wave_completion_bonus(&tunables, 3, false);
+        assert!(!damaged.perfect);
+        assert_eq!((damaged.wood, damaged.rock), (0, 0));
+        assert_eq!(damaged.silver, tunables.wave_completion_silver_bonus);
+    }
+
+    /// Ends a wave with every enemy spawned and killed, the village at `health` out of the
+    /// `start_health` it began the wave with, and returns the player's inventory afterwards.
+    fn finish_wave(start_health: u32, health: u32) -> (u32, u32, u64) {
+        let mut world = wave_world(false);
+        world.spawn(Village {
+            health,
+            max_health: start_health,
+        });
+        let player = world.spawn(Player::starting()).id();
+        {
+            let mut wave_state = world.resource_mut::<WaveState>();
+            wave_state.phase = WavePhase::Spawning;
+            wave_state.current_wave = 1;
+            wave_state.enemies_spawned = wave_state.enemies_to_spawn;
+            wave_state.health_at_wave_start = start_health;
+        }
+        step(&mut world, 1.0 / 60.0);
+
+        assert_eq!(world.resource::<WaveState>().phase, WavePhase::Intermission);
+        let bonuses = world.resource::<Messages<WaveCompletionBonusEvent>>();
+        assert_eq!(bonuses.len(), 1);
+        let start = Player::starting();
+        let player = world.get::<Player>(player).unwrap();
+        (
+            player.wood - start.wood,
+            player.rock - start.rock,
+            player.silver - start.silver,
+        )
+    }
+
+    #[test]
+    fn perfect_wave_pays_its_bonus_to_the_player() {
+        let tunables = Tunables::default();
+        assert_eq!(
+            finish_wave(100, 100),
+            (
+                tunables.perfect_wave_wood_bonus,
+                tunables.perfect_wave_rock_bonus,
+                tunables.wave_completion_silver_bonus
+            )
+        );
+    }
+
+    #[test]
+    fn damaged_wave_pays_only_silver() {
+        let tunables = Tunables::default();
+        assert_eq!(
+            finish_wave(100, 80),
+            (0, 0, tunables.wave_completion_silver_bonus)
+        );
+    }
+}
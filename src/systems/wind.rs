@@ -0,0 +1,112 @@
+use crate::constants::Tunables;
+use crate::core::rng::derive_seed;
+use crate::systems::chunks::WorldSeed;
+use bevy::prelude::*;
+use bevy::time::TimerMode;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::f32::consts::TAU;
+use std::time::Duration;
+
+/// Rate at which the current wind eases toward its target (per second).
+const WIND_EASE_RATE: f32 = 0.15;
+/// Wind at or above this fraction of `Tunables.wind_max_speed` counts as a storm.
+const STORM_WIND_FRACTION: f32 = 0.75;
+/// Salt mixed into the world seed for wind targets, keyed by how often the wind has turned.
+const WIND_SEED_SALT: u64 = 0x571D;
+
+/// Current wind affecting projectiles. `direction` is a unit vector on the XZ plane
+/// (x = world X, y = world Z); `speed` is in world units/second.
+#[derive(Resource, Debug, Clone)]
+pub struct WindState {
+    pub direction: Vec2,
+    pub speed: f32,
+    target: Vec2,
+    retarget_timer: Timer,
+    /// Targets picked so far; with the world seed it decides the next one.
+    retargets: u64,
+}
+
+impl WindState {
+    pub fn new(tunables: &Tunables) -> Self {
+        WindState {
+            direction: Vec2::X,
+            speed: 0.0,
+            target: random_wind(tunables.wind_max_speed, tunables.world_seed, 0),
+            retarget_timer: Timer::from_seconds(
+                tunables.wind_change_interval_secs.max(0.1),
+                TimerMode::Repeating,
+            ),
+            retargets: 0,
+        }
+    }
+
+    /// Whether the wind is currently strong enough to count as storm weather.
+    pub fn is_storm(&self, tunables: &Tunables) -> bool {
+        tunables.wind_max_speed > 0.0 && self.speed >= tunables.wind_max_speed * STORM_WIND_FRACTION
+    }
+
+    /// Wind as a world-space velocity on the XZ plane.
+    pub fn velocity(&self) -> Vec3 {
+        Vec3::new(self.direction.x, 0.0, self.direction.y) * self.speed
+    }
+}
+
+/// Picks a random target wind periodically and eases the current wind toward it.
+pub fn wind_update_system(
+    time: Res<Time>,
+    tunables: Res<Tunables>,
+    seed: Res<WorldSeed>,
+    mut wind: ResMut<WindState>,
+) {
+    // Keep the retarget interval in sync with Tunables in case they change at runtime
+    let interval = Duration::from_secs_f32(tunables.wind_change_interval_secs.max(0.1));
+    if wind.retarget_timer.duration() != interval {
+        wind.retarget_timer.set_duration(interval);
+    }
+    wind.retarget_timer.tick(time.delta());
+    if wind.retarget_timer.just_finished() {
+        wind.retargets += 1;
+        wind.target = random_wind(tunables.wind_max_speed, seed.0, wind.retargets);
+    }
+
+    let current = wind.direction * wind.speed;
+    let t = 1.0 - (-WIND_EASE_RATE * time.delta_secs()).exp();
+    let next = current.lerp(wind.target, t);
+    wind.speed = next.length();
+    if let Some(dir) = next.try_normalize() {
+        wind.direction = dir;
+    }
+}
+
+/// Target wind number `retargets` of the world `seed`: the same seed blows the same winds.
+fn random_wind(max_speed: f32, seed: u64, retargets: u64) -> Vec2 {
+    let mut rng = StdRng::seed_from_u64(derive_seed(seed, WIND_SEED_SALT, retargets));
+    let angle = rng.random::<f32>() * TAU;
+    let speed = rng.random::<f32>() * max_speed.max(0.0);
+    Vec2::from_angle(angle) * speed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_blows_the_same_winds() {
+        for retargets in 0..5 {
+            assert_eq!(
+                random_wind(10.0, 42, retargets),
+                random_wind(10.0, 42, retargets)
+            );
+        }
+        assert_ne!(random_wind(10.0, 42, 1), random_wind(10.0, 43, 1));
+        assert_ne!(random_wind(10.0, 42, 1), random_wind(10.0, 42, 2));
+    }
+
+    #[test]
+    fn wind_stays_within_max_speed() {
+        for retargets in 0..50 {
+            assert!(random_wind(10.0, 7, retargets).length() <= 10.0 + 1e-4);
+        }
+        assert_eq!(random_wind(-1.0, 7, 0), Vec2::ZERO);
+    }
+}
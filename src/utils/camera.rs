@@ -24,6 +24,19 @@ pub fn is_on_screen_ndc(
     }
 }
 
+/// Returns true if `view` (a position in camera space, looking down -Z) lies inside a perspective
+/// frustum with vertical field of view `fov_y` (radians) and `aspect` (width / height), widened by
+/// `margin` world units on every side. Cheaper than projecting through the camera.
+pub fn in_view_frustum(view: Vec3, fov_y: f32, aspect: f32, margin: f32) -> bool {
+    let depth = -view.z;
+    if depth + margin <= 0.0 {
+        return false;
+    }
+    let half_height = depth * (fov_y * 0.5).tan();
+    let half_width = half_height * aspect;
+    view.x.abs() <= half_width + margin && view.y.abs() <= half_height + margin
+}
+
 /// Convert a world position to logical (DPI-independent) viewport coordinates in pixels.
 /// Returns None if the point cannot be projected.
 pub fn world_to_viewport_logical(
@@ -37,3 +50,61 @@ pub fn world_to_viewport_logical(
 }
 
 // Removed unused to_camera_space helper
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FOV_Y: f32 = std::f32::consts::FRAC_PI_2;
+
+    #[test]
+    fn point_straight_ahead_is_inside() {
+        assert!(in_view_frustum(Vec3::new(0.0, 0.0, -10.0), FOV_Y, 1.5, 0.0));
+    }
+
+    #[test]
+    fn point_behind_the_camera_is_outside() {
+        assert!(!in_view_frustum(Vec3::new(0.0, 0.0, 10.0), FOV_Y, 1.5, 0.0));
+        // A margin only reaches that far behind the camera
+        assert!(in_view_frustum(Vec3::new(0.0, 0.0, 0.5), FOV_Y, 1.5, 1.0));
+        assert!(!in_view_frustum(Vec3::new(0.0, 0.0, 1.5), FOV_Y, 1.5, 1.0));
+    }
+
+    #[test]
+    fn edges_follow_fov_and_aspect() {
+        // With a 90 degree vertical fov the half height equals the depth
+        let depth = 10.0;
+        assert!(in_view_frustum(
+            Vec3::new(0.0, 9.9, -depth),
+            FOV_Y,
+            1.0,
+            0.0
+        ));
+        assert!(!in_view_frustum(
+            Vec3::new(0.0, 10.1, -depth),
+            FOV_Y,
+            1.0,
+            0.0
+        ));
+        // Twice as wide as it is tall
+        assert!(in_view_frustum(
+            Vec3::new(19.9, 0.0, -depth),
+            FOV_Y,
+            2.0,
+            0.0
+        ));
+        assert!(!in_view_frustum(
+            Vec3::new(20.1, 0.0, -depth),
+            FOV_Y,
+            2.0,
+            0.0
+        ));
+    }
+
+    #[test]
+    fn margin_widens_every_side() {
+        let outside = Vec3::new(0.0, -10.5, -10.0);
+        assert!(!in_view_frustum(outside, FOV_Y, 1.0, 0.0));
+        assert!(in_view_frustum(outside, FOV_Y, 1.0, 1.0));
+    }
+}
@@ -0,0 +1,11 @@
+use std::path::PathBuf;
+
+/// Per-user data directory for the game (`<data dir>/td`).
+pub fn app_data_dir() -> Option<PathBuf> {
+    // Prefer a standard data dir; fall back to current dir if unavailable.
+    let base_dir: PathBuf = match dirs_next::data_dir() {
+        Some(p) => p,
+        None => std::env::current_dir().ok()?,
+    };
+    Some(base_dir.join("td"))
+}
@@ -0,0 +1,294 @@
+//! Difficulty curve: per-wave scaling of enemy health, speed, count and composition, defined at a
+//! few breakpoint waves and interpolated linearly in between.
+
+use rand::{SeedableRng, rngs::StdRng, seq::SliceRandom};
+use serde::{Deserialize, Serialize};
+
+use crate::components::EnemyKind;
+
+/// Scaling applied to one wave on top of its `WaveRules` plan.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DifficultyParams {
+    pub health_multiplier: f32,
+    pub speed_multiplier: f32,
+    /// Scales the number of regular (non-Boss) enemies in the wave.
+    pub count_multiplier: f32,
+    /// Relative share of each kind among the regular enemies; empty keeps the plan's composition.
+    pub enemy_kind_weights: Vec<(EnemyKind, f32)>,
+}
+
+impl Default for DifficultyParams {
+    fn default() -> Self {
+        Self {
+            health_multiplier: 1.0,
+            speed_multiplier: 1.0,
+            count_multiplier: 1.0,
+            enemy_kind_weights: Vec::new(),
+        }
+    }
+}
+
+impl DifficultyParams {
+    fn with_health(health_multiplier: f32) -> Self {
+        Self {
+            health_multiplier,
+            ..Self::default()
+        }
+    }
+
+    fn weight_of(&self, kind: EnemyKind) -> f32 {
+        self.enemy_kind_weights
+            .iter()
+            .filter(|(k, _)| *k == kind)
+            .map(|(_, w)| *w)
+            .sum()
+    }
+
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        // Weights only blend when both ends set them; otherwise the side that does wins
+        let enemy_kind_weights = match (
+            self.enemy_kind_weights.is_empty(),
+            other.enemy_kind_weights.is_empty(),
+        ) {
+            (true, true) => Vec::new(),
+            (false, true) => self.enemy_kind_weights.clone(),
+            (true, false) => other.enemy_kind_weights.clone(),
+            (false, false) => EnemyKind::ALL
+                .iter()
+                .map(|kind| (*kind, lerp(self.weight_of(*kind), other.weight_of(*kind))))
+                .filter(|(_, w)| *w > 0.0)
+                .collect(),
+        };
+        Self {
+            health_multiplier: lerp(self.health_multiplier, other.health_multiplier),
+            speed_multiplier: lerp(self.speed_multiplier, other.speed_multiplier),
+            count_multiplier: lerp(self.count_multiplier, other.count_multiplier),
+            enemy_kind_weights,
+        }
+    }
+
+    /// Rescales a planned wave: the regular enemies are resized by `count_multiplier` and
+    /// redistributed over `enemy_kind_weights` (or the plan's own mix), then shuffled with `seed`.
+    /// Bosses are kept as they are, at the end of the wave.
+    pub fn apply_to_wave(&self, enemies: &[EnemyKind], seed: u64) -> Vec<EnemyKind> {
+        if self.count_multiplier == 1.0 && self.enemy_kind_weights.is_empty() {
+            return enemies.to_vec();
+        }
+        let bosses = enemies.iter().filter(|k| **k == EnemyKind::Boss).count();
+        let regular = enemies.len() - bosses;
+        if regular == 0 {
+            return enemies.to_vec();
+        }
+        let count = ((regular as f32 * self.count_multiplier.max(0.0)).round() as usize).max(1);
+
+        let weights: Vec<(EnemyKind, f32)> = if self.enemy_kind_weights.is_empty() {
+            EnemyKind::ALL
+                .iter()
+                .filter(|kind| **kind != EnemyKind::Boss)
+                .map(|kind| (*kind, enemies.iter().filter(|k| *k == kind).count() as f32))
+                .collect()
+        } else {
+            EnemyKind::ALL
+                .iter()
+                .filter(|kind| **kind != EnemyKind::Boss)
+                .map(|kind| (*kind, self.weight_of(*kind).max(0.0)))
+                .collect()
+        };
+        let total: f32 = weights.iter().map(|(_, w)| *w).sum();
+        if total <= 0.0 {
+            return enemies.to_vec();
+        }
+
+        // Largest-remainder split so the counts always add up to `count`
+        let mut shares: Vec<(EnemyKind, usize, f32)> = weights
+            .iter()
+            .map(|(kind, w)| {
+                let exact = w / total * count as f32;
+                (*kind, exact.floor() as usize, exact.fract())
+            })
+            .collect();
+        let assigned: usize = shares.iter().map(|(_, n, _)| *n).sum();
+        let mut by_remainder: Vec<usize> = (0..shares.len()).collect();
+        by_remainder.sort_by(|a, b| shares[*b].2.total_cmp(&shares[*a].2));
+        for i in by_remainder
+            .into_iter()
+            .take(count.saturating_sub(assigned))
+        {
+            shares[i].1 += 1;
+        }
+
+        let mut list: Vec<EnemyKind> = shares
+            .iter()
+            .flat_map(|(kind, n, _)| std::iter::repeat_n(*kind, *n))
+            .collect();
+        list.shuffle(&mut StdRng::seed_from_u64(seed));
+        list.extend(std::iter::repeat_n(EnemyKind::Boss, bosses));
+        list
+    }
+}
+
+/// Breakpoints of the difficulty curve as `(wave, params)`, sorted by wave. Waves between two
+/// breakpoints interpolate linearly; waves before the first or after the last use that
+/// breakpoint as is. Written in `tunables.toml` as an array of `[wave, { ... }]` pairs.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DifficultyCurve(pub Vec<(u32, DifficultyParams)>);
+
+impl Default for DifficultyCurve {
+    fn default() -> Self {
+        Self(vec![
+            (1, DifficultyParams::with_health(1.0)),
+            (10, DifficultyParams::with_health(2.0)),
+            (20, DifficultyParams::with_health(5.0)),
+        ])
+    }
+}
+
+impl DifficultyCurve {
+    /// Params for `wave`; an empty curve changes nothing.
+    pub fn interpolate(&self, wave: u32) -> DifficultyParams {
+        let points = &self.0;
+        let (Some(first), Some(last)) = (points.first(), points.last()) else {
+            return DifficultyParams::default();
+        };
+        if wave <= first.0 {
+            return first.1.clone();
+        }
+        if wave >= last.0 {
+            return last.1.clone();
+        }
+        let next = points.partition_point(|(w, _)| *w <= wave);
+        let (from_wave, from) = &points[next - 1];
+        let (to_wave, to) = &points[next];
+        let t = (wave - from_wave) as f32 / (to_wave - from_wave) as f32;
+        from.lerp(to, t)
+    }
+
+    /// Problems with the breakpoints, one message each.
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if self.0.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+            errors.push("difficulty_curve waves must be strictly increasing".to_string());
+        }
+        for (wave, params) in &self.0 {
+            let multipliers = [
+                ("health_multiplier", params.health_multiplier),
+                ("speed_multiplier", params.speed_multiplier),
+                ("count_multiplier", params.count_multiplier),
+            ];
+            for (name, value) in multipliers {
+                if value.is_nan() || value <= 0.0 {
+                    errors.push(format!(
+                        "difficulty_curve wave {} {} must be > 0.0 (got {})",
+                        wave, name, value
+                    ));
+                }
+            }
+            if params
+                .enemy_kind_weights
+                .iter()
+                .any(|(_, w)| w.is_nan() || *w < 0.0)
+            {
+                errors.push(format!(
+                    "difficulty_curve wave {} enemy_kind_weights must be >= 0.0",
+                    wave
+                ));
+            }
+        }
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count_of(list: &[EnemyKind], kind: EnemyKind) -> usize {
+        list.iter().filter(|k| **k == kind).count()
+    }
+
+    #[test]
+    fn interpolate_returns_breakpoints_exactly() {
+        let curve = DifficultyCurve::default();
+        assert_eq!(curve.interpolate(1).health_multiplier, 1.0);
+        assert_eq!(curve.interpolate(10).health_multiplier, 2.0);
+        assert_eq!(curve.interpolate(20).health_multiplier, 5.0);
+    }
+
+    #[test]
+    fn interpolate_is_linear_between_breakpoints() {
+        let curve = DifficultyCurve::default();
+        assert!((curve.interpolate(15).health_multiplier - 3.5).abs() < 1e-5);
+        let early = curve.interpolate(4).health_multiplier;
+        assert!((early - (1.0 + 3.0 / 9.0)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn interpolate_clamps_outside_the_curve() {
+        let curve = DifficultyCurve::default();
+        assert_eq!(curve.interpolate(0), curve.0[0].1);
+        assert_eq!(curve.interpolate(50).health_multiplier, 5.0);
+    }
+
+    #[test]
+    fn empty_curve_changes_nothing() {
+        let curve = DifficultyCurve(Vec::new());
+        assert_eq!(curve.interpolate(7), DifficultyParams::default());
+        let plan = vec![EnemyKind::Minion, EnemyKind::Zombie];
+        assert_eq!(curve.interpolate(7).apply_to_wave(&plan, 1), plan);
+    }
+
+    #[test]
+    fn apply_to_wave_counts_add_up_and_keep_bosses_last() {
+        let params = DifficultyParams {
+            count_multiplier: 2.0,
+            enemy_kind_weights: vec![
+                (EnemyKind::Minion, 1.0),
+                (EnemyKind::Zombie, 1.0),
+                (EnemyKind::Healer, 1.0),
+            ],
+            ..DifficultyParams::default()
+        };
+        let plan = vec![
+            EnemyKind::Minion,
+            EnemyKind::Minion,
+            EnemyKind::Minion,
+            EnemyKind::Zombie,
+            EnemyKind::Zombie,
+            EnemyKind::Boss,
+        ];
+        let wave = params.apply_to_wave(&plan, 42);
+        // 5 regular enemies doubled to 10, split 3.33 each: one kind takes the leftover
+        let regular = &wave[..wave.len() - 1];
+        assert_eq!(regular.len(), 10);
+        let mut counts = [
+            count_of(regular, EnemyKind::Minion),
+            count_of(regular, EnemyKind::Zombie),
+            count_of(regular, EnemyKind::Healer),
+        ];
+        counts.sort();
+        assert_eq!(counts, [3, 3, 4]);
+        assert_eq!(wave.last(), Some(&EnemyKind::Boss));
+        assert_eq!(count_of(&wave, EnemyKind::Boss), 1);
+    }
+
+    #[test]
+    fn apply_to_wave_keeps_the_plan_mix_without_weights() {
+        let params = DifficultyParams {
+            count_multiplier: 1.5,
+            ..DifficultyParams::default()
+        };
+        let plan = vec![
+            EnemyKind::Minion,
+            EnemyKind::Minion,
+            EnemyKind::Minion,
+            EnemyKind::Zombie,
+        ];
+        let wave = params.apply_to_wave(&plan, 7);
+        assert_eq!(wave.len(), 6);
+        assert_eq!(count_of(&wave, EnemyKind::Minion), 5);
+        assert_eq!(count_of(&wave, EnemyKind::Zombie), 1);
+        assert_eq!(params.apply_to_wave(&plan, 7), wave);
+    }
+}
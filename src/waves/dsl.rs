@@ -240,6 +240,11 @@ macro_rules! __defaults_block {
         $b = $b.defaults_health(h);
         $crate::__defaults_block!($b; $($rest)*);
     }};
+    ($b:ident; health = const($c:expr); $($rest:tt)*) => {{
+        let h = $crate::waves::rules::StatScale::Const($c);
+        $b = $b.defaults_health(h);
+        $crate::__defaults_block!($b; $($rest)*);
+    }};
     ($b:ident; damage = linear($st:expr, + $pw:expr); $($rest:tt)*) => {{
         let d = $crate::waves::rules::StatScale::Linear{ start: $st, per_wave: $pw };
         $b = $b.defaults_damage(d);
@@ -1,2 +1,3 @@
+pub mod difficulty;
 pub mod dsl;
 pub mod rules;
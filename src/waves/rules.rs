@@ -26,6 +26,8 @@ impl Default for Multipliers {
 pub enum StatScale {
     Const(f32),
     Linear { start: f32, per_wave: f32 },
+    // The game's own rules no longer use it; `DifficultyCurve` scales health instead
+    #[allow(dead_code)]
     Exp { factor_per_wave: f32 },
 }
 
@@ -303,7 +305,12 @@ impl WaveRules {
         // Build multipliers per kind: global then per-kind override scales, then acc multipliers applied equally to all kinds
         let mut multipliers: HashMap<EnemyKind, Multipliers> = HashMap::new();
         // enumerate current kinds; keep explicit list to avoid FromIterator missing variants
-        let kinds = [EnemyKind::Minion, EnemyKind::Zombie, EnemyKind::Boss];
+        let kinds = [
+            EnemyKind::Minion,
+            EnemyKind::Zombie,
+            EnemyKind::Boss,
+            EnemyKind::Healer,
+        ];
         for kind in kinds {
             let base = if let Some(rule) = self.per_kind.get(&kind) {
                 rule.evaluate(wave)
@@ -331,12 +338,17 @@ impl WaveRules {
             .normalized();
         let w_minion = *weights.get(&EnemyKind::Minion).unwrap_or(&0.6);
         let w_zombie = *weights.get(&EnemyKind::Zombie).unwrap_or(&0.4);
-        let sum = (w_minion + w_zombie).max(0.0001);
+        let w_healer = *weights.get(&EnemyKind::Healer).unwrap_or(&0.0);
+        let sum = (w_minion + w_zombie + w_healer).max(0.0001);
         let m = ((w_minion / sum) * count as f32).floor() as usize;
-        let z = count.saturating_sub(m);
+        let h = ((w_healer / sum) * count as f32).floor() as usize;
+        let z = count.saturating_sub(m + h);
         for _ in 0..m {
             list.push(EnemyKind::Minion);
         }
+        for _ in 0..h {
+            list.push(EnemyKind::Healer);
+        }
         for _ in 0..z {
             list.push(EnemyKind::Zombie);
         }
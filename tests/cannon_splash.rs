@@ -0,0 +1,65 @@
+//! Cannon shells: splash damage around the impact point, and what a Boss shield soaks up.
+
+mod support;
+
+use bevy::prelude::*;
+use support::TestHarness;
+use td::components::{BossShield, Enemy, EnemyKind, TowerKind};
+use td::constants::Tunables;
+use td::systems::combat::projectiles::{projectile_system, tower_shooting, update_spatial_grid};
+
+/// Frames for one shell to leave the tower and land, well short of a second shot.
+const FLIGHT_FRAMES: u32 = 30;
+
+fn health(harness: &TestHarness, enemy: Entity) -> u32 {
+    harness.world().get::<Enemy>(enemy).unwrap().health
+}
+
+#[test]
+fn one_cannon_shot_damages_enemies_standing_together() {
+    let mut harness = TestHarness::new();
+    let splash_radius = harness.world().resource::<Tunables>().cannon_splash_radius;
+    // Bosses have the health to survive a shell, so its damage can be read off
+    let first = harness.spawn_enemy(EnemyKind::Boss, Vec3::new(10.0, 0.0, 0.0), 0);
+    let second = harness.spawn_enemy(EnemyKind::Boss, Vec3::new(11.0, 0.0, 0.5), 0);
+    let outside = harness.spawn_enemy(
+        EnemyKind::Boss,
+        Vec3::new(10.0 + splash_radius + 2.0, 0.0, 0.0),
+        0,
+    );
+    harness.spawn_tower(TowerKind::Cannon, Vec3::ZERO, 11.0);
+    harness.app.add_systems(
+        Update,
+        (update_spatial_grid, tower_shooting, projectile_system).chain(),
+    );
+    harness.step_schedule(FLIGHT_FRAMES);
+
+    let full = EnemyKind::Boss.stats().health;
+    let damage = TowerKind::Cannon.stats().damage;
+    assert_eq!(health(&harness, first), full - damage);
+    assert_eq!(health(&harness, second), full - damage);
+    assert_eq!(health(&harness, outside), full);
+}
+
+#[test]
+fn boss_shield_takes_the_shell_before_its_health() {
+    let mut harness = TestHarness::new();
+    let absorb = harness.world().resource::<Tunables>().boss_shield_absorb;
+    let boss = harness.spawn_enemy(EnemyKind::Boss, Vec3::new(10.0, 0.0, 0.0), 0);
+    harness
+        .world_mut()
+        .entity_mut(boss)
+        .insert(BossShield::new(absorb));
+    harness.spawn_tower(TowerKind::Cannon, Vec3::ZERO, 11.0);
+    harness.app.add_systems(
+        Update,
+        (update_spatial_grid, tower_shooting, projectile_system).chain(),
+    );
+    harness.step_schedule(FLIGHT_FRAMES);
+
+    let damage = TowerKind::Cannon.stats().damage;
+    assert!(damage < absorb, "one shell should not break the shield");
+    assert_eq!(health(&harness, boss), EnemyKind::Boss.stats().health);
+    let shield = harness.world().get::<BossShield>(boss).unwrap();
+    assert_eq!(shield.absorb_remaining, absorb - damage);
+}
@@ -0,0 +1,87 @@
+//! Road generation as pure path data, without any ECS.
+
+use bevy::prelude::*;
+use rand::{SeedableRng, rngs::StdRng};
+use td::core::paths::generate_road_waypoints;
+
+const START: Vec3 = Vec3::new(30.0, 0.0, 0.0);
+const END: Vec3 = Vec3::new(250.0, 0.0, 40.0);
+const ROAD_WIDTH: f32 = 6.0;
+/// No road strays further than this from the straight line between its endpoints.
+const MAX_DEVIATION: f32 = 60.0;
+
+fn road(seed: u64) -> Vec<Vec3> {
+    generate_road_waypoints(START, END, ROAD_WIDTH, &mut StdRng::seed_from_u64(seed))
+        .expect("distinct endpoints give a road")
+}
+
+fn distance_to_segment_xz(p: Vec3, a: Vec3, b: Vec3) -> f32 {
+    let (p, a, b) = (p.xz(), a.xz(), b.xz());
+    let ab = b - a;
+    let t = ((p - a).dot(ab) / ab.length_squared()).clamp(0.0, 1.0);
+    p.distance(a + ab * t)
+}
+
+#[test]
+fn waypoints_start_and_end_at_the_given_positions() {
+    for seed in 0..50 {
+        let waypoints = road(seed);
+        assert_eq!(waypoints.first(), Some(&START), "seed {}", seed);
+        assert_eq!(waypoints.last(), Some(&END), "seed {}", seed);
+    }
+}
+
+#[test]
+fn endpoints_are_put_on_the_ground() {
+    let start = START.with_y(3.0);
+    let end = END.with_y(-2.0);
+    let waypoints =
+        generate_road_waypoints(start, end, ROAD_WIDTH, &mut StdRng::seed_from_u64(1)).unwrap();
+    assert_eq!(waypoints.first(), Some(&START));
+    assert_eq!(waypoints.last(), Some(&END));
+}
+
+#[test]
+fn path_stays_near_the_line_between_its_endpoints() {
+    for seed in 0..50 {
+        for waypoint in road(seed) {
+            assert!(
+                distance_to_segment_xz(waypoint, START, END) <= MAX_DEVIATION,
+                "seed {} strays to {:?}",
+                seed,
+                waypoint
+            );
+        }
+    }
+}
+
+#[test]
+fn path_length_is_reasonable() {
+    let straight = START.distance(END);
+    for seed in 0..50 {
+        let waypoints = road(seed);
+        assert!(waypoints.len() >= 2);
+        let length: f32 = waypoints.windows(2).map(|w| w[0].distance(w[1])).sum();
+        assert!(length >= straight - 1e-3, "seed {}: {}", seed, length);
+        assert!(length <= straight * 3.0, "seed {}: {}", seed, length);
+    }
+}
+
+#[test]
+fn same_seed_gives_the_same_road() {
+    assert_eq!(road(7), road(7));
+}
+
+#[test]
+fn degenerate_endpoints_give_no_road() {
+    let mut rng = StdRng::seed_from_u64(0);
+    assert_eq!(
+        generate_road_waypoints(START, START, ROAD_WIDTH, &mut rng),
+        None
+    );
+    // Only the height differs, so the road would have no length on the ground
+    assert_eq!(
+        generate_road_waypoints(START, START.with_y(5.0), ROAD_WIDTH, &mut rng),
+        None
+    );
+}
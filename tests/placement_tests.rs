@@ -0,0 +1,117 @@
+//! Tower footprint collisions used when placing a tower.
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use td::build::placement::{PlacedTowersQuery, find_overlapping_tower, towers_overlap};
+use td::components::{BuiltTower, Tower, TowerKind};
+
+const SIZE: (f32, f32, f32) = (2.0, 3.0, 2.0);
+
+#[test]
+fn coincident_towers_overlap() {
+    assert!(towers_overlap(Vec3::ZERO, SIZE, Vec3::ZERO, SIZE));
+}
+
+#[test]
+fn adjacent_towers_do_not_overlap() {
+    // Touching edges on X, on Z and at a corner
+    assert!(!towers_overlap(
+        Vec3::ZERO,
+        SIZE,
+        Vec3::new(2.0, 0.0, 0.0),
+        SIZE
+    ));
+    assert!(!towers_overlap(
+        Vec3::ZERO,
+        SIZE,
+        Vec3::new(0.0, 0.0, -2.0),
+        SIZE
+    ));
+    assert!(!towers_overlap(
+        Vec3::ZERO,
+        SIZE,
+        Vec3::new(2.0, 0.0, 2.0),
+        SIZE
+    ));
+}
+
+#[test]
+fn partially_overlapping_towers_overlap() {
+    assert!(towers_overlap(
+        Vec3::ZERO,
+        SIZE,
+        Vec3::new(1.5, 0.0, 0.5),
+        SIZE
+    ));
+    // Mixed sizes: half extents add up to 1.5 on X
+    assert!(towers_overlap(
+        Vec3::ZERO,
+        SIZE,
+        Vec3::new(1.4, 0.0, 0.0),
+        (1.0, 1.0, 1.0)
+    ));
+    assert!(!towers_overlap(
+        Vec3::ZERO,
+        SIZE,
+        Vec3::new(1.6, 0.0, 0.0),
+        (1.0, 1.0, 1.0)
+    ));
+}
+
+#[test]
+fn height_does_not_matter() {
+    assert!(towers_overlap(
+        Vec3::ZERO,
+        SIZE,
+        Vec3::new(0.5, 10.0, 0.5),
+        SIZE
+    ));
+}
+
+fn spawn_tower(world: &mut World, kind: TowerKind, position: Vec3) -> Entity {
+    let (width, height, depth) = kind.size();
+    world
+        .spawn((
+            Transform::from_translation(position),
+            Tower {
+                range: 10.0,
+                damage: 1,
+                fire_interval_secs: 1.0,
+                height,
+                width,
+                depth,
+                projectile_speed: 10.0,
+                last_shot: 0.0,
+            },
+            BuiltTower { kind },
+        ))
+        .id()
+}
+
+fn overlapping(world: &mut World, position: Vec3, kind: TowerKind) -> Option<Entity> {
+    world
+        .run_system_once(move |towers: PlacedTowersQuery| {
+            find_overlapping_tower(position, kind, &towers)
+        })
+        .expect("placement query runs")
+}
+
+#[test]
+fn finds_the_tower_in_the_way() {
+    let mut world = World::new();
+    let far = spawn_tower(&mut world, TowerKind::Bow, Vec3::new(20.0, 0.0, 0.0));
+    let near = spawn_tower(&mut world, TowerKind::Bow, Vec3::ZERO);
+
+    assert_eq!(
+        overlapping(&mut world, Vec3::ZERO, TowerKind::Bow),
+        Some(near)
+    );
+    assert_eq!(
+        overlapping(&mut world, Vec3::new(20.5, 0.0, 0.0), TowerKind::Bow),
+        Some(far)
+    );
+    assert_eq!(
+        overlapping(&mut world, Vec3::new(10.0, 0.0, 0.0), TowerKind::Bow),
+        None
+    );
+}
@@ -0,0 +1,114 @@
+//! Recording a few frames of tower building and playing them back into a fresh world.
+
+mod support;
+
+use bevy::prelude::*;
+use support::TestHarness;
+use td::components::{BuiltTower, Player, TowerKind, TowerUpgradeConfig, TowerUpgrades};
+use td::constants::Tunables;
+use td::core::replay::{ReplayFile, ReplayPlayback, ReplayRecorder};
+use td::events::TowerBuilt;
+use td::systems::combat::towers::build_tower;
+use td::systems::replay::{
+    advance_replay_playback, finish_replay_frame, replay_playback_placements,
+};
+
+const RECORDED_FRAMES: u32 = 10;
+
+/// Towers the "player" builds while recording: (frame, kind, position).
+const SCRIPT: [(u32, TowerKind, Vec3); 3] = [
+    (2, TowerKind::Bow, Vec3::new(10.0, 0.0, 4.0)),
+    (5, TowerKind::Crossbow, Vec3::new(-6.0, 0.0, 12.0)),
+    (5, TowerKind::Cannon, Vec3::new(3.0, 0.0, -9.0)),
+];
+
+/// Stands in for `tower_building`, building the scripted towers on their frames.
+#[allow(clippy::too_many_arguments)]
+fn scripted_building(
+    mut frame: Local<u32>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut tower_events: MessageWriter<TowerBuilt>,
+    tunables: Res<Tunables>,
+    upgrades: Res<TowerUpgrades>,
+    upgrade_config: Res<TowerUpgradeConfig>,
+) {
+    *frame += 1;
+    for (_, kind, position) in SCRIPT.iter().filter(|(at, ..)| *at == *frame) {
+        build_tower(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            &mut tower_events,
+            &tunables,
+            &upgrades,
+            &upgrade_config,
+            *kind,
+            *position,
+        );
+    }
+}
+
+fn building_harness() -> TestHarness {
+    let mut harness = TestHarness::new();
+    harness
+        .app
+        .add_message::<TowerBuilt>()
+        .init_resource::<TowerUpgrades>()
+        .init_resource::<TowerUpgradeConfig>();
+    harness
+}
+
+/// Kind and position of every built tower, in a stable order.
+fn placements(harness: &mut TestHarness) -> Vec<(TowerKind, [i32; 3])> {
+    let world = harness.world_mut();
+    let mut placed: Vec<_> = world
+        .query::<(&BuiltTower, &Transform)>()
+        .iter(world)
+        .map(|(built, transform)| {
+            let p = (transform.translation * 1000.0).round();
+            (built.kind, [p.x as i32, p.y as i32, p.z as i32])
+        })
+        .collect();
+    placed.sort_by_key(|(kind, p)| (*p, kind.name()));
+    placed
+}
+
+#[test]
+fn playback_rebuilds_the_recorded_towers() {
+    let mut recording = building_harness();
+    recording
+        .app
+        .insert_resource(ReplayRecorder::new(true))
+        .add_systems(Update, (scripted_building, finish_replay_frame).chain());
+    recording.step_schedule(RECORDED_FRAMES);
+
+    let recorder = recording.world().resource::<ReplayRecorder>();
+    assert_eq!(recorder.frames.len(), RECORDED_FRAMES as usize);
+    let file = recorder.to_file(1);
+    let bytes = file.encode().expect("replay encodes");
+    let decoded = ReplayFile::decode(&bytes).expect("replay decodes");
+    assert_eq!(decoded, file);
+
+    let mut playback = building_harness();
+    playback.world_mut().spawn(Player::starting());
+    playback
+        .app
+        .insert_resource(ReplayPlayback::new(decoded))
+        .add_systems(
+            Update,
+            (replay_playback_placements, advance_replay_playback)
+                .chain()
+                .run_if(resource_exists::<ReplayPlayback>),
+        );
+    playback.step_schedule(RECORDED_FRAMES);
+
+    assert!(
+        !playback.world().contains_resource::<ReplayPlayback>(),
+        "playback should end with the recording"
+    );
+    let recorded = placements(&mut recording);
+    assert_eq!(recorded.len(), SCRIPT.len());
+    assert_eq!(placements(&mut playback), recorded);
+}
@@ -0,0 +1,160 @@
+//! Headless app for integration tests: `MinimalPlugins` plus an asset server, a fixed frame
+//! delta, and the resources and messages the combat systems read, without any rendering.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+use td::audio::TowerShotEvent;
+use td::components::{
+    BuiltTower, ChunkBiomes, Enemy, EnemyKind, PathFollower, RoadPaths, Tower, TowerKind,
+    TownCenter, Village, VillageShield, WaveState, WaveTimerPaused,
+};
+use td::constants::Tunables;
+use td::core::grid::SpatialGrid;
+use td::core::paths::validate_and_repair_road_paths;
+use td::events::{DamageDealt, EnemyKilled, EnemySpawned, VillageDamaged};
+use td::materials::ImpactMaterial;
+use td::random_policy::RandomizationPolicy;
+use td::systems::chunks::WorldSeed;
+use td::systems::combat::assets::{
+    BiomeMaterialCache, CombatVfxAssets, EnemyHealthBarAssets, init_combat_vfx_assets,
+};
+use td::systems::day_night::DayNightCycle;
+use td::systems::resource_passes::TrafficHeatmap;
+use td::systems::wind::WindState;
+
+/// Game time every `step_schedule` frame advances by.
+pub const FRAME_SECS: f32 = 1.0 / 30.0;
+
+pub struct TestHarness {
+    pub app: App,
+}
+
+impl TestHarness {
+    pub fn new() -> Self {
+        let tunables = Tunables::default();
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TransformPlugin, AssetPlugin::default()))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+                FRAME_SECS,
+            )))
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .init_resource::<Assets<ImpactMaterial>>()
+            .init_resource::<CombatVfxAssets>()
+            .init_resource::<EnemyHealthBarAssets>()
+            .init_resource::<ChunkBiomes>()
+            .init_resource::<DayNightCycle>()
+            .init_resource::<TrafficHeatmap>()
+            .init_resource::<RandomizationPolicy>()
+            .insert_resource(WorldSeed(1))
+            .insert_resource(WaveTimerPaused::default())
+            .insert_resource(WaveState::new(&tunables))
+            .insert_resource(VillageShield::new(tunables.shield_duration_secs))
+            .insert_resource(WindState::new(&tunables))
+            .insert_resource(SpatialGrid::<Entity>::new(tunables.spatial_grid_cell_size))
+            .add_message::<EnemySpawned>()
+            .add_message::<EnemyKilled>()
+            .add_message::<VillageDamaged>()
+            .add_message::<DamageDealt>()
+            .add_message::<TowerShotEvent>()
+            .add_systems(Startup, init_combat_vfx_assets)
+            .insert_resource(tunables);
+        let cache = BiomeMaterialCache::new(
+            &mut app.world_mut().resource_mut::<Assets<StandardMaterial>>(),
+        );
+        app.insert_resource(cache);
+        Self { app }
+    }
+
+    /// Lays out `roads`, each of which must already be a valid road path.
+    pub fn with_roads(mut self, roads: Vec<Vec<Vec3>>) -> Self {
+        for road in &roads {
+            let mut repaired = road.clone();
+            assert_eq!(validate_and_repair_road_paths(&mut repaired), Ok(()));
+            assert_eq!(&repaired, road, "road path needed repairs");
+        }
+        self.app.insert_resource(RoadPaths { roads });
+        self
+    }
+
+    /// Spawns the village at `position` with `health` and returns it.
+    pub fn spawn_village(&mut self, position: Vec3, health: u32) -> Entity {
+        self.app
+            .world_mut()
+            .spawn((
+                Transform::from_translation(position),
+                TownCenter,
+                Village {
+                    health,
+                    max_health: health,
+                },
+            ))
+            .id()
+    }
+
+    /// Spawns a bare `kind` enemy with its base stats at `position`, following road `road_index`.
+    /// Its material is a placeholder, there only so projectile hits can land.
+    pub fn spawn_enemy(&mut self, kind: EnemyKind, position: Vec3, road_index: usize) -> Entity {
+        let stats = kind.stats();
+        self.app
+            .world_mut()
+            .spawn((
+                Transform::from_translation(position.with_y(stats.size * 0.5)),
+                MeshMaterial3d::<StandardMaterial>::default(),
+                kind,
+                Enemy {
+                    health: stats.health,
+                    max_health: stats.health,
+                    speed: stats.speed,
+                    damage: stats.damage,
+                },
+                PathFollower {
+                    road_index,
+                    next_index: 1,
+                    path_t: 0.0,
+                },
+            ))
+            .id()
+    }
+
+    /// Spawns a built `kind` tower with its base stats at `position`, ready to fire at once.
+    pub fn spawn_tower(&mut self, kind: TowerKind, position: Vec3, range: f32) -> Entity {
+        let stats = kind.stats();
+        let (width, height, depth) = kind.size();
+        self.app
+            .world_mut()
+            .spawn((
+                Transform::from_translation(position),
+                Tower {
+                    range,
+                    damage: stats.damage,
+                    fire_interval_secs: stats.fire_interval_secs,
+                    height,
+                    width,
+                    depth,
+                    projectile_speed: stats.projectile_speed,
+                    last_shot: stats.fire_interval_secs,
+                },
+                BuiltTower { kind },
+            ))
+            .id()
+    }
+
+    /// Runs `frames` updates, each `FRAME_SECS` of game time after the first.
+    pub fn step_schedule(&mut self, frames: u32) {
+        for _ in 0..frames {
+            self.app.update();
+        }
+    }
+
+    pub fn world(&self) -> &World {
+        self.app.world()
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+}
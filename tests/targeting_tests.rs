@@ -0,0 +1,116 @@
+//! Which enemy a tower fires at under each `TargetingMode`.
+
+mod support;
+
+use bevy::prelude::*;
+use support::TestHarness;
+use td::components::{Enemy, EnemyKind, TargetingMode, TowerKind};
+use td::systems::combat::projectiles::{Projectile, tower_shooting, update_spatial_grid};
+
+const RANGE: f32 = 10.0;
+
+/// Enemies lined up along +X from a Bow tower at the origin.
+struct Field {
+    harness: TestHarness,
+    nearest: Entity,
+    healer: Entity,
+    weakest: Entity,
+    strongest: Entity,
+    furthest: Entity,
+}
+
+fn field(mode: TargetingMode) -> Field {
+    let mut harness = TestHarness::new();
+    let at = |x: f32| Vec3::new(x, 0.0, 0.0);
+    let nearest = harness.spawn_enemy(EnemyKind::Minion, at(3.0), 0);
+    let healer = harness.spawn_enemy(EnemyKind::Healer, at(4.0), 0);
+    let weakest = harness.spawn_enemy(EnemyKind::Minion, at(5.0), 0);
+    harness
+        .world_mut()
+        .get_mut::<Enemy>(weakest)
+        .unwrap()
+        .health = 5;
+    let strongest = harness.spawn_enemy(EnemyKind::Boss, at(6.0), 0);
+    let furthest = harness.spawn_enemy(EnemyKind::Minion, at(9.0), 0);
+    // Weaker and further than anything else, but out of range
+    let out_of_range = harness.spawn_enemy(EnemyKind::Minion, at(RANGE + 5.0), 0);
+    harness
+        .world_mut()
+        .get_mut::<Enemy>(out_of_range)
+        .unwrap()
+        .health = 1;
+
+    let tower = harness.spawn_tower(TowerKind::Bow, Vec3::ZERO, RANGE);
+    harness.world_mut().entity_mut(tower).insert(mode);
+    harness
+        .app
+        .add_systems(Update, (update_spatial_grid, tower_shooting).chain());
+    Field {
+        harness,
+        nearest,
+        healer,
+        weakest,
+        strongest,
+        furthest,
+    }
+}
+
+/// Target of the one shot fired on the first frame.
+fn first_target(mut field: Field) -> (Entity, Field) {
+    field.harness.step_schedule(1);
+    let targets: Vec<Entity> = field
+        .harness
+        .world_mut()
+        .query::<&Projectile>()
+        .iter(field.harness.world())
+        .map(Projectile::target)
+        .collect();
+    assert_eq!(targets.len(), 1, "expected exactly one shot");
+    (targets[0], field)
+}
+
+#[test]
+fn nearest_shoots_the_closest_enemy() {
+    let (target, field) = first_target(field(TargetingMode::Nearest));
+    assert_eq!(target, field.nearest);
+}
+
+#[test]
+fn furthest_shoots_the_furthest_enemy_in_range() {
+    let (target, field) = first_target(field(TargetingMode::Furthest));
+    assert_eq!(target, field.furthest);
+}
+
+#[test]
+fn lowest_hp_shoots_the_weakest_enemy_in_range() {
+    let (target, field) = first_target(field(TargetingMode::LowestHp));
+    assert_eq!(target, field.weakest);
+}
+
+#[test]
+fn highest_hp_shoots_the_strongest_enemy() {
+    let (target, field) = first_target(field(TargetingMode::HighestHp));
+    assert_eq!(target, field.strongest);
+}
+
+#[test]
+fn priority_shoots_healers_first() {
+    let (target, field) = first_target(field(TargetingMode::Priority));
+    assert_eq!(target, field.healer);
+}
+
+#[test]
+fn towers_default_to_nearest() {
+    let mut harness = TestHarness::new();
+    let nearest = harness.spawn_enemy(EnemyKind::Minion, Vec3::new(3.0, 0.0, 0.0), 0);
+    harness.spawn_enemy(EnemyKind::Boss, Vec3::new(6.0, 0.0, 0.0), 0);
+    harness.spawn_tower(TowerKind::Bow, Vec3::ZERO, RANGE);
+    harness
+        .app
+        .add_systems(Update, (update_spatial_grid, tower_shooting).chain());
+    harness.step_schedule(1);
+
+    let world = harness.world_mut();
+    let projectile = world.query::<&Projectile>().single(world).unwrap();
+    assert_eq!(projectile.target(), nearest);
+}
@@ -0,0 +1,139 @@
+//! A wave from spawn to village damage, run headless through the real combat systems.
+
+mod support;
+
+use bevy::prelude::*;
+use support::{FRAME_SECS, TestHarness};
+use td::components::{
+    BuiltTower, EnemyKind, PathFollower, Player, Tower, TowerKind, Village, WavePhase, WaveState,
+};
+use td::events::EnemyKilled;
+use td::systems::combat::enemy::enemy_spawning;
+use td::systems::combat::projectiles::{projectile_system, tower_shooting, update_spatial_grid};
+use td::systems::movement::enemy_movement;
+
+const VILLAGE_HEALTH: u32 = 100;
+/// Frames allotted for the wave: spawning, walking the road and reaching the village.
+const WAVE_FRAMES: u32 = (12.0 / FRAME_SECS) as u32;
+
+#[derive(Resource, Default)]
+struct KillCount(usize);
+
+fn count_kills(mut killed: MessageReader<EnemyKilled>, mut count: ResMut<KillCount>) {
+    count.0 += killed.read().count();
+}
+
+/// Three Minions on a single straight road ending at the village.
+fn wave_harness() -> (TestHarness, Entity) {
+    let mut harness = TestHarness::new().with_roads(vec![vec![
+        Vec3::new(60.0, 0.0, 0.0),
+        Vec3::new(0.0, 0.0, 0.0),
+    ]]);
+    let village = harness.spawn_village(Vec3::ZERO, VILLAGE_HEALTH);
+    harness.world_mut().spawn(Player::starting());
+    {
+        let mut wave_state = harness.world_mut().resource_mut::<WaveState>();
+        wave_state.current_wave = 1;
+        wave_state.phase = WavePhase::Spawning;
+        wave_state.spawn_queue = [EnemyKind::Minion; 3].into_iter().collect();
+        wave_state.enemies_to_spawn = 3;
+        wave_state.enemies_spawned = 0;
+    }
+    harness.app.init_resource::<KillCount>().add_systems(
+        Update,
+        (
+            enemy_spawning,
+            update_spatial_grid,
+            tower_shooting,
+            projectile_system,
+            enemy_movement,
+            count_kills,
+        )
+            .chain(),
+    );
+    (harness, village)
+}
+
+#[test]
+fn wave_reaches_the_village() {
+    let (mut harness, village) = wave_harness();
+    harness.step_schedule(WAVE_FRAMES);
+
+    assert_eq!(harness.world().resource::<WaveState>().enemies_spawned, 3);
+    let health = harness.world().get::<Village>(village).unwrap().health;
+    assert!(health < VILLAGE_HEALTH, "village took no damage");
+}
+
+#[test]
+fn tower_on_the_road_kills_an_enemy() {
+    let (mut harness, _) = wave_harness();
+    harness.world_mut().spawn((
+        Transform::from_xyz(30.0, 0.0, 3.0),
+        Tower {
+            range: 10.0,
+            damage: 1000,
+            fire_interval_secs: 0.1,
+            height: 4.2,
+            width: 1.2,
+            depth: 1.2,
+            projectile_speed: 0.0,
+            last_shot: 0.0,
+        },
+        BuiltTower {
+            kind: TowerKind::Lightning,
+        },
+    ));
+    harness.step_schedule(WAVE_FRAMES);
+
+    assert!(harness.world().resource::<KillCount>().0 >= 1);
+}
+
+#[test]
+fn enemy_reaching_the_village_deals_its_kind_damage() {
+    let mut harness = TestHarness::new().with_roads(vec![vec![
+        Vec3::new(60.0, 0.0, 0.0),
+        Vec3::new(0.0, 0.0, 0.0),
+    ]]);
+    let village = harness.spawn_village(Vec3::ZERO, VILLAGE_HEALTH);
+    harness.spawn_enemy(EnemyKind::Zombie, Vec3::new(60.0, 0.0, 0.0), 0);
+    harness.app.add_systems(Update, enemy_movement);
+    harness.step_schedule(WAVE_FRAMES);
+
+    let health = harness.world().get::<Village>(village).unwrap().health;
+    assert_eq!(health, VILLAGE_HEALTH - EnemyKind::Zombie.stats().damage);
+}
+
+#[test]
+fn consecutive_enemies_take_the_roads_in_turn() {
+    let entrances = [
+        Vec3::new(60.0, 0.0, 0.0),
+        Vec3::new(0.0, 0.0, 60.0),
+        Vec3::new(-60.0, 0.0, 0.0),
+    ];
+    let mut harness = TestHarness::new().with_roads(
+        entrances
+            .iter()
+            .map(|entrance| vec![*entrance, Vec3::ZERO])
+            .collect(),
+    );
+    {
+        // Too few for formations, so each spawn is a single enemy
+        let mut wave_state = harness.world_mut().resource_mut::<WaveState>();
+        wave_state.current_wave = 1;
+        wave_state.phase = WavePhase::Spawning;
+        wave_state.spawn_queue = [EnemyKind::Minion; 4].into_iter().collect();
+        wave_state.enemies_to_spawn = 4;
+    }
+    harness.app.add_systems(Update, enemy_spawning);
+    harness.step_schedule(WAVE_FRAMES);
+
+    let world = harness.world_mut();
+    let mut spawned: Vec<(Entity, usize)> = world
+        .query::<(Entity, &PathFollower)>()
+        .iter(world)
+        .map(|(entity, follower)| (entity, follower.road_index))
+        .collect();
+    spawned.sort_by_key(|(entity, _)| entity.index());
+    let roads: Vec<usize> = spawned.into_iter().map(|(_, road)| road).collect();
+    assert_eq!(roads, vec![0, 1, 2, 0]);
+}
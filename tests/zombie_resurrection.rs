@@ -0,0 +1,112 @@
+//! Zombies: the first death knocks them down, they rise again with a third of their health, and
+//! the second death is final.
+
+mod support;
+
+use bevy::prelude::*;
+use support::{FRAME_SECS, TestHarness};
+use td::components::{Enemy, EnemyKind, Player, Tower, TowerKind};
+use td::constants::Tunables;
+use td::events::EnemyKilled;
+use td::systems::combat::projectiles::{
+    EnemyFadeOut, ZombieResurrection, enemy_fade_out_system, projectile_system, tower_shooting,
+    update_spatial_grid, zombie_resurrection_system,
+};
+
+/// How long a Zombie lies on the ground before rising.
+const LIE_DOWN_SECS: f32 = 3.0;
+
+#[derive(Resource, Default)]
+struct KillCount(usize);
+
+fn count_kills(mut killed: MessageReader<EnemyKilled>, mut count: ResMut<KillCount>) {
+    count.0 += killed.read().count();
+}
+
+/// A Zombie in reach of a Lightning tower strong enough to drop it in one strike.
+fn zombie_harness() -> (TestHarness, Entity, Entity) {
+    let mut harness = TestHarness::new();
+    harness.world_mut().spawn(Player::starting());
+    harness.world_mut().spawn(Window::default());
+    // Facing away from the fight, so no reward text is spawned
+    harness
+        .world_mut()
+        .spawn((Camera3d::default(), GlobalTransform::IDENTITY));
+    let zombie = harness.spawn_enemy(EnemyKind::Zombie, Vec3::new(10.0, 0.0, 0.0), 0);
+    let tower = harness.spawn_tower(TowerKind::Lightning, Vec3::ZERO, 15.0);
+    harness
+        .world_mut()
+        .entity_mut(tower)
+        .get_mut::<Tower>()
+        .unwrap()
+        .damage = 1000;
+    harness.app.init_resource::<KillCount>().add_systems(
+        Update,
+        (
+            update_spatial_grid,
+            tower_shooting,
+            projectile_system,
+            enemy_fade_out_system,
+            zombie_resurrection_system,
+            count_kills,
+        )
+            .chain(),
+    );
+    (harness, zombie, tower)
+}
+
+fn frames(secs: f32) -> u32 {
+    (secs / FRAME_SECS).ceil() as u32
+}
+
+fn fade_secs(harness: &TestHarness) -> f32 {
+    harness
+        .world()
+        .resource::<Tunables>()
+        .enemy_fade_out_duration_secs
+}
+
+#[test]
+fn first_death_knocks_a_zombie_down_without_a_kill() {
+    let (mut harness, zombie, tower) = zombie_harness();
+    harness.step_schedule(2);
+    harness.world_mut().despawn(tower);
+    harness.step_schedule(frames(fade_secs(&harness)) + 2);
+
+    let world = harness.world();
+    assert_eq!(world.get::<Enemy>(zombie).unwrap().health, 0);
+    assert!(world.entity(zombie).contains::<EnemyFadeOut>());
+    assert!(!world.get::<ZombieResurrection>(zombie).unwrap().risen);
+    assert!(world.get::<Transform>(zombie).unwrap().translation.y < 0.5);
+    assert_eq!(world.resource::<KillCount>().0, 0);
+}
+
+#[test]
+fn zombie_rises_with_a_third_of_its_health() {
+    let (mut harness, zombie, tower) = zombie_harness();
+    harness.step_schedule(2);
+    harness.world_mut().despawn(tower);
+    harness.step_schedule(frames(fade_secs(&harness) + LIE_DOWN_SECS) + 4);
+
+    let world = harness.world();
+    let enemy = world.get::<Enemy>(zombie).unwrap();
+    assert_eq!(enemy.health, enemy.max_health / 3);
+    assert!(!world.entity(zombie).contains::<EnemyFadeOut>());
+    assert!(world.get::<ZombieResurrection>(zombie).unwrap().risen);
+    let standing = EnemyKind::Zombie.stats().size * 0.5;
+    assert_eq!(
+        world.get::<Transform>(zombie).unwrap().translation.y,
+        standing
+    );
+}
+
+#[test]
+fn second_death_is_final() {
+    let (mut harness, zombie, _) = zombie_harness();
+    // Down, up again, and down for good under the same tower
+    let fade = fade_secs(&harness);
+    harness.step_schedule(frames(fade * 2.0 + LIE_DOWN_SECS + 2.0));
+
+    assert!(harness.world().get_entity(zombie).is_err());
+    assert_eq!(harness.world().resource::<KillCount>().0, 1);
+}